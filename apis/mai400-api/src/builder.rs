@@ -0,0 +1,238 @@
+/*
+ * Copyright (C) 2018 Kubos Corporation
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::mai400::*;
+use rust_uart::*;
+use std::collections::VecDeque;
+use std::ops::RangeInclusive;
+use std::sync::atomic::{AtomicBool, AtomicU32};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Builder for configuring and creating a [`MAI400`] instance
+///
+/// Either a UART path or an existing connection (for example, one wrapping a
+/// `MockStream`) must be given before calling [`build`].
+///
+/// # Examples
+///
+/// ```
+/// use mai400_api::*;
+/// use std::time::Duration;
+///
+/// # fn func() -> MAIResult<()> {
+/// let mai = MAI400Builder::new()
+///     .uart_path("/dev/ttyS5")
+///     .baud(serial::Baud115200)
+///     .read_timeout(Duration::from_secs(1))
+///     .retries(3, Duration::from_millis(10))
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`MAI400`]: struct.MAI400.html
+/// [`build`]: #method.build
+pub struct MAI400Builder {
+    uart_path: Option<String>,
+    baud: serial::BaudRate,
+    read_timeout: Option<Duration>,
+    retry: RetryPolicy,
+    connection: Option<Connection>,
+    connector: Option<Connector>,
+    auto_reconnect: bool,
+    rv_warn_age: Option<u32>,
+    gps_time_range: RangeInclusive<u32>,
+    ack_window: usize,
+}
+
+impl Default for MAI400Builder {
+    fn default() -> Self {
+        MAI400Builder {
+            uart_path: None,
+            baud: serial::Baud115200,
+            read_timeout: None,
+            retry: RetryPolicy::default(),
+            connection: None,
+            connector: None,
+            auto_reconnect: false,
+            rv_warn_age: Some(DEFAULT_RV_WARN_AGE),
+            gps_time_range: DEFAULT_GPS_TIME_RANGE,
+            ack_window: DEFAULT_ACK_WINDOW,
+        }
+    }
+}
+
+impl MAI400Builder {
+    /// Create a new builder with the default settings (115200 baud, no read timeout, no retries)
+    pub fn new() -> Self {
+        MAI400Builder::default()
+    }
+
+    /// Set the UART bus the device is connected to
+    pub fn uart_path(mut self, path: &str) -> Self {
+        self.uart_path = Some(path.to_owned());
+        self
+    }
+
+    /// Set the baud rate of the UART bus
+    ///
+    /// Ignored if an existing connection is provided with [`connection`]
+    ///
+    /// [`connection`]: #method.connection
+    pub fn baud(mut self, baud: serial::BaudRate) -> Self {
+        self.baud = baud;
+        self
+    }
+
+    /// Set the maximum amount of time [`MAI400::get_message`] should wait for a message
+    ///
+    /// [`MAI400::get_message`]: struct.MAI400.html#method.get_message
+    pub fn read_timeout(mut self, read_timeout: Duration) -> Self {
+        self.read_timeout = Some(read_timeout);
+        self
+    }
+
+    /// Retry commands which fail with a transient UART error
+    ///
+    /// See [`MAI400::with_retries`](struct.MAI400.html#method.with_retries)
+    pub fn retries(mut self, count: u8, backoff: Duration) -> Self {
+        self.retry = RetryPolicy { count, backoff };
+        self
+    }
+
+    /// Use an existing connection rather than opening a UART bus
+    ///
+    /// This is primarily intended for testing with a `MockStream`.
+    /// A client created this way can only [`reconnect`] if a [`connector`] is also given.
+    ///
+    /// [`reconnect`]: struct.MAI400.html#method.reconnect
+    /// [`connector`]: #method.connector
+    pub fn connection(mut self, connection: Connection) -> Self {
+        self.connection = Some(connection);
+        self
+    }
+
+    /// Use a custom function to open the connection, rather than opening a UART bus
+    ///
+    /// The function is called again each time the client [`reconnect`]s.
+    ///
+    /// [`reconnect`]: struct.MAI400.html#method.reconnect
+    pub fn connector<F>(mut self, connector: F) -> Self
+    where
+        F: Fn() -> UartResult<Connection> + Send + Sync + 'static,
+    {
+        self.connector = Some(Arc::new(connector));
+        self
+    }
+
+    /// Automatically [`reconnect`] before sending a command if the previous one failed
+    /// with a UART error
+    ///
+    /// [`reconnect`]: struct.MAI400.html#method.reconnect
+    pub fn auto_reconnect(mut self, enabled: bool) -> Self {
+        self.auto_reconnect = enabled;
+        self
+    }
+
+    /// Set the age, in seconds, after which R/V data uploaded with [`MAI400::set_rv`] is
+    /// considered stale. `None` disables the stale R/V warning.
+    /// Defaults to [`DEFAULT_RV_WARN_AGE`]
+    ///
+    /// [`MAI400::set_rv`]: struct.MAI400.html#method.set_rv
+    /// [`DEFAULT_RV_WARN_AGE`]: constant.DEFAULT_RV_WARN_AGE.html
+    pub fn rv_warn_age(mut self, rv_warn_age: Option<u32>) -> Self {
+        self.rv_warn_age = rv_warn_age;
+        self
+    }
+
+    /// Set the range of GPS times, in seconds, accepted by [`MAI400::set_gps_time`]
+    ///
+    /// Restricting this to the mission's time window catches clock values which would
+    /// break orbit propagation. Defaults to [`DEFAULT_GPS_TIME_RANGE`]
+    ///
+    /// [`MAI400::set_gps_time`]: struct.MAI400.html#method.set_gps_time
+    /// [`DEFAULT_GPS_TIME_RANGE`]: constant.DEFAULT_GPS_TIME_RANGE.html
+    pub fn gps_time_range(mut self, range: RangeInclusive<u32>) -> Self {
+        self.gps_time_range = range;
+        self
+    }
+
+    /// Set the number of message sets [`MAI400::confirm_command`] will read while waiting for
+    /// one which post-dates the command. Defaults to [`DEFAULT_ACK_WINDOW`].
+    /// A value of zero is treated as one
+    ///
+    /// [`MAI400::confirm_command`]: struct.MAI400.html#method.confirm_command
+    /// [`DEFAULT_ACK_WINDOW`]: constant.DEFAULT_ACK_WINDOW.html
+    pub fn ack_window(mut self, frames: usize) -> Self {
+        self.ack_window = frames;
+        self
+    }
+
+    /// Create the configured MAI400 instance
+    ///
+    /// # Errors
+    ///
+    /// If neither a UART path, a connection, nor a connector was given,
+    /// `MAIError::InvalidInput` will be returned.
+    /// If the UART bus could not be opened, `MAIError::UartError` will be returned.
+    pub fn build(self) -> MAIResult<MAI400> {
+        let connector = match (self.connector, self.uart_path) {
+            (Some(connector), _) => Some(connector),
+            (None, Some(path)) => {
+                let settings = serial::PortSettings {
+                    baud_rate: self.baud,
+                    char_size: serial::Bits8,
+                    parity: serial::ParityNone,
+                    stop_bits: serial::Stop1,
+                    flow_control: serial::FlowNone,
+                };
+
+                let connector: Connector =
+                    Arc::new(move || Connection::from_path(&path, settings, TIMEOUT));
+                Some(connector)
+            }
+            (None, None) => None,
+        };
+
+        let conn = match (self.connection, &connector) {
+            (Some(conn), _) => conn,
+            (None, Some(connector)) => connector()?,
+            (None, None) => {
+                return Err(MAIError::InvalidInput {
+                    reason: "Either a UART path or a connection must be given".to_owned(),
+                })
+            }
+        };
+
+        Ok(MAI400 {
+            conn: Arc::new(Mutex::new(conn)),
+            read_timeout: self.read_timeout,
+            retry: self.retry,
+            frames: Arc::new(Mutex::new(LastFrames::default())),
+            auto_reconnect: self.auto_reconnect,
+            connector,
+            link_failed: Arc::new(AtomicBool::new(false)),
+            rv_warn_age: self.rv_warn_age,
+            gps_time_range: self.gps_time_range,
+            ack_window: self.ack_window,
+            rv_epoch: Arc::new(AtomicU32::new(0)),
+            last_gps_time: Arc::new(AtomicU32::new(0)),
+            link_counters: Arc::new(LinkCounters::default()),
+            rx_buffer: Arc::new(Mutex::new(VecDeque::new())),
+        })
+    }
+}
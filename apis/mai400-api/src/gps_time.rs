@@ -0,0 +1,138 @@
+/*
+ * Copyright (C) 2018 Kubos Corporation
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::mai400::*;
+use chrono::{DateTime, TimeZone, Utc};
+
+/// GPS epoch (Jan 06, 1980 00:00:00 UTC) as a Unix timestamp
+pub const GPS_EPOCH_UNIX: i64 = 315_964_800;
+
+// Unix timestamps (UTC) at which each leap second since the GPS epoch took effect.
+// The GPS-UTC offset is the number of entries at or before a given time.
+// Note: This table must be extended whenever the IERS announces a new leap second.
+// The last entry is Jan 01, 2017 (GPS-UTC = 18s)
+const LEAP_SECONDS: [i64; 18] = [
+    362_793_600,   // Jul 01, 1981
+    394_329_600,   // Jul 01, 1982
+    425_865_600,   // Jul 01, 1983
+    489_024_000,   // Jul 01, 1985
+    567_993_600,   // Jan 01, 1988
+    631_152_000,   // Jan 01, 1990
+    662_688_000,   // Jan 01, 1991
+    709_948_800,   // Jul 01, 1992
+    741_484_800,   // Jul 01, 1993
+    773_020_800,   // Jul 01, 1994
+    820_454_400,   // Jan 01, 1996
+    867_715_200,   // Jul 01, 1997
+    915_148_800,   // Jan 01, 1999
+    1_136_073_600, // Jan 01, 2006
+    1_230_768_000, // Jan 01, 2009
+    1_341_100_800, // Jul 01, 2012
+    1_435_708_800, // Jul 01, 2015
+    1_483_228_800, // Jan 01, 2017
+];
+
+/// Convert a UTC date and time into the number of seconds since the GPS epoch,
+/// as expected by [`MAI400::set_gps_time`] and [`MAI400::set_rv`]
+///
+/// Leap seconds inserted since the GPS epoch are accounted for.
+/// Times before the GPS epoch return 0 and times past the range of a `u32` return `u32::MAX`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use mai400_api::*;
+///
+/// let time = Utc.ymd(2018, 1, 1).and_hms(0, 0, 0);
+/// assert_eq!(gps_seconds_from_datetime(time), 1198800018);
+/// ```
+///
+/// [`MAI400::set_gps_time`]: struct.MAI400.html#method.set_gps_time
+/// [`MAI400::set_rv`]: struct.MAI400.html#method.set_rv
+pub fn gps_seconds_from_datetime(time: DateTime<Utc>) -> u32 {
+    let unix = time.timestamp();
+    let leap = LEAP_SECONDS.iter().filter(|&&leap| unix >= leap).count() as i64;
+
+    let gps = unix - GPS_EPOCH_UNIX + leap;
+
+    if gps < 0 {
+        0
+    } else if gps > i64::from(u32::max_value()) {
+        u32::max_value()
+    } else {
+        gps as u32
+    }
+}
+
+/// Convert a number of seconds since the GPS epoch into a UTC date and time
+///
+/// Leap seconds inserted since the GPS epoch are accounted for.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use mai400_api::*;
+///
+/// let time = datetime_from_gps_seconds(1198800018);
+/// assert_eq!(time, Utc.ymd(2018, 1, 1).and_hms(0, 0, 0));
+/// ```
+pub fn datetime_from_gps_seconds(gps_time: u32) -> DateTime<Utc> {
+    let gps_unix = i64::from(gps_time) + GPS_EPOCH_UNIX;
+
+    // Each leap second takes effect one second later in GPS time than the previous one
+    let leap = LEAP_SECONDS
+        .iter()
+        .enumerate()
+        .filter(|&(index, &leap)| gps_unix >= leap + index as i64 + 1)
+        .count() as i64;
+
+    Utc.timestamp(gps_unix - leap, 0)
+}
+
+impl MAI400 {
+    /// Set the ADACS clock from a UTC date and time
+    ///
+    /// Only available when the `chrono` feature is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * time - UTC time to set. It will be converted with [`gps_seconds_from_datetime`]
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`MAIError`] variant will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mai400_api::*;
+    /// # fn func() -> MAIResult<()> {
+    /// use chrono::Utc;
+    ///
+    /// let mai = MAI400::new("/dev/ttyS5")?;
+    /// mai.set_gps_time_utc(Utc::now())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`gps_seconds_from_datetime`]: fn.gps_seconds_from_datetime.html
+    /// [`MAIError`]: enum.MAIError.html
+    pub fn set_gps_time_utc(&self, time: DateTime<Utc>) -> MAIResult<()> {
+        self.set_gps_time(gps_seconds_from_datetime(time))
+    }
+}
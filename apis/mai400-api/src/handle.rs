@@ -0,0 +1,272 @@
+/*
+ * Copyright (C) 2018 Kubos Corporation
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::mai400::*;
+use crate::messages::*;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Cloneable, thread-safe handle to a shared MAI-400 client
+///
+/// Every operation holds the handle's lock from start to finish, so reads and writes
+/// are mutually exclusive: a command can't be interleaved with another command or with a
+/// telemetry read on the UART. Multi-frame commands (such as [`reset`]) are sent as an
+/// unbroken sequence.
+///
+/// Because [`get_message`] holds the lock until a full message set has arrived, a command
+/// issued while another task is reading telemetry will wait for up to one telemetry
+/// period (250ms), or longer if the device has stopped responding and no read timeout is set.
+///
+/// Use [`lock`] to run several operations back-to-back without other tasks getting in between.
+///
+/// # Examples
+///
+/// ```
+/// use mai400_api::*;
+/// use std::thread;
+///
+/// # fn func() -> MAIResult<()> {
+/// let mai = MAI400Handle::from(MAI400::new("/dev/ttyS5")?);
+///
+/// let reader = mai.clone();
+/// thread::spawn(move || loop {
+///     if let Ok((Some(std), _, _)) = reader.get_message() {
+///         println!("Current mode: {}", std.acs_mode);
+///     }
+/// });
+///
+/// mai.set_acs_mode(AcsMode::NadirPointing, [0, 0, 0, 0])?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`reset`]: #method.reset
+/// [`get_message`]: #method.get_message
+/// [`lock`]: #method.lock
+#[derive(Clone)]
+pub struct MAI400Handle {
+    inner: Arc<Mutex<MAI400>>,
+}
+
+impl MAI400Handle {
+    /// Create a new shared handle around an existing client
+    pub fn new(mai: MAI400) -> Self {
+        MAI400Handle {
+            inner: Arc::new(Mutex::new(mai)),
+        }
+    }
+
+    /// Take exclusive access to the underlying client
+    ///
+    /// Other users of the handle will block until the returned guard is dropped
+    pub fn lock(&self) -> MutexGuard<MAI400> {
+        // A panic in another task can't leave the client in a bad state,
+        // so ignore any poisoning
+        self.inner.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// Request a hardware reset of the MAI-400
+    ///
+    /// See [`MAI400::reset`](struct.MAI400.html#method.reset)
+    pub fn reset(&self) -> MAIResult<()> {
+        self.lock().reset()
+    }
+
+    /// Set the ACS mode
+    ///
+    /// See [`MAI400::set_mode`](struct.MAI400.html#method.set_mode)
+    pub fn set_mode(&self, mode: u8, params: [i16; 4]) -> MAIResult<()> {
+        self.lock().set_mode(mode, params)
+    }
+
+    /// Set the ACS mode, without checking the mode value
+    ///
+    /// See [`MAI400::set_mode_unchecked`](struct.MAI400.html#method.set_mode_unchecked)
+    pub fn set_mode_unchecked(&self, mode: u8, params: [i16; 4]) -> MAIResult<()> {
+        self.lock().set_mode_unchecked(mode, params)
+    }
+
+    /// Set the ACS mode and confirm that the command was accepted
+    ///
+    /// See [`MAI400::set_mode_confirmed`](struct.MAI400.html#method.set_mode_confirmed)
+    pub fn set_mode_confirmed(&self, mode: u8, params: [i16; 4]) -> MAIResult<CommandStatus> {
+        self.lock().set_mode_confirmed(mode, params)
+    }
+
+    /// Set the ACS mode
+    ///
+    /// See [`MAI400::set_acs_mode`](struct.MAI400.html#method.set_acs_mode)
+    pub fn set_acs_mode(&self, mode: AcsMode, params: [i16; 4]) -> MAIResult<()> {
+        self.lock().set_acs_mode(mode, params)
+    }
+
+    /// Slew to and hold a target attitude quaternion
+    ///
+    /// See [`MAI400::set_target_quaternion`](struct.MAI400.html#method.set_target_quaternion)
+    pub fn set_target_quaternion(&self, q: [f32; 4]) -> MAIResult<()> {
+        self.lock().set_target_quaternion(q)
+    }
+
+    /// Set the ACS mode (Normal-Sun or Lat/Long-Sun)
+    ///
+    /// See [`MAI400::set_mode_sun`](struct.MAI400.html#method.set_mode_sun)
+    pub fn set_mode_sun(
+        &self,
+        mode: u8,
+        sun_angle_enable: i16,
+        sun_rot_angle: f32,
+    ) -> MAIResult<()> {
+        self.lock()
+            .set_mode_sun(mode, sun_angle_enable, sun_rot_angle)
+    }
+
+    /// Set the ADACS clock with the desired GPS time
+    ///
+    /// See [`MAI400::set_gps_time`](struct.MAI400.html#method.set_gps_time)
+    pub fn set_gps_time(&self, gps_time: u32) -> MAIResult<()> {
+        self.lock().set_gps_time(gps_time)
+    }
+
+    /// Set orbital position and velocity at epoch for RK4 integration method of orbit propagation
+    ///
+    /// See [`MAI400::set_rv`](struct.MAI400.html#method.set_rv)
+    pub fn set_rv(&self, eci_pos: [f32; 3], eci_vel: [f32; 3], time_epoch: u32) -> MAIResult<()> {
+        self.lock().set_rv(eci_pos, eci_vel, time_epoch)
+    }
+
+    /// Upload magnetometer and gyro bias corrections
+    ///
+    /// See [`MAI400::set_calibration`](struct.MAI400.html#method.set_calibration)
+    pub fn set_calibration(&self, mag_bias: [f32; 3], gyro_bias: [f32; 3]) -> MAIResult<()> {
+        self.lock().set_calibration(mag_bias, gyro_bias)
+    }
+
+    /// Directly send a message without formatting or checksum calculation
+    ///
+    /// See [`MAI400::passthrough`](struct.MAI400.html#method.passthrough)
+    pub fn passthrough(&self, msg: &[u8]) -> MAIResult<()> {
+        self.lock().passthrough(msg)
+    }
+
+    /// Directly send a message and read back the device's response
+    ///
+    /// See [`MAI400::passthrough_with_response`](struct.MAI400.html#method.passthrough_with_response)
+    pub fn passthrough_with_response(&self, msg: &[u8], response_len: usize) -> MAIResult<Vec<u8>> {
+        self.lock().passthrough_with_response(msg, response_len)
+    }
+
+    /// Wait for and read a message set from the MAI-400
+    ///
+    /// See [`MAI400::get_message`](struct.MAI400.html#method.get_message)
+    pub fn get_message(
+        &self,
+    ) -> MAIResult<(
+        Option<StandardTelemetry>,
+        Option<RawIMU>,
+        Option<IREHSTelemetry>,
+    )> {
+        self.lock().get_message()
+    }
+
+    /// Wait for and read a message set from the MAI-400 as individual messages
+    ///
+    /// See [`MAI400::get_messages`](struct.MAI400.html#method.get_messages)
+    pub fn get_messages(&self) -> MAIResult<Vec<MAIMessage>> {
+        self.lock().get_messages()
+    }
+
+    /// Read every complete message set queued in the UART buffer
+    ///
+    /// See [`MAI400::drain_messages`](struct.MAI400.html#method.drain_messages)
+    pub fn drain_messages(&self) -> MAIResult<Vec<StandardTelemetry>> {
+        self.lock().drain_messages()
+    }
+
+    /// Read the next message set from the MAI-400, if one is already available
+    ///
+    /// See [`MAI400::try_get_message`](struct.MAI400.html#method.try_get_message)
+    pub fn try_get_message(&self) -> MAIResult<Option<StandardTelemetry>> {
+        self.lock().try_get_message()
+    }
+
+    /// Check whether the MAI-400 accepted the most recently sent command
+    ///
+    /// See [`MAI400::confirm_command`](struct.MAI400.html#method.confirm_command)
+    pub fn confirm_command(&self, before: &StandardTelemetry) -> MAIResult<CommandStatus> {
+        self.lock().confirm_command(before)
+    }
+
+    /// Read the ACS mode the MAI-400 is currently in
+    ///
+    /// See [`MAI400::get_mode`](struct.MAI400.html#method.get_mode)
+    pub fn get_mode(&self) -> MAIResult<AcsMode> {
+        self.lock().get_mode()
+    }
+
+    /// Request and read the MAI-400's firmware version information
+    ///
+    /// See [`MAI400::get_version`](struct.MAI400.html#method.get_version)
+    pub fn get_version(&self) -> MAIResult<MAIVersion> {
+        self.lock().get_version()
+    }
+
+    /// Run one of the MAI-400's built-in self-tests and read back the results
+    ///
+    /// See [`MAI400::run_self_test`](struct.MAI400.html#method.run_self_test)
+    pub fn run_self_test(&self, test_type: SelfTestType) -> MAIResult<SelfTestResults> {
+        self.lock().run_self_test(test_type)
+    }
+
+    /// Get a copy of the most recent raw frame sent to the MAI-400
+    ///
+    /// See [`MAI400::last_tx`](struct.MAI400.html#method.last_tx)
+    pub fn last_tx(&self) -> Option<Vec<u8>> {
+        self.lock().last_tx()
+    }
+
+    /// Get a copy of the most recent raw message set received from the MAI-400
+    ///
+    /// See [`MAI400::last_rx`](struct.MAI400.html#method.last_rx)
+    pub fn last_rx(&self) -> Option<Vec<u8>> {
+        self.lock().last_rx()
+    }
+
+    /// Get the totals describing the quality of the UART link
+    ///
+    /// See [`MAI400::link_stats`](struct.MAI400.html#method.link_stats)
+    pub fn link_stats(&self) -> LinkStats {
+        self.lock().link_stats()
+    }
+
+    /// Get the epoch of the last R/V data uploaded to the MAI-400
+    ///
+    /// See [`MAI400::rv_epoch`](struct.MAI400.html#method.rv_epoch)
+    pub fn rv_epoch(&self) -> Option<u32> {
+        self.lock().rv_epoch()
+    }
+
+    /// Get the age, in seconds, of the last R/V data uploaded to the MAI-400
+    ///
+    /// See [`MAI400::rv_age`](struct.MAI400.html#method.rv_age)
+    pub fn rv_age(&self, now_gps: u32) -> u32 {
+        self.lock().rv_age(now_gps)
+    }
+}
+
+impl From<MAI400> for MAI400Handle {
+    fn from(mai: MAI400) -> Self {
+        MAI400Handle::new(mai)
+    }
+}
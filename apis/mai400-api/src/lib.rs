@@ -40,17 +40,78 @@
 //! # }
 //! ```
 //!
+//! # Features
+//!
+//! - `std` (default) - Enables the [`MAI400`] client, along with everything which needs
+//!   the UART transport or timeouts. Without it, the crate is `no_std` (with `alloc`) and
+//!   only provides the protocol layer: building command frames ([`frame`] and the command
+//!   structures), checksums, and parsing the received telemetry messages.
+//! - `async` - Enables `MAI400Async`, a non-blocking variant of the client which can be
+//!   awaited from any async executor. Implies `std`, and the synchronous client remains available.
+//! - `chrono` - Enables conversions between GPS seconds and `chrono::DateTime<Utc>`
+//!   (`gps_seconds_from_datetime`, `datetime_from_gps_seconds`) and `MAI400::set_gps_time_utc`.
+//!
 //! [`MAI400`]: struct.MAI400.html
+//! [`frame`]: fn.frame.html
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![deny(missing_docs)]
-//Need a higher recursion limit for nom when parsing larger (>60 bytes) structures
-#![recursion_limit = "256"]
 
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+mod builder;
+#[cfg(all(feature = "std", feature = "chrono"))]
+mod gps_time;
+#[cfg(feature = "std")]
+mod handle;
+#[cfg(feature = "std")]
 mod mai400;
+#[cfg(feature = "async")]
+mod mai400_async;
 mod messages;
-#[cfg(test)]
+#[cfg(feature = "std")]
+mod mock_mai400;
+#[cfg(feature = "std")]
+mod queue;
+#[cfg(feature = "std")]
+mod telemetry_stream;
+#[cfg(all(test, feature = "std"))]
 mod tests;
 
+// Items which are in the std prelude, but need to be imported from `alloc` without it
+#[cfg(not(feature = "std"))]
+mod no_std_prelude {
+    pub use alloc::borrow::ToOwned;
+    pub use alloc::format;
+    pub use alloc::string::{String, ToString};
+    pub use alloc::vec;
+    pub use alloc::vec::Vec;
+}
+
+#[cfg(feature = "std")]
+pub use crate::builder::*;
+#[cfg(all(feature = "std", feature = "chrono"))]
+pub use crate::gps_time::*;
+#[cfg(feature = "std")]
+pub use crate::handle::*;
+#[cfg(feature = "std")]
 pub use crate::mai400::*;
+#[cfg(feature = "async")]
+pub use crate::mai400_async::*;
 pub use crate::messages::rx::*;
+pub use crate::messages::{
+    aux_checksum, checksum, frame, CommandId, ConfirmReset, Message, RequestReset, RequestVersion,
+    RunSelfTest, SetAcsMode, SetAcsModeSun, SetCalibration, SetGPSTime, SetRV, CMD_LEN, SYNC,
+};
+#[cfg(feature = "std")]
+pub use crate::mock_mai400::MockMAI400;
+#[cfg(feature = "std")]
+pub use crate::queue::*;
+#[cfg(feature = "std")]
+pub use crate::telemetry_stream::*;
+#[cfg(feature = "std")]
 pub use rust_uart::{mock, Connection, UartError};
+#[cfg(feature = "std")]
+pub use serial;
@@ -14,21 +14,279 @@
  * limitations under the License.
  */
 
+use crate::builder::MAI400Builder;
+use crate::messages::rx::std_telem::QUATERNION_LSB_DIVISOR;
 use crate::messages::*;
-use byteorder::{LittleEndian, WriteBytesExt};
 use failure::Fail;
+use log::{debug, info, log_enabled, trace, warn, Level};
 use rust_uart::UartError;
 use rust_uart::*;
-use std::sync::{Arc, Mutex};
-use std::time::Duration;
+use std::collections::VecDeque;
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
 
-const TIMEOUT: Duration = Duration::from_millis(60);
+pub(crate) const TIMEOUT: Duration = Duration::from_millis(60);
+// Read timeout used to check whether a byte has already arrived, without waiting for one
+const NO_WAIT: Duration = Duration::from_millis(0);
+
+/// Maximum number of bytes [`MAI400::get_message`] will discard while searching for
+/// the sync bytes before giving up (two full message sets)
+///
+/// [`MAI400::get_message`]: struct.MAI400.html#method.get_message
+pub const MAX_SYNC_DISCARD: usize = 2 * MSG_SET_LEN;
+
+// Length of a complete message set: standard telemetry, IREHS telemetry and raw IMU packets
+const MSG_SET_LEN: usize = 238;
+
+/// Maximum magnitude of each ECI position component accepted by [`MAI400::set_rv`] (km)
+///
+/// [`MAI400::set_rv`]: struct.MAI400.html#method.set_rv
+pub const RV_POS_MAX: f32 = 1.0e5;
+
+/// Maximum magnitude of each ECI velocity component accepted by [`MAI400::set_rv`] (km/s)
+///
+/// [`MAI400::set_rv`]: struct.MAI400.html#method.set_rv
+pub const RV_VEL_MAX: f32 = 100.0;
+
+/// Default age, in seconds, after which the R/V data uploaded with [`MAI400::set_rv`]
+/// is considered stale (one day)
+///
+/// [`MAI400::set_rv`]: struct.MAI400.html#method.set_rv
+pub const DEFAULT_RV_WARN_AGE: u32 = 86_400;
+
+/// Default range of GPS times accepted by [`MAI400::set_gps_time`]. Any non-zero value
+///
+/// [`MAI400::set_gps_time`]: struct.MAI400.html#method.set_gps_time
+pub const DEFAULT_GPS_TIME_RANGE: RangeInclusive<u32> = 1..=u32::MAX;
+
+/// Default number of message sets [`MAI400::confirm_command`] will read while waiting for
+/// one which post-dates the command (roughly one second of telemetry)
+///
+/// [`MAI400::confirm_command`]: struct.MAI400.html#method.confirm_command
+pub const DEFAULT_ACK_WINDOW: usize = 4;
 
 /// Structure for MAI-400 device instance
 #[derive(Clone)]
 pub struct MAI400 {
     /// Device connection structure
     pub conn: Arc<Mutex<Connection>>,
+    /// Maximum amount of time to wait for a message from the device.
+    /// If `None`, [`get_message`] will wait indefinitely
+    ///
+    /// [`get_message`]: #method.get_message
+    pub read_timeout: Option<Duration>,
+    /// Retry behavior for commands which fail due to a transient UART error
+    pub retry: RetryPolicy,
+    /// Most recently transmitted and received frames
+    pub(crate) frames: Arc<Mutex<LastFrames>>,
+    /// Whether a command issued after a transport failure should first try to [`reconnect`]
+    ///
+    /// [`reconnect`]: #method.reconnect
+    pub auto_reconnect: bool,
+    /// Function used to (re)open the connection, if known
+    pub(crate) connector: Option<Connector>,
+    /// Set when a write to the device fails, cleared once the link is reopened
+    pub(crate) link_failed: Arc<AtomicBool>,
+    /// Age, in seconds, after which the R/V data uploaded with [`set_rv`] is considered stale.
+    /// If `None`, no warning is logged when ACS mode commands are sent with stale R/V data
+    ///
+    /// [`set_rv`]: #method.set_rv
+    pub rv_warn_age: Option<u32>,
+    /// GPS times, in seconds, accepted by [`set_gps_time`]. Values outside of this range
+    /// are rejected without being sent to the device
+    ///
+    /// [`set_gps_time`]: #method.set_gps_time
+    pub gps_time_range: RangeInclusive<u32>,
+    /// Maximum number of message sets [`confirm_command`] reads while waiting for one which
+    /// post-dates the command. Message sets timestamped at or before the reference telemetry
+    /// were buffered before the command was sent, so are skipped
+    ///
+    /// [`confirm_command`]: #method.confirm_command
+    pub ack_window: usize,
+    /// Epoch of the last R/V data uploaded with `set_rv`. 0 if none has been uploaded
+    pub(crate) rv_epoch: Arc<AtomicU32>,
+    /// GPS time of the most recently received standard telemetry. 0 if none has been received
+    pub(crate) last_gps_time: Arc<AtomicU32>,
+    /// Running totals reported by [`link_stats`]
+    ///
+    /// [`link_stats`]: #method.link_stats
+    pub(crate) link_counters: Arc<LinkCounters>,
+    /// Bytes which have been read from the connection, but don't yet make up a complete
+    /// message set. Always locked after `conn`
+    pub(crate) rx_buffer: Arc<Mutex<VecDeque<u8>>>,
+}
+
+/// Function used to open a new connection to the MAI-400
+pub type Connector = Arc<dyn Fn() -> UartResult<Connection> + Send + Sync>;
+
+// Placeholder stream, used so that the old port is closed before it is reopened
+struct Closed;
+
+impl Stream for Closed {
+    fn write(&self, _data: &[u8]) -> UartResult<()> {
+        Err(closed_error())
+    }
+
+    fn read(&self, _len: usize, _timeout: Duration) -> UartResult<Vec<u8>> {
+        Err(closed_error())
+    }
+}
+
+fn closed_error() -> UartError {
+    UartError::IoError {
+        cause: ::std::io::ErrorKind::NotConnected,
+        description: "Connection closed".to_owned(),
+    }
+}
+
+// Copies of the most recent raw frames, kept for diagnostics.
+// The buffers are reused between frames, so recording doesn't allocate once warmed up
+#[derive(Default)]
+pub(crate) struct LastFrames {
+    tx: Option<Vec<u8>>,
+    rx: Option<Vec<u8>>,
+}
+
+fn record_frame(buffer: &mut Option<Vec<u8>>, data: &[u8]) {
+    let buffer = buffer.get_or_insert_with(|| Vec::with_capacity(data.len()));
+    buffer.clear();
+    buffer.extend_from_slice(data);
+}
+
+// Running totals behind `MAI400::link_stats`. Shared between clones of the client
+#[derive(Default)]
+pub(crate) struct LinkCounters {
+    frames_received: AtomicU32,
+    checksum_failures: AtomicU32,
+    resyncs: AtomicU32,
+    timeouts: AtomicU32,
+}
+
+fn count(counter: &AtomicU32) {
+    counter.fetch_add(1, Ordering::Relaxed);
+}
+
+// Read `len` bytes, starting with any left in the receive buffer. If the read fails,
+// the buffered bytes are put back so that they aren't lost
+fn read_buffered(
+    conn: &Connection,
+    rx: &mut VecDeque<u8>,
+    len: usize,
+    timeout: Duration,
+) -> UartResult<Vec<u8>> {
+    let buffered = len.min(rx.len());
+    let mut data: Vec<u8> = rx.drain(..buffered).collect();
+
+    if data.len() < len {
+        match conn.read(len - data.len(), timeout) {
+            Ok(mut rest) => data.append(&mut rest),
+            Err(err) => {
+                for byte in data.into_iter().rev() {
+                    rx.push_front(byte);
+                }
+                return Err(err);
+            }
+        }
+    }
+
+    Ok(data)
+}
+
+// Drop bytes from the front of the receive buffer until it starts with the sync bytes,
+// or with a first sync byte which the next read may complete.
+// Returns the number of bytes dropped
+fn discard_before_sync(rx: &mut VecDeque<u8>) -> usize {
+    let mut dropped = 0;
+
+    loop {
+        match (rx.get(0), rx.get(1)) {
+            (Some(&first), Some(&second)) if [first, second] == SYNC => break,
+            (Some(&first), None) if first == SYNC[0] => break,
+            (None, _) => break,
+            _ => {
+                rx.pop_front();
+                dropped += 1;
+            }
+        }
+    }
+
+    dropped
+}
+
+/// Totals describing the quality of the UART link to the MAI-400
+///
+/// Returned by [`MAI400::link_stats`]. The counters are monotonic: they start at zero when
+/// the client is created, are never reset, and are shared by every clone of the client.
+/// A rolling error rate can be computed from the difference between two snapshots
+/// (see [`since`]). Counters wrap around on overflow.
+///
+/// [`MAI400::link_stats`]: struct.MAI400.html#method.link_stats
+/// [`since`]: #method.since
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LinkStats {
+    /// Complete telemetry message sets read from the device, including ones which were
+    /// later rejected
+    pub frames_received: u32,
+    /// Message sets which failed checksum validation
+    pub checksum_failures: u32,
+    /// Times data had to be discarded to realign on the sync bytes, including
+    /// reads which gave up with `MAIError::SyncLost`
+    pub resyncs: u32,
+    /// Reads which timed out before a complete message set arrived
+    pub timeouts: u32,
+}
+
+impl LinkStats {
+    /// Counts accumulated after an earlier snapshot was taken
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mai400_api::*;
+    /// # fn func() -> MAIResult<()> {
+    /// let mai = MAI400::new("/dev/ttyS5")?;
+    ///
+    /// let before = mai.link_stats();
+    /// mai.get_message()?;
+    /// let recent = mai.link_stats().since(&before);
+    ///
+    /// println!("Recent error rate: {}", recent.error_rate());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn since(&self, earlier: &LinkStats) -> LinkStats {
+        LinkStats {
+            frames_received: self.frames_received.wrapping_sub(earlier.frames_received),
+            checksum_failures: self
+                .checksum_failures
+                .wrapping_sub(earlier.checksum_failures),
+            resyncs: self.resyncs.wrapping_sub(earlier.resyncs),
+            timeouts: self.timeouts.wrapping_sub(earlier.timeouts),
+        }
+    }
+
+    /// Fraction of received message sets which failed checksum validation,
+    /// from 0.0 (no corruption) to 1.0. Zero if nothing has been received
+    pub fn error_rate(&self) -> f32 {
+        if self.frames_received == 0 {
+            return 0.0;
+        }
+
+        self.checksum_failures as f32 / self.frames_received as f32
+    }
+}
+
+/// Retry behavior for commands which fail due to a transient UART error
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Number of times a failed command should be re-sent
+    pub count: u8,
+    /// Time to wait before the first retry. The wait is doubled for each subsequent retry
+    pub backoff: Duration,
 }
 
 impl MAI400 {
@@ -50,19 +308,241 @@ impl MAI400 {
     /// ```
     ///
     pub fn new(bus: &str) -> MAIResult<MAI400> {
-        let settings = serial::PortSettings {
-            baud_rate: serial::Baud115200,
-            char_size: serial::Bits8,
-            parity: serial::ParityNone,
-            stop_bits: serial::Stop1,
-            flow_control: serial::FlowNone,
-        };
+        MAI400Builder::new().uart_path(bus).build()
+    }
 
-        let conn = Connection::from_path(bus, settings, TIMEOUT)?;
+    /// Constructor for MAI400 structure with a bounded read timeout
+    ///
+    /// # Arguments
+    ///
+    /// * bus - The UART bus the device is connected to
+    /// * read_timeout - Maximum amount of time [`get_message`] should wait for a message
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mai400_api::*;
+    /// use std::time::Duration;
+    ///
+    /// # fn func() -> MAIResult<()> {
+    /// let mai = MAI400::with_read_timeout("/dev/ttyS5", Duration::from_secs(1))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`get_message`]: #method.get_message
+    pub fn with_read_timeout(bus: &str, read_timeout: Duration) -> MAIResult<MAI400> {
+        MAI400Builder::new()
+            .uart_path(bus)
+            .read_timeout(read_timeout)
+            .build()
+    }
 
-        Ok(MAI400 {
-            conn: Arc::new(Mutex::new(conn)),
-        })
+    /// Set the maximum amount of time [`get_message`] should wait for a message
+    ///
+    /// # Arguments
+    ///
+    /// * read_timeout - Maximum wait time
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mai400_api::*;
+    /// use std::time::Duration;
+    ///
+    /// # fn func() -> MAIResult<()> {
+    /// let mut mai = MAI400::new("/dev/ttyS5")?;
+    /// mai.set_read_timeout(Duration::from_millis(500));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`get_message`]: #method.get_message
+    pub fn set_read_timeout(&mut self, read_timeout: Duration) {
+        self.read_timeout = Some(read_timeout);
+    }
+
+    /// Get a copy of the most recent raw frame sent to the MAI-400
+    ///
+    /// The frame is recorded before it is sent, so it is available even if the
+    /// transfer failed. Returns `None` if nothing has been sent yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mai400_api::*;
+    ///
+    /// # fn func() -> MAIResult<()> {
+    /// let mai = MAI400::new("/dev/ttyS5")?;
+    /// if let Err(err) = mai.reset() {
+    ///     println!("Reset failed: {}. Sent: {:X?}", err, mai.last_tx());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn last_tx(&self) -> Option<Vec<u8>> {
+        self.lock_frames().tx.clone()
+    }
+
+    /// Get a copy of the most recent raw message set (or passthrough response)
+    /// received from the MAI-400
+    ///
+    /// The data is recorded before it is validated, so it is available even if
+    /// the message set was rejected (for example, due to a checksum mismatch).
+    /// Returns `None` if nothing has been received yet.
+    pub fn last_rx(&self) -> Option<Vec<u8>> {
+        self.lock_frames().rx.clone()
+    }
+
+    /// Get the totals describing the quality of the UART link
+    ///
+    /// See [`LinkStats`] for what is counted. The counters are never reset, so compare
+    /// two snapshots with [`LinkStats::since`] to get recent activity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mai400_api::*;
+    /// # fn func() -> MAIResult<()> {
+    /// let mai = MAI400::new("/dev/ttyS5")?;
+    ///
+    /// let stats = mai.link_stats();
+    /// println!(
+    ///     "{} checksum failures in {} message sets",
+    ///     stats.checksum_failures, stats.frames_received
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`LinkStats`]: struct.LinkStats.html
+    /// [`LinkStats::since`]: struct.LinkStats.html#method.since
+    pub fn link_stats(&self) -> LinkStats {
+        let counters = &self.link_counters;
+        LinkStats {
+            frames_received: counters.frames_received.load(Ordering::Relaxed),
+            checksum_failures: counters.checksum_failures.load(Ordering::Relaxed),
+            resyncs: counters.resyncs.load(Ordering::Relaxed),
+            timeouts: counters.timeouts.load(Ordering::Relaxed),
+        }
+    }
+
+    // A panic while holding the lock can't leave the buffers in a bad state,
+    // so ignore any poisoning
+    fn lock_frames(&self) -> MutexGuard<LastFrames> {
+        self.frames.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    // The buffer only holds raw bytes, so it is still usable if another thread panicked
+    fn lock_rx_buffer(&self) -> MutexGuard<VecDeque<u8>> {
+        self.rx_buffer.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// Retry commands which fail with `UartError::GenericError`
+    ///
+    /// Only idempotent commands are retried: [`reset`], [`set_mode`], [`set_acs_mode`] and
+    /// [`set_mode_sun`]. [`set_gps_time`] and [`set_rv`] carry time-dependent data which
+    /// would be stale by the time a retry was sent, so they are always attempted once.
+    ///
+    /// # Arguments
+    ///
+    /// * count - Number of times a failed command should be re-sent
+    /// * backoff - Time to wait before the first retry. The wait is doubled for each subsequent retry
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mai400_api::*;
+    /// use std::time::Duration;
+    ///
+    /// # fn func() -> MAIResult<()> {
+    /// let mai = MAI400::new("/dev/ttyS5")?.with_retries(3, Duration::from_millis(10));
+    /// mai.reset()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`reset`]: #method.reset
+    /// [`set_mode`]: #method.set_mode
+    /// [`set_acs_mode`]: #method.set_acs_mode
+    /// [`set_mode_sun`]: #method.set_mode_sun
+    /// [`set_gps_time`]: #method.set_gps_time
+    /// [`set_rv`]: #method.set_rv
+    pub fn with_retries(mut self, count: u8, backoff: Duration) -> Self {
+        self.retry = RetryPolicy { count, backoff };
+        self
+    }
+
+    /// Close and reopen the connection to the MAI-400
+    ///
+    /// The connection is reopened with the parameters it was originally created with
+    /// (the UART path and baud rate, or the function given to [`MAI400Builder::connector`]).
+    /// If the port can't be reopened, the client is left disconnected and this function
+    /// may be called again later.
+    ///
+    /// If [`auto_reconnect`] is set, this is done automatically by the first command issued
+    /// after a command fails with a UART error.
+    ///
+    /// # Errors
+    ///
+    /// If the client was created from an existing connection with no way to reopen it,
+    /// `MAIError::InvalidInput` will be returned.
+    /// If the port could not be reopened, `MAIError::UartError` will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mai400_api::*;
+    /// # fn func() -> MAIResult<()> {
+    /// let mai = MAI400::new("/dev/ttyS5")?;
+    ///
+    /// if let Err(MAIError::UartError { .. }) = mai.reset() {
+    ///     mai.reconnect()?;
+    ///     mai.reset()?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`MAI400Builder::connector`]: struct.MAI400Builder.html#method.connector
+    /// [`auto_reconnect`]: #structfield.auto_reconnect
+    pub fn reconnect(&self) -> MAIResult<()> {
+        let connector = self
+            .connector
+            .as_ref()
+            .ok_or_else(|| MAIError::InvalidInput {
+                reason: "No connection parameters available to reconnect with".to_owned(),
+            })?;
+
+        let mut conn = self.conn.lock().unwrap_or_else(|err| err.into_inner());
+
+        // Make sure the old port is closed before trying to open it again.
+        // Anything left over from it can't be completed by the new one
+        *conn = Connection::new(Box::new(Closed));
+        self.lock_rx_buffer().clear();
+        *conn = connector().map_err(|err| {
+            warn!("Failed to reconnect: {}", err);
+            MAIError::from(err)
+        })?;
+
+        self.link_failed.store(false, Ordering::SeqCst);
+        info!("Reconnected to MAI-400");
+        Ok(())
+    }
+
+    // Reopen the connection before sending a command if the previous one failed
+    fn check_link(&self) -> MAIResult<()> {
+        if self.auto_reconnect && self.link_failed.load(Ordering::SeqCst) {
+            self.reconnect()
+        } else {
+            Ok(())
+        }
+    }
+
+    // Note that the transport has failed, so that the next command can try to reconnect
+    fn link_error(&self, err: UartError) -> MAIError {
+        self.link_failed.store(true, Ordering::SeqCst);
+        err.into()
     }
 
     /// Request a hardware reset of the MAI-400
@@ -85,11 +565,13 @@ impl MAI400 {
     /// [`MAIError`]: enum.MAIError.html
     // Resetting requires a pair of commands: request, then confirm
     pub fn reset(&self) -> MAIResult<()> {
+        debug!("Requesting reset");
+
         let request = RequestReset::default();
-        self.send_message(&request)?;
+        self.send_message_retry(&request)?;
 
         let request = ConfirmReset::default();
-        self.send_message(&request)
+        self.send_message_retry(&request)
     }
 
     /// Set the ACS mode
@@ -104,6 +586,9 @@ impl MAI400 {
     /// # Errors
     ///
     /// If this function encounters any errors, an [`MAIError`] variant will be returned.
+    /// If `mode` is not a known [`AcsMode`] value, or is one of the reserved modes,
+    /// `MAIError::InvalidInput` will be returned
+    /// and nothing will be sent to the device.
     ///
     /// # Examples
     ///
@@ -116,31 +601,37 @@ impl MAI400 {
     /// # }
     /// ```
     ///
+    /// [`AcsMode`]: enum.AcsMode.html
     /// [`MAIError`]: enum.MAIError.html
     // TODO: Get good values for examples
     pub fn set_mode(&self, mode: u8, params: [i16; 4]) -> MAIResult<()> {
-        let request = SetAcsMode {
-            mode,
-            qbi_cmd: params,
-            ..Default::default()
-        };
+        check_mode(mode)?;
 
-        self.send_message(&request)
+        self.set_mode_unchecked(mode, params)
     }
 
-    /// Set the ACS mode (Normal-Sun or Lat/Long-Sun)
+    /// Set the ACS mode and confirm that the MAI-400 accepted the command
+    ///
+    /// A standard telemetry message is read before the command is sent, so that its command
+    /// counters can be compared against those in the telemetry message which follows it.
+    /// See [`confirm_command`] for details.
     ///
     /// # Arguments
     ///
     /// *Note: Arguments should be set to `0x00` when not needed for desired mode*
     ///
-    /// * mode - ACS mode to enter. Should be either Normal-Sun or Lat/Long-Sun
-    /// * sun_angle_enable - Sun angle enable (0 = no update, 1 = update)
-    /// * sun_rot_angle - Sun rotation angle, in degrees
+    /// * mode - ACS mode to enter
+    /// * params - Array of signed shorts containing the arguments for configuring the requested mode
     ///
     /// # Errors
     ///
     /// If this function encounters any errors, an [`MAIError`] variant will be returned.
+    /// If `mode` is not a known [`AcsMode`] value, or is one of the reserved modes,
+    /// `MAIError::InvalidInput` will be returned
+    /// and nothing will be sent to the device.
+    /// If the MAI-400 reports that the command was rejected, `MAIError::CommandRejected`
+    /// will be returned. If the command failed verification, its `reason` field will
+    /// contain the likely [`ModeRejectReason`].
     ///
     /// # Examples
     ///
@@ -148,34 +639,70 @@ impl MAI400 {
     /// # use mai400_api::*;
     /// # fn func() -> MAIResult<()> {
     /// let mai = MAI400::new("/dev/ttyS5")?;
-    /// mai.set_mode_sun(7, 1, 45.0)?;
+    /// mai.set_mode_confirmed(9, [1, -1, -3, 0])?;
     /// # Ok(())
     /// # }
     /// ```
     ///
+    /// [`AcsMode`]: enum.AcsMode.html
+    /// [`confirm_command`]: #method.confirm_command
+    /// [`ModeRejectReason`]: enum.ModeRejectReason.html
     /// [`MAIError`]: enum.MAIError.html
-    // TODO: Get good values for examples
-    pub fn set_mode_sun(
-        &self,
-        mode: u8,
-        sun_angle_enable: i16,
-        sun_rot_angle: f32,
-    ) -> MAIResult<()> {
-        let request = SetAcsModeSun {
+    pub fn set_mode_confirmed(&self, mode: u8, params: [i16; 4]) -> MAIResult<CommandStatus> {
+        check_mode(mode)?;
+
+        let before = self.get_std_telemetry()?;
+        self.set_mode_unchecked(mode, params)?;
+
+        let after = match self.get_ack_telemetry(&before)? {
+            Some(after) => after,
+            None => return Ok(CommandStatus::NoChange),
+        };
+        let reason = AcsMode::from_u8(mode)
+            .map(|mode| ModeRejectReason::from_telemetry(mode, params, &after));
+        check_command(&before, &after, reason)
+    }
+
+    /// Set the ACS mode, without checking that the mode is a known [`AcsMode`] value
+    ///
+    /// Intended for experimenting with firmware-specific modes. Sending an undefined mode
+    /// may leave the ACS in an undefined state, so [`set_mode`] should be preferred.
+    ///
+    /// # Arguments
+    ///
+    /// * mode - Raw ACS mode byte
+    /// * params - Array of signed shorts containing the arguments for configuring the requested mode
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`MAIError`] variant will be returned.
+    ///
+    /// [`AcsMode`]: enum.AcsMode.html
+    /// [`set_mode`]: #method.set_mode
+    /// [`MAIError`]: enum.MAIError.html
+    pub fn set_mode_unchecked(&self, mode: u8, params: [i16; 4]) -> MAIResult<()> {
+        debug!("Setting ACS mode {} with parameters {:?}", mode, params);
+        self.check_rv_age();
+
+        let request = SetAcsMode {
             mode,
-            sun_angle_enable,
-            sun_rot_angle,
+            qbi_cmd: params,
             ..Default::default()
         };
 
-        self.send_message(&request)
+        self.send_message_retry(&request)
     }
 
-    /// Set the ADACS clock with the desired GPS time
+    /// Set the ACS mode
+    ///
+    /// Equivalent to [`set_mode`], but takes an [`AcsMode`] value rather than the raw mode byte
     ///
     /// # Arguments
     ///
-    /// * gps_time - New clock time (elapsed seconds after Jan 6, 1980 00:00:00)
+    /// *Note: Arguments should be set to `0x00` when not needed for desired mode*
+    ///
+    /// * mode - ACS mode to enter
+    /// * params - Array of signed shorts containing the arguments for configuring the requested mode
     ///
     /// # Errors
     ///
@@ -187,33 +714,34 @@ impl MAI400 {
     /// # use mai400_api::*;
     /// # fn func() -> MAIResult<()> {
     /// let mai = MAI400::new("/dev/ttyS5")?;
-    /// // Jan 01, 2018
-    /// mai.set_gps_time(1198800018)?;
+    /// mai.set_acs_mode(AcsMode::Qinertial, [1, -1, -3, 0])?;
     /// # Ok(())
     /// # }
     /// ```
     ///
+    /// [`set_mode`]: #method.set_mode
+    /// [`AcsMode`]: enum.AcsMode.html
     /// [`MAIError`]: enum.MAIError.html
-    pub fn set_gps_time(&self, gps_time: u32) -> MAIResult<()> {
-        let request = SetGPSTime {
-            gps_time,
-            ..Default::default()
-        };
-
-        self.send_message(&request)
+    pub fn set_acs_mode(&self, mode: AcsMode, params: [i16; 4]) -> MAIResult<()> {
+        self.set_mode(mode.as_u8(), params)
     }
 
-    /// Set orbital position and velocity at epoch for RK4 integration method of orbit propagation
+    /// Slew to and hold a target attitude, by entering Qinertial mode with the given
+    /// commanded quaternion
+    ///
+    /// The quaternion rotates from the inertial reference frame into the body frame and is
+    /// ordered `[q1, q2, q3, q4]`, with the scalar component last. It is normalized before
+    /// being converted into the fixed-point values sent to the MAI-400 (lsb: 1/32767).
     ///
     /// # Arguments
     ///
-    /// * eci_pos - ECI position [X, Y, Z]
-    /// * eci_vel - ECI velocity [X, Y, Z]
-    /// * time_epoch - GPS time at Epoch
+    /// * q - Target inertial-to-body quaternion. Does not need to be unit length
     ///
     /// # Errors
     ///
     /// If this function encounters any errors, an [`MAIError`] variant will be returned.
+    /// If any component of `q` is not finite, or `q` has a norm of zero, `MAIError::InvalidInput`
+    /// will be returned and nothing will be sent to the device.
     ///
     /// # Examples
     ///
@@ -221,32 +749,995 @@ impl MAI400 {
     /// # use mai400_api::*;
     /// # fn func() -> MAIResult<()> {
     /// let mai = MAI400::new("/dev/ttyS5")?;
-    /// mai.set_rv([0.0, 0.0, 0.0], [0.0, 0.0, 0.0], 1198800018)?;
+    /// mai.set_target_quaternion([0.0, 0.0, 0.707_107, 0.707_107])?;
     /// # Ok(())
     /// # }
     /// ```
     ///
     /// [`MAIError`]: enum.MAIError.html
-    pub fn set_rv(&self, eci_pos: [f32; 3], eci_vel: [f32; 3], time_epoch: u32) -> MAIResult<()> {
-        let request = SetRV {
-            eci_pos,
-            eci_vel,
+    pub fn set_target_quaternion(&self, q: [f32; 4]) -> MAIResult<()> {
+        let params = quaternion_params(q)?;
+
+        self.set_acs_mode(AcsMode::Qinertial, params)
+    }
+
+    /// Set the ACS mode (Normal-Sun or Lat/Long-Sun)
+    ///
+    /// # Arguments
+    ///
+    /// *Note: Arguments should be set to `0x00` when not needed for desired mode*
+    ///
+    /// * mode - ACS mode to enter. Should be either Normal-Sun or Lat/Long-Sun
+    /// * sun_angle_enable - Sun angle enable (0 = no update, 1 = update)
+    /// * sun_rot_angle - Sun rotation angle, in degrees
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`MAIError`] variant will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mai400_api::*;
+    /// # fn func() -> MAIResult<()> {
+    /// let mai = MAI400::new("/dev/ttyS5")?;
+    /// mai.set_mode_sun(7, 1, 45.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`MAIError`]: enum.MAIError.html
+    // TODO: Get good values for examples
+    pub fn set_mode_sun(
+        &self,
+        mode: u8,
+        sun_angle_enable: i16,
+        sun_rot_angle: f32,
+    ) -> MAIResult<()> {
+        debug!(
+            "Setting ACS sun mode {} (sun angle enable: {}, sun rotation angle: {})",
+            mode, sun_angle_enable, sun_rot_angle
+        );
+        self.check_rv_age();
+
+        let request = SetAcsModeSun {
+            mode,
+            sun_angle_enable,
+            sun_rot_angle,
+            ..Default::default()
+        };
+
+        self.send_message_retry(&request)
+    }
+
+    /// Set the ADACS clock with the desired GPS time
+    ///
+    /// # Arguments
+    ///
+    /// * gps_time - New clock time (elapsed seconds after Jan 6, 1980 00:00:00).
+    ///              Must be within [`gps_time_range`]
+    ///
+    /// The clock is a `u32` count of seconds, so it wraps back to zero after
+    /// Feb 12, 2116 06:28:15 GPS time. No dates after that can be represented.
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`MAIError`] variant will be returned.
+    /// If `gps_time` is outside of [`gps_time_range`], `MAIError::InvalidInput` will be
+    /// returned and nothing will be sent to the device.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mai400_api::*;
+    /// # fn func() -> MAIResult<()> {
+    /// let mai = MAI400::new("/dev/ttyS5")?;
+    /// // Jan 01, 2018
+    /// mai.set_gps_time(1198800018)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`gps_time_range`]: #structfield.gps_time_range
+    /// [`MAIError`]: enum.MAIError.html
+    pub fn set_gps_time(&self, gps_time: u32) -> MAIResult<()> {
+        debug!("Setting GPS time to {}", gps_time);
+
+        if !self.gps_time_range.contains(&gps_time) {
+            return Err(MAIError::InvalidInput {
+                reason: format!(
+                    "GPS time {} outside of accepted range ({}-{})",
+                    gps_time,
+                    self.gps_time_range.start(),
+                    self.gps_time_range.end()
+                ),
+            });
+        }
+
+        let request = SetGPSTime {
+            gps_time,
+            ..Default::default()
+        };
+
+        self.send_message(&request)
+    }
+
+    /// Set orbital position and velocity at epoch for RK4 integration method of orbit propagation
+    ///
+    /// # Arguments
+    ///
+    /// * eci_pos - ECI position [X, Y, Z] (km). Each component must be finite and
+    ///             no larger in magnitude than [`RV_POS_MAX`]
+    /// * eci_vel - ECI velocity [X, Y, Z] (km/s). Each component must be finite and
+    ///             no larger in magnitude than [`RV_VEL_MAX`]
+    /// * time_epoch - GPS time at Epoch. Must be non-zero
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`MAIError`] variant will be returned.
+    /// If any argument is outside of its accepted range, `MAIError::InvalidInput` will be
+    /// returned and nothing will be sent to the device.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mai400_api::*;
+    /// # fn func() -> MAIResult<()> {
+    /// let mai = MAI400::new("/dev/ttyS5")?;
+    /// mai.set_rv([0.0, 0.0, 0.0], [0.0, 0.0, 0.0], 1198800018)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`MAIError`]: enum.MAIError.html
+    /// [`RV_POS_MAX`]: constant.RV_POS_MAX.html
+    /// [`RV_VEL_MAX`]: constant.RV_VEL_MAX.html
+    pub fn set_rv(&self, eci_pos: [f32; 3], eci_vel: [f32; 3], time_epoch: u32) -> MAIResult<()> {
+        debug!(
+            "Setting RV: position {:?}, velocity {:?}, epoch {}",
+            eci_pos, eci_vel, time_epoch
+        );
+
+        check_components("eci_pos", &eci_pos, RV_POS_MAX)?;
+        check_components("eci_vel", &eci_vel, RV_VEL_MAX)?;
+
+        if time_epoch == 0 {
+            return Err(MAIError::InvalidInput {
+                reason: "time_epoch must be non-zero".to_owned(),
+            });
+        }
+
+        let request = SetRV {
+            eci_pos,
+            eci_vel,
             time_epoch,
             ..Default::default()
         };
 
-        self.send_message(&request)
+        self.send_message(&request)?;
+        self.rv_epoch.store(time_epoch, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Upload magnetometer and gyro bias corrections
+    ///
+    /// The biases replace any corrections previously uploaded. Since repeating the command
+    /// has no additional effect, it is retried according to the configured [`RetryPolicy`].
+    ///
+    /// # Arguments
+    ///
+    /// * mag_bias - Magnetometer bias [X, Y, Z] (nT). Each component must be finite
+    /// * gyro_bias - Gyro bias [X, Y, Z] (deg/s). Each component must be finite
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`MAIError`] variant will be returned.
+    /// If any component is NaN or infinite, `MAIError::InvalidInput` will be returned and
+    /// nothing will be sent to the device.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mai400_api::*;
+    /// # fn func() -> MAIResult<()> {
+    /// let mai = MAI400::new("/dev/ttyS5")?;
+    /// mai.set_calibration([120.0, -45.5, 10.25], [0.01, -0.02, 0.005])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`MAIError`]: enum.MAIError.html
+    /// [`RetryPolicy`]: struct.RetryPolicy.html
+    pub fn set_calibration(&self, mag_bias: [f32; 3], gyro_bias: [f32; 3]) -> MAIResult<()> {
+        debug!(
+            "Setting calibration: magnetometer bias {:?}, gyro bias {:?}",
+            mag_bias, gyro_bias
+        );
+
+        check_finite("mag_bias", &mag_bias)?;
+        check_finite("gyro_bias", &gyro_bias)?;
+
+        let request = SetCalibration {
+            mag_bias,
+            gyro_bias,
+            ..Default::default()
+        };
+
+        self.send_message_retry(&request)
+    }
+
+    /// Get the epoch of the last R/V data successfully uploaded with [`set_rv`]
+    ///
+    /// Returns `None` if no R/V data has been uploaded by this client
+    ///
+    /// [`set_rv`]: #method.set_rv
+    pub fn rv_epoch(&self) -> Option<u32> {
+        match self.rv_epoch.load(Ordering::Relaxed) {
+            0 => None,
+            epoch => Some(epoch),
+        }
+    }
+
+    /// Get the age, in seconds, of the last R/V data uploaded with [`set_rv`]
+    ///
+    /// If no R/V data has been uploaded, the age is measured from the start of GPS time,
+    /// so will always exceed any reasonable threshold.
+    /// Returns 0 if `now_gps` is earlier than the R/V epoch.
+    ///
+    /// # Arguments
+    ///
+    /// * now_gps - Current GPS time (elapsed seconds after Jan 6, 1980 00:00:00)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mai400_api::*;
+    /// # fn func() -> MAIResult<()> {
+    /// let mai = MAI400::new("/dev/ttyS5")?;
+    /// mai.set_rv([6778.0, 0.0, 0.0], [0.0, 7.67, 0.0], 1198800018)?;
+    ///
+    /// assert_eq!(mai.rv_age(1198800618), 600);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`set_rv`]: #method.set_rv
+    pub fn rv_age(&self, now_gps: u32) -> u32 {
+        now_gps.saturating_sub(self.rv_epoch.load(Ordering::Relaxed))
+    }
+
+    /// Check whether the last R/V data uploaded with [`set_rv`] is older than [`rv_warn_age`]
+    ///
+    /// Always returns `false` if `rv_warn_age` is `None`
+    ///
+    /// # Arguments
+    ///
+    /// * now_gps - Current GPS time (elapsed seconds after Jan 6, 1980 00:00:00)
+    ///
+    /// [`set_rv`]: #method.set_rv
+    /// [`rv_warn_age`]: #structfield.rv_warn_age
+    pub fn rv_stale(&self, now_gps: u32) -> bool {
+        match self.rv_warn_age {
+            Some(max_age) => self.rv_age(now_gps) > max_age,
+            None => false,
+        }
+    }
+
+    // Warn the operator if an ACS mode command is being sent while the uploaded R/V
+    // data is stale. The current time is taken from the last telemetry received
+    fn check_rv_age(&self) {
+        let now = self.last_gps_time.load(Ordering::Relaxed);
+        if self.rv_epoch().is_none() || now == 0 {
+            return;
+        }
+
+        if self.rv_stale(now) {
+            warn!(
+                "R/V data is {}s old, which exceeds the {}s limit. Re-upload it with set_rv",
+                self.rv_age(now),
+                self.rv_warn_age.unwrap_or_default()
+            );
+        }
+    }
+
+    /// Directly send a message without formatting or checksum calculation
+    ///
+    /// # Arguments
+    ///
+    /// * msg - Message to send. Must be exactly [`CMD_LEN`] (40) bytes long
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`MAIError`] variant will be returned.
+    /// If the message is not [`CMD_LEN`] bytes long, `MAIError::InvalidInput` will be returned
+    /// and nothing will be sent to the device.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mai400_api::*;
+    /// # fn func() -> MAIResult<()> {
+    /// let mai = MAI400::new("/dev/ttyS5")?;
+    ///
+    /// let mut array = [0; CMD_LEN];
+    /// array[0] = 0x90;  // SYNC byte 1
+    /// array[1] = 0xEB;  // SYNC byte 2
+    /// array[2] = CommandId::RequestReset.as_u8();
+    /// array[38] = 0xD5; // CRC byte 1
+    /// array[39] = 0x01; // CRC byte 2
+    ///
+    /// mai.passthrough(&array)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`CMD_LEN`]: constant.CMD_LEN.html
+    /// [`MAIError`]: enum.MAIError.html
+    pub fn passthrough(&self, msg: &[u8]) -> MAIResult<()> {
+        self.passthrough_with_response(msg, 0).map(|_| ())
+    }
+
+    /// Directly send a message without formatting or checksum calculation,
+    /// and then read back the device's response
+    ///
+    /// The response must begin with the MAI-400 sync bytes (0x90 0xEB). It will be read using
+    /// the configured read timeout or, if none has been set, the default UART timeout (60ms).
+    ///
+    /// *Note*: The MAI-400 also emits a telemetry message set every 250ms, so any concurrent
+    /// calls to [`get_message`] should be stopped while using this function.
+    ///
+    /// # Arguments
+    ///
+    /// * msg - Message to send. Must be exactly [`CMD_LEN`] (40) bytes long
+    /// * response_len - Number of response bytes to read. If 0, no response will be read
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`MAIError`] variant will be returned.
+    /// If the response does not arrive in time, `MAIError::Timeout` will be returned.
+    /// If the response is too short to hold the sync bytes, `MAIError::FramingError` will be returned.
+    /// If the response does not begin with the sync bytes, `MAIError::SyncMismatch` will be returned.
+    /// If the message is not [`CMD_LEN`] bytes long, `MAIError::InvalidInput` will be returned
+    /// and nothing will be sent to the device.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mai400_api::*;
+    /// # fn func() -> MAIResult<()> {
+    /// let mai = MAI400::new("/dev/ttyS5")?;
+    ///
+    /// let mut array = [0; CMD_LEN];
+    /// array[0] = 0x90;
+    /// array[1] = 0xEB;
+    /// array[2] = CommandId::RequestReset.as_u8();
+    /// array[38] = 0xD5;
+    /// array[39] = 0x01;
+    ///
+    /// let response = mai.passthrough_with_response(&array, 8)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`CMD_LEN`]: constant.CMD_LEN.html
+    /// [`get_message`]: #method.get_message
+    /// [`MAIError`]: enum.MAIError.html
+    pub fn passthrough_with_response(&self, msg: &[u8], response_len: usize) -> MAIResult<Vec<u8>> {
+        if msg.len() != CMD_LEN {
+            return Err(MAIError::InvalidInput {
+                reason: format!(
+                    "Message must be exactly {} bytes, not {}",
+                    CMD_LEN,
+                    msg.len()
+                ),
+            });
+        }
+
+        if response_len == 1 {
+            return Err(MAIError::InvalidInput {
+                reason: "response_len must be 0 or large enough to hold the sync bytes".to_owned(),
+            });
+        }
+
+        self.check_link()?;
+
+        debug!("Sending {}-byte passthrough message", msg.len());
+        trace_bytes("TX", msg);
+
+        // If the mutex has been poisoned by the read thread,
+        // go ahead and attempt the transfer anyways, to try to
+        // preserve functionality, but inform the caller afterwards
+        let (conn, poisoned) = match self.conn.lock() {
+            Ok(conn) => (conn, false),
+            Err(conn) => (conn.into_inner(), true),
+        };
+
+        record_frame(&mut self.lock_frames().tx, msg);
+        conn.write(msg).map_err(|err| self.link_error(err))?;
+
+        let mut response = vec![];
+        if response_len > 0 {
+            let timeout = self.read_timeout.unwrap_or(TIMEOUT);
+            response = conn.read(response_len, timeout).map_err(|err| match err {
+                UartError::IoError {
+                    cause: ::std::io::ErrorKind::TimedOut,
+                    ..
+                } => MAIError::Timeout,
+                other => other.into(),
+            })?;
+
+            trace_bytes("RX", &response);
+            record_frame(&mut self.lock_frames().rx, &response);
+
+            if response.len() < 2 {
+                return Err(MAIError::FramingError {
+                    detail: format!("Response too short: {} bytes", response.len()),
+                });
+            }
+
+            if response[0..2] != SYNC {
+                return Err(MAIError::SyncMismatch {
+                    found: (u16::from(response[0]) << 8) | u16::from(response[1]),
+                });
+            }
+        }
+
+        if poisoned {
+            Err(MAIError::ThreadCommError)
+        } else {
+            Ok(response)
+        }
+    }
+
+    /// Request and read the MAI-400's firmware version information
+    ///
+    /// Sends the version request command and reads back the [`VERSION_LEN`]-byte response frame.
+    ///
+    /// *Note*: The MAI-400 also emits a telemetry message set every 250ms, so any concurrent
+    /// calls to [`get_message`] should be stopped while using this function.
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`MAIError`] variant will be returned.
+    /// If the response does not arrive in time, `MAIError::Timeout` will be returned.
+    /// If the response does not begin with the sync bytes, `MAIError::SyncMismatch` will be returned.
+    /// If the response fails checksum validation, `MAIError::ChecksumMismatch` will be returned.
+    /// If the response is not a version frame, `MAIError::FramingError` will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mai400_api::*;
+    /// # fn func() -> MAIResult<()> {
+    /// let mai = MAI400::new("/dev/ttyS5")?;
+    /// let version = mai.get_version()?;
+    ///
+    /// println!("Firmware {} (build {})", version.firmware, version.build);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`VERSION_LEN`]: constant.VERSION_LEN.html
+    /// [`get_message`]: #method.get_message
+    /// [`MAIError`]: enum.MAIError.html
+    pub fn get_version(&self) -> MAIResult<MAIVersion> {
+        debug!("Requesting firmware version");
+
+        let raw = frame(&RequestVersion::default());
+
+        let response = self.passthrough_with_response(&raw, VERSION_LEN)?;
+
+        verify_checksum(&response, checksum)?;
+
+        if response[2] != CommandId::RequestVersion.as_u8() {
+            return Err(MAIError::FramingError {
+                detail: format!("Unexpected response ID: {:#04X}", response[2]),
+            });
+        }
+
+        MAIVersion::new(response).ok_or_else(|| MAIError::FramingError {
+            detail: "Unable to parse version response".to_owned(),
+        })
+    }
+
+    /// Run one of the MAI-400's built-in self-tests and read back the results
+    ///
+    /// Sends the self-test command and reads back the [`SELF_TEST_LEN`]-byte results frame.
+    /// A [`SelfTestType::Full`] test moves the reaction wheels and torque rods, so it should
+    /// only be run while the ACS is in test mode.
+    ///
+    /// *Note*: The MAI-400 also emits a telemetry message set every 250ms, so any concurrent
+    /// calls to [`get_message`] should be stopped while using this function.
+    ///
+    /// # Arguments
+    ///
+    /// * test_type - The self-test to run
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`MAIError`] variant will be returned.
+    /// If the results do not arrive in time, `MAIError::Timeout` will be returned.
+    /// If the results do not begin with the sync bytes, `MAIError::SyncMismatch` will be returned.
+    /// If the results fail checksum validation, `MAIError::ChecksumMismatch` will be returned.
+    /// If the response is not a results frame for the requested test,
+    /// `MAIError::FramingError` will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mai400_api::*;
+    /// # fn func() -> MAIResult<()> {
+    /// let mai = MAI400::new("/dev/ttyS5")?;
+    /// let results = mai.run_self_test(SelfTestType::Quick)?;
+    ///
+    /// if !results.passed() {
+    ///     println!("Self-test failed: {:?}", results);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`SELF_TEST_LEN`]: constant.SELF_TEST_LEN.html
+    /// [`SelfTestType::Full`]: enum.SelfTestType.html#variant.Full
+    /// [`get_message`]: #method.get_message
+    /// [`MAIError`]: enum.MAIError.html
+    pub fn run_self_test(&self, test_type: SelfTestType) -> MAIResult<SelfTestResults> {
+        debug!("Running {:?} self-test", test_type);
+
+        let raw = frame(&RunSelfTest {
+            test_type: test_type as u8,
+            ..Default::default()
+        });
+
+        let response = self.passthrough_with_response(&raw, SELF_TEST_LEN)?;
+
+        verify_checksum(&response, checksum)?;
+
+        if response[2] != CommandId::RunSelfTest.as_u8() {
+            return Err(MAIError::FramingError {
+                detail: format!("Unexpected response ID: {:#04X}", response[2]),
+            });
+        }
+
+        if response[3] != test_type as u8 {
+            return Err(MAIError::FramingError {
+                detail: format!("Results are for test type {:#04X}", response[3]),
+            });
+        }
+
+        SelfTestResults::new(response).ok_or_else(|| MAIError::FramingError {
+            detail: "Unable to parse self-test results".to_owned(),
+        })
+    }
+
+    // Send a message, re-sending it according to the retry policy if a transient error occurs
+    fn send_message_retry<T: Message>(&self, msg: &T) -> MAIResult<()> {
+        let mut backoff = self.retry.backoff;
+        let mut result = self.send_message(msg);
+
+        for attempt in 1..=self.retry.count {
+            match result {
+                Err(MAIError::UartError {
+                    cause: UartError::GenericError,
+                }) => {
+                    warn!(
+                        "Transient UART error, retrying in {:?} ({}/{})",
+                        backoff, attempt, self.retry.count
+                    );
+                    thread::sleep(backoff);
+                    backoff *= 2;
+                    result = self.send_message(msg);
+                }
+                _ => break,
+            }
+        }
+
+        result
+    }
+
+    fn send_message<T: Message>(&self, msg: &T) -> MAIResult<()> {
+        self.check_link()?;
+
+        let raw = frame(msg);
+
+        trace_bytes("TX", &raw);
+        record_frame(&mut self.lock_frames().tx, &raw);
+
+        // If the mutex has been poisoned by the read thread,
+        // go ahead and attempt the write anyways, to try to
+        // preserve functionality, but inform the caller afterwards
+        match self.conn.lock() {
+            Ok(conn) => conn
+                .write(raw.as_slice())
+                .map_err(|err| self.link_error(err)),
+            Err(conn) => conn
+                .into_inner()
+                .write(raw.as_slice())
+                .map_err(|err| self.link_error(err))
+                .and(Err(MAIError::ThreadCommError)),
+        }
+    }
+
+    /// Wait for and read a message set from the MAI-400.
+    ///
+    /// Returns a tuple potentially containing a standard telemetry message, a raw IMU telemetry message,
+    /// and an IREHS telemetry packet.
+    ///
+    /// *Note*: Messages are sent every 250ms, so, to the human eye, this should
+    /// appear to be instantaneous
+    ///
+    /// *Note*: The MAI-400 always emits the full, fixed-length telemetry set. The device does
+    /// not offer a command for selecting a reduced (compact) field set, so there is no
+    /// frame-size negotiation to perform here.
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`MAIError`] variant will be returned.
+    /// If an auxiliary packet in the message set does not begin with its sync bytes,
+    /// `MAIError::FramingError` will be returned.
+    /// If any packet in the message set fails checksum validation, `MAIError::ChecksumMismatch`
+    /// will be returned. Any data received ahead of the sync bytes is discarded; if no sync bytes
+    /// are found within [`MAX_SYNC_DISCARD`] bytes, `MAIError::SyncLost` will be returned.
+    /// If a read timeout has been set and no message set is received within it,
+    /// `MAIError::Timeout` will be returned.
+    /// If the UART connection fails for any reason other than a timeout,
+    /// `MAIError::UartError` will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mai400_api::*;
+    /// # fn func() -> MAIResult<()> {
+    /// let mai = MAI400::new("/dev/ttyS5")?;
+    /// let (std, imu, irehs) = mai.get_message()?;
+    ///
+    /// if let Some(telem) = std {
+    ///     println!("Num successful commands: {}", telem.cmd_valid_cntr);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`MAIError`]: enum.MAIError.html
+    /// [`MAX_SYNC_DISCARD`]: constant.MAX_SYNC_DISCARD.html
+    pub fn get_message(
+        &self,
+    ) -> MAIResult<(
+        Option<StandardTelemetry>,
+        Option<RawIMU>,
+        Option<IREHSTelemetry>,
+    )> {
+        let start = Instant::now();
+        let mut msg = SYNC.to_vec();
+        let mut prev: Option<u8> = None;
+        let mut discarded = 0;
+        loop {
+            if let Some(timeout) = self.read_timeout {
+                if start.elapsed() >= timeout {
+                    count(&self.link_counters.timeouts);
+                    return Err(MAIError::Timeout);
+                }
+            }
+
+            {
+                // Take the stream connection mutex
+                // If the lock() call fails, it means that a different thread poisoned
+                // the mutex. We want to maintain our ability to read messages from the
+                // device for as long as possible, so we'll go ahead and just ignore the
+                // poisoned status. Ideally, the master thread will have detected whatever
+                // error caused the problem and will take error handling measures.
+                let conn = self.conn.lock().unwrap_or_else(|err| err.into_inner());
+                let mut rx = self.lock_rx_buffer();
+
+                // Scan for the SYNC bytes one byte at a time, so that we can recover
+                // if a byte was dropped or garbage was inserted into the stream
+                let byte = match read_buffered(&conn, &mut rx, 1, Duration::from_millis(250)) {
+                    Ok(v) => v[0],
+                    Err(err) => match err {
+                        UartError::IoError {
+                            cause: ::std::io::ErrorKind::TimedOut,
+                            ..
+                        } => continue,
+                        _ => return Err(MAIError::UartError { cause: err }),
+                    },
+                };
+
+                if prev != Some(SYNC[0]) || byte != SYNC[1] {
+                    if prev.is_some() {
+                        discarded += 1;
+                        if discarded > MAX_SYNC_DISCARD {
+                            warn!("No sync bytes found in {} bytes", discarded);
+                            count(&self.link_counters.resyncs);
+                            return Err(MAIError::SyncLost);
+                        }
+                    }
+                    prev = Some(byte);
+                    continue;
+                }
+
+                if discarded > 0 {
+                    warn!("Discarded {} bytes before sync", discarded);
+                    count(&self.link_counters.resyncs);
+                }
+
+                // Read the rest of the message
+                let mut body =
+                    match read_buffered(&conn, &mut rx, MSG_SET_LEN - SYNC.len(), TIMEOUT) {
+                        Ok(v) => v,
+                        Err(err) => match err {
+                            UartError::IoError {
+                                cause: ::std::io::ErrorKind::TimedOut,
+                                ..
+                            } => {
+                                count(&self.link_counters.timeouts);
+                                prev = None;
+                                discarded = 0;
+                                continue;
+                            }
+                            _ => return Err(MAIError::UartError { cause: err }),
+                        },
+                    };
+
+                msg.append(&mut body);
+                break;
+            }
+        }
+
+        self.parse_message_set(msg)
+    }
+
+    /// Read every complete message set currently queued in the UART buffer, returning the
+    /// standard telemetry from each
+    ///
+    /// At high telemetry rates, several message sets may have arrived by the time the device
+    /// is read. Rather than returning a single message set, like [`get_message`], this
+    /// function keeps reading until no further complete message set is available. Any trailing
+    /// partial message set is kept by the client and completed by a later read, so no
+    /// telemetry is lost.
+    ///
+    /// Unlike [`get_message`], this function does not wait for data to arrive, so an empty
+    /// list will be returned if nothing is queued. Message sets which fail checksum or
+    /// framing validation are skipped.
+    ///
+    /// # Errors
+    ///
+    /// If the connection to the device fails, an [`MAIError`] variant will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mai400_api::*;
+    /// # fn func() -> MAIResult<()> {
+    /// let mai = MAI400::new("/dev/ttyS5")?;
+    ///
+    /// for telem in mai.drain_messages()? {
+    ///     println!("Num successful commands: {}", telem.cmd_valid_cntr);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`get_message`]: #method.get_message
+    /// [`MAIError`]: enum.MAIError.html
+    pub fn drain_messages(&self) -> MAIResult<Vec<StandardTelemetry>> {
+        let mut messages = vec![];
+
+        loop {
+            let msg = match self.read_queued_message_set() {
+                Ok(Some(msg)) => msg,
+                Ok(None) | Err(MAIError::SyncLost) => break,
+                Err(err) => return Err(err),
+            };
+
+            // Bad message sets have already been reported, so just move on to the next one
+            if let Ok((Some(std), _, _)) = self.parse_message_set(msg) {
+                messages.push(std);
+            }
+        }
+
+        Ok(messages)
+    }
+
+    /// Read the standard telemetry from the next message set, if a complete one has
+    /// already been received
+    ///
+    /// Unlike [`get_message`], this function does not wait for a message set to arrive,
+    /// which allows the MAI-400 to be polled from a loop which is also doing other work.
+    /// Only bytes which have already arrived are read, so `Ok(None)` is returned without
+    /// blocking if no complete message set is available. Any partial message set is kept
+    /// by the client and completed by a later call (or by [`get_message`]).
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`MAIError`] variant will be returned.
+    /// If the message set fails checksum validation, `MAIError::ChecksumMismatch` will be
+    /// returned. If the message set is otherwise malformed, `MAIError::FramingError` will be
+    /// returned. If no sync bytes are found within [`MAX_SYNC_DISCARD`] bytes,
+    /// `MAIError::SyncLost` will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mai400_api::*;
+    /// # fn func() -> MAIResult<()> {
+    /// let mai = MAI400::new("/dev/ttyS5")?;
+    ///
+    /// loop {
+    ///     if let Some(telem) = mai.try_get_message()? {
+    ///         println!("Mode: {}", telem.acs_mode);
+    ///     }
+    ///
+    ///     // Go do other work
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`get_message`]: #method.get_message
+    /// [`MAIError`]: enum.MAIError.html
+    /// [`MAX_SYNC_DISCARD`]: constant.MAX_SYNC_DISCARD.html
+    pub fn try_get_message(&self) -> MAIResult<Option<StandardTelemetry>> {
+        let msg = match self.read_queued_message_set()? {
+            Some(msg) => msg,
+            None => return Ok(None),
+        };
+
+        let (std, _, _) = self.parse_message_set(msg)?;
+
+        std.map(Some).ok_or_else(|| MAIError::FramingError {
+            detail: "Unable to parse standard telemetry".to_owned(),
+        })
     }
 
-    /// Directly send a message without formatting or checksum calculation
+    // Read a complete message set, if one has already arrived.
+    // Bytes are read one at a time without waiting, so that a read which finds nothing
+    // can't consume part of a message set. Incomplete message sets are kept in `rx_buffer`
+    fn read_queued_message_set(&self) -> MAIResult<Option<Vec<u8>>> {
+        let conn = self.conn.lock().unwrap_or_else(|err| err.into_inner());
+        let mut rx = self.lock_rx_buffer();
+        let mut discarded = 0;
+
+        let result = loop {
+            // Realign on the next sync bytes if the stream has drifted
+            discarded += discard_before_sync(&mut rx);
+            if discarded > MAX_SYNC_DISCARD {
+                warn!("No sync bytes found in {} bytes", discarded);
+                count(&self.link_counters.resyncs);
+                return Err(MAIError::SyncLost);
+            }
+
+            if rx.len() >= MSG_SET_LEN {
+                break Ok(Some(rx.drain(..MSG_SET_LEN).collect()));
+            }
+
+            match conn.read(1, NO_WAIT) {
+                Ok(byte) => rx.extend(byte),
+                Err(UartError::IoError {
+                    cause: ::std::io::ErrorKind::TimedOut,
+                    ..
+                }) => break Ok(None),
+                Err(err) => break Err(err.into()),
+            }
+        };
+
+        if discarded > 0 {
+            warn!("Discarded {} bytes before sync", discarded);
+            count(&self.link_counters.resyncs);
+        }
+
+        result
+    }
+
+    // Validate a complete message set and split it into its component messages
+    fn parse_message_set(
+        &self,
+        mut msg: Vec<u8>,
+    ) -> MAIResult<(
+        Option<StandardTelemetry>,
+        Option<RawIMU>,
+        Option<IREHSTelemetry>,
+    )> {
+        trace_bytes("RX", &msg);
+        record_frame(&mut self.lock_frames().rx, &msg);
+        count(&self.link_counters.frames_received);
+
+        // Make sure none of the packets were corrupted in transit.
+        // The auxiliary packets' sync bytes aren't covered by their checksums,
+        // so they need to be checked separately
+        let imu_start = msg.len() - 21;
+        let irehs_start = imu_start - 56;
+        verify_aux_sync("IREHS", &msg[irehs_start..])?;
+        verify_aux_sync("Raw IMU", &msg[imu_start..])?;
+        verify_checksum(&msg[..irehs_start], checksum)
+            .and_then(|_| verify_checksum(&msg[(irehs_start + 2)..imu_start], aux_checksum))
+            .and_then(|_| verify_checksum(&msg[(imu_start + 2)..], aux_checksum))
+            .map_err(|err| {
+                count(&self.link_counters.checksum_failures);
+                err
+            })?;
+
+        // Pull out raw IMU message
+        let len = msg.len();
+        let imu = msg.split_off(len - 21);
+        let imu = RawIMU::new(imu);
+
+        // Pull out IREHS telemetry message
+        let len = msg.len();
+        let irehs = msg.split_off(len - 56);
+        let irehs = IREHSTelemetry::new(irehs);
+
+        // Process remaining bytes as standard telemetry message
+        let std = StandardTelemetry::new(msg);
+        if let Some(ref telem) = std {
+            self.last_gps_time.store(telem.gps_time, Ordering::Relaxed);
+        }
+
+        Ok((std, imu, irehs))
+    }
+
+    /// Read the ACS mode the MAI-400 is currently in
+    ///
+    /// The mode is taken from the next standard telemetry message, so this can be used to
+    /// confirm that a commanded mode transition has completed
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`MAIError`] variant will be returned.
+    /// If the standard telemetry message could not be parsed, `MAIError::FramingError`
+    /// will be returned.
+    /// If the reported mode is not a known ACS mode, `MAIError::UnknownMode` will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mai400_api::*;
+    /// # fn func() -> MAIResult<()> {
+    /// let mai = MAI400::new("/dev/ttyS5")?;
+    /// mai.set_acs_mode(AcsMode::NadirPointing, [0, 0, 0, 0])?;
+    ///
+    /// if mai.get_mode()? != AcsMode::NadirPointing {
+    ///     println!("Mode change was rejected");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`MAIError`]: enum.MAIError.html
+    pub fn get_mode(&self) -> MAIResult<AcsMode> {
+        let mode = self.get_std_telemetry()?.acs_mode;
+
+        AcsMode::from_u8(mode).ok_or(MAIError::UnknownMode { mode })
+    }
+
+    /// Check whether the MAI-400 accepted the most recently sent command
+    ///
+    /// The MAI-400 does not send a direct response to commands. Instead, each standard
+    /// telemetry message contains counters of the valid, invalid, and bad-checksum commands
+    /// it has received. The counters in the next standard telemetry message are compared
+    /// against those in `before`, which should be a message received before the command was sent.
+    ///
+    /// `CommandStatus::NoChange` is returned if none of the counters have moved, which
+    /// usually means that the command had not yet been processed when the telemetry
+    /// message was generated.
+    ///
+    /// Only a message which post-dates `before` can acknowledge the command. Messages whose
+    /// device time (see [`StandardTelemetry::device_time`]) is at or before that of `before`
+    /// were buffered before the command was sent, so they are skipped. Up to [`ack_window`]
+    /// messages are read; if none of them post-date `before`, `CommandStatus::NoChange` is
+    /// returned. Commands which move the device clock backwards, such as [`set_gps_time`] with
+    /// an earlier time, can't be correlated this way and will also report
+    /// `CommandStatus::NoChange`.
     ///
     /// # Arguments
     ///
-    /// * msg - Message to send
+    /// * before - Standard telemetry received before the command was sent
     ///
     /// # Errors
     ///
     /// If this function encounters any errors, an [`MAIError`] variant will be returned.
+    /// If the standard telemetry message could not be parsed, `MAIError::FramingError`
+    /// will be returned.
+    /// If the command failed verification or was received with a bad checksum,
+    /// `MAIError::CommandRejected` will be returned.
     ///
     /// # Examples
     ///
@@ -254,67 +1745,66 @@ impl MAI400 {
     /// # use mai400_api::*;
     /// # fn func() -> MAIResult<()> {
     /// let mai = MAI400::new("/dev/ttyS5")?;
+    /// let (before, _, _) = mai.get_message()?;
     ///
-    /// let mut array = [0; 8];
-    /// array[0] = 0x90; // SYNC byte 1
-    /// array[1] = 0xEB; // SYNC byte 2
-    /// array[2] = 0x0;  // Data_len byte 1
-    /// array[3] = 0x0;  // Data_len byte 2
-    /// array[4] = 0x5A; // Msg_id
-    /// array[5] = 0x0;  // Addr
-    /// array[6] = 0x00; // CRC byte 1
-    /// array[7] = 0x00; // CRC byte 2
+    /// mai.set_gps_time(1198800018)?;
     ///
-    /// mai.passthrough(&array)?;
+    /// if let Some(before) = before {
+    ///     mai.confirm_command(&before)?;
+    /// }
     /// # Ok(())
     /// # }
     /// ```
     ///
     /// [`MAIError`]: enum.MAIError.html
-    pub fn passthrough(&self, msg: &[u8]) -> MAIResult<()> {
-        // If the mutex has been poisoned by the read thread,
-        // go ahead and attempt the write anyways, to try to
-        // preserve functionality, but inform the caller afterwards
-        match self.conn.lock() {
-            Ok(conn) => conn.write(msg).map_err(|err| err.into()),
-            Err(conn) => conn
-                .into_inner()
-                .write(msg)
-                .map_err(|err| err.into())
-                .and(Err(MAIError::ThreadCommError)),
+    /// [`StandardTelemetry::device_time`]: struct.StandardTelemetry.html#method.device_time
+    /// [`ack_window`]: #structfield.ack_window
+    /// [`set_gps_time`]: #method.set_gps_time
+    pub fn confirm_command(&self, before: &StandardTelemetry) -> MAIResult<CommandStatus> {
+        match self.get_ack_telemetry(before)? {
+            Some(after) => check_command(before, &after, None),
+            None => Ok(CommandStatus::NoChange),
         }
     }
 
-    fn send_message<T: Message>(&self, msg: &T) -> MAIResult<()> {
-        let mut raw = msg.serialize();
+    // Read standard telemetry until a message which post-dates `before` arrives.
+    // Returns `None` if none did within the acknowledgment window
+    fn get_ack_telemetry(
+        &self,
+        before: &StandardTelemetry,
+    ) -> MAIResult<Option<StandardTelemetry>> {
+        let sent_after = before.device_time();
 
-        // Get the calculated CRC
-        let mut crc: u16 = 0;
-        for byte in raw.iter() {
-            crc += u16::from(*byte);
-        }
-        raw.write_u16::<LittleEndian>(crc).unwrap();
+        for _ in 0..self.ack_window.max(1) {
+            let after = self.get_std_telemetry()?;
+            if after.device_time() > sent_after {
+                return Ok(Some(after));
+            }
 
-        // If the mutex has been poisoned by the read thread,
-        // go ahead and attempt the write anyways, to try to
-        // preserve functionality, but inform the caller afterwards
-        match self.conn.lock() {
-            Ok(conn) => conn.write(raw.as_slice()).map_err(|err| err.into()),
-            Err(conn) => conn
-                .into_inner()
-                .write(raw.as_slice())
-                .map_err(|err| err.into())
-                .and(Err(MAIError::ThreadCommError)),
+            debug!(
+                "Skipping stale telemetry from {:?}, command sent after {:?}",
+                after.device_time(),
+                sent_after
+            );
         }
+
+        Ok(None)
     }
 
-    /// Wait for and read a message set from the MAI-400.
-    ///
-    /// Returns a tuple potentially containing a standard telemetry message, a raw IMU telemetry message,
-    /// and an IREHS telemetry packet.
+    // Read the standard telemetry from the next message set
+    pub(crate) fn get_std_telemetry(&self) -> MAIResult<StandardTelemetry> {
+        let (std, _, _) = self.get_message()?;
+
+        std.ok_or_else(|| MAIError::FramingError {
+            detail: "Unable to parse standard telemetry".to_owned(),
+        })
+    }
+
+    /// Wait for and read a message set from the MAI-400, returning each successfully
+    /// parsed message as a separate [`MAIMessage`] so that callers can match on the message type
     ///
-    /// *Note*: Messages are sent every 250ms, so, to the human eye, this should
-    /// appear to be instantaneous
+    /// Messages are returned in the order they appear in the message set: standard telemetry,
+    /// IREHS telemetry, and then raw IMU data
     ///
     /// # Errors
     ///
@@ -326,86 +1816,339 @@ impl MAI400 {
     /// # use mai400_api::*;
     /// # fn func() -> MAIResult<()> {
     /// let mai = MAI400::new("/dev/ttyS5")?;
-    /// let (std, imu, irehs) = mai.get_message()?;
     ///
-    /// if let Some(telem) = std {
-    ///     println!("Num successful commands: {}", telem.cmd_valid_cntr);
+    /// for msg in mai.get_messages()? {
+    ///     match msg {
+    ///         MAIMessage::StandardTelemetry(std) => println!("Mode: {}", std.acs_mode),
+    ///         MAIMessage::IREHSTelemetry(irehs) => println!("Dip A: {}", irehs.dip_angle_a),
+    ///         MAIMessage::RawIMU(imu) => println!("Gyro: {:?}", imu.gyro),
+    ///     }
     /// }
     /// # Ok(())
     /// # }
     /// ```
     ///
+    /// [`MAIMessage`]: enum.MAIMessage.html
     /// [`MAIError`]: enum.MAIError.html
-    pub fn get_message(
-        &self,
-    ) -> MAIResult<(
-        Option<StandardTelemetry>,
-        Option<RawIMU>,
-        Option<IREHSTelemetry>,
-    )> {
-        let mut msg = vec![];
-        loop {
-            {
-                // Take the stream connection mutex
-                // If the lock() call fails, it means that a different thread poisoned
-                // the mutex. We want to maintain our ability to read messages from the
-                // device for as long as possible, so we'll go ahead and just ignore the
-                // poisoned status. Ideally, the master thread will have detected whatever
-                // error caused the problem and will take error handling measures.
-                let conn = self.conn.lock().unwrap_or_else(|err| err.into_inner());
+    pub fn get_messages(&self) -> MAIResult<Vec<MAIMessage>> {
+        let (std, imu, irehs) = self.get_message()?;
 
-                // Read SYNC bytes
-                let mut sync = match conn.read(2, Duration::from_millis(250)) {
-                    Ok(v) => v,
-                    Err(err) => match err {
-                        #[cfg(test)]
-                        UartError::GenericError => return Err(MAIError::GenericError),
-                        UartError::IoError {
-                            cause: ::std::io::ErrorKind::TimedOut,
-                            ..
-                        } => continue,
-                        _ => panic!("{}", err),
-                    },
-                };
+        let mut messages = vec![];
+        if let Some(std) = std {
+            messages.push(MAIMessage::StandardTelemetry(std));
+        }
+        if let Some(irehs) = irehs {
+            messages.push(MAIMessage::IREHSTelemetry(irehs));
+        }
+        if let Some(imu) = imu {
+            messages.push(MAIMessage::RawIMU(imu));
+        }
 
-                if sync != SYNC {
-                    continue;
-                }
+        Ok(messages)
+    }
+}
 
-                msg.append(&mut sync);
+/// A single message read from the MAI-400
+#[derive(Clone, Debug, PartialEq)]
+pub enum MAIMessage {
+    /// Standard telemetry
+    StandardTelemetry(StandardTelemetry),
+    /// IR Earth Horizon Sensor telemetry
+    IREHSTelemetry(IREHSTelemetry),
+    /// Raw IMU data
+    RawIMU(RawIMU),
+}
 
-                // Read the rest of the message
-                let mut body = match conn.read(236, TIMEOUT) {
-                    Ok(v) => v,
-                    Err(err) => match err {
-                        UartError::IoError {
-                            cause: ::std::io::ErrorKind::TimedOut,
-                            ..
-                        } => continue,
-                        _ => panic!("{}", err),
-                    },
-                };
+/// ACS modes supported by the MAI-400
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AcsMode {
+    /// Test mode
+    TestMode = 0,
+    /// Rate nulling
+    RateNulling = 1,
+    /// Reserved for future use
+    Reserved1 = 2,
+    /// Nadir pointing (normal mode)
+    NadirPointing = 3,
+    /// Lat/Long pointing
+    LatLongPointing = 4,
+    /// QbX mode
+    QbxMode = 5,
+    /// Reserved for future use
+    Reserved2 = 6,
+    /// Normal sun (nadir with sun rotation)
+    NormalSun = 7,
+    /// Lat/long sun
+    LatLongSun = 8,
+    /// Qinertial
+    Qinertial = 9,
+    /// Reserved for future use
+    Reserved3 = 10,
+    /// QTable
+    Qtable = 11,
+    /// Sun-Ram
+    SunRam = 12,
+}
 
-                msg.append(&mut body);
-                break;
-            }
+impl AcsMode {
+    /// Get the raw mode byte sent to the MAI-400
+    pub fn as_u8(self) -> u8 {
+        self as u8
+    }
+
+    /// Whether this mode is reserved for future use
+    ///
+    /// Reserved modes can be decoded from telemetry, but are rejected by [`MAI400::set_mode`]
+    ///
+    /// [`MAI400::set_mode`]: struct.MAI400.html#method.set_mode
+    pub fn is_reserved(self) -> bool {
+        matches!(
+            self,
+            AcsMode::Reserved1 | AcsMode::Reserved2 | AcsMode::Reserved3
+        )
+    }
+
+    /// Convert a raw mode byte reported by the MAI-400 into an `AcsMode` value
+    ///
+    /// Returns `None` if the byte does not correspond to a known mode
+    pub fn from_u8(mode: u8) -> Option<AcsMode> {
+        match mode {
+            0 => Some(AcsMode::TestMode),
+            1 => Some(AcsMode::RateNulling),
+            2 => Some(AcsMode::Reserved1),
+            3 => Some(AcsMode::NadirPointing),
+            4 => Some(AcsMode::LatLongPointing),
+            5 => Some(AcsMode::QbxMode),
+            6 => Some(AcsMode::Reserved2),
+            7 => Some(AcsMode::NormalSun),
+            8 => Some(AcsMode::LatLongSun),
+            9 => Some(AcsMode::Qinertial),
+            10 => Some(AcsMode::Reserved3),
+            11 => Some(AcsMode::Qtable),
+            12 => Some(AcsMode::SunRam),
+            _ => None,
         }
+    }
+}
 
-        // Pull out raw IMU message
-        let len = msg.len();
-        let imu = msg.split_off(len - 21);
-        let imu = RawIMU::new(imu);
+/// Likely reason for the MAI-400 rejecting a mode transition
+///
+/// The MAI-400 only reports that a command failed verification, not why. The reason is
+/// inferred from the standard telemetry received after the rejection, so it should be
+/// treated as a diagnostic hint rather than a definitive answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ModeRejectReason {
+    /// The requested mode needs an attitude estimate, and none is available
+    /// (the estimated orbit-to-body quaternion is zero)
+    InsufficientKnowledge,
+    /// The magnetometer or sun sensors are not producing readings
+    SensorFault,
+    /// No sensor or knowledge problem was found, so the mode arguments were likely invalid
+    InvalidParams,
+    /// No likely reason could be determined
+    Unknown,
+}
 
-        // Pull out IREHS telemetry message
-        let len = msg.len();
-        let irehs = msg.split_off(len - 56);
-        let irehs = IREHSTelemetry::new(irehs);
+impl ModeRejectReason {
+    /// Infer why a transition into `mode` was rejected
+    ///
+    /// # Arguments
+    ///
+    /// * mode - The ACS mode which was requested
+    /// * params - The arguments sent with the mode request
+    /// * after - Standard telemetry received after the command was rejected
+    pub fn from_telemetry(
+        mode: AcsMode,
+        params: [i16; 4],
+        after: &StandardTelemetry,
+    ) -> ModeRejectReason {
+        let needs_attitude = !matches!(mode, AcsMode::TestMode | AcsMode::RateNulling);
 
-        // Process remaining bytes as standard telemetry message
-        let std = StandardTelemetry::new(msg);
+        if needs_attitude && after.qbo_hat == [0; 4] {
+            ModeRejectReason::InsufficientKnowledge
+        } else if after.i_b_field_meas == [0; 3] || (after.eclipse_flag == 0 && after.css == [0; 6])
+        {
+            ModeRejectReason::SensorFault
+        } else if params != [0; 4] {
+            ModeRejectReason::InvalidParams
+        } else {
+            ModeRejectReason::Unknown
+        }
+    }
+}
 
-        Ok((std, imu, irehs))
+/// Outcome of a command, as reported by the command counters in the MAI-400's standard telemetry
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CommandStatus {
+    /// The valid command counter advanced
+    Accepted,
+    /// The invalid command counter advanced. The command did not pass verification
+    Invalid,
+    /// The invalid checksum counter advanced. The command was corrupted in transit
+    BadChecksum,
+    /// None of the command counters changed
+    NoChange,
+}
+
+impl CommandStatus {
+    /// Determine the outcome of a command by comparing the command counters in standard
+    /// telemetry received before and after the command was sent
+    ///
+    /// The counters are compared for inequality rather than ordering, since they wrap.
+    pub fn from_telemetry(before: &StandardTelemetry, after: &StandardTelemetry) -> CommandStatus {
+        if after.cmd_invalid_chksum_cntr != before.cmd_invalid_chksum_cntr {
+            CommandStatus::BadChecksum
+        } else if after.cmd_invalid_cntr != before.cmd_invalid_cntr {
+            CommandStatus::Invalid
+        } else if after.cmd_valid_cntr != before.cmd_valid_cntr {
+            CommandStatus::Accepted
+        } else {
+            CommandStatus::NoChange
+        }
+    }
+}
+
+// Compare command counters, attaching `reason` if the command failed verification
+fn check_command(
+    before: &StandardTelemetry,
+    after: &StandardTelemetry,
+    reason: Option<ModeRejectReason>,
+) -> MAIResult<CommandStatus> {
+    match CommandStatus::from_telemetry(before, after) {
+        CommandStatus::Invalid => {
+            warn!(
+                "Command rejected: {:?} ({:?})",
+                CommandStatus::Invalid,
+                reason
+            );
+            Err(MAIError::CommandRejected {
+                status: CommandStatus::Invalid,
+                reason,
+            })
+        }
+        CommandStatus::BadChecksum => {
+            warn!("Command rejected: {:?}", CommandStatus::BadChecksum);
+            Err(MAIError::CommandRejected {
+                status: CommandStatus::BadChecksum,
+                reason: None,
+            })
+        }
+        status => Ok(status),
+    }
+}
+
+// Reject ACS mode bytes which don't correspond to a documented, commandable mode
+fn check_mode(mode: u8) -> MAIResult<()> {
+    match AcsMode::from_u8(mode) {
+        Some(acs_mode) if acs_mode.is_reserved() => Err(MAIError::InvalidInput {
+            reason: format!("Reserved ACS mode: {}", mode),
+        }),
+        Some(_) => Ok(()),
+        None => Err(MAIError::InvalidInput {
+            reason: format!("Unknown ACS mode: {}", mode),
+        }),
+    }
+}
+
+// Normalize a commanded quaternion and convert it into the fixed-point values sent
+// to the MAI-400
+fn quaternion_params(q: [f32; 4]) -> MAIResult<[i16; 4]> {
+    if q.iter().any(|elem| !elem.is_finite()) {
+        return Err(MAIError::InvalidInput {
+            reason: format!("Quaternion components must be finite: {:?}", q),
+        });
+    }
+
+    let norm = q.iter().map(|elem| elem * elem).sum::<f32>().sqrt();
+    if norm == 0.0 || !norm.is_finite() {
+        return Err(MAIError::InvalidInput {
+            reason: format!("Quaternion must have a non-zero, finite norm: {:?}", q),
+        });
+    }
+
+    let mut params = [0; 4];
+    for (param, elem) in params.iter_mut().zip(q.iter()) {
+        *param = (elem / norm * QUATERNION_LSB_DIVISOR).round() as i16;
+    }
+
+    Ok(params)
+}
+
+// Compare the checksum in the last two bytes of a packet against the one
+// calculated from the rest of the packet
+fn verify_checksum(packet: &[u8], calc: fn(&[u8]) -> u16) -> MAIResult<()> {
+    let len = packet.len() - 2;
+    let expected = calc(&packet[..len]);
+    let found = u16::from(packet[len]) | (u16::from(packet[len + 1]) << 8);
+
+    if expected == found {
+        Ok(())
+    } else {
+        warn!(
+            "Checksum mismatch: expected {:#06X}, found {:#06X}",
+            expected, found
+        );
+        Err(MAIError::ChecksumMismatch { expected, found })
+    }
+}
+
+// Make sure an auxiliary packet starts with the auxiliary sync bytes (0x91EA)
+fn verify_aux_sync(name: &str, packet: &[u8]) -> MAIResult<()> {
+    let found = u16::from(packet[0]) | (u16::from(packet[1]) << 8);
+
+    if found == AUX_SYNC {
+        Ok(())
+    } else {
+        warn!("{} packet sync mismatch: found {:#06X}", name, found);
+        Err(MAIError::FramingError {
+            detail: format!("{} packet has bad sync bytes: {:#06X}", name, found),
+        })
+    }
+}
+
+// Log a raw buffer as hex. Skipped entirely unless trace logging is enabled,
+// and formatted in place so that no intermediate string is allocated
+fn trace_bytes(direction: &str, data: &[u8]) {
+    if log_enabled!(Level::Trace) {
+        trace!("{}: {}", direction, HexBytes(data));
+    }
+}
+
+struct HexBytes<'a>(&'a [u8]);
+
+impl<'a> fmt::Display for HexBytes<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for byte in self.0 {
+            write!(f, "{:02X}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+// Make sure each component of a vector is a usable value
+fn check_components(name: &str, values: &[f32], max: f32) -> MAIResult<()> {
+    for (index, value) in values.iter().enumerate() {
+        if !value.is_finite() || value.abs() > max {
+            return Err(MAIError::InvalidInput {
+                reason: format!("{}[{}] must be finite and within +/-{}", name, index, max),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+// Make sure each component of a vector is a real number
+fn check_finite(name: &str, values: &[f32]) -> MAIResult<()> {
+    for (index, value) in values.iter().enumerate() {
+        if !value.is_finite() {
+            return Err(MAIError::InvalidInput {
+                reason: format!("{}[{}] must be finite", name, index),
+            });
+        }
     }
+
+    Ok(())
 }
 
 /// Common Error for MAI Actions
@@ -423,6 +2166,62 @@ pub enum MAIError {
         /// ID of message received
         id: u16,
     },
+    /// The device reported an ACS mode which doesn't match any known mode
+    #[fail(display = "Unknown ACS Mode: {}", mode)]
+    UnknownMode {
+        /// Raw mode byte reported
+        mode: u8,
+    },
+    /// A received packet's checksum did not match the one calculated from its contents
+    #[fail(
+        display = "Checksum Mismatch: Expected {:#06X}, Found {:#06X}",
+        expected, found
+    )]
+    ChecksumMismatch {
+        /// Checksum calculated from the received packet
+        expected: u16,
+        /// Checksum contained in the received packet
+        found: u16,
+    },
+    /// A received message set was not laid out as expected
+    #[fail(display = "Framing Error: {}", detail)]
+    FramingError {
+        /// Description of the problem
+        detail: String,
+    },
+    /// The MAI-400 reported that it rejected a command
+    #[fail(display = "Command Rejected: {:?} ({:?})", status, reason)]
+    CommandRejected {
+        /// Reason the command was rejected
+        status: CommandStatus,
+        /// Likely reason a mode transition was rejected.
+        /// Only populated by [`set_mode_confirmed`](struct.MAI400.html#method.set_mode_confirmed)
+        /// when the command failed verification
+        reason: Option<ModeRejectReason>,
+    },
+    /// The MAI-400's command counters didn't change after a command was sent
+    #[fail(display = "Command Not Acknowledged")]
+    NotAcknowledged,
+    /// A command argument was outside of its accepted range
+    #[fail(display = "Invalid Input: {}", reason)]
+    InvalidInput {
+        /// Description of the problem
+        reason: String,
+    },
+    /// No sync bytes were found within [`MAX_SYNC_DISCARD`] bytes of received data
+    ///
+    /// [`MAX_SYNC_DISCARD`]: constant.MAX_SYNC_DISCARD.html
+    #[fail(display = "Unable to find sync bytes")]
+    SyncLost,
+    /// A response from the device did not begin with the expected sync bytes (0x90EB)
+    #[fail(display = "Sync Mismatch: Found {:#06X}", found)]
+    SyncMismatch {
+        /// First two bytes of the response
+        found: u16,
+    },
+    /// No message was received from the device within the configured read timeout
+    #[fail(display = "Timed out waiting for message")]
+    Timeout,
     /// An error was thrown by the serial communication driver
     #[fail(display = "UART Error")]
     UartError {
@@ -438,5 +2237,13 @@ impl From<UartError> for MAIError {
     }
 }
 
+impl From<ShortFrame> for MAIError {
+    fn from(error: ShortFrame) -> Self {
+        MAIError::FramingError {
+            detail: error.to_string(),
+        }
+    }
+}
+
 /// Custom error type for MAI400 operations.
 pub type MAIResult<T> = Result<T, MAIError>;
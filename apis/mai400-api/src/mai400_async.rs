@@ -0,0 +1,293 @@
+/*
+ * Copyright (C) 2018 Kubos Corporation
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::mai400::*;
+use crate::messages::*;
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+
+/// Structure for a non-blocking MAI-400 device instance
+///
+/// All frame-building and checksum logic is shared with [`MAI400`].
+///
+/// Runtime assumptions:
+///
+/// - The client is executor-agnostic. It does not depend on tokio (or any other runtime)
+///   and may be awaited from any executor which honors the standard `Waker` contract.
+/// - The underlying UART transport is the same blocking [`Connection`] used by [`MAI400`].
+///   Each request is run to completion on its own short-lived OS thread, so the executor's
+///   worker threads are never blocked by device I/O.
+/// - Requests made through clones of the same client share a single connection and are
+///   serialized by its mutex, exactly as they would be with the synchronous client.
+///
+/// Only available when the `async` feature is enabled.
+///
+/// [`Connection`]: struct.Connection.html
+/// [`MAI400`]: struct.MAI400.html
+#[derive(Clone)]
+pub struct MAI400Async {
+    /// Underlying synchronous client
+    pub inner: MAI400,
+}
+
+impl MAI400Async {
+    /// Constructor for MAI400Async structure
+    ///
+    /// # Arguments
+    ///
+    /// * bus - The UART bus the device is connected to
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mai400_api::*;
+    ///
+    /// # fn func() -> MAIResult<()> {
+    /// let mai = MAI400Async::new("/dev/ttyS5")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn new(bus: &str) -> MAIResult<MAI400Async> {
+        Ok(MAI400Async {
+            inner: MAI400::new(bus)?,
+        })
+    }
+
+    /// Request a hardware reset of the MAI-400
+    ///
+    /// See [`MAI400::reset`](struct.MAI400.html#method.reset)
+    pub async fn reset(&self) -> MAIResult<()> {
+        let mai = self.inner.clone();
+        blocking(move || mai.reset()).await
+    }
+
+    /// Set the ACS mode
+    ///
+    /// See [`MAI400::set_mode`](struct.MAI400.html#method.set_mode)
+    pub async fn set_mode(&self, mode: u8, params: [i16; 4]) -> MAIResult<()> {
+        let mai = self.inner.clone();
+        blocking(move || mai.set_mode(mode, params)).await
+    }
+
+    /// Set the ACS mode, without checking the mode value
+    ///
+    /// See [`MAI400::set_mode_unchecked`](struct.MAI400.html#method.set_mode_unchecked)
+    pub async fn set_mode_unchecked(&self, mode: u8, params: [i16; 4]) -> MAIResult<()> {
+        let mai = self.inner.clone();
+        blocking(move || mai.set_mode_unchecked(mode, params)).await
+    }
+
+    /// Set the ACS mode and confirm that the command was accepted
+    ///
+    /// See [`MAI400::set_mode_confirmed`](struct.MAI400.html#method.set_mode_confirmed)
+    pub async fn set_mode_confirmed(&self, mode: u8, params: [i16; 4]) -> MAIResult<CommandStatus> {
+        let mai = self.inner.clone();
+        blocking(move || mai.set_mode_confirmed(mode, params)).await
+    }
+
+    /// Set the ACS mode
+    ///
+    /// See [`MAI400::set_acs_mode`](struct.MAI400.html#method.set_acs_mode)
+    pub async fn set_acs_mode(&self, mode: AcsMode, params: [i16; 4]) -> MAIResult<()> {
+        let mai = self.inner.clone();
+        blocking(move || mai.set_acs_mode(mode, params)).await
+    }
+
+    /// Slew to and hold a target attitude quaternion
+    ///
+    /// See [`MAI400::set_target_quaternion`](struct.MAI400.html#method.set_target_quaternion)
+    pub async fn set_target_quaternion(&self, q: [f32; 4]) -> MAIResult<()> {
+        let mai = self.inner.clone();
+        blocking(move || mai.set_target_quaternion(q)).await
+    }
+
+    /// Set the ACS mode (Normal-Sun or Lat/Long-Sun)
+    ///
+    /// See [`MAI400::set_mode_sun`](struct.MAI400.html#method.set_mode_sun)
+    pub async fn set_mode_sun(
+        &self,
+        mode: u8,
+        sun_angle_enable: i16,
+        sun_rot_angle: f32,
+    ) -> MAIResult<()> {
+        let mai = self.inner.clone();
+        blocking(move || mai.set_mode_sun(mode, sun_angle_enable, sun_rot_angle)).await
+    }
+
+    /// Set the ADACS clock with the desired GPS time
+    ///
+    /// See [`MAI400::set_gps_time`](struct.MAI400.html#method.set_gps_time)
+    pub async fn set_gps_time(&self, gps_time: u32) -> MAIResult<()> {
+        let mai = self.inner.clone();
+        blocking(move || mai.set_gps_time(gps_time)).await
+    }
+
+    /// Set orbital position and velocity at epoch for RK4 integration method of orbit propagation
+    ///
+    /// See [`MAI400::set_rv`](struct.MAI400.html#method.set_rv)
+    pub async fn set_rv(
+        &self,
+        eci_pos: [f32; 3],
+        eci_vel: [f32; 3],
+        time_epoch: u32,
+    ) -> MAIResult<()> {
+        let mai = self.inner.clone();
+        blocking(move || mai.set_rv(eci_pos, eci_vel, time_epoch)).await
+    }
+
+    /// Upload magnetometer and gyro bias corrections
+    ///
+    /// See [`MAI400::set_calibration`](struct.MAI400.html#method.set_calibration)
+    pub async fn set_calibration(&self, mag_bias: [f32; 3], gyro_bias: [f32; 3]) -> MAIResult<()> {
+        let mai = self.inner.clone();
+        blocking(move || mai.set_calibration(mag_bias, gyro_bias)).await
+    }
+
+    /// Directly send a message without formatting or checksum calculation
+    ///
+    /// See [`MAI400::passthrough`](struct.MAI400.html#method.passthrough)
+    pub async fn passthrough(&self, msg: Vec<u8>) -> MAIResult<()> {
+        let mai = self.inner.clone();
+        blocking(move || mai.passthrough(&msg)).await
+    }
+
+    /// Check whether the MAI-400 accepted the most recently sent command
+    ///
+    /// See [`MAI400::confirm_command`](struct.MAI400.html#method.confirm_command)
+    pub async fn confirm_command(&self, before: &StandardTelemetry) -> MAIResult<CommandStatus> {
+        let mai = self.inner.clone();
+        let before = before.clone();
+        blocking(move || mai.confirm_command(&before)).await
+    }
+
+    /// Read the ACS mode the MAI-400 is currently in
+    ///
+    /// See [`MAI400::get_mode`](struct.MAI400.html#method.get_mode)
+    pub async fn get_mode(&self) -> MAIResult<AcsMode> {
+        let mai = self.inner.clone();
+        blocking(move || mai.get_mode()).await
+    }
+
+    /// Request and read the MAI-400's firmware version information
+    ///
+    /// See [`MAI400::get_version`](struct.MAI400.html#method.get_version)
+    pub async fn get_version(&self) -> MAIResult<MAIVersion> {
+        let mai = self.inner.clone();
+        blocking(move || mai.get_version()).await
+    }
+
+    /// Run one of the MAI-400's built-in self-tests and read back the results
+    ///
+    /// See [`MAI400::run_self_test`](struct.MAI400.html#method.run_self_test)
+    pub async fn run_self_test(&self, test_type: SelfTestType) -> MAIResult<SelfTestResults> {
+        let mai = self.inner.clone();
+        blocking(move || mai.run_self_test(test_type)).await
+    }
+
+    /// Wait for and read a message set from the MAI-400
+    ///
+    /// See [`MAI400::get_message`](struct.MAI400.html#method.get_message)
+    pub async fn get_message(
+        &self,
+    ) -> MAIResult<(
+        Option<StandardTelemetry>,
+        Option<RawIMU>,
+        Option<IREHSTelemetry>,
+    )> {
+        let mai = self.inner.clone();
+        blocking(move || mai.get_message()).await
+    }
+
+    /// Read every complete message set queued in the UART buffer
+    ///
+    /// See [`MAI400::drain_messages`](struct.MAI400.html#method.drain_messages)
+    pub async fn drain_messages(&self) -> MAIResult<Vec<StandardTelemetry>> {
+        let mai = self.inner.clone();
+        blocking(move || mai.drain_messages()).await
+    }
+
+    /// Read the next message set from the MAI-400, if one is already available
+    ///
+    /// See [`MAI400::try_get_message`](struct.MAI400.html#method.try_get_message)
+    pub async fn try_get_message(&self) -> MAIResult<Option<StandardTelemetry>> {
+        let mai = self.inner.clone();
+        blocking(move || mai.try_get_message()).await
+    }
+}
+
+impl From<MAI400> for MAI400Async {
+    fn from(inner: MAI400) -> Self {
+        MAI400Async { inner }
+    }
+}
+
+struct Shared<T> {
+    result: Option<MAIResult<T>>,
+    waker: Option<Waker>,
+}
+
+// Future which resolves once a blocking request has completed on its helper thread
+pub(crate) struct Blocking<T> {
+    shared: Arc<Mutex<Shared<T>>>,
+}
+
+impl<T> Future for Blocking<T> {
+    type Output = MAIResult<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut shared = self.shared.lock().unwrap_or_else(|err| err.into_inner());
+
+        match shared.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                shared.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+// Run a blocking request on a helper thread.
+// If the request panics, the future resolves to `MAIError::ThreadCommError`
+pub(crate) fn blocking<T, F>(request: F) -> Blocking<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> MAIResult<T> + Send + 'static,
+{
+    let shared = Arc::new(Mutex::new(Shared {
+        result: None,
+        waker: None,
+    }));
+    let thread_shared = shared.clone();
+
+    thread::spawn(move || {
+        let result = panic::catch_unwind(AssertUnwindSafe(request))
+            .unwrap_or(Err(MAIError::ThreadCommError));
+
+        let mut shared = thread_shared.lock().unwrap_or_else(|err| err.into_inner());
+        shared.result = Some(result);
+        if let Some(waker) = shared.waker.take() {
+            waker.wake();
+        }
+    });
+
+    Blocking { shared }
+}
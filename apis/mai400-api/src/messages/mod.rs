@@ -17,8 +17,42 @@
 pub mod rx;
 mod tx;
 
+use crc16::{State, ARC};
+
 pub use self::rx::*;
 pub use self::tx::*;
 
 /// IRIG-106 sync word
 pub const SYNC: [u8; 2] = [0x90, 0xEB];
+
+/// Length of every command message sent to the MAI-400, including the sync and checksum bytes
+pub const CMD_LEN: usize = 40;
+
+/// Calculate the checksum used by the MAI-400 for command messages and
+/// standard telemetry packets: the sum of all bytes, including the sync bytes
+///
+/// # Examples
+///
+/// ```
+/// use mai400_api::*;
+///
+/// assert_eq!(checksum(&[0x90, 0xEB, 0x01, 0x02]), 0x017E);
+/// ```
+pub fn checksum(data: &[u8]) -> u16 {
+    data.iter()
+        .fold(0u16, |sum, byte| sum.wrapping_add(u16::from(*byte)))
+}
+
+/// Calculate the CRC-16 (ARC) used by the MAI-400 for the auxiliary IREHS and raw IMU
+/// telemetry packets. The auxiliary sync bytes are not included in the calculation
+///
+/// # Examples
+///
+/// ```
+/// use mai400_api::*;
+///
+/// assert_eq!(aux_checksum(b"123456789"), 0xBB3D);
+/// ```
+pub fn aux_checksum(data: &[u8]) -> u16 {
+    State::<ARC>::calculate(data)
+}
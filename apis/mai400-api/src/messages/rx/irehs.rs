@@ -15,11 +15,10 @@
 //
 
 use super::*;
+use crate::messages::aux_checksum;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
 use bitflags::bitflags;
-use byteorder::{LittleEndian, ReadBytesExt};
-use crc16::*;
-use nom::*;
-use std::io::Cursor;
 
 /// IR Earth Horizon Sensor telemetry data
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
@@ -47,107 +46,46 @@ pub struct IREHSTelemetry {
 
 impl IREHSTelemetry {
     /// Constructor. Converts a raw data array received from the MAI-400 into a usable structure
-    pub fn new(mut msg: Vec<u8>) -> Option<Self> {
+    pub fn new(msg: Vec<u8>) -> Option<Self> {
+        // Get the CRC bytes
+        let (body, crc) = split_checksum(&msg).ok()?;
+
         // Verify message starts with sync bytes
-        let mut data = msg.split_off(2);
+        let mut frame = FrameReader::new(body);
+        if frame.read_u16_le().ok()? != AUX_SYNC {
+            return None;
+        }
 
-        let mut wrapper = Cursor::new(msg);
-        let check = wrapper.read_u16::<LittleEndian>().unwrap_or(0);
-        if check != AUX_SYNC {
+        // Note: Yes, this is a different way of calculating the checksum than everywhere else.
+        // The sync bytes are not included
+        if aux_checksum(&body[frame.offset()..]) != crc {
             return None;
         }
 
-        // Get the CRC bytes
-        let len = data.len() - 2;
-        let mut crc = Cursor::new(data.split_off(len));
-        let crc = crc.read_u16::<LittleEndian>().unwrap_or(0);
+        // Convert the raw data to an official struct
+        irehs_telem(body).ok()
+    }
+}
 
-        // Note: Yes, this is a different way of calculating the checksum than everywhere else
-        let calc = State::<ARC>::calculate(&data);
+// Field offsets are from the start of the frame, including the sync word
+fn irehs_telem(msg: &[u8]) -> Result<IREHSTelemetry, ShortFrame> {
+    let mut frame = FrameReader::new(msg);
+    frame.skip(6)?; // 0: sync word, followed by two unused words
 
-        // Verify the CRC bytes at the end of the message
-        if calc == crc {
-            // Convert the raw data to an official struct
-            match irehs_telem(&data) {
-                Ok(conv) => Some(conv.1),
-                _ => None,
-            }
-        } else {
-            None
-        }
-    }
+    Ok(IREHSTelemetry {
+        thermopiles_a: frame.read_array(FrameReader::read_u16_le)?, // 6
+        thermopiles_b: frame.read_array(FrameReader::read_u16_le)?, // 14
+        temp_a: frame.read_array(FrameReader::read_u16_le)?,        // 22
+        temp_b: frame.read_array(FrameReader::read_u16_le)?,        // 30
+        dip_angle_a: frame.read_i32_le()?,                          // 38
+        dip_angle_b: frame.read_i32_le()?,                          // 42
+        solution_degraded: frame.read_array(read_thermopile_flags)?, // 46
+    })
 }
 
-named!(irehs_telem(&[u8]) -> IREHSTelemetry,
-    do_parse!(
-        le_i16 >>
-        le_i16 >>
-        thermopiles_a_earth_limb: le_u16 >>
-        thermopiles_a_earth_ref: le_u16 >>
-        thermopiles_a_space_ref: le_u16 >>
-        thermopiles_a_wide_fov: le_u16 >>
-        thermopiles_b_earth_limb: le_u16 >>
-        thermopiles_b_earth_ref: le_u16 >>
-        thermopiles_b_space_ref: le_u16 >>
-        thermopiles_b_wide_fov: le_u16 >>
-        temp_a_earth_limb: le_u16 >>
-        temp_a_earth_ref: le_u16 >>
-        temp_a_space_ref: le_u16 >>
-        temp_a_wide_fov: le_u16 >>
-        temp_b_earth_limb: le_u16 >>
-        temp_b_earth_ref: le_u16 >>
-        temp_b_space_ref: le_u16 >>
-        temp_b_wide_fov: le_u16 >>
-        dip_angle_a: le_i32 >>
-        dip_angle_b: le_i32 >>
-        solution_degraded_earth_limb_a: le_u8 >>
-        solution_degraded_earth_ref_a: le_u8 >>
-        solution_degraded_space_ref_a: le_u8 >>
-        solution_degraded_wide_fov_a: le_u8 >>
-        solution_degraded_earth_limb_b: le_u8 >>
-        solution_degraded_earth_ref_b: le_u8 >>
-        solution_degraded_space_ref_b: le_u8 >>
-        solution_degraded_wide_fov_b: le_u8 >>
-        (IREHSTelemetry {
-                thermopiles_a: [
-                    thermopiles_a_earth_limb,
-                    thermopiles_a_earth_ref,
-                    thermopiles_a_space_ref,
-                    thermopiles_a_wide_fov
-                ],
-                thermopiles_b: [
-                    thermopiles_b_earth_limb,
-                    thermopiles_b_earth_ref,
-                    thermopiles_b_space_ref,
-                    thermopiles_b_wide_fov
-                ],
-                temp_a: [
-                    temp_a_earth_limb,
-                    temp_a_earth_ref,
-                    temp_a_space_ref,
-                    temp_a_wide_fov
-                ],
-                temp_b: [
-                    temp_b_earth_limb,
-                    temp_b_earth_ref,
-                    temp_b_space_ref,
-                    temp_b_wide_fov
-                ],
-                dip_angle_a,
-                dip_angle_b,
-                solution_degraded: [
-                    ThermopileFlags::from_bits_truncate(solution_degraded_earth_limb_a),
-                    ThermopileFlags::from_bits_truncate(solution_degraded_earth_ref_a),
-                    ThermopileFlags::from_bits_truncate(solution_degraded_space_ref_a),
-                    ThermopileFlags::from_bits_truncate(solution_degraded_wide_fov_a),
-                    ThermopileFlags::from_bits_truncate(solution_degraded_earth_limb_b),
-                    ThermopileFlags::from_bits_truncate(solution_degraded_earth_ref_b),
-                    ThermopileFlags::from_bits_truncate(solution_degraded_space_ref_b),
-                    ThermopileFlags::from_bits_truncate(solution_degraded_wide_fov_b)
-                ]
-        })
-    )
-);
+fn read_thermopile_flags(frame: &mut FrameReader) -> Result<ThermopileFlags, ShortFrame> {
+    frame.read_u8().map(ThermopileFlags::from_bits_truncate)
+}
 
 bitflags! {
     /// Thermopile error flags
@@ -18,15 +18,24 @@
 pub mod irehs;
 /// Module for receiving and processing the raw IMU telemetry message
 pub mod raw_imu;
+/// Module for bounds-checked reading of received frames
+pub mod reader;
 /// Module for extracting and saving the rotating variables from the standard telemetry messages
 pub mod rotating;
+/// Module for receiving and processing the self-test results frame
+pub mod self_test;
 /// Module for receiving and processing the standard telemetry message
 pub mod std_telem;
+/// Module for receiving and processing the firmware version response
+pub mod version;
 
 pub use self::irehs::*;
 pub use self::raw_imu::*;
+pub use self::reader::*;
 pub use self::rotating::*;
+pub use self::self_test::*;
 pub use self::std_telem::*;
+pub use self::version::*;
 
 /// Sync word for raw IMU and IREHS telemetry packets
 pub const AUX_SYNC: u16 = 0xEA91;
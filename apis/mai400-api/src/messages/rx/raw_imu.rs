@@ -15,10 +15,9 @@
 //
 
 use super::*;
-use byteorder::{LittleEndian, ReadBytesExt};
-use crc16::*;
-use nom::*;
-use std::io::Cursor;
+use crate::messages::aux_checksum;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
 
 /// Raw accelerometer and gyroscope data
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
@@ -33,53 +32,35 @@ pub struct RawIMU {
 
 impl RawIMU {
     /// Constructor. Converts a raw data array received from the MAI-400 into a usable structure
-    pub fn new(mut msg: Vec<u8>) -> Option<Self> {
-        // Verify message starts with sync bytes
-        let mut data = msg.split_off(2);
+    pub fn new(msg: Vec<u8>) -> Option<Self> {
+        // Get the CRC bytes
+        let (body, crc) = split_checksum(&msg).ok()?;
 
-        let mut wrapper = Cursor::new(msg);
-        let check = wrapper.read_u16::<LittleEndian>().unwrap_or(0);
-        if check != AUX_SYNC {
-            println!("RawIMU: Bad sync - {:x}", check);
+        // Verify message starts with sync bytes
+        let mut frame = FrameReader::new(body);
+        if frame.read_u16_le().ok()? != AUX_SYNC {
             return None;
         }
 
-        // Get the CRC bytes
-        let len = data.len() - 2;
-        let mut crc = Cursor::new(data.split_off(len));
-        let crc = crc.read_u16::<LittleEndian>().unwrap_or(0);
-
-        // Note: Yes, this is a different way of calculating the checksum than everywhere else
-        let calc = State::<ARC>::calculate(&data);
-
-        // Verify the CRC bytes at the end of the message
-        if calc == crc {
-            // Convert the raw data to an official struct
-            match raw_imu(&data) {
-                Ok(conv) => Some(conv.1),
-                _ => None,
-            }
-        } else {
-            None
+        // Note: Yes, this is a different way of calculating the checksum than everywhere else.
+        // The sync bytes are not included
+        if aux_checksum(&body[frame.offset()..]) != crc {
+            return None;
         }
+
+        // Convert the raw data to an official struct
+        raw_imu(body).ok()
     }
 }
 
-named!(raw_imu(&[u8]) -> RawIMU,
-    do_parse!(
-        le_i16 >>
-        le_i16 >>
-        accel_x: le_i16 >>
-        accel_y: le_i16 >>
-        accel_z: le_i16 >>
-        gyro_x: le_i16 >>
-        gyro_y: le_i16 >>
-        gyro_z: le_i16 >>
-        gyro_temp: le_u8 >>
-        (RawIMU {
-                accel: [accel_x ,accel_y, accel_z],
-                gyro: [gyro_x, gyro_y, gyro_z],
-                gyro_temp
-        })
-    )
-);
+// Field offsets are from the start of the frame, including the sync word
+fn raw_imu(msg: &[u8]) -> Result<RawIMU, ShortFrame> {
+    let mut frame = FrameReader::new(msg);
+    frame.skip(6)?; // 0: sync word, followed by two unused words
+
+    Ok(RawIMU {
+        accel: frame.read_array(FrameReader::read_i16_le)?, // 6
+        gyro: frame.read_array(FrameReader::read_i16_le)?,  // 12
+        gyro_temp: frame.read_u8()?,                        // 18
+    })
+}
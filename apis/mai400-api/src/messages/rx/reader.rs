@@ -0,0 +1,176 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use byteorder::{ByteOrder, LittleEndian};
+use core::fmt;
+
+/// Error returned by [`FrameReader`] when a frame ends before the requested field
+///
+/// When the `std` feature is enabled, this converts into `MAIError::FramingError`.
+///
+/// [`FrameReader`]: struct.FrameReader.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ShortFrame {
+    /// Offset of the field which could not be read
+    pub offset: usize,
+    /// Number of bytes the field needed
+    pub needed: usize,
+    /// Total length of the frame
+    pub len: usize,
+}
+
+impl fmt::Display for ShortFrame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Frame too short: needed {} bytes at offset {}, but frame is {} bytes",
+            self.needed, self.offset, self.len
+        )
+    }
+}
+
+/// Cursor for reading little-endian fields out of a received MAI-400 frame
+///
+/// Every read is bounds-checked. Reading past the end of the frame returns a
+/// [`ShortFrame`] error instead of panicking.
+///
+/// # Examples
+///
+/// ```
+/// use mai400_api::*;
+///
+/// let mut frame = FrameReader::new(&[0x90, 0xEB, 0x34, 0x12]);
+///
+/// frame.skip(2).unwrap(); // 0: sync word
+/// assert_eq!(frame.read_u16_le(), Ok(0x1234)); // 2
+/// assert!(frame.read_u8().is_err());
+/// ```
+///
+/// [`ShortFrame`]: struct.ShortFrame.html
+#[derive(Clone, Debug)]
+pub struct FrameReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> FrameReader<'a> {
+    /// Start reading from the beginning of a frame
+    pub fn new(data: &'a [u8]) -> Self {
+        FrameReader { data, offset: 0 }
+    }
+
+    /// Offset of the next byte to be read
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Number of bytes left to be read
+    pub fn remaining(&self) -> usize {
+        self.data.len() - self.offset
+    }
+
+    /// Read the next `len` bytes as a slice
+    pub fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], ShortFrame> {
+        if len > self.remaining() {
+            return Err(ShortFrame {
+                offset: self.offset,
+                needed: len,
+                len: self.data.len(),
+            });
+        }
+
+        let bytes = &self.data[self.offset..self.offset + len];
+        self.offset += len;
+        Ok(bytes)
+    }
+
+    /// Skip over `len` bytes which aren't needed
+    pub fn skip(&mut self, len: usize) -> Result<(), ShortFrame> {
+        self.read_bytes(len).map(|_| ())
+    }
+
+    /// Read a `u8`
+    pub fn read_u8(&mut self) -> Result<u8, ShortFrame> {
+        self.read_bytes(1).map(|bytes| bytes[0])
+    }
+
+    /// Read an `i8`
+    pub fn read_i8(&mut self) -> Result<i8, ShortFrame> {
+        self.read_u8().map(|byte| byte as i8)
+    }
+
+    /// Read a little-endian `u16`
+    pub fn read_u16_le(&mut self) -> Result<u16, ShortFrame> {
+        self.read_bytes(2).map(LittleEndian::read_u16)
+    }
+
+    /// Read a little-endian `i16`
+    pub fn read_i16_le(&mut self) -> Result<i16, ShortFrame> {
+        self.read_bytes(2).map(LittleEndian::read_i16)
+    }
+
+    /// Read a little-endian `u32`
+    pub fn read_u32_le(&mut self) -> Result<u32, ShortFrame> {
+        self.read_bytes(4).map(LittleEndian::read_u32)
+    }
+
+    /// Read a little-endian `i32`
+    pub fn read_i32_le(&mut self) -> Result<i32, ShortFrame> {
+        self.read_bytes(4).map(LittleEndian::read_i32)
+    }
+
+    /// Read a little-endian `f32`
+    pub fn read_f32_le(&mut self) -> Result<f32, ShortFrame> {
+        self.read_bytes(4).map(LittleEndian::read_f32)
+    }
+
+    /// Read `N` consecutive fields with the given read function
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mai400_api::*;
+    ///
+    /// let mut frame = FrameReader::new(&[0x01, 0x00, 0xFF, 0xFF]);
+    /// let values: [i16; 2] = frame.read_array(FrameReader::read_i16_le).unwrap();
+    ///
+    /// assert_eq!(values, [1, -1]);
+    /// ```
+    pub fn read_array<T: Copy + Default, const N: usize>(
+        &mut self,
+        read: fn(&mut Self) -> Result<T, ShortFrame>,
+    ) -> Result<[T; N], ShortFrame> {
+        let mut values = [T::default(); N];
+        for value in values.iter_mut() {
+            *value = read(self)?;
+        }
+        Ok(values)
+    }
+}
+
+/// Split a frame into its body and the little-endian checksum stored in its final two bytes
+pub(crate) fn split_checksum(msg: &[u8]) -> Result<(&[u8], u16), ShortFrame> {
+    if msg.len() < 2 {
+        return Err(ShortFrame {
+            offset: 0,
+            needed: 2,
+            len: msg.len(),
+        });
+    }
+
+    let (body, crc) = msg.split_at(msg.len() - 2);
+    Ok((body, LittleEndian::read_u16(crc)))
+}
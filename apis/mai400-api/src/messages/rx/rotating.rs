@@ -282,6 +282,83 @@ impl RotatingTelemetry {
     }
 }
 
+/// Number of distinct rotating variable sets reported by the standard telemetry message
+pub const ROTATING_SETS: u8 = 24;
+
+// Bitmask with one bit set for each rotating variable set
+const ROTATING_ALL: u32 = (1 << ROTATING_SETS) - 1;
+
+/// Accumulator which reconstructs the full rotating variable set from a sequence
+/// of standard telemetry messages
+///
+/// Each standard telemetry message only carries one of the [`ROTATING_SETS`] rotating
+/// variable sets, selected by the lower bits of its `tlm_counter` field. The accumulator
+/// tracks which sets have been received and publishes a snapshot once every set has been
+/// seen. The snapshot is then held until every set has been received again, so it never
+/// mixes values from an incomplete cycle.
+///
+/// [`ROTATING_SETS`]: constant.ROTATING_SETS.html
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RotatingVariable {
+    working: RotatingTelemetry,
+    seen: u32,
+    snapshot: Option<RotatingTelemetry>,
+}
+
+impl RotatingVariable {
+    /// Extract the rotating variables from a standard telemetry message and add them
+    /// to the set currently being accumulated
+    ///
+    /// # Arguments
+    ///
+    /// * msg - Standard telemetry message to extract variables from
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mai400_api::*;
+    /// # fn func() -> MAIResult<()> {
+    /// let mai = MAI400::new("/dev/ttyS5")?;
+    ///
+    /// let mut accumulator = RotatingVariable::default();
+    ///
+    /// while accumulator.rotating().is_none() {
+    ///     if let (Some(std), _imu, _irehs) = mai.get_message()? {
+    ///         accumulator.update(&std);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&mut self, msg: &StandardTelemetry) {
+        let index = msg.tlm_counter & 0x1F;
+        if index >= ROTATING_SETS {
+            return;
+        }
+
+        self.working.update(msg);
+        self.seen |= 1 << index;
+
+        if self.seen == ROTATING_ALL {
+            self.snapshot = Some(self.working.clone());
+            self.seen = 0;
+        }
+    }
+
+    /// Get the most recent complete rotating variable set
+    ///
+    /// Returns `None` until every rotating variable set has been received at least once
+    pub fn rotating(&self) -> Option<&RotatingTelemetry> {
+        self.snapshot.as_ref()
+    }
+
+    /// Check whether the given rotating variable set has been received since the
+    /// last complete snapshot was taken
+    pub fn seen(&self, index: u8) -> bool {
+        index < ROTATING_SETS && self.seen & (1 << index) != 0
+    }
+}
+
 /// Structure for keplarian elements returned in the standard telemetry message
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct KeplerElem {
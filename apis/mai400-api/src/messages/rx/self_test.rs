@@ -0,0 +1,149 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::reader::*;
+use crate::messages::{checksum, SYNC};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+
+/// Length of the self-test results frame, including the sync and checksum bytes
+pub const SELF_TEST_LEN: usize = 8;
+
+/// Built-in tests which can be run by the MAI-400
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SelfTestType {
+    /// Check the sensors and actuator electronics without moving anything
+    Quick = 0x00,
+    /// Also briefly spin each reaction wheel and pulse each torque rod
+    Full = 0x01,
+}
+
+impl SelfTestType {
+    /// Convert a raw test type byte into a `SelfTestType` value
+    ///
+    /// Returns `None` if the byte does not correspond to a known test
+    pub fn from_u8(raw: u8) -> Option<SelfTestType> {
+        match raw {
+            0x00 => Some(SelfTestType::Quick),
+            0x01 => Some(SelfTestType::Full),
+            _ => None,
+        }
+    }
+}
+
+/// Bit flags reported in the self-test results frame. A set bit indicates a failure
+pub mod self_test_faults {
+    /// X-axis gyro
+    pub const GYRO_X: u16 = 0x0001;
+    /// Y-axis gyro
+    pub const GYRO_Y: u16 = 0x0002;
+    /// Z-axis gyro
+    pub const GYRO_Z: u16 = 0x0004;
+    /// Magnetometer
+    pub const MAGNETOMETER: u16 = 0x0008;
+    /// X-axis reaction wheel
+    pub const WHEEL_X: u16 = 0x0010;
+    /// Y-axis reaction wheel
+    pub const WHEEL_Y: u16 = 0x0020;
+    /// Z-axis reaction wheel
+    pub const WHEEL_Z: u16 = 0x0040;
+    /// X-axis torque rod
+    pub const TORQUE_ROD_X: u16 = 0x0080;
+    /// Y-axis torque rod
+    pub const TORQUE_ROD_Y: u16 = 0x0100;
+    /// Z-axis torque rod
+    pub const TORQUE_ROD_Z: u16 = 0x0200;
+    /// Coarse sun sensors
+    pub const SUN_SENSORS: u16 = 0x0400;
+    /// IREHS (Earth horizon sensors)
+    pub const HORIZON_SENSORS: u16 = 0x0800;
+}
+
+/// Per-subsystem results of a MAI-400 self-test. `true` indicates the subsystem passed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SelfTestResults {
+    /// Test which was run
+    pub test_type: SelfTestType,
+    /// Gyros {X, Y, Z}
+    pub gyros: [bool; 3],
+    /// Magnetometer
+    pub magnetometer: bool,
+    /// Reaction wheels {X, Y, Z}
+    pub wheels: [bool; 3],
+    /// Torque rods {X, Y, Z}
+    pub torque_rods: [bool; 3],
+    /// Coarse sun sensors
+    pub sun_sensors: bool,
+    /// IREHS (Earth horizon sensors)
+    pub horizon_sensors: bool,
+    /// Raw fault flags, as reported by the device. See [`self_test_faults`]
+    ///
+    /// [`self_test_faults`]: self_test_faults/index.html
+    pub faults: u16,
+}
+
+impl SelfTestResults {
+    /// Constructor. Converts a raw data array received from the MAI-400 into a usable structure
+    pub fn new(msg: Vec<u8>) -> Option<Self> {
+        if msg.len() != SELF_TEST_LEN || msg[0..2] != SYNC {
+            return None;
+        }
+
+        // Get the CRC bytes
+        let (body, crc) = split_checksum(&msg).ok()?;
+
+        // Make sure it matches the calculated CRC
+        if checksum(body) != crc {
+            return None;
+        }
+
+        self_test(body).ok()?
+    }
+
+    /// Whether every subsystem passed
+    ///
+    /// Fault flags which don't correspond to a known subsystem also count as failures
+    pub fn passed(&self) -> bool {
+        self.faults == 0
+    }
+}
+
+fn results(test_type: u8, faults: u16) -> Option<SelfTestResults> {
+    use self::self_test_faults::*;
+
+    let pass = |flag: u16| faults & flag == 0;
+
+    Some(SelfTestResults {
+        test_type: SelfTestType::from_u8(test_type)?,
+        gyros: [pass(GYRO_X), pass(GYRO_Y), pass(GYRO_Z)],
+        magnetometer: pass(MAGNETOMETER),
+        wheels: [pass(WHEEL_X), pass(WHEEL_Y), pass(WHEEL_Z)],
+        torque_rods: [pass(TORQUE_ROD_X), pass(TORQUE_ROD_Y), pass(TORQUE_ROD_Z)],
+        sun_sensors: pass(SUN_SENSORS),
+        horizon_sensors: pass(HORIZON_SENSORS),
+        faults,
+    })
+}
+
+fn self_test(msg: &[u8]) -> Result<Option<SelfTestResults>, ShortFrame> {
+    let mut frame = FrameReader::new(msg);
+    frame.skip(3)?; // 0: sync word and message ID
+
+    let test_type = frame.read_u8()?; // 3
+    let faults = frame.read_u16_le()?; // 4
+
+    Ok(results(test_type, faults))
+}
@@ -14,9 +14,22 @@
 // limitations under the License.
 //
 
-use byteorder::{LittleEndian, ReadBytesExt};
-use nom::*;
-use std::io::Cursor;
+use super::reader::*;
+use crate::messages::checksum;
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+use core::time::Duration;
+
+// Fixed-point quaternion components are reported in units of 1/32767
+#[cfg(feature = "std")]
+pub(crate) const QUATERNION_LSB_DIVISOR: f32 = 32767.0;
+// The subsecond counter increments at 4Hz
+const SUBSEC_MILLIS: u64 = 250;
+// Wheel torque commands are reported in mN·m
+const TORQUE_MNM_PER_NM: f32 = 1000.0;
+
+/// Number of reaction wheels on the MAI-400, one for each body axis
+pub const WHEEL_COUNT: usize = 3;
 
 /// Standard telemetry packet sent by the MAI-400 every 250ms
 #[derive(Clone, Debug, Default, PartialEq)]
@@ -83,202 +96,132 @@ pub struct StandardTelemetry {
 
 impl StandardTelemetry {
     /// Constructor. Converts a raw data array received from the MAI-400 into a usable structure
-    pub fn new(mut msg: Vec<u8>) -> Option<Self> {
+    pub fn new(msg: Vec<u8>) -> Option<Self> {
         // Get the CRC bytes
-        let len = msg.len() - 2;
+        let (body, crc) = split_checksum(&msg).ok()?;
+
+        // Make sure they match the calculated CRC
+        if checksum(body) != crc {
+            return None;
+        }
+
+        // Convert the raw data to an official struct
+        standardtelem(body).ok()
+    }
+
+    /// Value of the MAI-400's internal clock, with 250ms resolution
+    ///
+    /// Combines the whole seconds from `gps_time` with the 4Hz `time_subsec` counter.
+    /// The clock counts up from zero at power-on until it is set with
+    /// [`set_gps_time`], after which it is the time since the GPS epoch. A clock which
+    /// jumps backwards without a matching `set_gps_time` call means the device has rebooted.
+    ///
+    /// The standard telemetry frame does not contain a reset counter. The number of
+    /// processor resets is reported in [`RotatingTelemetry::proc_reset_cntr`].
+    ///
+    /// [`set_gps_time`]: struct.MAI400.html#method.set_gps_time
+    /// [`RotatingTelemetry::proc_reset_cntr`]: struct.RotatingTelemetry.html#structfield.proc_reset_cntr
+    pub fn device_time(&self) -> Duration {
+        Duration::from_secs(u64::from(self.gps_time))
+            + Duration::from_millis(u64::from(self.time_subsec) * SUBSEC_MILLIS)
+    }
+
+    /// Body angular rates, in radians per second, about the body frame X, Y and Z axes
+    ///
+    /// The MAI-400 reports these as floating point values, so no scaling is needed
+    pub fn omega(&self) -> [f32; 3] {
+        self.omega_b
+    }
 
-        let mut crc = Cursor::new(msg.split_off(len));
-        let crc = crc.read_u16::<LittleEndian>().unwrap_or(0);
+    /// Measured reaction wheel speeds, in RPM, for the X, Y and Z axis wheels
+    ///
+    /// Taken from the `rws_speed_tach` tachometer readings (lsb: 1 RPM). Signs are kept as the
+    /// MAI-400 reports them: a positive speed is a right-handed rotation about the positive
+    /// body axis of the wheel, and a negative speed is a rotation in the opposite direction.
+    pub fn wheel_speeds(&self) -> [f32; WHEEL_COUNT] {
+        let mut speeds = [0.0; WHEEL_COUNT];
+        for (speed, &raw) in speeds.iter_mut().zip(self.rws_speed_tach.iter()) {
+            *speed = f32::from(raw);
+        }
+        speeds
+    }
 
-        // Get the calculated CRC
-        let mut calc: u16 = 0;
-        for byte in msg.iter() {
-            calc += u16::from(*byte);
+    /// Wheel torques commanded by the ADACS, in N·m, for the X, Y and Z axis wheels
+    ///
+    /// Converted from the mN·m values reported in `rwa_torque_cmd`. Uses the same sign
+    /// convention as [`wheel_speeds`], so a positive torque accelerates a wheel towards
+    /// more positive speeds.
+    ///
+    /// [`wheel_speeds`]: #method.wheel_speeds
+    pub fn wheel_torques(&self) -> [f32; WHEEL_COUNT] {
+        let mut torques = [0.0; WHEEL_COUNT];
+        for (torque, &raw) in torques.iter_mut().zip(self.rwa_torque_cmd.iter()) {
+            *torque = raw / TORQUE_MNM_PER_NM;
         }
+        torques
+    }
 
-        // Make sure they match
-        if calc == crc {
-            // Convert the raw data to an official struct
-            match standardtelem(&msg) {
-                Ok(conv) => Some(conv.1),
-                _ => None,
+    /// Current estimated orbit-to-body quaternion, normalized to unit length
+    ///
+    /// The quaternion rotates from the orbit (LVLH) reference frame into the body frame and is
+    /// ordered `[q1, q2, q3, q4]`, with the scalar component last. The raw fixed-point values
+    /// have an lsb of 1/32767.
+    ///
+    /// All components will be zero if the MAI-400 has not produced an attitude estimate
+    ///
+    /// Only available when the `std` feature is enabled, since `core` has no square root.
+    #[cfg(feature = "std")]
+    pub fn quaternion(&self) -> [f32; 4] {
+        let mut quat = [0.0; 4];
+        for (scaled, &raw) in quat.iter_mut().zip(self.qbo_hat.iter()) {
+            *scaled = f32::from(raw) / QUATERNION_LSB_DIVISOR;
+        }
+
+        let norm = quat.iter().map(|elem| elem * elem).sum::<f32>().sqrt();
+        if norm > 0.0 {
+            for elem in quat.iter_mut() {
+                *elem /= norm;
             }
-        } else {
-            None
         }
+
+        quat
     }
 }
 
-named!(standardtelem(&[u8]) -> StandardTelemetry,
-    do_parse!(
-        le_u16 >>
-        tlm_counter: le_u8 >>
-        gps_time: le_u32 >>
-        time_subsec: le_u8 >>
-        cmd_valid_cntr: le_u16 >>
-        cmd_invalid_cntr: le_u16 >>
-        cmd_invalid_chksum_cntr: le_u16 >>
-        last_command: le_u8 >>
-        acs_mode: le_u8 >>
-        css_0: le_u16 >>
-        css_1: le_u16 >>
-        css_2: le_u16 >>
-        css_3: le_u16 >>
-        css_4: le_u16 >>
-        css_5: le_u16 >>
-        eclipse_flag: le_u8 >>
-        sun_vec_b_0: le_i16 >>
-        sun_vec_b_1: le_i16 >>
-        sun_vec_b_2: le_i16 >>
-        i_b_field_meas_0: le_i16 >>
-        i_b_field_meas_1: le_i16 >>
-        i_b_field_meas_2: le_i16 >>
-        bd_0: le_f32 >>
-        bd_1: le_f32 >>
-        bd_2: le_f32 >>
-        rws_speed_cmd_0: le_i16 >>
-        rws_speed_cmd_1: le_i16 >>
-        rws_speed_cmd_2: le_i16 >>
-        rws_speed_tach_0: le_i16 >>
-        rws_speed_tach_1: le_i16 >>
-        rws_speed_tach_2: le_i16 >>
-        rwa_torque_cmd_0: le_f32 >>
-        rwa_torque_cmd_1: le_f32 >>
-        rwa_torque_cmd_2: le_f32 >>
-        gc_rwa_torque_cmd_0: le_i8 >>
-        gc_rwa_torque_cmd_1: le_i8 >>
-        gc_rwa_torque_cmd_2: le_i8 >>
-        torque_coil_cmd_0: le_f32 >>
-        torque_coil_cmd_1: le_f32 >>
-        torque_coil_cmd_2: le_f32 >>
-        gc_torque_coil_cmd_0: le_i8 >>
-        gc_torque_coil_cmd_1: le_i8 >>
-        gc_torque_coil_cmd_2: le_i8 >>
-        qbo_cmd_0: le_i16 >>
-        qbo_cmd_1: le_i16 >>
-        qbo_cmd_2: le_i16 >>
-        qbo_cmd_3: le_i16 >>
-        qbo_hat_0: le_i16 >>
-        qbo_hat_1: le_i16 >>
-        qbo_hat_2: le_i16 >>
-        qbo_hat_3: le_i16 >>
-        angle_to_go: le_f32 >>
-        q_error_0: le_i16 >>
-        q_error_1: le_i16 >>
-        q_error_2: le_i16 >>
-        q_error_3: le_i16 >>
-        omega_b_0: le_f32 >>
-        omega_b_1: le_f32 >>
-        omega_b_2: le_f32 >>
-        rotating_variable_a: le_u32 >>
-        rotating_variable_b: le_u32 >>
-        rotating_variable_c: le_u32 >>
-        nb_0: le_i16 >>
-        nb_1: le_i16 >>
-        nb_2: le_i16 >>
-        neci_0: le_i16 >>
-        neci_1: le_i16 >>
-        neci_2: le_i16 >>
-        (StandardTelemetry{
-            tlm_counter,
-            gps_time,
-            time_subsec,
-            cmd_valid_cntr,
-            cmd_invalid_cntr,
-            cmd_invalid_chksum_cntr,
-            last_command,
-            acs_mode,
-            css: [
-                css_0,
-                css_1,
-                css_2,
-                css_3,
-                css_4,
-                css_5
-            ],
-            eclipse_flag,
-            sun_vec_b: [
-                sun_vec_b_0,
-                sun_vec_b_1,
-                sun_vec_b_2
-            ],
-            i_b_field_meas: [
-                i_b_field_meas_0,
-                i_b_field_meas_1,
-                i_b_field_meas_2
-            ],
-            bd: [
-                bd_0,
-                bd_1,
-                bd_2
-            ],
-            rws_speed_cmd: [
-                rws_speed_cmd_0,
-                rws_speed_cmd_1,
-                rws_speed_cmd_2
-            ],
-            rws_speed_tach: [
-                rws_speed_tach_0,
-                rws_speed_tach_1,
-                rws_speed_tach_2
-            ],
-            rwa_torque_cmd: [
-                rwa_torque_cmd_0,
-                rwa_torque_cmd_1,
-                rwa_torque_cmd_2
-            ],
-            gc_rwa_torque_cmd: [
-                gc_rwa_torque_cmd_0,
-                gc_rwa_torque_cmd_1,
-                gc_rwa_torque_cmd_2
-            ],
-            torque_coil_cmd: [
-                torque_coil_cmd_0,
-                torque_coil_cmd_1,
-                torque_coil_cmd_2
-            ],
-            gc_torque_coil_cmd: [
-                gc_torque_coil_cmd_0,
-                gc_torque_coil_cmd_1,
-                gc_torque_coil_cmd_2
-            ],
-            qbo_cmd: [
-                qbo_cmd_0,
-                qbo_cmd_1,
-                qbo_cmd_2,
-                qbo_cmd_3
-            ],
-            qbo_hat: [
-                qbo_hat_0,
-                qbo_hat_1,
-                qbo_hat_2,
-                qbo_hat_3
-            ],
-            angle_to_go,
-            q_error: [
-                q_error_0,
-                q_error_1,
-                q_error_2,
-                q_error_3
-            ],
-            omega_b: [
-                omega_b_0,
-                omega_b_1,
-                omega_b_2
-            ],
-            rotating_variable_a,
-            rotating_variable_b,
-            rotating_variable_c,
-            nb: [
-                nb_0,
-                nb_1,
-                nb_2
-            ],
-            neci: [
-                neci_0,
-                neci_1,
-                neci_2
-            ]
-        })
-    )
-);
+// Field offsets are from the start of the frame, including the sync word
+fn standardtelem(msg: &[u8]) -> Result<StandardTelemetry, ShortFrame> {
+    let mut frame = FrameReader::new(msg);
+    frame.skip(2)?; // 0: sync word
+
+    Ok(StandardTelemetry {
+        tlm_counter: frame.read_u8()?,                                // 2
+        gps_time: frame.read_u32_le()?,                               // 3
+        time_subsec: frame.read_u8()?,                                // 7
+        cmd_valid_cntr: frame.read_u16_le()?,                         // 8
+        cmd_invalid_cntr: frame.read_u16_le()?,                       // 10
+        cmd_invalid_chksum_cntr: frame.read_u16_le()?,                // 12
+        last_command: frame.read_u8()?,                               // 14
+        acs_mode: frame.read_u8()?,                                   // 15
+        css: frame.read_array(FrameReader::read_u16_le)?,             // 16
+        eclipse_flag: frame.read_u8()?,                               // 28
+        sun_vec_b: frame.read_array(FrameReader::read_i16_le)?,       // 29
+        i_b_field_meas: frame.read_array(FrameReader::read_i16_le)?,  // 35
+        bd: frame.read_array(FrameReader::read_f32_le)?,              // 41
+        rws_speed_cmd: frame.read_array(FrameReader::read_i16_le)?,   // 53
+        rws_speed_tach: frame.read_array(FrameReader::read_i16_le)?,  // 59
+        rwa_torque_cmd: frame.read_array(FrameReader::read_f32_le)?,  // 65
+        gc_rwa_torque_cmd: frame.read_array(FrameReader::read_i8)?,   // 77
+        torque_coil_cmd: frame.read_array(FrameReader::read_f32_le)?, // 80
+        gc_torque_coil_cmd: frame.read_array(FrameReader::read_i8)?,  // 92
+        qbo_cmd: frame.read_array(FrameReader::read_i16_le)?,         // 95
+        qbo_hat: frame.read_array(FrameReader::read_i16_le)?,         // 103
+        angle_to_go: frame.read_f32_le()?,                            // 111
+        q_error: frame.read_array(FrameReader::read_i16_le)?,         // 115
+        omega_b: frame.read_array(FrameReader::read_f32_le)?,         // 123
+        rotating_variable_a: frame.read_u32_le()?,                    // 135
+        rotating_variable_b: frame.read_u32_le()?,                    // 139
+        rotating_variable_c: frame.read_u32_le()?,                    // 143
+        nb: frame.read_array(FrameReader::read_i16_le)?,              // 147
+        neci: frame.read_array(FrameReader::read_i16_le)?,            // 153
+    })
+}
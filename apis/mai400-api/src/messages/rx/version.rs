@@ -0,0 +1,71 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::reader::*;
+use crate::messages::{checksum, SYNC};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
+
+/// Length of the version response frame, including the sync and checksum bytes
+pub const VERSION_LEN: usize = 40;
+
+/// Firmware and identification information reported by the MAI-400
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MAIVersion {
+    /// Firmware revision string
+    pub firmware: String,
+    /// Unit serial number
+    pub serial_number: u32,
+    /// Firmware build identifier
+    pub build: String,
+}
+
+impl MAIVersion {
+    /// Constructor. Converts a raw data array received from the MAI-400 into a usable structure
+    pub fn new(msg: Vec<u8>) -> Option<Self> {
+        if msg.len() != VERSION_LEN || msg[0..2] != SYNC {
+            return None;
+        }
+
+        // Get the CRC bytes
+        let (body, crc) = split_checksum(&msg).ok()?;
+
+        // Make sure they match the calculated CRC
+        if checksum(body) != crc {
+            return None;
+        }
+
+        // Convert the raw data to an official struct
+        version(body).ok()
+    }
+}
+
+// Text fields are fixed-width and padded with NULs
+fn padded_string(raw: &[u8]) -> String {
+    let end = raw.iter().position(|&byte| byte == 0).unwrap_or(raw.len());
+    String::from_utf8_lossy(&raw[..end]).trim().to_owned()
+}
+
+fn version(msg: &[u8]) -> Result<MAIVersion, ShortFrame> {
+    let mut frame = FrameReader::new(msg);
+    frame.skip(3)?; // 0: sync word and message ID
+
+    Ok(MAIVersion {
+        firmware: padded_string(frame.read_bytes(8)?), // 3
+        serial_number: frame.read_u32_le()?,           // 11
+        build: padded_string(frame.read_bytes(23)?),   // 15
+    })
+}
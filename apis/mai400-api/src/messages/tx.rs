@@ -15,45 +15,138 @@
 //
 
 use super::*;
-use byteorder::{LittleEndian, WriteBytesExt};
+#[cfg(not(feature = "std"))]
+use crate::no_std_prelude::*;
 
+/// IDs of the commands which can be sent to the MAI-400
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CommandId {
+    /// Set the ACS mode. Also used for the Normal-Sun and Lat/Long-Sun modes
+    SetAcsMode = 0x00,
+    /// Set the orbital position and velocity at epoch
+    SetRV = 0x41,
+    /// Set the ADACS clock
+    SetGPSTime = 0x44,
+    /// Upload magnetometer and gyro bias corrections
+    SetCalibration = 0x46,
+    /// Request the firmware version response frame
+    RequestVersion = 0x48,
+    /// Run a built-in self-test and return the results frame
+    RunSelfTest = 0x4A,
+    /// Request a hardware reset. Must be followed by `ConfirmReset`
+    RequestReset = 0x5A,
+    /// Confirm a previously requested hardware reset
+    ConfirmReset = 0xF1,
+}
+
+impl CommandId {
+    /// Get the raw ID byte sent to the MAI-400
+    pub fn as_u8(self) -> u8 {
+        self as u8
+    }
+
+    /// Convert a raw ID byte into a `CommandId` value
+    ///
+    /// Returns `None` if the byte does not correspond to a known command
+    pub fn from_u8(id: u8) -> Option<CommandId> {
+        match id {
+            0x00 => Some(CommandId::SetAcsMode),
+            0x41 => Some(CommandId::SetRV),
+            0x44 => Some(CommandId::SetGPSTime),
+            0x46 => Some(CommandId::SetCalibration),
+            0x48 => Some(CommandId::RequestVersion),
+            0x4A => Some(CommandId::RunSelfTest),
+            0x5A => Some(CommandId::RequestReset),
+            0xF1 => Some(CommandId::ConfirmReset),
+            _ => None,
+        }
+    }
+}
+
+/// A command which can be sent to the MAI-400
 pub trait Message {
+    /// Convert the command into its raw bytes, starting with the sync word and
+    /// excluding the trailing checksum
     fn serialize(&self) -> Vec<u8>;
 }
 
-#[derive(Default)]
+/// Build the complete frame for a command, ready to be written to the MAI-400
+///
+/// The command is serialized and its [`checksum`] is appended, producing [`CMD_LEN`] bytes
+///
+/// # Examples
+///
+/// ```
+/// use mai400_api::*;
+///
+/// let raw = frame(&SetGPSTime {
+///     gps_time: 1198800018,
+///     ..Default::default()
+/// });
+///
+/// assert_eq!(raw.len(), CMD_LEN);
+/// ```
+///
+/// [`checksum`]: fn.checksum.html
+/// [`CMD_LEN`]: constant.CMD_LEN.html
+pub fn frame<T: Message>(msg: &T) -> Vec<u8> {
+    let mut raw = msg.serialize();
+    let crc = checksum(&raw);
+    raw.extend_from_slice(&crc.to_le_bytes());
+    raw
+}
+
+/// Set the ACS mode
 pub struct SetAcsMode {
+    /// Command ID
     pub id: u8,
+    /// ACS mode to enter
     pub mode: u8,
+    /// Arguments for configuring the requested mode
     pub qbi_cmd: [i16; 4],
 }
 
+impl Default for SetAcsMode {
+    fn default() -> Self {
+        SetAcsMode {
+            id: CommandId::SetAcsMode.as_u8(),
+            mode: 0,
+            qbi_cmd: [0, 0, 0, 0],
+        }
+    }
+}
+
 impl Message for SetAcsMode {
     fn serialize(&self) -> Vec<u8> {
         let mut vec = SYNC.to_vec();
 
         vec.push(self.id);
         vec.push(self.mode);
-        vec.write_i16::<LittleEndian>(self.qbi_cmd[0]).unwrap();
-        vec.write_i16::<LittleEndian>(self.qbi_cmd[1]).unwrap();
-        vec.write_i16::<LittleEndian>(self.qbi_cmd[2]).unwrap();
-        vec.write_i16::<LittleEndian>(self.qbi_cmd[3]).unwrap();
+        vec.extend_from_slice(&self.qbi_cmd[0].to_le_bytes());
+        vec.extend_from_slice(&self.qbi_cmd[1].to_le_bytes());
+        vec.extend_from_slice(&self.qbi_cmd[2].to_le_bytes());
+        vec.extend_from_slice(&self.qbi_cmd[3].to_le_bytes());
         vec.append(&mut vec![0; 26]);
         vec
     }
 }
 
+/// Set the ACS mode to one of the sun-pointing modes
 pub struct SetAcsModeSun {
+    /// Command ID
     pub id: u8,
+    /// ACS mode to enter
     pub mode: u8,
+    /// Sun angle enable (0 = no update, 1 = update)
     pub sun_angle_enable: i16,
+    /// Sun rotation angle (degrees)
     pub sun_rot_angle: f32,
 }
 
 impl Default for SetAcsModeSun {
     fn default() -> Self {
         SetAcsModeSun {
-            id: 0,
+            id: CommandId::SetAcsMode.as_u8(),
             mode: 0,
             sun_angle_enable: 0,
             sun_rot_angle: 0.0,
@@ -67,23 +160,25 @@ impl Message for SetAcsModeSun {
 
         vec.push(self.id);
         vec.push(self.mode);
-        vec.write_i16::<LittleEndian>(self.sun_angle_enable)
-            .unwrap();
-        vec.write_f32::<LittleEndian>(self.sun_rot_angle).unwrap();
+        vec.extend_from_slice(&self.sun_angle_enable.to_le_bytes());
+        vec.extend_from_slice(&self.sun_rot_angle.to_le_bytes());
         vec.append(&mut vec![0; 28]);
         vec
     }
 }
 
+/// Set the ADACS clock
 pub struct SetGPSTime {
+    /// Command ID
     pub id: u8,
+    /// New clock time (elapsed seconds after Jan 6, 1980 00:00:00)
     pub gps_time: u32,
 }
 
 impl Default for SetGPSTime {
     fn default() -> Self {
         SetGPSTime {
-            id: 0x44,
+            id: CommandId::SetGPSTime.as_u8(),
             gps_time: 0,
         }
     }
@@ -94,23 +189,28 @@ impl Message for SetGPSTime {
         let mut vec = SYNC.to_vec();
 
         vec.push(self.id);
-        vec.write_u32::<LittleEndian>(self.gps_time).unwrap();
+        vec.extend_from_slice(&self.gps_time.to_le_bytes());
         vec.append(&mut vec![0; 31]);
         vec
     }
 }
 
+/// Set the orbital position and velocity at epoch
 pub struct SetRV {
+    /// Command ID
     pub id: u8,
+    /// ECI position (km)
     pub eci_pos: [f32; 3],
+    /// ECI velocity (km/s)
     pub eci_vel: [f32; 3],
+    /// GPS time at which the vectors are valid
     pub time_epoch: u32,
 }
 
 impl Default for SetRV {
     fn default() -> Self {
         SetRV {
-            id: 0x41,
+            id: CommandId::SetRV.as_u8(),
             eci_pos: [0.0, 0.0, 0.0],
             eci_vel: [0.0, 0.0, 0.0],
             time_epoch: 0,
@@ -123,18 +223,52 @@ impl Message for SetRV {
         let mut vec = SYNC.to_vec();
 
         vec.push(self.id);
-        vec.write_f32::<LittleEndian>(self.eci_pos[0]).unwrap();
-        vec.write_f32::<LittleEndian>(self.eci_pos[1]).unwrap();
-        vec.write_f32::<LittleEndian>(self.eci_pos[2]).unwrap();
-        vec.write_f32::<LittleEndian>(self.eci_vel[0]).unwrap();
-        vec.write_f32::<LittleEndian>(self.eci_vel[1]).unwrap();
-        vec.write_f32::<LittleEndian>(self.eci_vel[2]).unwrap();
-        vec.write_u32::<LittleEndian>(self.time_epoch).unwrap();
+        vec.extend_from_slice(&self.eci_pos[0].to_le_bytes());
+        vec.extend_from_slice(&self.eci_pos[1].to_le_bytes());
+        vec.extend_from_slice(&self.eci_pos[2].to_le_bytes());
+        vec.extend_from_slice(&self.eci_vel[0].to_le_bytes());
+        vec.extend_from_slice(&self.eci_vel[1].to_le_bytes());
+        vec.extend_from_slice(&self.eci_vel[2].to_le_bytes());
+        vec.extend_from_slice(&self.time_epoch.to_le_bytes());
         vec.append(&mut vec![0; 7]);
         vec
     }
 }
 
+/// Upload magnetometer and gyro bias corrections
+pub struct SetCalibration {
+    /// Command ID
+    pub id: u8,
+    /// Magnetometer bias [X, Y, Z] (nT)
+    pub mag_bias: [f32; 3],
+    /// Gyro bias [X, Y, Z] (deg/s)
+    pub gyro_bias: [f32; 3],
+}
+
+impl Default for SetCalibration {
+    fn default() -> Self {
+        SetCalibration {
+            id: CommandId::SetCalibration.as_u8(),
+            mag_bias: [0.0, 0.0, 0.0],
+            gyro_bias: [0.0, 0.0, 0.0],
+        }
+    }
+}
+
+impl Message for SetCalibration {
+    fn serialize(&self) -> Vec<u8> {
+        let mut vec = SYNC.to_vec();
+
+        vec.push(self.id);
+        for value in self.mag_bias.iter().chain(self.gyro_bias.iter()) {
+            vec.extend_from_slice(&value.to_le_bytes());
+        }
+        vec.append(&mut vec![0; 11]);
+        vec
+    }
+}
+
+/// Request a hardware reset. Must be followed by [`ConfirmReset`](struct.ConfirmReset.html)
 pub struct RequestReset(pub [u8; 38]);
 
 impl Default for RequestReset {
@@ -142,7 +276,7 @@ impl Default for RequestReset {
         let mut array = [0; 38];
         array[0] = 0x90; // SYNC byte 1
         array[1] = 0xEB; // SYNC byte 2
-        array[2] = 0x5A; // Command ID
+        array[2] = CommandId::RequestReset.as_u8();
         RequestReset(array)
     }
 }
@@ -153,6 +287,54 @@ impl Message for RequestReset {
     }
 }
 
+/// Request the firmware version response frame
+pub struct RequestVersion([u8; 38]);
+
+impl Default for RequestVersion {
+    fn default() -> Self {
+        let mut array = [0; 38];
+        array[0] = 0x90; // SYNC byte 1
+        array[1] = 0xEB; // SYNC byte 2
+        array[2] = CommandId::RequestVersion.as_u8();
+        RequestVersion(array)
+    }
+}
+
+impl Message for RequestVersion {
+    fn serialize(&self) -> Vec<u8> {
+        self.0.to_vec()
+    }
+}
+
+/// Run a built-in self-test
+pub struct RunSelfTest {
+    /// Command ID
+    pub id: u8,
+    /// Test to run. See [`SelfTestType`](enum.SelfTestType.html)
+    pub test_type: u8,
+}
+
+impl Default for RunSelfTest {
+    fn default() -> Self {
+        RunSelfTest {
+            id: CommandId::RunSelfTest.as_u8(),
+            test_type: 0,
+        }
+    }
+}
+
+impl Message for RunSelfTest {
+    fn serialize(&self) -> Vec<u8> {
+        let mut vec = SYNC.to_vec();
+
+        vec.push(self.id);
+        vec.push(self.test_type);
+        vec.append(&mut vec![0; 34]);
+        vec
+    }
+}
+
+/// Confirm a previously requested hardware reset
 pub struct ConfirmReset([u8; 38]);
 
 impl Default for ConfirmReset {
@@ -160,7 +342,7 @@ impl Default for ConfirmReset {
         let mut array = [0; 38];
         array[0] = 0x90; // SYNC byte 1
         array[1] = 0xEB; // SYNC byte 2
-        array[2] = 0xF1; // Command ID
+        array[2] = CommandId::ConfirmReset.as_u8();
         ConfirmReset(array)
     }
 }
@@ -0,0 +1,208 @@
+/*
+ * Copyright (C) 2018 Kubos Corporation
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::messages::*;
+use byteorder::{LittleEndian, WriteBytesExt};
+use rust_uart::*;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::time::Duration;
+
+// Length of the standard telemetry packet, not including its checksum
+const STD_LEN: usize = 159;
+
+#[derive(Default)]
+struct DeviceState {
+    tlm_counter: u8,
+    gps_time: u32,
+    time_subsec: u8,
+    cmd_valid_cntr: u16,
+    cmd_invalid_cntr: u16,
+    cmd_invalid_chksum_cntr: u16,
+    last_command: u8,
+    acs_mode: u8,
+    reset_requested: bool,
+    output: VecDeque<u8>,
+}
+
+/// Simulated MAI-400 device for use with unit and integration tests
+///
+/// Unlike `MockStream`, which replays scripted bytes, this stream parses each command frame
+/// written to it and reflects the result in the telemetry it generates, the same way the real
+/// device does:
+///
+/// - Valid commands increment `cmd_valid_cntr` and update `last_command`
+/// - Frames with a bad checksum increment `cmd_invalid_chksum_cntr`
+/// - Unknown commands, and `ConfirmReset` without a preceding `RequestReset`,
+///   increment `cmd_invalid_cntr`
+/// - `SetAcsMode`/`SetAcsModeSun` update `acs_mode` and `SetGPSTime` updates `gps_time`
+/// - A `RequestReset` followed by a `ConfirmReset` restores the power-on state
+///
+/// Every read is served from a continuous stream of complete message sets (standard telemetry,
+/// IREHS and raw IMU packets, with valid checksums). Each message set advances the rotating
+/// telemetry counter and the 4Hz subsecond counter.
+///
+/// `MockStream` should still be used for tests which need to verify the exact bytes sent
+/// or which need to inject malformed data.
+///
+/// # Examples
+///
+/// ```
+/// use mai400_api::*;
+///
+/// # fn func() -> MAIResult<()> {
+/// let mai = MAI400Builder::new()
+///     .connection(Connection::new(Box::new(MockMAI400::new())))
+///     .build()?;
+///
+/// mai.set_acs_mode(AcsMode::NadirPointing, [0, 0, 0, 0])?;
+///
+/// let (std, _imu, _irehs) = mai.get_message()?;
+/// assert_eq!(std.unwrap().acs_mode, 3);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct MockMAI400 {
+    state: RefCell<DeviceState>,
+}
+
+impl MockMAI400 {
+    /// Create a new simulated device in its power-on state
+    pub fn new() -> Self {
+        MockMAI400::default()
+    }
+
+    /// Create a new simulated device with its clock set to the given GPS time
+    pub fn with_gps_time(gps_time: u32) -> Self {
+        let mock = MockMAI400::default();
+        mock.state.borrow_mut().gps_time = gps_time;
+        mock
+    }
+}
+
+impl DeviceState {
+    fn handle_command(&mut self, data: &[u8]) {
+        if data.len() != CMD_LEN || data[0..2] != SYNC {
+            self.cmd_invalid_cntr = self.cmd_invalid_cntr.wrapping_add(1);
+            return;
+        }
+
+        let len = CMD_LEN - 2;
+        let crc = u16::from(data[len]) | (u16::from(data[len + 1]) << 8);
+        if checksum(&data[..len]) != crc {
+            self.cmd_invalid_chksum_cntr = self.cmd_invalid_chksum_cntr.wrapping_add(1);
+            return;
+        }
+
+        let id = data[2];
+        let reset_requested = self.reset_requested;
+        self.reset_requested = false;
+
+        match CommandId::from_u8(id) {
+            Some(CommandId::SetAcsMode) => self.acs_mode = data[3],
+            Some(CommandId::SetGPSTime) => {
+                self.gps_time = u32::from(data[3])
+                    | (u32::from(data[4]) << 8)
+                    | (u32::from(data[5]) << 16)
+                    | (u32::from(data[6]) << 24);
+                self.time_subsec = 0;
+            }
+            Some(CommandId::SetRV) => {}
+            Some(CommandId::RequestReset) => self.reset_requested = true,
+            Some(CommandId::ConfirmReset) if reset_requested => {
+                // The device reboots, so everything other than the clock
+                // (and any telemetry already in flight) returns to its power-on state
+                *self = DeviceState {
+                    gps_time: self.gps_time,
+                    output: self.output.split_off(0),
+                    ..Default::default()
+                };
+                return;
+            }
+            _ => {
+                self.cmd_invalid_cntr = self.cmd_invalid_cntr.wrapping_add(1);
+                return;
+            }
+        }
+
+        self.cmd_valid_cntr = self.cmd_valid_cntr.wrapping_add(1);
+        self.last_command = id;
+    }
+
+    // Generate the next message set and add it to the output stream
+    fn generate(&mut self) {
+        let mut std = SYNC.to_vec();
+        std.push(self.tlm_counter);
+        std.write_u32::<LittleEndian>(self.gps_time).unwrap();
+        std.push(self.time_subsec);
+        std.write_u16::<LittleEndian>(self.cmd_valid_cntr).unwrap();
+        std.write_u16::<LittleEndian>(self.cmd_invalid_cntr)
+            .unwrap();
+        std.write_u16::<LittleEndian>(self.cmd_invalid_chksum_cntr)
+            .unwrap();
+        std.push(self.last_command);
+        std.push(self.acs_mode);
+        std.resize(STD_LEN, 0);
+        let crc = checksum(&std);
+        std.write_u16::<LittleEndian>(crc).unwrap();
+
+        self.output.extend(std);
+        self.output
+            .extend(aux_packet(&[0x34, 0x00, 0xA6, 0x01], 52));
+        self.output
+            .extend(aux_packet(&[0x11, 0x00, 0xC8, 0x00], 17));
+
+        // Telemetry is sent at 4Hz
+        self.tlm_counter = (self.tlm_counter + 1) % ROTATING_SETS;
+        self.time_subsec += 1;
+        if self.time_subsec == 4 {
+            self.time_subsec = 0;
+            self.gps_time = self.gps_time.wrapping_add(1);
+        }
+    }
+}
+
+// Build an IREHS or raw IMU packet containing the given header and no readings
+fn aux_packet(header: &[u8], len: usize) -> Vec<u8> {
+    let mut data = header.to_vec();
+    data.resize(len, 0);
+
+    let mut packet = vec![];
+    packet.write_u16::<LittleEndian>(AUX_SYNC).unwrap();
+    packet.extend_from_slice(&data);
+    packet
+        .write_u16::<LittleEndian>(aux_checksum(&data))
+        .unwrap();
+    packet
+}
+
+impl Stream for MockMAI400 {
+    fn write(&self, data: &[u8]) -> UartResult<()> {
+        self.state.borrow_mut().handle_command(data);
+        Ok(())
+    }
+
+    fn read(&self, len: usize, _timeout: Duration) -> UartResult<Vec<u8>> {
+        let mut state = self.state.borrow_mut();
+
+        while state.output.len() < len {
+            state.generate();
+        }
+
+        Ok(state.output.drain(..len).collect())
+    }
+}
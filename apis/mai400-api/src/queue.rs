@@ -0,0 +1,239 @@
+/*
+ * Copyright (C) 2018 Kubos Corporation
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::mai400::*;
+use log::{debug, warn};
+use std::collections::VecDeque;
+
+/// Default number of message sets to wait through for a command to be acknowledged
+///
+/// The MAI-400 sends a message set every 250ms, so this allows roughly one second.
+pub const DEFAULT_ACK_ATTEMPTS: usize = 4;
+
+/// A command which can be placed in a [`CommandQueue`]
+///
+/// [`CommandQueue`]: struct.CommandQueue.html
+#[derive(Clone, Debug, PartialEq)]
+pub enum QueuedCommand {
+    /// Set the ACS mode. See [`MAI400::set_mode`](struct.MAI400.html#method.set_mode)
+    SetMode {
+        /// ACS mode to enter
+        mode: u8,
+        /// Arguments for configuring the requested mode
+        params: [i16; 4],
+    },
+    /// Set the GPS time. See [`MAI400::set_gps_time`](struct.MAI400.html#method.set_gps_time)
+    SetGpsTime(u32),
+    /// Set the orbital position and velocity. See [`MAI400::set_rv`](struct.MAI400.html#method.set_rv)
+    SetRV {
+        /// ECI position, in kilometers
+        eci_pos: [f32; 3],
+        /// ECI velocity, in kilometers per second
+        eci_vel: [f32; 3],
+        /// GPS time at which the vectors are valid
+        time_epoch: u32,
+    },
+}
+
+impl QueuedCommand {
+    fn send(&self, mai: &MAI400) -> MAIResult<()> {
+        match *self {
+            QueuedCommand::SetMode { mode, params } => mai.set_mode(mode, params),
+            QueuedCommand::SetGpsTime(gps_time) => mai.set_gps_time(gps_time),
+            QueuedCommand::SetRV {
+                eci_pos,
+                eci_vel,
+                time_epoch,
+            } => mai.set_rv(eci_pos, eci_vel, time_epoch),
+        }
+    }
+}
+
+/// Outcome of a single command sent by a [`CommandQueue`]
+///
+/// [`CommandQueue`]: struct.CommandQueue.html
+#[derive(Clone, Debug, PartialEq)]
+pub struct CommandResult {
+    /// The command which was sent
+    pub command: QueuedCommand,
+    /// The acknowledgment status reported by the MAI-400, or the error which
+    /// prevented the command from being delivered
+    pub result: MAIResult<CommandStatus>,
+}
+
+/// Queue of MAI-400 commands which are delivered strictly in order
+///
+/// Each command is only sent once the previous one has been acknowledged. Before a command
+/// is sent, the current command counters are read from standard telemetry. After it is sent,
+/// incoming message sets are checked until the counters change (see [`confirm_command`]).
+///
+/// If a command fails, is rejected, or is not acknowledged within the configured number of
+/// message sets, delivery stops. The failed command is reported and any commands after it
+/// are left in the queue, since they may depend on it having been applied.
+///
+/// # Examples
+///
+/// ```
+/// use mai400_api::*;
+///
+/// # fn func() -> MAIResult<()> {
+/// let mut queue = CommandQueue::new(MAI400::new("/dev/ttyS5")?);
+///
+/// queue.push(QueuedCommand::SetGpsTime(1198800018));
+/// queue.push(QueuedCommand::SetMode {
+///     mode: 3,
+///     params: [0, 0, 0, 0],
+/// });
+///
+/// for sent in queue.flush() {
+///     println!("{:?}: {:?}", sent.command, sent.result);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`confirm_command`]: struct.MAI400.html#method.confirm_command
+pub struct CommandQueue {
+    mai: MAI400,
+    pending: VecDeque<QueuedCommand>,
+    ack_attempts: usize,
+}
+
+impl CommandQueue {
+    /// Constructor for CommandQueue structure
+    ///
+    /// # Arguments
+    ///
+    /// * mai - The MAI-400 client to send commands with
+    pub fn new(mai: MAI400) -> Self {
+        CommandQueue {
+            mai,
+            pending: VecDeque::new(),
+            ack_attempts: DEFAULT_ACK_ATTEMPTS,
+        }
+    }
+
+    /// Set the number of message sets to wait through for each command to be acknowledged
+    ///
+    /// Defaults to [`DEFAULT_ACK_ATTEMPTS`]. A value of zero is treated as one.
+    ///
+    /// [`DEFAULT_ACK_ATTEMPTS`]: constant.DEFAULT_ACK_ATTEMPTS.html
+    pub fn with_ack_attempts(mut self, attempts: usize) -> Self {
+        self.ack_attempts = attempts.max(1);
+        self
+    }
+
+    /// Add a command to the end of the queue
+    pub fn push(&mut self, command: QueuedCommand) {
+        self.pending.push_back(command);
+    }
+
+    /// Number of commands waiting to be sent
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Whether there are no commands waiting to be sent
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Send all queued commands, in order, waiting for each one to be acknowledged
+    ///
+    /// Returns one [`CommandResult`] per command sent. Successful results will contain
+    /// `CommandStatus::Accepted`. If a command fails, its result is the last one returned
+    /// and the remaining commands stay queued.
+    ///
+    /// # Errors
+    ///
+    /// Per-command errors are reported in [`CommandResult::result`]:
+    /// `MAIError::CommandRejected` if the MAI-400 rejected the command, and
+    /// `MAIError::NotAcknowledged` if its command counters didn't change in time.
+    ///
+    /// [`CommandResult`]: struct.CommandResult.html
+    /// [`CommandResult::result`]: struct.CommandResult.html#structfield.result
+    pub fn flush(&mut self) -> Vec<CommandResult> {
+        deliver(&self.mai, &mut self.pending, self.ack_attempts)
+    }
+
+    /// Send all queued commands without blocking the calling task
+    ///
+    /// See [`flush`](#method.flush). Only available when the `async` feature is enabled.
+    ///
+    /// # Errors
+    ///
+    /// If the helper thread delivering the commands fails, `MAIError::ThreadCommError` will be
+    /// returned and the queue will be left empty, since it can't be known which commands were sent.
+    #[cfg(feature = "async")]
+    pub async fn drain(&mut self) -> MAIResult<Vec<CommandResult>> {
+        let mai = self.mai.clone();
+        let mut pending = std::mem::replace(&mut self.pending, VecDeque::new());
+        let attempts = self.ack_attempts;
+
+        let (pending, results) = crate::mai400_async::blocking(move || {
+            let results = deliver(&mai, &mut pending, attempts);
+            Ok((pending, results))
+        })
+        .await?;
+
+        self.pending = pending;
+        Ok(results)
+    }
+}
+
+// Send commands from the front of the queue until it is empty or a command fails
+fn deliver(
+    mai: &MAI400,
+    pending: &mut VecDeque<QueuedCommand>,
+    attempts: usize,
+) -> Vec<CommandResult> {
+    let mut results = vec![];
+
+    while let Some(command) = pending.pop_front() {
+        debug!("Sending queued command {:?}", command);
+
+        let result = send_acknowledged(mai, &command, attempts);
+        let failed = result.is_err();
+        if let Err(ref err) = result {
+            warn!("Queued command {:?} failed: {}", command, err);
+        }
+
+        results.push(CommandResult { command, result });
+        if failed {
+            break;
+        }
+    }
+
+    results
+}
+
+fn send_acknowledged(
+    mai: &MAI400,
+    command: &QueuedCommand,
+    attempts: usize,
+) -> MAIResult<CommandStatus> {
+    let before = mai.get_std_telemetry()?;
+    command.send(mai)?;
+
+    for _ in 0..attempts {
+        match mai.confirm_command(&before)? {
+            CommandStatus::NoChange => continue,
+            status => return Ok(status),
+        }
+    }
+
+    Err(MAIError::NotAcknowledged)
+}
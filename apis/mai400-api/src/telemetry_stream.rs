@@ -0,0 +1,137 @@
+/*
+ * Copyright (C) 2018 Kubos Corporation
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::mai400::*;
+use crate::messages::*;
+use log::warn;
+
+/// Iterator over the standard telemetry frames sent by the MAI-400
+///
+/// Created with [`MAI400::telemetry_stream`].
+///
+/// Each call to `next` waits for the next message set to arrive. Resyncing and checksum
+/// validation are handled by [`get_message`]. Message sets which are corrupt
+/// (`MAIError::ChecksumMismatch`, `MAIError::FramingError`, `MAIError::SyncLost` or
+/// `MAIError::SyncMismatch`), or which don't contain a standard telemetry frame, are skipped
+/// with a logged warning.
+///
+/// The stream never ends on its own. Any other error (such as `MAIError::Timeout` or a UART
+/// failure) is returned as an `Err` item, and the caller may keep iterating to resume reading.
+///
+/// [`MAI400::telemetry_stream`]: struct.MAI400.html#method.telemetry_stream
+/// [`get_message`]: struct.MAI400.html#method.get_message
+#[derive(Clone)]
+pub struct TelemetryStream {
+    mai: MAI400,
+}
+
+impl Iterator for TelemetryStream {
+    type Item = MAIResult<StandardTelemetry>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(next_telemetry(&self.mai))
+    }
+}
+
+impl MAI400 {
+    /// Create an iterator which yields standard telemetry frames as they arrive
+    ///
+    /// See [`TelemetryStream`] for how corrupt frames and errors are handled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mai400_api::*;
+    /// # fn func() -> MAIResult<()> {
+    /// let mai = MAI400::new("/dev/ttyS5")?;
+    ///
+    /// for telem in mai.telemetry_stream().take(10) {
+    ///     match telem {
+    ///         Ok(telem) => println!("GPS time: {}", telem.gps_time),
+    ///         Err(err) => println!("Failed to read telemetry: {}", err),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`TelemetryStream`]: struct.TelemetryStream.html
+    pub fn telemetry_stream(&self) -> TelemetryStream {
+        TelemetryStream { mai: self.clone() }
+    }
+}
+
+// Read message sets until one containing a valid standard telemetry frame arrives,
+// or until a non-recoverable error occurs
+fn next_telemetry(mai: &MAI400) -> MAIResult<StandardTelemetry> {
+    loop {
+        match mai.get_message() {
+            Ok((Some(std), _, _)) => return Ok(std),
+            Ok((None, _, _)) => warn!("Skipping message set without standard telemetry"),
+            Err(err @ MAIError::ChecksumMismatch { .. })
+            | Err(err @ MAIError::FramingError { .. })
+            | Err(err @ MAIError::SyncLost)
+            | Err(err @ MAIError::SyncMismatch { .. }) => {
+                warn!("Skipping corrupt telemetry frame: {}", err)
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+/// Non-blocking counterpart of [`TelemetryStream`]
+///
+/// Created with [`MAI400Async::telemetry_stream`]. Call [`next`] repeatedly to receive
+/// each frame as it arrives. Only available when the `async` feature is enabled.
+///
+/// [`TelemetryStream`]: struct.TelemetryStream.html
+/// [`MAI400Async::telemetry_stream`]: struct.MAI400Async.html#method.telemetry_stream
+/// [`next`]: #method.next
+#[cfg(feature = "async")]
+#[derive(Clone)]
+pub struct AsyncTelemetryStream {
+    mai: MAI400,
+}
+
+#[cfg(feature = "async")]
+impl AsyncTelemetryStream {
+    /// Wait for the next valid standard telemetry frame
+    ///
+    /// Corrupt frames are skipped, exactly as with [`TelemetryStream`].
+    ///
+    /// # Errors
+    ///
+    /// Any non-corruption error encountered while reading is returned. The stream
+    /// may continue to be used afterwards.
+    ///
+    /// [`TelemetryStream`]: struct.TelemetryStream.html
+    pub async fn next(&mut self) -> MAIResult<StandardTelemetry> {
+        let mai = self.mai.clone();
+        crate::mai400_async::blocking(move || next_telemetry(&mai)).await
+    }
+}
+
+#[cfg(feature = "async")]
+impl crate::mai400_async::MAI400Async {
+    /// Create a stream which yields standard telemetry frames as they arrive
+    ///
+    /// See [`MAI400::telemetry_stream`](struct.MAI400.html#method.telemetry_stream)
+    pub fn telemetry_stream(&self) -> AsyncTelemetryStream {
+        AsyncTelemetryStream {
+            mai: self.inner.clone(),
+        }
+    }
+}
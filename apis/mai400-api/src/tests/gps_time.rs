@@ -0,0 +1,77 @@
+/*
+ * Copyright (C) 2018 Kubos Corporation
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::*;
+use chrono::{TimeZone, Utc};
+
+#[test]
+fn documented_example() {
+    // Jan 01, 2018
+    let time = Utc.ymd(2018, 1, 1).and_hms(0, 0, 0);
+
+    assert_eq!(gps_seconds_from_datetime(time), 1198800018);
+    assert_eq!(datetime_from_gps_seconds(1198800018), time);
+}
+
+#[test]
+fn gps_epoch() {
+    let time = Utc.ymd(1980, 1, 6).and_hms(0, 0, 0);
+
+    assert_eq!(gps_seconds_from_datetime(time), 0);
+    assert_eq!(datetime_from_gps_seconds(0), time);
+}
+
+#[test]
+fn before_gps_epoch() {
+    let time = Utc.ymd(1979, 12, 31).and_hms(0, 0, 0);
+
+    assert_eq!(gps_seconds_from_datetime(time), 0);
+}
+
+#[test]
+fn leap_second_boundary() {
+    // Offset was 17 seconds just before Jan 01, 2017 and 18 seconds afterwards
+    let before = Utc.ymd(2016, 12, 31).and_hms(23, 59, 59);
+    let after = Utc.ymd(2017, 1, 1).and_hms(0, 0, 0);
+
+    let gps_before = gps_seconds_from_datetime(before);
+    let gps_after = gps_seconds_from_datetime(after);
+
+    assert_eq!(gps_after - gps_before, 2);
+    assert_eq!(datetime_from_gps_seconds(gps_before), before);
+    assert_eq!(datetime_from_gps_seconds(gps_after), after);
+}
+
+#[test]
+fn set_gps_time_utc_good() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0x90, 0xEB, 0x44, 0x92, 0x3C, 0x74, 0x47, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x48, 0x3,
+    ]);
+
+    let tracker = mock.tracker();
+
+    let mai = mock_new!(mock);
+
+    assert!(mai
+        .set_gps_time_utc(Utc.ymd(2018, 1, 1).and_hms(0, 0, 0))
+        .is_ok());
+
+    tracker.assert_consumed();
+}
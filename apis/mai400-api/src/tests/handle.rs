@@ -0,0 +1,96 @@
+/*
+ * Copyright (C) 2018 Kubos Corporation
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::*;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+// Simulated device which also keeps a log of every frame written to it
+struct RecordingStream {
+    device: MockMAI400,
+    log: Arc<Mutex<Vec<Vec<u8>>>>,
+}
+
+impl Stream for RecordingStream {
+    fn write(&self, data: &[u8]) -> UartResult<()> {
+        self.log.lock().unwrap().push(data.to_vec());
+        self.device.write(data)
+    }
+
+    fn read(&self, len: usize, timeout: Duration) -> UartResult<Vec<u8>> {
+        self.device.read(len, timeout)
+    }
+}
+
+#[test]
+fn handle_threads_serialized() {
+    let log = Arc::new(Mutex::new(vec![]));
+    let mock = RecordingStream {
+        device: MockMAI400::new(),
+        log: log.clone(),
+    };
+
+    let mai = MAI400Handle::from(mock_new!(mock));
+
+    let resetter = mai.clone();
+    let reset_thread = thread::spawn(move || {
+        for _ in 0..20 {
+            resetter.reset().unwrap();
+        }
+    });
+
+    let commander = mai.clone();
+    let command_thread = thread::spawn(move || {
+        for _ in 0..20 {
+            commander.set_mode(3, [0, 0, 0, 0]).unwrap();
+            commander.get_message().unwrap();
+        }
+    });
+
+    reset_thread.join().unwrap();
+    command_thread.join().unwrap();
+
+    // Every reset request must be immediately followed by its confirmation
+    let log = log.lock().unwrap();
+    assert_eq!(log.len(), 60);
+    for (index, frame) in log.iter().enumerate() {
+        if frame[2] == CommandId::RequestReset.as_u8() {
+            assert_eq!(log[index + 1][2], CommandId::ConfirmReset.as_u8());
+        }
+    }
+
+    let (std, _, _) = mai.get_message().unwrap();
+    assert_eq!(std.unwrap().cmd_invalid_cntr, 0);
+}
+
+#[test]
+fn handle_lock_sequence() {
+    let mock = MockMAI400::new();
+
+    let mai = MAI400Handle::from(mock_new!(mock));
+
+    {
+        let guard = mai.lock();
+        guard.set_mode(9, [0, 0, 0, 0]).unwrap();
+        guard.set_gps_time(1198800018).unwrap();
+    }
+
+    let (std, _, _) = mai.get_message().unwrap();
+    let std = std.unwrap();
+    assert_eq!(std.acs_mode, 9);
+    assert_eq!(std.gps_time, 1198800018);
+    assert_eq!(std.cmd_valid_cntr, 2);
+}
@@ -0,0 +1,107 @@
+/*
+ * Copyright (C) 2018 Kubos Corporation
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::*;
+use log::{Level, Log, Metadata, Record};
+use std::cell::RefCell;
+use std::time::Duration;
+
+// Captures records emitted on the current thread, so that tests running
+// in parallel don't see each other's messages
+struct RecordCapture;
+
+thread_local! {
+    static RECORDS: RefCell<Vec<(Level, String)>> = RefCell::new(vec![]);
+}
+
+impl Log for RecordCapture {
+    fn enabled(&self, _metadata: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        RECORDS.with(|records| {
+            records
+                .borrow_mut()
+                .push((record.level(), format!("{}", record.args())))
+        });
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: RecordCapture = RecordCapture;
+
+fn capture_records<F: FnOnce()>(func: F) -> Vec<(Level, String)> {
+    // Only the first call can succeed. Later calls reuse the same logger
+    let _ = log::set_logger(&LOGGER);
+    log::set_max_level(log::LevelFilter::Trace);
+
+    RECORDS.with(|records| records.borrow_mut().clear());
+    func();
+    RECORDS.with(|records| records.borrow().clone())
+}
+
+#[test]
+fn set_mode_logs_command() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0x90, 0xEB, 0x0, 0x1, 0x2, 0x0, 0x3, 0x0, 0x4, 0x0, 0x5, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x8A, 0x1,
+    ]);
+
+    let mai = mock_new!(mock);
+
+    let records = capture_records(|| {
+        assert!(mai.set_mode(0x01, [0x02, 0x03, 0x04, 0x05]).is_ok());
+    });
+
+    assert!(records.contains(&(
+        Level::Debug,
+        "Setting ACS mode 1 with parameters [2, 3, 4, 5]".to_owned()
+    )));
+    assert!(records.contains(&(
+        Level::Trace,
+        "TX: 90EB000102000300040005000000000000000000000000000000000000000000000000000000008A01"
+            .to_owned()
+    )));
+}
+
+#[test]
+fn retry_logs_warning() {
+    let mut mock = MockStream::default();
+
+    // Fail once, then succeed
+    mock.write.set_input(vec![]);
+    mock.write.set_input(vec![
+        0x90, 0xEB, 0x0, 0x1, 0x2, 0x0, 0x3, 0x0, 0x4, 0x0, 0x5, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x8A, 0x1,
+    ]);
+
+    let mai = mock_new!(mock).with_retries(1, Duration::from_millis(1));
+
+    let records = capture_records(|| {
+        assert!(mai.set_mode(0x01, [0x02, 0x03, 0x04, 0x05]).is_ok());
+    });
+
+    assert!(records.contains(&(
+        Level::Warn,
+        "Transient UART error, retrying in 1ms (1/1)".to_owned()
+    )));
+}
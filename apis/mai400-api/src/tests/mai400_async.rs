@@ -0,0 +1,122 @@
+/*
+ * Copyright (C) 2018 Kubos Corporation
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::*;
+use crate::mai400::*;
+use crate::mai400_async::*;
+use std::future::Future;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake};
+use std::thread::{self, Thread};
+
+// Minimal executor for driving the async client to completion
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = Box::pin(future);
+    let waker = Arc::new(ThreadWaker(thread::current())).into();
+    let mut cx = Context::from_waker(&waker);
+
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(result) => return result,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+#[test]
+fn async_set_mode_good() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0x90, 0xEB, 0x0, 0x1, 0x2, 0x0, 0x3, 0x0, 0x4, 0x0, 0x5, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x8A, 0x1,
+    ]);
+
+    let mai = MAI400Async::from(mock_new!(mock));
+
+    assert!(block_on(mai.set_mode(0x01, [0x02, 0x03, 0x04, 0x05])).is_ok());
+}
+
+#[test]
+fn async_set_mode_bad() {
+    let mock = MockStream::default();
+
+    let mai = MAI400Async::from(mock_new!(mock));
+
+    assert_eq!(
+        block_on(mai.set_acs_mode(AcsMode::RateNulling, [0x02, 0x03, 0x04, 0x05])).unwrap_err(),
+        MAIError::UartError {
+            cause: UartError::GenericError,
+        }
+    );
+}
+
+#[test]
+fn async_get_message_bad() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_result(Err(UartError::GenericError));
+
+    let mai = MAI400Async::from(mock_new!(mock));
+
+    assert_eq!(
+        block_on(mai.get_message()).unwrap_err(),
+        MAIError::UartError {
+            cause: UartError::GenericError
+        }
+    );
+}
+
+#[test]
+fn async_queue_drain() {
+    let mock = MockMAI400::new();
+
+    let mut queue = CommandQueue::new(mock_new!(mock));
+    queue.push(QueuedCommand::SetMode {
+        mode: 3,
+        params: [0, 0, 0, 0],
+    });
+    queue.push(QueuedCommand::SetGpsTime(1198800018));
+
+    let results = block_on(queue.drain()).unwrap();
+
+    assert_eq!(results.len(), 2);
+    assert!(results
+        .iter()
+        .all(|sent| sent.result == Ok(CommandStatus::Accepted)));
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn async_telemetry_stream() {
+    let mock = MockMAI400::new();
+
+    let mai = MAI400Async::from(mock_new!(mock));
+    let mut stream = mai.telemetry_stream();
+
+    let first = block_on(stream.next()).unwrap();
+    let second = block_on(stream.next()).unwrap();
+    assert_eq!(second.tlm_counter, first.tlm_counter.wrapping_add(1));
+}
@@ -0,0 +1,124 @@
+/*
+ * Copyright (C) 2018 Kubos Corporation
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::*;
+
+fn get_std(mai: &MAI400) -> StandardTelemetry {
+    let (std, imu, irehs) = mai.get_message().unwrap();
+
+    assert!(imu.is_some());
+    assert!(irehs.is_some());
+    std.unwrap()
+}
+
+#[test]
+fn mock_device_set_mode() {
+    let mock = MockMAI400::new();
+    let mai = mock_new!(mock);
+
+    mai.set_mode(9, [1, 2, 3, 4]).unwrap();
+
+    let std = get_std(&mai);
+    assert_eq!(std.acs_mode, 9);
+    assert_eq!(std.last_command, CommandId::SetAcsMode.as_u8());
+    assert_eq!(std.cmd_valid_cntr, 1);
+    assert_eq!(std.cmd_invalid_chksum_cntr, 0);
+}
+
+#[test]
+fn mock_device_set_gps_time() {
+    let mock = MockMAI400::new();
+    let mai = mock_new!(mock);
+
+    mai.set_gps_time(1198800018).unwrap();
+
+    let std = get_std(&mai);
+    assert_eq!(std.gps_time, 1198800018);
+    assert_eq!(std.last_command, CommandId::SetGPSTime.as_u8());
+    assert_eq!(std.cmd_valid_cntr, 1);
+}
+
+#[test]
+fn mock_device_clock() {
+    let mock = MockMAI400::with_gps_time(1198800018);
+    let mai = mock_new!(mock);
+
+    let times: Vec<(u8, u32, u8)> = (0..5)
+        .map(|_| {
+            let std = get_std(&mai);
+            (std.tlm_counter, std.gps_time, std.time_subsec)
+        })
+        .collect();
+
+    assert_eq!(
+        times,
+        vec![
+            (0, 1198800018, 0),
+            (1, 1198800018, 1),
+            (2, 1198800018, 2),
+            (3, 1198800018, 3),
+            (4, 1198800019, 0),
+        ]
+    );
+}
+
+#[test]
+fn mock_device_reset() {
+    let mock = MockMAI400::new();
+    let mai = mock_new!(mock);
+
+    mai.set_mode(3, [0, 0, 0, 0]).unwrap();
+    mai.reset().unwrap();
+
+    let std = get_std(&mai);
+    assert_eq!(std.acs_mode, 0);
+    assert_eq!(std.last_command, 0);
+    assert_eq!(std.cmd_valid_cntr, 0);
+}
+
+#[test]
+fn mock_device_confirm_without_request() {
+    let mock = MockMAI400::new();
+    let mai = mock_new!(mock);
+
+    let mut msg = vec![0x90, 0xEB, CommandId::ConfirmReset.as_u8()];
+    msg.resize(38, 0);
+    let crc = checksum(&msg);
+    msg.push(crc as u8);
+    msg.push((crc >> 8) as u8);
+
+    mai.passthrough(&msg).unwrap();
+
+    let std = get_std(&mai);
+    assert_eq!(std.cmd_valid_cntr, 0);
+    assert_eq!(std.cmd_invalid_cntr, 1);
+}
+
+#[test]
+fn mock_device_bad_checksum() {
+    let mock = MockMAI400::new();
+    let mai = mock_new!(mock);
+
+    let mut msg = vec![0x90, 0xEB, CommandId::SetAcsMode.as_u8(), 0x01];
+    msg.resize(40, 0);
+
+    mai.passthrough(&msg).unwrap();
+
+    let std = get_std(&mai);
+    assert_eq!(std.acs_mode, 0);
+    assert_eq!(std.cmd_valid_cntr, 0);
+    assert_eq!(std.cmd_invalid_chksum_cntr, 1);
+}
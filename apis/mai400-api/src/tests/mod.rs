@@ -17,18 +17,18 @@
 use super::*;
 use rust_uart::mock::*;
 use rust_uart::*;
+use std::time::Duration;
 
 /// Helper macro for generating mock mai objects
 #[macro_export]
 macro_rules! mock_new {
     ($mock:ident) => {{
-        use std::sync::{Arc, Mutex};
-
-        let conn = Arc::new(Mutex::new(Connection {
-            stream: Box::new($mock),
-        }));
-
-        MAI400 { conn }
+        MAI400Builder::new()
+            .connection(Connection {
+                stream: Box::new($mock),
+            })
+            .build()
+            .unwrap()
     }};
 }
 
@@ -47,6 +47,48 @@ fn mock_test() {
     );
 }
 
+#[test]
+fn builder_settings() {
+    let mock = MockStream::default();
+
+    let mai = MAI400Builder::new()
+        .connection(Connection {
+            stream: Box::new(mock),
+        })
+        .read_timeout(Duration::from_millis(300))
+        .retries(2, Duration::from_millis(5))
+        .build()
+        .unwrap();
+
+    assert_eq!(mai.read_timeout, Some(Duration::from_millis(300)));
+    assert_eq!(
+        mai.retry,
+        RetryPolicy {
+            count: 2,
+            backoff: Duration::from_millis(5),
+        }
+    );
+}
+
+#[test]
+fn builder_no_bus() {
+    assert_eq!(
+        MAI400Builder::new().build().err().unwrap(),
+        MAIError::InvalidInput {
+            reason: "Either a UART path or a connection must be given".to_owned(),
+        }
+    );
+}
+
+#[cfg(feature = "chrono")]
+mod gps_time;
+mod handle;
+mod logging;
+#[cfg(feature = "async")]
+mod mai400_async;
+mod mock_mai400;
+mod queue;
+mod reconnect;
 mod rotating;
 mod rx;
 mod tx;
@@ -0,0 +1,165 @@
+/*
+ * Copyright (C) 2018 Kubos Corporation
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::*;
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, PartialEq)]
+enum Event {
+    Write(u8),
+    Read,
+}
+
+// Simulated device which logs every command frame and read, in order.
+// If `deaf` is set, commands are logged but never reach the device
+struct RecordingStream {
+    device: MockMAI400,
+    log: Arc<Mutex<Vec<Event>>>,
+    deaf: bool,
+}
+
+impl Stream for RecordingStream {
+    fn write(&self, data: &[u8]) -> UartResult<()> {
+        self.log.lock().unwrap().push(Event::Write(data[2]));
+        if self.deaf {
+            return Ok(());
+        }
+        self.device.write(data)
+    }
+
+    fn read(&self, len: usize, timeout: Duration) -> UartResult<Vec<u8>> {
+        let mut log = self.log.lock().unwrap();
+        if log.last() != Some(&Event::Read) {
+            log.push(Event::Read);
+        }
+        self.device.read(len, timeout)
+    }
+}
+
+fn recording_mai(deaf: bool) -> (MAI400, Arc<Mutex<Vec<Event>>>) {
+    let log = Arc::new(Mutex::new(vec![]));
+    let mock = RecordingStream {
+        device: MockMAI400::new(),
+        log: log.clone(),
+        deaf,
+    };
+
+    (mock_new!(mock), log)
+}
+
+#[test]
+fn queue_flush_in_order() {
+    let (mai, log) = recording_mai(false);
+
+    let mut queue = CommandQueue::new(mai.clone());
+    queue.push(QueuedCommand::SetMode {
+        mode: 9,
+        params: [0, 0, 0, 0],
+    });
+    queue.push(QueuedCommand::SetGpsTime(1198800018));
+    assert_eq!(queue.len(), 2);
+
+    let results = queue.flush();
+
+    assert_eq!(
+        results,
+        vec![
+            CommandResult {
+                command: QueuedCommand::SetMode {
+                    mode: 9,
+                    params: [0, 0, 0, 0],
+                },
+                result: Ok(CommandStatus::Accepted),
+            },
+            CommandResult {
+                command: QueuedCommand::SetGpsTime(1198800018),
+                result: Ok(CommandStatus::Accepted),
+            },
+        ]
+    );
+    assert!(queue.is_empty());
+
+    // Each command is preceded by a counter read and followed by its acknowledgment,
+    // so the second command can't be sent until the first has been acknowledged
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec![
+            Event::Read,
+            Event::Write(CommandId::SetAcsMode.as_u8()),
+            Event::Read,
+            Event::Write(CommandId::SetGPSTime.as_u8()),
+            Event::Read,
+        ]
+    );
+
+    let (std, _, _) = mai.get_message().unwrap();
+    let std = std.unwrap();
+    assert_eq!(std.cmd_valid_cntr, 2);
+    assert_eq!(std.acs_mode, 9);
+    assert_eq!(std.gps_time, 1198800018);
+}
+
+#[test]
+fn queue_flush_stops_on_error() {
+    let (mai, log) = recording_mai(false);
+
+    let mut queue = CommandQueue::new(mai);
+    queue.push(QueuedCommand::SetMode {
+        mode: 0x20,
+        params: [0, 0, 0, 0],
+    });
+    queue.push(QueuedCommand::SetGpsTime(1198800018));
+
+    let results = queue.flush();
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        results[0].result,
+        Err(MAIError::InvalidInput {
+            reason: "Unknown ACS mode: 32".to_owned()
+        })
+    );
+    assert_eq!(queue.len(), 1);
+
+    // The bad mode is caught before anything is sent
+    assert_eq!(*log.lock().unwrap(), vec![Event::Read]);
+}
+
+#[test]
+fn queue_flush_not_acknowledged() {
+    let (mai, log) = recording_mai(true);
+
+    let mut queue = CommandQueue::new(mai).with_ack_attempts(2);
+    queue.push(QueuedCommand::SetGpsTime(1198800018));
+    queue.push(QueuedCommand::SetMode {
+        mode: 3,
+        params: [0, 0, 0, 0],
+    });
+
+    let results = queue.flush();
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].result, Err(MAIError::NotAcknowledged));
+    assert_eq!(queue.len(), 1);
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec![
+            Event::Read,
+            Event::Write(CommandId::SetGPSTime.as_u8()),
+            Event::Read
+        ]
+    );
+}
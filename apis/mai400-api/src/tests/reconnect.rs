@@ -0,0 +1,121 @@
+/*
+ * Copyright (C) 2018 Kubos Corporation
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+// Simulates a device which has dropped off the bus and comes back once the port is reopened:
+// the first connection rejects every write, later ones are a working device
+fn flaky_builder(opens: Arc<AtomicUsize>) -> MAI400Builder {
+    MAI400Builder::new().connector(move || {
+        let stream: Box<dyn Stream> = match opens.fetch_add(1, Ordering::SeqCst) {
+            0 => Box::new(MockStream::default()),
+            _ => Box::new(MockMAI400::new()),
+        };
+        Ok(Connection::new(stream))
+    })
+}
+
+#[test]
+fn reconnect_good() {
+    let opens = Arc::new(AtomicUsize::new(0));
+    let mai = flaky_builder(opens.clone()).build().unwrap();
+
+    assert_eq!(
+        mai.set_mode(3, [0, 0, 0, 0]).unwrap_err(),
+        MAIError::UartError {
+            cause: UartError::GenericError,
+        }
+    );
+
+    mai.reconnect().unwrap();
+    mai.set_mode(3, [0, 0, 0, 0]).unwrap();
+
+    assert_eq!(mai.get_mode().unwrap(), AcsMode::NadirPointing);
+    assert_eq!(opens.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn reconnect_auto() {
+    let opens = Arc::new(AtomicUsize::new(0));
+    let mai = flaky_builder(opens.clone())
+        .auto_reconnect(true)
+        .build()
+        .unwrap();
+
+    assert!(mai.set_mode(3, [0, 0, 0, 0]).is_err());
+    mai.set_mode(3, [0, 0, 0, 0]).unwrap();
+    mai.set_gps_time(1198800018).unwrap();
+
+    assert_eq!(opens.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn reconnect_auto_disabled() {
+    let opens = Arc::new(AtomicUsize::new(0));
+    let mai = flaky_builder(opens.clone()).build().unwrap();
+
+    assert!(mai.set_mode(3, [0, 0, 0, 0]).is_err());
+    assert!(mai.set_mode(3, [0, 0, 0, 0]).is_err());
+
+    assert_eq!(opens.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn reconnect_failed() {
+    let opens = Arc::new(AtomicUsize::new(0));
+    let counter = opens.clone();
+    let mai = MAI400Builder::new()
+        .connector(move || match counter.fetch_add(1, Ordering::SeqCst) {
+            0 => Ok(Connection::new(Box::new(MockMAI400::new()))),
+            _ => Err(UartError::PortBusy),
+        })
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        mai.reconnect().unwrap_err(),
+        MAIError::UartError {
+            cause: UartError::PortBusy,
+        }
+    );
+
+    // The old connection has been closed
+    assert_eq!(
+        mai.set_mode(3, [0, 0, 0, 0]).unwrap_err(),
+        MAIError::UartError {
+            cause: UartError::IoError {
+                cause: ::std::io::ErrorKind::NotConnected,
+                description: "Connection closed".to_owned(),
+            },
+        }
+    );
+}
+
+#[test]
+fn reconnect_no_params() {
+    let mock = MockStream::default();
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(
+        mai.reconnect().unwrap_err(),
+        MAIError::InvalidInput {
+            reason: "No connection parameters available to reconnect with".to_owned(),
+        }
+    );
+}
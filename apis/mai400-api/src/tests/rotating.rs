@@ -489,3 +489,70 @@ fn update_23() {
     assert_eq!(rotating.rws_motor_current[2], 0x090A);
     assert_eq!(rotating.rws_motor_temp, 0x0B0C);
 }
+
+#[test]
+fn accumulator_fills_in() {
+    let mut accumulator = RotatingVariable::default();
+
+    for index in 0..ROTATING_SETS {
+        assert!(accumulator.rotating().is_none());
+
+        let input = StandardTelemetry {
+            // Top bits hold version information and should be ignored
+            tlm_counter: index | 0x20,
+            rotating_variable_a: 0x3F8CCCCD,
+            rotating_variable_b: 0x400CCCCD,
+            rotating_variable_c: 0x40533333,
+            ..Default::default()
+        };
+
+        accumulator.update(&input);
+
+        if index < ROTATING_SETS - 1 {
+            assert!(accumulator.seen(index));
+            assert!(!accumulator.seen(index + 1));
+        }
+    }
+
+    let snapshot = accumulator.rotating().unwrap();
+    assert_eq!(snapshot.b_field_igrf, [1.1, 2.2, 3.3]);
+    assert_eq!(snapshot.kepler_elem.true_anomoly, 3.3);
+    assert_eq!(snapshot.rwa_trq_max, 1.1);
+    assert!(!accumulator.seen(0));
+}
+
+#[test]
+fn accumulator_holds_snapshot() {
+    let mut accumulator = RotatingVariable::default();
+
+    for index in 0..ROTATING_SETS {
+        accumulator.update(&StandardTelemetry {
+            tlm_counter: index,
+            rotating_variable_a: 0x3F8CCCCD,
+            ..Default::default()
+        });
+    }
+
+    // A partial cycle should not leak into the published snapshot
+    accumulator.update(&StandardTelemetry {
+        tlm_counter: 0,
+        rotating_variable_a: 0x40533333,
+        ..Default::default()
+    });
+
+    assert_eq!(accumulator.rotating().unwrap().b_field_igrf[0], 1.1);
+    assert!(accumulator.seen(0));
+}
+
+#[test]
+fn accumulator_ignores_unknown_index() {
+    let mut accumulator = RotatingVariable::default();
+
+    accumulator.update(&StandardTelemetry {
+        tlm_counter: 30,
+        ..Default::default()
+    });
+
+    assert!(!accumulator.seen(30));
+    assert!(accumulator.rotating().is_none());
+}
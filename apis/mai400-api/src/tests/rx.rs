@@ -16,6 +16,9 @@
 
 use super::*;
 use crate::mai400::*;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 static RAW_READ: [u8; 238] = [
     0x90, 0xEB, 0x3, 0x93, 0x3C, 0x74, 0x47, 0x0, 0x2, 0x0, 0x0, 0x0, 0x0, 0x0, 0x44, 0x1, 0x0,
@@ -36,11 +39,45 @@ static RAW_READ: [u8; 238] = [
 
 #[test]
 fn get_message_bad() {
-    let mock = MockStream::default();
+    let mut mock = MockStream::default();
+
+    mock.read.set_result(Err(UartError::GenericError));
 
     let mai = mock_new!(mock);
 
-    assert_eq!(mai.get_message().unwrap_err(), MAIError::GenericError);
+    assert_eq!(
+        mai.get_message().unwrap_err(),
+        MAIError::UartError {
+            cause: UartError::GenericError
+        }
+    );
+}
+
+#[test]
+fn get_message_timeout() {
+    let mut mock = MockStream::default();
+
+    // No data will ever arrive
+    mock.read.set_output(vec![]);
+
+    let mut mai = mock_new!(mock);
+    mai.set_read_timeout(Duration::from_millis(300));
+
+    assert_eq!(mai.get_message().unwrap_err(), MAIError::Timeout);
+}
+
+#[test]
+fn get_message_timeout_not_reached() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(RAW_READ.to_vec());
+
+    let mut mai = mock_new!(mock);
+    mai.set_read_timeout(Duration::from_millis(300));
+
+    let (std, _, _) = mai.get_message().unwrap();
+
+    assert!(std.is_some());
 }
 
 #[test]
@@ -88,6 +125,281 @@ fn get_message_good_stdtelem() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn drain_messages_multiple() {
+    let mut mock = MockStream::default();
+
+    // Two complete message sets, followed by the start of a third
+    let mut output = RAW_READ.to_vec();
+    output.extend_from_slice(&RAW_READ);
+    output.extend_from_slice(&RAW_READ[0..100]);
+    mock.read.set_output(output);
+
+    let mai = mock_new!(mock);
+
+    let messages = mai.drain_messages().unwrap();
+
+    assert_eq!(messages.len(), 2);
+    assert_eq!(messages[0].tlm_counter, 3);
+    assert_eq!(messages[1].tlm_counter, 3);
+}
+
+// Stream which behaves like the real serial port: a read which times out still
+// consumes whatever bytes had arrived. More data can arrive after the client is created
+#[derive(Clone, Default)]
+struct ConsumingStream {
+    data: Arc<Mutex<VecDeque<u8>>>,
+}
+
+impl ConsumingStream {
+    fn arrive(&self, bytes: &[u8]) {
+        self.data.lock().unwrap().extend(bytes);
+    }
+}
+
+impl Stream for ConsumingStream {
+    fn write(&self, _data: &[u8]) -> UartResult<()> {
+        Ok(())
+    }
+
+    fn read(&self, len: usize, _timeout: Duration) -> UartResult<Vec<u8>> {
+        let mut data = self.data.lock().unwrap();
+        let available = len.min(data.len());
+        let response: Vec<u8> = data.drain(..available).collect();
+
+        if response.len() < len {
+            return Err(UartError::IoError {
+                cause: ::std::io::ErrorKind::TimedOut,
+                description: "Operation timed out".to_owned(),
+            });
+        }
+
+        Ok(response)
+    }
+}
+
+#[test]
+fn drain_messages_partial_kept() {
+    let stream = ConsumingStream::default();
+
+    let mut output = RAW_READ.to_vec();
+    output.extend_from_slice(&RAW_READ[0..100]);
+    stream.arrive(&output);
+
+    let mock = stream.clone();
+    let mai = mock_new!(mock);
+
+    assert_eq!(mai.drain_messages().unwrap().len(), 1);
+
+    // The rest of the partial message set arrives later
+    stream.arrive(&RAW_READ[100..]);
+
+    let messages = mai.drain_messages().unwrap();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].tlm_counter, 3);
+}
+
+#[test]
+fn drain_messages_misaligned() {
+    let mut mock = MockStream::default();
+
+    let mut output = vec![0x01, 0x02, 0x03];
+    output.extend_from_slice(&RAW_READ);
+    output.extend_from_slice(&RAW_READ);
+    mock.read.set_output(output);
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(mai.drain_messages().unwrap().len(), 2);
+}
+
+#[test]
+fn drain_messages_bad_checksum() {
+    let mut mock = MockStream::default();
+
+    let mut bad = RAW_READ.to_vec();
+    bad[5] = 0xFF;
+    bad.extend_from_slice(&RAW_READ);
+    mock.read.set_output(bad);
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(mai.drain_messages().unwrap().len(), 1);
+}
+
+#[test]
+fn telemetry_stream_two_frames() {
+    let mut mock = MockStream::default();
+
+    // Two good message sets with a corrupt one in between
+    let mut output = RAW_READ.to_vec();
+    let mut bad = RAW_READ.to_vec();
+    bad[5] = 0xFF;
+    output.extend_from_slice(&bad);
+    output.extend_from_slice(&RAW_READ);
+    mock.read.set_output(output);
+
+    let mut mai = mock_new!(mock);
+    mai.set_read_timeout(Duration::from_millis(300));
+
+    let mut stream = mai.telemetry_stream();
+
+    let first = stream.next().unwrap().unwrap();
+    let second = stream.next().unwrap().unwrap();
+    assert_eq!(first.tlm_counter, 3);
+    assert_eq!(second, first);
+
+    // Running out of data is reported, but doesn't end the stream
+    assert_eq!(stream.next(), Some(Err(MAIError::Timeout)));
+}
+
+#[test]
+fn drain_messages_empty() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(vec![]);
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(mai.drain_messages().unwrap(), vec![]);
+}
+
+#[test]
+fn try_get_message_good() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(RAW_READ.to_vec());
+
+    let mai = mock_new!(mock);
+
+    let std = mai.try_get_message().unwrap().unwrap();
+
+    assert_eq!(std.tlm_counter, 3);
+    assert_eq!(mai.try_get_message().unwrap(), None);
+}
+
+#[test]
+fn try_get_message_none() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(vec![]);
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(mai.try_get_message().unwrap(), None);
+}
+
+#[test]
+fn try_get_message_partial() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(RAW_READ[0..100].to_vec());
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(mai.try_get_message().unwrap(), None);
+}
+
+#[test]
+fn try_get_message_partial_kept() {
+    let stream = ConsumingStream::default();
+    stream.arrive(&RAW_READ[0..100]);
+
+    let mock = stream.clone();
+    let mai = mock_new!(mock);
+
+    assert_eq!(mai.try_get_message().unwrap(), None);
+
+    stream.arrive(&RAW_READ[100..]);
+
+    let std = mai.try_get_message().unwrap().unwrap();
+    assert_eq!(std.tlm_counter, 3);
+    assert_eq!(mai.try_get_message().unwrap(), None);
+}
+
+#[test]
+fn get_message_after_partial() {
+    let stream = ConsumingStream::default();
+    stream.arrive(&RAW_READ[0..100]);
+
+    let mock = stream.clone();
+    let mai = mock_new!(mock);
+
+    assert_eq!(mai.try_get_message().unwrap(), None);
+
+    // A blocking read picks up where the non-blocking one left off
+    stream.arrive(&RAW_READ[100..]);
+
+    let (std, _, _) = mai.get_message().unwrap();
+    assert_eq!(std.unwrap().tlm_counter, 3);
+}
+
+#[test]
+fn try_get_message_bad_checksum() {
+    let mut mock = MockStream::default();
+
+    let mut bad = RAW_READ.to_vec();
+    bad[5] = 0xFF;
+    mock.read.set_output(bad);
+
+    let mai = mock_new!(mock);
+
+    match mai.try_get_message().unwrap_err() {
+        MAIError::ChecksumMismatch { .. } => {}
+        err => panic!("Unexpected error: {:?}", err),
+    }
+}
+
+#[test]
+fn get_message_good_stdtelem_chunked() {
+    let mut mock = MockStream::default();
+
+    // Deliver the frame in 8-byte fragments, so neither the sync word nor the
+    // message body line up with the chunk boundaries
+    mock.read
+        .set_output_chunked(RAW_READ.chunks(8).map(|chunk| chunk.to_vec()).collect());
+
+    let mai = mock_new!(mock);
+
+    let (std, imu, irehs) = mai.get_message().unwrap();
+
+    let std = std.unwrap();
+    assert_eq!(std.tlm_counter, 3);
+    assert_eq!(std.gps_time, 1198800019);
+    assert_eq!(std.acs_mode, 1);
+    assert_eq!(std.neci, [-32754, -627, -627]);
+    assert!(imu.is_some());
+    assert!(irehs.is_some());
+}
+
+#[test]
+fn get_message_resync() {
+    let mut mock = MockStream::default();
+
+    // An odd number of junk bytes, including a lone first sync byte,
+    // ahead of a valid message set
+    let mut raw = vec![0x00, 0x90, 0x12, 0xEB, 0x90];
+    raw.extend_from_slice(&RAW_READ);
+    mock.read.set_output(raw);
+
+    let mai = mock_new!(mock);
+
+    let (result, _, _) = mai.get_message().unwrap();
+
+    assert_eq!(result.unwrap().gps_time, 1198800019);
+}
+
+#[test]
+fn get_message_sync_lost() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(vec![0x55; MAX_SYNC_DISCARD + 2]);
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(mai.get_message().unwrap_err(), MAIError::SyncLost);
+}
+
 #[test]
 fn get_message_good_rawimu() {
     let mut mock = MockStream::default();
@@ -138,3 +450,843 @@ fn get_message_good_irehs() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn get_message_bad_checksum_stdtelem() {
+    let mut mock = MockStream::default();
+
+    let mut raw = RAW_READ.to_vec();
+    raw[10] ^= 0x01;
+    mock.read.set_output(raw);
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(
+        mai.get_message().unwrap_err(),
+        MAIError::ChecksumMismatch {
+            expected: 0x26BE,
+            found: 0x26BD,
+        }
+    );
+}
+
+#[test]
+fn get_message_bad_checksum_rawimu() {
+    let mut mock = MockStream::default();
+
+    let mut raw = RAW_READ.to_vec();
+    raw[230] ^= 0x01;
+    mock.read.set_output(raw);
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(
+        mai.get_message().unwrap_err(),
+        MAIError::ChecksumMismatch {
+            expected: 0x4283,
+            found: 0x9382,
+        }
+    );
+}
+
+#[test]
+fn get_message_bad_sync_irehs() {
+    let mut mock = MockStream::default();
+
+    let mut raw = RAW_READ.to_vec();
+    raw[162] = 0x00;
+    mock.read.set_output(raw);
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(
+        mai.get_message().unwrap_err(),
+        MAIError::FramingError {
+            detail: "IREHS packet has bad sync bytes: 0x0091".to_owned(),
+        }
+    );
+}
+
+#[test]
+fn get_message_rebuilt_checksum() {
+    let mut mock = MockStream::default();
+
+    // Update the telemetry counter and then recalculate the standard telemetry checksum
+    let mut raw = RAW_READ.to_vec();
+    raw[2] = 0x04;
+    let crc = checksum(&raw[0..159]);
+    raw[159] = crc as u8;
+    raw[160] = (crc >> 8) as u8;
+    mock.read.set_output(raw);
+
+    let mai = mock_new!(mock);
+
+    let (result, _, _) = mai.get_message().unwrap();
+
+    assert_eq!(result.unwrap().tlm_counter, 4);
+}
+
+#[test]
+fn get_mode_good() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(RAW_READ.to_vec());
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(mai.get_mode().unwrap(), AcsMode::RateNulling);
+}
+
+#[test]
+fn get_mode_rebuilt_checksum() {
+    let mut mock = MockStream::default();
+
+    // Update the mode and then recalculate the standard telemetry checksum
+    let mut raw = RAW_READ.to_vec();
+    raw[15] = 0x09;
+    let crc = checksum(&raw[0..159]);
+    raw[159] = crc as u8;
+    raw[160] = (crc >> 8) as u8;
+    mock.read.set_output(raw);
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(mai.get_mode().unwrap(), AcsMode::Qinertial);
+}
+
+#[test]
+fn get_mode_unknown() {
+    let mut mock = MockStream::default();
+
+    let mut raw = RAW_READ.to_vec();
+    raw[15] = 0x20;
+    let crc = checksum(&raw[0..159]);
+    raw[159] = crc as u8;
+    raw[160] = (crc >> 8) as u8;
+    mock.read.set_output(raw);
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(
+        mai.get_mode().unwrap_err(),
+        MAIError::UnknownMode { mode: 0x20 }
+    );
+}
+
+#[test]
+fn std_telem_omega_quaternion() {
+    let mut mock = MockStream::default();
+
+    // Fill in known body rates and attitude estimate, then recalculate the checksum
+    let mut raw = RAW_READ.to_vec();
+    raw[103..111].copy_from_slice(&[0x00, 0x40, 0x00, 0xE0, 0x00, 0x00, 0x90, 0x65]);
+    raw[123..135].copy_from_slice(&[
+        0x00, 0x00, 0x80, 0x3E, 0x00, 0x00, 0x00, 0xBF, 0x00, 0x00, 0x00, 0x3E,
+    ]);
+    let crc = checksum(&raw[0..159]);
+    raw[159] = crc as u8;
+    raw[160] = (crc >> 8) as u8;
+    mock.read.set_output(raw);
+
+    let mai = mock_new!(mock);
+
+    let (std, _, _) = mai.get_message().unwrap();
+    let std = std.unwrap();
+
+    assert_eq!(std.qbo_hat, [16384, -8192, 0, 26000]);
+    assert_eq!(std.omega(), [0.25, -0.5, 0.125]);
+
+    let quat = std.quaternion();
+    let expected = [0.515_142_6, -0.257_571_3, 0.0, 0.817_487_1];
+    for (actual, expected) in quat.iter().zip(expected.iter()) {
+        assert!((actual - expected).abs() < 1e-6);
+    }
+    let norm: f32 = quat.iter().map(|elem| elem * elem).sum();
+    assert!((norm - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn std_telem_quaternion_identity() {
+    let telem = StandardTelemetry {
+        qbo_hat: [0, 0, 0, 32767],
+        ..Default::default()
+    };
+
+    assert_eq!(telem.quaternion(), [0.0, 0.0, 0.0, 1.0]);
+}
+
+#[test]
+fn std_telem_quaternion_no_estimate() {
+    let telem = StandardTelemetry::default();
+
+    assert_eq!(telem.quaternion(), [0.0, 0.0, 0.0, 0.0]);
+}
+
+#[test]
+fn std_telem_wheels() {
+    let mut mock = MockStream::default();
+
+    // Fill in known wheel speeds and torque commands, with mixed signs, then recalculate
+    // the checksum
+    let mut raw = RAW_READ.to_vec();
+    raw[59..65].copy_from_slice(&[0xE8, 0x03, 0x0C, 0xFE, 0x00, 0x00]);
+    raw[65..77].copy_from_slice(&[
+        0x00, 0x00, 0x20, 0x40, 0x00, 0x00, 0x80, 0xBF, 0x00, 0x00, 0x00, 0x00,
+    ]);
+    let crc = checksum(&raw[0..159]);
+    raw[159] = crc as u8;
+    raw[160] = (crc >> 8) as u8;
+    mock.read.set_output(raw);
+
+    let mai = mock_new!(mock);
+
+    let (std, _, _) = mai.get_message().unwrap();
+    let std = std.unwrap();
+
+    assert_eq!(std.rws_speed_tach, [1000, -500, 0]);
+    assert_eq!(std.rwa_torque_cmd, [2.5, -1.0, 0.0]);
+    assert_eq!(std.wheel_speeds(), [1000.0, -500.0, 0.0]);
+    assert_eq!(std.wheel_torques(), [0.0025, -0.001, 0.0]);
+}
+
+// Build a message set whose command counters differ from RAW_READ's
+#[test]
+fn std_telem_device_time() {
+    let mut mock = MockStream::default();
+
+    // Fill in a known clock value, then recalculate the checksum
+    let mut raw = RAW_READ.to_vec();
+    raw[3..7].copy_from_slice(&[0x78, 0x56, 0x34, 0x12]);
+    raw[7] = 3;
+    let crc = checksum(&raw[0..159]);
+    raw[159] = crc as u8;
+    raw[160] = (crc >> 8) as u8;
+    mock.read.set_output(raw);
+
+    let mai = mock_new!(mock);
+
+    let (std, _, _) = mai.get_message().unwrap();
+    let std = std.unwrap();
+
+    assert_eq!(std.gps_time, 0x1234_5678);
+    assert_eq!(std.time_subsec, 3);
+    assert_eq!(
+        std.device_time(),
+        Duration::from_secs(0x1234_5678) + Duration::from_millis(750)
+    );
+}
+
+// Standard telemetry sent one message set after RAW_READ, with the given command counters
+fn command_counters(valid: u16, invalid: u16, invalid_chksum: u16) -> Vec<u8> {
+    command_counters_at(1, valid, invalid, invalid_chksum)
+}
+
+fn command_counters_at(time_subsec: u8, valid: u16, invalid: u16, invalid_chksum: u16) -> Vec<u8> {
+    let mut raw = RAW_READ.to_vec();
+    raw[7] = time_subsec;
+    raw[8..10].copy_from_slice(&valid.to_le_bytes());
+    raw[10..12].copy_from_slice(&invalid.to_le_bytes());
+    raw[12..14].copy_from_slice(&invalid_chksum.to_le_bytes());
+    let crc = checksum(&raw[0..159]);
+    raw[159] = crc as u8;
+    raw[160] = (crc >> 8) as u8;
+    raw
+}
+
+#[test]
+fn confirm_command_accepted() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(command_counters(3, 0, 0));
+
+    let mai = mock_new!(mock);
+
+    let before = StandardTelemetry {
+        cmd_valid_cntr: 2,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        mai.confirm_command(&before).unwrap(),
+        CommandStatus::Accepted
+    );
+}
+
+#[test]
+fn confirm_command_no_change() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(RAW_READ.to_vec());
+
+    let mai = mock_new!(mock);
+
+    let before = StandardTelemetry {
+        cmd_valid_cntr: 2,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        mai.confirm_command(&before).unwrap(),
+        CommandStatus::NoChange
+    );
+}
+
+#[test]
+fn confirm_command_rejected() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(command_counters(2, 1, 0));
+
+    let mai = mock_new!(mock);
+
+    let before = StandardTelemetry {
+        cmd_valid_cntr: 2,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        mai.confirm_command(&before).unwrap_err(),
+        MAIError::CommandRejected {
+            status: CommandStatus::Invalid,
+            reason: None,
+        }
+    );
+}
+
+#[test]
+fn confirm_command_bad_checksum() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(command_counters(2, 0, 1));
+
+    let mai = mock_new!(mock);
+
+    let before = StandardTelemetry {
+        cmd_valid_cntr: 2,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        mai.confirm_command(&before).unwrap_err(),
+        MAIError::CommandRejected {
+            status: CommandStatus::BadChecksum,
+            reason: None,
+        }
+    );
+}
+
+#[test]
+fn confirm_command_counter_wrap() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(command_counters(0, 0, 0));
+
+    let mai = mock_new!(mock);
+
+    let before = StandardTelemetry {
+        cmd_valid_cntr: 0xFFFF,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        mai.confirm_command(&before).unwrap(),
+        CommandStatus::Accepted
+    );
+}
+
+#[test]
+fn confirm_command_skips_stale() {
+    let mut mock = MockStream::default();
+
+    // The first message set was buffered before `before` was read, so its counters
+    // must not be compared
+    let mut output = command_counters_at(1, 1, 0, 0);
+    output.extend_from_slice(&command_counters_at(3, 2, 1, 0));
+    mock.read.set_output(output);
+
+    let mai = mock_new!(mock);
+
+    let before = StandardTelemetry {
+        gps_time: 0x47743C93,
+        time_subsec: 2,
+        cmd_valid_cntr: 2,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        mai.confirm_command(&before).unwrap_err(),
+        MAIError::CommandRejected {
+            status: CommandStatus::Invalid,
+            reason: None,
+        }
+    );
+}
+
+#[test]
+fn confirm_command_stale_window() {
+    let mut mock = MockStream::default();
+
+    let mut output = command_counters_at(2, 3, 0, 0);
+    output.extend_from_slice(&command_counters_at(3, 3, 0, 0));
+    mock.read.set_output(output);
+
+    let mai = MAI400Builder::new()
+        .connection(Connection {
+            stream: Box::new(mock),
+        })
+        .ack_window(1)
+        .build()
+        .unwrap();
+
+    let before = StandardTelemetry {
+        gps_time: 0x47743C93,
+        time_subsec: 2,
+        cmd_valid_cntr: 2,
+        ..Default::default()
+    };
+
+    // Only the stale message set fits in the window
+    assert_eq!(
+        mai.confirm_command(&before).unwrap(),
+        CommandStatus::NoChange
+    );
+    assert_eq!(
+        mai.confirm_command(&before).unwrap(),
+        CommandStatus::Accepted
+    );
+}
+
+#[test]
+fn set_mode_confirmed_rejected() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0x90, 0xEB, 0x0, 0x1, 0x2, 0x0, 0x3, 0x0, 0x4, 0x0, 0x5, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x8A, 0x1,
+    ]);
+
+    let mut output = RAW_READ.to_vec();
+    output.extend_from_slice(&command_counters(2, 1, 0));
+    mock.read.set_output(output);
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(
+        mai.set_mode_confirmed(0x01, [0x02, 0x03, 0x04, 0x05])
+            .unwrap_err(),
+        MAIError::CommandRejected {
+            status: CommandStatus::Invalid,
+            reason: Some(ModeRejectReason::InvalidParams),
+        }
+    );
+}
+
+#[test]
+fn mode_reject_reason_insufficient_knowledge() {
+    let after = StandardTelemetry {
+        css: [0, 4, 1, 0, 0, 4],
+        i_b_field_meas: [-1369, 105, -785],
+        qbo_hat: [0, 0, 0, 32767],
+        ..Default::default()
+    };
+    let unknown_attitude = StandardTelemetry {
+        qbo_hat: [0; 4],
+        ..after.clone()
+    };
+
+    assert_eq!(
+        ModeRejectReason::from_telemetry(AcsMode::NadirPointing, [0; 4], &unknown_attitude),
+        ModeRejectReason::InsufficientKnowledge
+    );
+    // Rate nulling doesn't need an attitude estimate
+    assert_eq!(
+        ModeRejectReason::from_telemetry(AcsMode::RateNulling, [0; 4], &unknown_attitude),
+        ModeRejectReason::Unknown
+    );
+    assert_eq!(
+        ModeRejectReason::from_telemetry(AcsMode::NadirPointing, [0; 4], &after),
+        ModeRejectReason::Unknown
+    );
+}
+
+#[test]
+fn mode_reject_reason_sensor_fault() {
+    let after = StandardTelemetry {
+        css: [0, 4, 1, 0, 0, 4],
+        i_b_field_meas: [-1369, 105, -785],
+        qbo_hat: [0, 0, 0, 32767],
+        ..Default::default()
+    };
+    let dead_mag = StandardTelemetry {
+        i_b_field_meas: [0; 3],
+        ..after
+    };
+
+    assert_eq!(
+        ModeRejectReason::from_telemetry(AcsMode::Qinertial, [1, -1, -3, 0], &dead_mag),
+        ModeRejectReason::SensorFault
+    );
+}
+
+#[test]
+fn set_mode_confirmed_bad_mode() {
+    let mock = MockStream::default();
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(
+        mai.set_mode_confirmed(0x20, [0, 0, 0, 0]).unwrap_err(),
+        MAIError::InvalidInput {
+            reason: "Unknown ACS mode: 32".to_owned()
+        }
+    );
+}
+
+#[test]
+fn get_messages_good() {
+    let mut mock = MockStream::default();
+
+    // Fill in representative IREHS readings and then recalculate the IREHS checksum
+    let mut raw = RAW_READ.to_vec();
+    let thermopiles: [u8; 8] = [0x23, 0x01, 0x56, 0x04, 0x89, 0x07, 0xBC, 0x0A];
+    raw[167..175].copy_from_slice(&thermopiles);
+    raw[199] = 0x0C;
+    raw[203] = 0xF4;
+    raw[204] = 0xFF;
+    raw[205] = 0xFF;
+    raw[206] = 0xFF;
+    raw[207] = 0x21;
+    raw[211] = 0x05;
+    let crc = aux_checksum(&raw[163..215]);
+    raw[215] = crc as u8;
+    raw[216] = (crc >> 8) as u8;
+    mock.read.set_output(raw);
+
+    let mai = mock_new!(mock);
+
+    let result = mai.get_messages().unwrap();
+
+    assert_eq!(result.len(), 3);
+
+    match result[0] {
+        MAIMessage::StandardTelemetry(ref std) => assert_eq!(std.tlm_counter, 3),
+        ref other => panic!("Unexpected message: {:?}", other),
+    }
+
+    match result[1] {
+        MAIMessage::IREHSTelemetry(ref irehs) => {
+            assert_eq!(irehs.thermopiles_a, [0x123, 0x456, 0x789, 0xABC]);
+            assert_eq!(irehs.dip_angle_a, 12);
+            assert_eq!(irehs.dip_angle_b, -12);
+            assert_eq!(
+                irehs.solution_degraded[0],
+                ThermopileFlags::DIP_ANGLE_LIMIT | ThermopileFlags::AUX_WIDE_FOV
+            );
+            assert_eq!(
+                irehs.solution_degraded[4],
+                ThermopileFlags::DIP_ANGLE_LIMIT | ThermopileFlags::THERMOPILE_SAT
+            );
+        }
+        ref other => panic!("Unexpected message: {:?}", other),
+    }
+
+    match result[2] {
+        MAIMessage::RawIMU(_) => {}
+        ref other => panic!("Unexpected message: {:?}", other),
+    }
+}
+
+#[test]
+fn last_rx_recorded_on_error() {
+    let mut mock = MockStream::default();
+
+    let mut raw = RAW_READ.to_vec();
+    raw[10] ^= 0x01;
+    mock.read.set_output(raw.clone());
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(mai.last_rx(), None);
+
+    assert!(mai.get_message().is_err());
+
+    assert_eq!(mai.last_rx(), Some(raw));
+}
+
+#[test]
+fn link_stats_checksum_failure() {
+    let mut mock = MockStream::default();
+
+    let mut raw = RAW_READ.to_vec();
+    raw[10] ^= 0x01;
+    mock.read.set_output(raw);
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(mai.link_stats(), LinkStats::default());
+
+    match mai.get_message().unwrap_err() {
+        MAIError::ChecksumMismatch { .. } => {}
+        other => panic!("Unexpected error: {:?}", other),
+    }
+
+    let stats = mai.link_stats();
+    assert_eq!(
+        stats,
+        LinkStats {
+            frames_received: 1,
+            checksum_failures: 1,
+            resyncs: 0,
+            timeouts: 0,
+        }
+    );
+    assert_eq!(stats.error_rate(), 1.0);
+}
+
+#[test]
+fn link_stats_resync_and_timeout() {
+    let mut mock = MockStream::default();
+
+    let mut raw = vec![0x00, 0x12];
+    raw.extend_from_slice(&RAW_READ);
+    mock.read.set_output(raw);
+
+    let mut mai = mock_new!(mock);
+    mai.set_read_timeout(Duration::from_millis(300));
+
+    assert!(mai.get_message().is_ok());
+    let before = mai.link_stats();
+
+    assert_eq!(mai.get_message().unwrap_err(), MAIError::Timeout);
+
+    assert_eq!(
+        before,
+        LinkStats {
+            frames_received: 1,
+            checksum_failures: 0,
+            resyncs: 1,
+            timeouts: 0,
+        }
+    );
+    assert_eq!(before.error_rate(), 0.0);
+    assert_eq!(
+        mai.link_stats().since(&before),
+        LinkStats {
+            timeouts: 1,
+            ..Default::default()
+        }
+    );
+
+    // Counters are shared between clones
+    assert_eq!(mai.clone().link_stats().timeouts, 1);
+}
+
+static VERSION_RESPONSE: [u8; 40] = [
+    0x90, 0xEB, 0x48, 0x34, 0x2E, 0x31, 0x2E, 0x32, 0x0, 0x0, 0x0, 0x40, 0xE2, 0x1, 0x0, 0x32,
+    0x30, 0x31, 0x38, 0x2D, 0x30, 0x36, 0x2D, 0x31, 0x32, 0x20, 0x72, 0x31, 0x30, 0x39, 0x33, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x26, 0x7,
+];
+
+#[test]
+fn version_parse() {
+    assert_eq!(
+        MAIVersion::new(VERSION_RESPONSE.to_vec()),
+        Some(MAIVersion {
+            firmware: "4.1.2".to_owned(),
+            serial_number: 123456,
+            build: "2018-06-12 r1093".to_owned(),
+        })
+    );
+}
+
+#[test]
+fn version_parse_bad_checksum() {
+    let mut response = VERSION_RESPONSE.to_vec();
+    response[38] = 0x27;
+
+    assert_eq!(MAIVersion::new(response), None);
+}
+
+#[test]
+fn version_parse_bad_sync() {
+    let mut response = VERSION_RESPONSE.to_vec();
+    response[0] = 0xEB;
+
+    assert_eq!(MAIVersion::new(response), None);
+}
+
+#[test]
+fn get_version_good() {
+    let mut mock = MockStream::default();
+
+    let mut request = vec![0x90, 0xEB, 0x48];
+    request.extend_from_slice(&[0; 35]);
+    request.extend_from_slice(&[0xC3, 0x1]);
+
+    mock.write.set_input(request);
+    mock.read.set_output(VERSION_RESPONSE.to_vec());
+
+    let mai = mock_new!(mock);
+
+    let version = mai.get_version().unwrap();
+
+    assert_eq!(version.firmware, "4.1.2");
+    assert_eq!(version.serial_number, 123456);
+    assert_eq!(version.build, "2018-06-12 r1093");
+}
+
+#[test]
+fn get_version_bad_checksum() {
+    let mut mock = MockStream::default();
+
+    let mut request = vec![0x90, 0xEB, 0x48];
+    request.extend_from_slice(&[0; 35]);
+    request.extend_from_slice(&[0xC3, 0x1]);
+
+    let mut response = VERSION_RESPONSE.to_vec();
+    response[39] = 0x8;
+
+    mock.write.set_input(request);
+    mock.read.set_output(response);
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(
+        mai.get_version().unwrap_err(),
+        MAIError::ChecksumMismatch {
+            expected: 0x0726,
+            found: 0x0826
+        }
+    );
+}
+
+// Full self-test with failures on the Y-axis wheel and the sun sensors
+static SELF_TEST_RESPONSE: [u8; 8] = [0x90, 0xEB, 0x4A, 0x01, 0x20, 0x04, 0xEA, 0x01];
+
+#[test]
+fn self_test_parse() {
+    let results = SelfTestResults::new(SELF_TEST_RESPONSE.to_vec()).unwrap();
+
+    assert_eq!(
+        results,
+        SelfTestResults {
+            test_type: SelfTestType::Full,
+            gyros: [true, true, true],
+            magnetometer: true,
+            wheels: [true, false, true],
+            torque_rods: [true, true, true],
+            sun_sensors: false,
+            horizon_sensors: true,
+            faults: self_test_faults::WHEEL_Y | self_test_faults::SUN_SENSORS,
+        }
+    );
+    assert!(!results.passed());
+}
+
+#[test]
+fn self_test_parse_pass() {
+    let response = vec![0x90, 0xEB, 0x4A, 0x00, 0x00, 0x00, 0xC5, 0x01];
+
+    let results = SelfTestResults::new(response).unwrap();
+
+    assert_eq!(results.test_type, SelfTestType::Quick);
+    assert!(results.passed());
+}
+
+#[test]
+fn self_test_parse_bad_checksum() {
+    let mut response = SELF_TEST_RESPONSE.to_vec();
+    response[6] = 0xEB;
+
+    assert_eq!(SelfTestResults::new(response), None);
+}
+
+#[test]
+fn self_test_parse_unknown_type() {
+    let response = vec![0x90, 0xEB, 0x4A, 0x07, 0x00, 0x00, 0xCC, 0x01];
+
+    assert_eq!(SelfTestResults::new(response), None);
+}
+
+#[test]
+fn run_self_test_good() {
+    let mut mock = MockStream::default();
+
+    let mut request = vec![0x90, 0xEB, 0x4A, 0x01];
+    request.extend_from_slice(&[0; 34]);
+    request.extend_from_slice(&[0xC6, 0x1]);
+
+    mock.write.set_input(request);
+    mock.read.set_output(SELF_TEST_RESPONSE.to_vec());
+
+    let mai = mock_new!(mock);
+
+    let results = mai.run_self_test(SelfTestType::Full).unwrap();
+
+    assert_eq!(results.wheels, [true, false, true]);
+    assert!(!results.sun_sensors);
+}
+
+#[test]
+fn run_self_test_wrong_type() {
+    let mut mock = MockStream::default();
+
+    let mut request = vec![0x90, 0xEB, 0x4A, 0x00];
+    request.extend_from_slice(&[0; 34]);
+    request.extend_from_slice(&[0xC5, 0x1]);
+
+    mock.write.set_input(request);
+    mock.read.set_output(SELF_TEST_RESPONSE.to_vec());
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(
+        mai.run_self_test(SelfTestType::Quick).unwrap_err(),
+        MAIError::FramingError {
+            detail: "Results are for test type 0x01".to_owned()
+        }
+    );
+}
+
+#[test]
+fn frame_reader_short_frame() {
+    let mut frame = FrameReader::new(&[0x90, 0xEB, 0x01]);
+
+    assert_eq!(frame.read_u16_le(), Ok(0xEB90));
+
+    let err = frame.read_u32_le().unwrap_err();
+    assert_eq!(
+        err,
+        ShortFrame {
+            offset: 2,
+            needed: 4,
+            len: 3,
+        }
+    );
+    assert_eq!(frame.offset(), 2);
+    assert_eq!(frame.read_u8(), Ok(0x01));
+    assert_eq!(frame.remaining(), 0);
+
+    assert_eq!(
+        MAIError::from(err),
+        MAIError::FramingError {
+            detail: "Frame too short: needed 4 bytes at offset 2, but frame is 3 bytes".to_owned()
+        }
+    );
+}
+
+#[test]
+fn short_frames_not_parsed() {
+    // Truncated standard telemetry frame with a valid checksum
+    let mut truncated = RAW_READ[0..20].to_vec();
+    let crc = checksum(&truncated);
+    truncated.extend_from_slice(&[crc as u8, (crc >> 8) as u8]);
+
+    assert_eq!(StandardTelemetry::new(truncated), None);
+    assert_eq!(StandardTelemetry::new(vec![]), None);
+    assert_eq!(StandardTelemetry::new(vec![0x90]), None);
+    assert_eq!(RawIMU::new(vec![0x91]), None);
+    // Sync word and an (empty) checksum, but no data
+    assert_eq!(IREHSTelemetry::new(vec![0x91, 0xEA, 0x00, 0x00]), None);
+}
@@ -16,6 +16,29 @@
 
 use super::*;
 use crate::mai400::*;
+use crate::messages::{ConfirmReset, Message, RequestReset};
+use std::time::Duration;
+
+#[test]
+fn command_id_values() {
+    let ids = [
+        (CommandId::SetAcsMode, 0x00),
+        (CommandId::SetRV, 0x41),
+        (CommandId::SetGPSTime, 0x44),
+        (CommandId::SetCalibration, 0x46),
+        (CommandId::RequestVersion, 0x48),
+        (CommandId::RunSelfTest, 0x4A),
+        (CommandId::RequestReset, 0x5A),
+        (CommandId::ConfirmReset, 0xF1),
+    ];
+
+    for &(id, raw) in ids.iter() {
+        assert_eq!(id.as_u8(), raw);
+        assert_eq!(CommandId::from_u8(raw), Some(id));
+    }
+
+    assert_eq!(CommandId::from_u8(0x01), None);
+}
 
 #[test]
 fn reset_good() {
@@ -35,9 +58,24 @@ fn reset_good() {
         0x0, 0x0, 0x0, 0x6C, 0x2,
     ]);
 
+    let tracker = mock.tracker();
+
     let mai = mock_new!(mock);
 
     assert!(mai.reset().is_ok());
+
+    tracker.assert_consumed();
+}
+
+#[test]
+fn checksum_reset_frames() {
+    // The trailers of the reset frames in reset_good() should be reproducible
+    // from the serialized frame bodies
+    let request = RequestReset::default().serialize();
+    assert_eq!(checksum(&request).to_le_bytes(), [0xD5, 0x01]);
+
+    let confirm = ConfirmReset::default().serialize();
+    assert_eq!(checksum(&confirm).to_le_bytes(), [0x6C, 0x02]);
 }
 
 #[test]
@@ -64,9 +102,13 @@ fn set_mode_good() {
         0x0, 0x0, 0x8A, 0x1,
     ]);
 
+    let tracker = mock.tracker();
+
     let mai = mock_new!(mock);
 
     assert!(mai.set_mode(0x01, [0x02, 0x03, 0x04, 0x05]).is_ok());
+
+    tracker.assert_consumed();
 }
 
 #[test]
@@ -83,6 +125,186 @@ fn set_mode_bad() {
     );
 }
 
+#[test]
+fn set_mode_bad_mode() {
+    let mut mock = MockStream::default();
+
+    // Nothing should be sent to the device
+    mock.write.set_result(Ok(()));
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(
+        mai.set_mode(0x20, [0x02, 0x03, 0x04, 0x05]).unwrap_err(),
+        MAIError::InvalidInput {
+            reason: "Unknown ACS mode: 32".to_owned(),
+        }
+    );
+    assert_eq!(mai.last_tx(), None);
+}
+
+#[test]
+fn set_mode_reserved_mode() {
+    let mut mock = MockStream::default();
+
+    // Nothing should be sent to the device
+    mock.write.set_result(Ok(()));
+
+    let mai = mock_new!(mock);
+
+    for &mode in &[2, 6, 10] {
+        assert_eq!(
+            mai.set_mode(mode, [0, 0, 0, 0]).unwrap_err(),
+            MAIError::InvalidInput {
+                reason: format!("Reserved ACS mode: {}", mode),
+            }
+        );
+    }
+    assert_eq!(
+        mai.set_acs_mode(AcsMode::Reserved2, [0, 0, 0, 0])
+            .unwrap_err(),
+        MAIError::InvalidInput {
+            reason: "Reserved ACS mode: 6".to_owned(),
+        }
+    );
+    assert_eq!(mai.last_tx(), None);
+
+    // Reserved modes are still decoded from telemetry
+    assert_eq!(AcsMode::from_u8(10), Some(AcsMode::Reserved3));
+}
+
+#[test]
+fn set_mode_unchecked_good() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0x90, 0xEB, 0x0, 0x20, 0x2, 0x0, 0x3, 0x0, 0x4, 0x0, 0x5, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0xA9, 0x1,
+    ]);
+
+    let tracker = mock.tracker();
+
+    let mai = mock_new!(mock);
+
+    assert!(mai
+        .set_mode_unchecked(0x20, [0x02, 0x03, 0x04, 0x05])
+        .is_ok());
+
+    tracker.assert_consumed();
+}
+
+#[test]
+fn set_mode_retry_good() {
+    let mut mock = MockStream::default();
+
+    // Fail twice, then succeed
+    mock.write.set_input(vec![]);
+    mock.write.set_input(vec![]);
+    mock.write.set_input(vec![
+        0x90, 0xEB, 0x0, 0x1, 0x2, 0x0, 0x3, 0x0, 0x4, 0x0, 0x5, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x8A, 0x1,
+    ]);
+
+    let tracker = mock.tracker();
+
+    let mai = mock_new!(mock).with_retries(2, Duration::from_millis(1));
+
+    assert!(mai.set_mode(0x01, [0x02, 0x03, 0x04, 0x05]).is_ok());
+
+    tracker.assert_consumed();
+}
+
+#[test]
+fn set_mode_retry_exhausted() {
+    let mut mock = MockStream::default();
+
+    // Fail the original attempt and both retries
+    mock.write.set_input(vec![]);
+    mock.write.set_input(vec![]);
+    mock.write.set_input(vec![]);
+
+    let tracker = mock.tracker();
+
+    let mai = mock_new!(mock).with_retries(2, Duration::from_millis(1));
+
+    assert_eq!(
+        mai.set_mode(0x01, [0x02, 0x03, 0x04, 0x05]).unwrap_err(),
+        MAIError::UartError {
+            cause: UartError::GenericError,
+        }
+    );
+
+    tracker.assert_consumed();
+}
+
+#[test]
+fn set_rv_no_retry() {
+    let mut mock = MockStream::default();
+
+    // set_rv is not idempotent, so the failed write should not be re-sent
+    mock.write.set_input(vec![]);
+    mock.write.set_input(vec![0x90]);
+
+    let mai = mock_new!(mock).with_retries(2, Duration::from_millis(1));
+
+    assert_eq!(
+        mai.set_rv([0.0, 0.0, 0.0], [0.0, 0.0, 0.0], 1198800018)
+            .unwrap_err(),
+        MAIError::UartError {
+            cause: UartError::GenericError,
+        }
+    );
+}
+
+#[test]
+fn set_acs_mode_good() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0x90, 0xEB, 0x0, 0x1, 0x2, 0x0, 0x3, 0x0, 0x4, 0x0, 0x5, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x8A, 0x1,
+    ]);
+
+    let tracker = mock.tracker();
+
+    let mai = mock_new!(mock);
+
+    assert!(mai
+        .set_acs_mode(AcsMode::RateNulling, [0x02, 0x03, 0x04, 0x05])
+        .is_ok());
+
+    tracker.assert_consumed();
+}
+
+#[test]
+fn set_acs_mode_bad() {
+    let mock = MockStream::default();
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(
+        mai.set_acs_mode(AcsMode::RateNulling, [0x02, 0x03, 0x04, 0x05])
+            .unwrap_err(),
+        MAIError::UartError {
+            cause: UartError::GenericError,
+        }
+    );
+}
+
+#[test]
+fn acs_mode_values() {
+    assert_eq!(AcsMode::TestMode.as_u8(), 0x00);
+    assert_eq!(AcsMode::RateNulling.as_u8(), 0x01);
+    assert_eq!(AcsMode::NadirPointing.as_u8(), 0x03);
+    assert_eq!(AcsMode::NormalSun.as_u8(), 0x07);
+    assert_eq!(AcsMode::LatLongSun.as_u8(), 0x08);
+    assert_eq!(AcsMode::Qinertial.as_u8(), 0x09);
+    assert_eq!(AcsMode::SunRam.as_u8(), 0x0C);
+}
+
 #[test]
 fn set_mode_sun_good() {
     let mut mock = MockStream::default();
@@ -93,9 +315,13 @@ fn set_mode_sun_good() {
         0x0, 0x0, 0x0, 0x69, 0x3,
     ]);
 
+    let tracker = mock.tracker();
+
     let mai = mock_new!(mock);
 
     assert!(mai.set_mode_sun(0x08, 1, 2.2).is_ok());
+
+    tracker.assert_consumed();
 }
 
 #[test]
@@ -112,6 +338,55 @@ fn set_mode_sun_bad() {
     );
 }
 
+#[test]
+fn set_target_quaternion_good() {
+    let mut mock = MockStream::default();
+
+    // Qinertial mode, with the quaternion normalized to [0.2, -0.4, -0.4, 0.8]
+    mock.write.set_input(vec![
+        0x90, 0xEB, 0x0, 0x9, 0x99, 0x19, 0xCD, 0xCC, 0xCD, 0xCC, 0x66, 0x66, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x34, 0x6,
+    ]);
+
+    let tracker = mock.tracker();
+
+    let mai = mock_new!(mock);
+
+    assert!(mai.set_target_quaternion([0.5, -1.0, -1.0, 2.0]).is_ok());
+
+    tracker.assert_consumed();
+}
+
+#[test]
+fn set_target_quaternion_not_finite() {
+    let mock = MockStream::default();
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(
+        mai.set_target_quaternion([0.0, f32::NAN, 0.0, 1.0])
+            .unwrap_err(),
+        MAIError::InvalidInput {
+            reason: "Quaternion components must be finite: [0.0, NaN, 0.0, 1.0]".to_owned()
+        }
+    );
+}
+
+#[test]
+fn set_target_quaternion_zero_norm() {
+    let mock = MockStream::default();
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(
+        mai.set_target_quaternion([0.0, 0.0, 0.0, 0.0]).unwrap_err(),
+        MAIError::InvalidInput {
+            reason: "Quaternion must have a non-zero, finite norm: [0.0, 0.0, 0.0, 0.0]".to_owned()
+        }
+    );
+}
+
 #[test]
 fn set_gps_time_good() {
     let mut mock = MockStream::default();
@@ -122,9 +397,31 @@ fn set_gps_time_good() {
         0x0, 0x0, 0x0, 0x48, 0x3,
     ]);
 
+    let tracker = mock.tracker();
+
     let mai = mock_new!(mock);
 
     assert!(mai.set_gps_time(1198800018).is_ok());
+
+    tracker.assert_consumed();
+}
+
+#[test]
+fn frame_set_gps_time() {
+    // Frames built without the UART transport match what the client sends
+    let raw = frame(&SetGPSTime {
+        gps_time: 1198800018,
+        ..Default::default()
+    });
+
+    assert_eq!(
+        raw,
+        vec![
+            0x90, 0xEB, 0x44, 0x92, 0x3C, 0x74, 0x47, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+            0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+            0x0, 0x0, 0x0, 0x0, 0x0, 0x48, 0x3,
+        ]
+    );
 }
 
 #[test]
@@ -139,6 +436,52 @@ fn set_gps_time_bad() {
             cause: UartError::GenericError,
         }
     );
+
+    // Zero is rejected before anything is sent
+    assert_eq!(
+        mai.set_gps_time(0).unwrap_err(),
+        MAIError::InvalidInput {
+            reason: format!("GPS time 0 outside of accepted range (1-{})", u32::MAX),
+        }
+    );
+}
+
+#[test]
+fn set_gps_time_outside_window() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0x90, 0xEB, 0x44, 0x92, 0x3C, 0x74, 0x47, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x48, 0x3,
+    ]);
+
+    let tracker = mock.tracker();
+
+    let mai = MAI400Builder::new()
+        .connection(Connection {
+            stream: Box::new(mock),
+        })
+        .gps_time_range(1_167_264_018..=1_325_030_418)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        mai.set_gps_time(15).unwrap_err(),
+        MAIError::InvalidInput {
+            reason: "GPS time 15 outside of accepted range (1167264018-1325030418)".to_owned(),
+        }
+    );
+    assert_eq!(
+        mai.set_gps_time(1_325_030_419).unwrap_err(),
+        MAIError::InvalidInput {
+            reason: "GPS time 1325030419 outside of accepted range (1167264018-1325030418)"
+                .to_owned(),
+        }
+    );
+
+    assert!(mai.set_gps_time(1198800018).is_ok());
+    tracker.assert_consumed();
 }
 
 #[test]
@@ -151,11 +494,69 @@ fn set_rv_good() {
         0x47, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x55, 0xD,
     ]);
 
+    let tracker = mock.tracker();
+
     let mai = mock_new!(mock);
 
     assert!(mai
         .set_rv([1.1, 2.2, 3.3], [4.4, 5.5, 6.6], 1198800018)
         .is_ok());
+
+    tracker.assert_consumed();
+}
+
+#[test]
+fn rv_age_after_set_rv() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0x90, 0xEB, 0x41, 0xCD, 0xCC, 0x8C, 0x3F, 0xCD, 0xCC, 0xC, 0x40, 0x33, 0x33, 0x53, 0x40,
+        0xCD, 0xCC, 0x8C, 0x40, 0x0, 0x0, 0xB0, 0x40, 0x33, 0x33, 0xD3, 0x40, 0x92, 0x3C, 0x74,
+        0x47, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x55, 0xD,
+    ]);
+
+    let mai = mock_new!(mock);
+
+    mai.set_rv([1.1, 2.2, 3.3], [4.4, 5.5, 6.6], 1198800018)
+        .unwrap();
+
+    assert_eq!(mai.rv_epoch(), Some(1198800018));
+    assert_eq!(mai.rv_age(1198800618), 600);
+    // A time before the epoch shouldn't underflow
+    assert_eq!(mai.rv_age(1198800000), 0);
+    assert!(!mai.rv_stale(1198800018 + DEFAULT_RV_WARN_AGE));
+    assert!(mai.rv_stale(1198800018 + DEFAULT_RV_WARN_AGE + 1));
+}
+
+#[test]
+fn rv_age_not_set() {
+    let mock = MockStream::default();
+
+    let mai = mock_new!(mock);
+
+    // A failed upload shouldn't be tracked
+    assert!(mai
+        .set_rv([1.1, 2.2, 3.3], [4.4, 5.5, 6.6], 1198800018)
+        .is_err());
+
+    assert_eq!(mai.rv_epoch(), None);
+    assert_eq!(mai.rv_age(1198800618), 1198800618);
+    assert!(mai.rv_stale(1198800618));
+}
+
+#[test]
+fn rv_stale_disabled() {
+    let mock = MockStream::default();
+
+    let mai = MAI400Builder::new()
+        .connection(Connection {
+            stream: Box::new(mock),
+        })
+        .rv_warn_age(None)
+        .build()
+        .unwrap();
+
+    assert!(!mai.rv_stale(1198800618));
 }
 
 #[test]
@@ -165,13 +566,118 @@ fn set_rv_bad() {
     let mai = mock_new!(mock);
 
     assert_eq!(
-        mai.set_rv([0.0, 0.0, 0.0], [0.0, 0.0, 0.0], 0).unwrap_err(),
+        mai.set_rv([0.0, 0.0, 0.0], [0.0, 0.0, 0.0], 1198800018)
+            .unwrap_err(),
         MAIError::UartError {
             cause: UartError::GenericError,
         }
     );
 }
 
+#[test]
+fn set_rv_bad_nan() {
+    let mock = MockStream::default();
+
+    // Nothing should be sent to the device
+    let tracker = mock.tracker();
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(
+        mai.set_rv([1.1, std::f32::NAN, 3.3], [4.4, 5.5, 6.6], 1198800018)
+            .unwrap_err(),
+        MAIError::InvalidInput {
+            reason: "eci_pos[1] must be finite and within +/-100000".to_owned(),
+        }
+    );
+
+    tracker.assert_consumed();
+}
+
+#[test]
+fn set_rv_bad_velocity() {
+    let mock = MockStream::default();
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(
+        mai.set_rv([1.1, 2.2, 3.3], [4.4, 5.5, std::f32::INFINITY], 1198800018)
+            .unwrap_err(),
+        MAIError::InvalidInput {
+            reason: "eci_vel[2] must be finite and within +/-100".to_owned(),
+        }
+    );
+    assert_eq!(
+        mai.set_rv([1.1, 2.2, 3.3], [-100.5, 5.5, 6.6], 1198800018)
+            .unwrap_err(),
+        MAIError::InvalidInput {
+            reason: "eci_vel[0] must be finite and within +/-100".to_owned(),
+        }
+    );
+}
+
+#[test]
+fn set_rv_bad_epoch() {
+    let mock = MockStream::default();
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(
+        mai.set_rv([1.1, 2.2, 3.3], [4.4, 5.5, 6.6], 0).unwrap_err(),
+        MAIError::InvalidInput {
+            reason: "time_epoch must be non-zero".to_owned(),
+        }
+    );
+}
+
+#[test]
+fn set_calibration_good() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0x90, 0xEB, 0x46, 0x00, 0x00, 0xC0, 0x3F, 0x00, 0x00, 0x00, 0xC0, 0x00, 0x00, 0x80, 0x3E,
+        0x00, 0x00, 0x00, 0x3F, 0x00, 0x00, 0x00, 0xBE, 0x00, 0x00, 0x80, 0x3F, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFA, 0x05,
+    ]);
+
+    let tracker = mock.tracker();
+
+    let mai = mock_new!(mock);
+
+    assert!(mai
+        .set_calibration([1.5, -2.0, 0.25], [0.5, -0.125, 1.0])
+        .is_ok());
+
+    tracker.assert_consumed();
+}
+
+#[test]
+fn set_calibration_bad_input() {
+    let mock = MockStream::default();
+
+    // Nothing should be sent to the device
+    let tracker = mock.tracker();
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(
+        mai.set_calibration([1.5, -2.0, std::f32::NAN], [0.5, -0.125, 1.0])
+            .unwrap_err(),
+        MAIError::InvalidInput {
+            reason: "mag_bias[2] must be finite".to_owned(),
+        }
+    );
+    assert_eq!(
+        mai.set_calibration([1.5, -2.0, 0.25], [std::f32::NEG_INFINITY, -0.125, 1.0])
+            .unwrap_err(),
+        MAIError::InvalidInput {
+            reason: "gyro_bias[0] must be finite".to_owned(),
+        }
+    );
+
+    tracker.assert_consumed();
+}
+
 #[test]
 fn passthrough_good() {
     let mut mock = MockStream::default();
@@ -180,9 +686,13 @@ fn passthrough_good() {
 
     mock.write.set_input(msg.to_vec());
 
+    let tracker = mock.tracker();
+
     let mai = mock_new!(mock);
 
     assert!(mai.passthrough(&msg).is_ok());
+
+    tracker.assert_consumed();
 }
 
 #[test]
@@ -200,3 +710,128 @@ fn passthrough_bad() {
         }
     );
 }
+
+#[test]
+fn passthrough_bad_len() {
+    let mock = MockStream::default();
+
+    let msg: [u8; 39] = [0x00; 39];
+
+    let tracker = mock.tracker();
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(
+        mai.passthrough(&msg).unwrap_err(),
+        MAIError::InvalidInput {
+            reason: "Message must be exactly 40 bytes, not 39".to_owned(),
+        }
+    );
+
+    // Nothing should have been sent
+    tracker.assert_consumed();
+}
+
+#[test]
+fn passthrough_with_response_good() {
+    let mut mock = MockStream::default();
+
+    let mut msg = [0; CMD_LEN];
+    msg[0] = 0x90;
+    msg[1] = 0xEB;
+    msg[2] = 0x5A;
+    let response = vec![0x90, 0xEB, 0x2, 0x0, 0x5A, 0x0, 0x1, 0x2, 0x5D, 0x0];
+
+    mock.write.set_input(msg.to_vec());
+    mock.read.set_output(response.clone());
+
+    let tracker = mock.tracker();
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(mai.passthrough_with_response(&msg, 10).unwrap(), response);
+
+    tracker.assert_consumed();
+}
+
+#[test]
+fn passthrough_with_response_bad_sync() {
+    let mut mock = MockStream::default();
+
+    let mut msg = [0; CMD_LEN];
+    msg[0] = 0x90;
+    msg[1] = 0xEB;
+    msg[2] = 0x5A;
+
+    mock.write.set_input(msg.to_vec());
+    mock.read.set_output(vec![0xEB, 0x90, 0x0, 0x0]);
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(
+        mai.passthrough_with_response(&msg, 4).unwrap_err(),
+        MAIError::SyncMismatch { found: 0xEB90 }
+    );
+}
+
+#[test]
+fn passthrough_with_response_short_read() {
+    let mut mock = MockStream::default();
+
+    let mut msg = [0; CMD_LEN];
+    msg[0] = 0x90;
+    msg[1] = 0xEB;
+    msg[2] = 0x5A;
+
+    mock.write.set_input(msg.to_vec());
+    mock.read.set_result(Ok(vec![0x90]));
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(
+        mai.passthrough_with_response(&msg, 4).unwrap_err(),
+        MAIError::FramingError {
+            detail: "Response too short: 1 bytes".to_owned()
+        }
+    );
+}
+
+#[test]
+fn passthrough_with_response_timeout() {
+    let mut mock = MockStream::default();
+
+    let mut msg = [0; CMD_LEN];
+    msg[0] = 0x90;
+    msg[1] = 0xEB;
+    msg[2] = 0x5A;
+
+    mock.write.set_input(msg.to_vec());
+    mock.read.set_output(vec![0x90, 0xEB]);
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(
+        mai.passthrough_with_response(&msg, 4).unwrap_err(),
+        MAIError::Timeout
+    );
+}
+
+#[test]
+fn last_tx_recorded_on_error() {
+    let mock = MockStream::default();
+
+    let mai = mock_new!(mock);
+
+    assert_eq!(mai.last_tx(), None);
+
+    assert!(mai.set_mode(0x01, [0x02, 0x03, 0x04, 0x05]).is_err());
+
+    assert_eq!(
+        mai.last_tx(),
+        Some(vec![
+            0x90, 0xEB, 0x0, 0x1, 0x2, 0x0, 0x3, 0x0, 0x4, 0x0, 0x5, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+            0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+            0x0, 0x0, 0x0, 0x0, 0x8A, 0x1,
+        ])
+    );
+}
@@ -73,13 +73,22 @@
 mod crc32;
 mod messages;
 mod oem6;
+mod replay;
 #[cfg(test)]
 mod tests;
 
+pub use crate::messages::commands::Constellation;
+pub use crate::messages::commands::FResetTarget;
+pub use crate::messages::commands::LogFormat;
 pub use crate::messages::commands::ResponseID;
+pub use crate::messages::commands::SUPPORTED_BAUD_RATES;
 pub use crate::messages::logs::*;
+pub use crate::messages::port_name;
 pub use crate::messages::MessageID;
+pub use crate::messages::Port;
 pub use crate::messages::ReceiverStatusFlags;
+pub use crate::messages::SUPPORTED_LOG_PORTS;
 pub use crate::oem6::*;
-pub use rust_uart::{mock, Connection, UartError};
+pub use crate::replay::ReplaySource;
+pub use rust_uart::{mock, Connection, Stream, UartError, UartResult};
 pub use serial::BaudRate;
@@ -0,0 +1,208 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+use crate::crc32::calc_crc;
+use std::str;
+use std::str::FromStr;
+
+/// First character of every ASCII log message
+pub const ASCII_SYNC: u8 = 0x23;
+
+/// Number of fields in an ASCII log header
+const ASCII_HDR_FIELDS: usize = 10;
+
+/// Convert a raw ASCII log message (ex. "#TIMEA,COM1,...;...*1234abcd") into a header
+/// and the body fields
+///
+/// The CRC is calculated over all characters between the '#' and '*' characters.
+/// The returned header will have the ASCII format bits set in its message type
+pub fn parse_ascii_log(raw: &[u8]) -> Option<(Header, Vec<u8>)> {
+    let raw = str::from_utf8(raw).ok()?.trim_end();
+
+    if !raw.starts_with(ASCII_SYNC as char) {
+        return None;
+    }
+
+    let crc_index = raw.rfind('*')?;
+    let crc = u32::from_str_radix(&raw[(crc_index + 1)..], 16).ok()?;
+    let message = &raw[1..crc_index];
+
+    if calc_crc(message.as_bytes()) != crc {
+        return None;
+    }
+
+    let body_index = message.find(';')?;
+    let fields: Vec<&str> = message[..body_index].split(',').collect();
+
+    if fields.len() != ASCII_HDR_FIELDS {
+        return None;
+    }
+
+    // The message name always ends with the 'A' format suffix
+    let name = fields[0];
+    if !name.ends_with('A') {
+        return None;
+    }
+
+    let msg_id = message_id(&name[..(name.len() - 1)]);
+    let idle_time = (fields[3].parse::<f32>().ok()? * 2.0) as u8;
+    let time_status = time_status(fields[4])?;
+    let week = fields[5].parse::<u16>().ok()?;
+    let ms = (fields[6].parse::<f64>().ok()? * 1000.0).round() as i32;
+    let recv_status = u32::from_str_radix(fields[7], 16).ok()?;
+    let recv_ver = fields[9].parse::<u16>().ok()?;
+
+    let hdr = Header {
+        sync: SYNC,
+        hdr_len: HDR_LEN,
+        msg_id,
+        msg_type: LogFormat::Ascii as u8,
+        port_addr: port_address(fields[1]).unwrap_or(0),
+        msg_len: (message.len() - body_index - 1) as u16,
+        seq: fields[2].parse::<u16>().ok()?,
+        idle_time,
+        time_status,
+        week,
+        ms,
+        recv_status: ReceiverStatusFlags::from_bits_truncate(recv_status),
+        recv_ver,
+    };
+
+    Some((hdr, message[(body_index + 1)..].as_bytes().to_vec()))
+}
+
+/// Split the body of an ASCII log message into its comma-separated fields
+pub fn ascii_fields(raw: &[u8]) -> Option<Vec<&str>> {
+    str::from_utf8(raw)
+        .ok()
+        .map(|body| body.split(',').collect())
+}
+
+/// Convert a single field of an ASCII log message into the requested type
+pub fn field<T: FromStr>(fields: &[&str], index: usize) -> Option<T> {
+    fields.get(index)?.parse::<T>().ok()
+}
+
+/// Convert a single hex field of an ASCII log message (ex. "02000040")
+pub fn hex_field(fields: &[&str], index: usize) -> Option<u32> {
+    u32::from_str_radix(fields.get(index)?, 16).ok()
+}
+
+/// Convert an ASCII log name (without the format suffix) into its message ID
+fn message_id(name: &str) -> MessageID {
+    match name {
+        "BESTVEL" => MessageID::BestVel,
+        "BESTXYZ" => MessageID::BestXYZ,
+        "PSRDOP" => MessageID::PsrDop,
+        "RXCONFIG" => MessageID::RxConfig,
+        "RXSTATUSEVENT" => MessageID::RxStatusEvent,
+        "TIME" => MessageID::Time,
+        "VERSION" => MessageID::Version,
+        _ => MessageID::Unknown,
+    }
+}
+
+/// Convert an ASCII time status name into its numeric value
+pub fn time_status(name: &str) -> Option<u8> {
+    match name {
+        "UNKNOWN" => Some(20),
+        "APPROXIMATE" => Some(60),
+        "COARSEADJUSTING" => Some(80),
+        "COARSE" => Some(100),
+        "COARSESTEERING" => Some(120),
+        "FREEWHEELING" => Some(130),
+        "FINEADJUSTING" => Some(140),
+        "FINE" => Some(160),
+        "FINEBACKUPSTEERING" => Some(170),
+        "FINESTEERING" => Some(180),
+        "SATTIME" => Some(200),
+        _ => None,
+    }
+}
+
+/// Convert an ASCII solution status name into its numeric value
+pub fn solution_status(name: &str) -> Option<u32> {
+    match name {
+        "SOL_COMPUTED" => Some(0),
+        "INSUFFICIENT_OBS" => Some(1),
+        "NO_CONVERGENCE" => Some(2),
+        "SINGULARITY" => Some(3),
+        "COV_TRACE" => Some(4),
+        "TEST_DIST" => Some(5),
+        "COLD_START" => Some(6),
+        "V_H_LIMIT" => Some(7),
+        "VARIANCE" => Some(8),
+        "RESIDUALS" => Some(9),
+        "INTEGRITY_WARNING" => Some(13),
+        "PENDING" => Some(18),
+        "INVALID_FIX" => Some(19),
+        "UNAUTHORIZED" => Some(20),
+        _ => None,
+    }
+}
+
+/// Convert an ASCII position or velocity type name into its numeric value
+pub fn pos_vel_type(name: &str) -> Option<u32> {
+    match name {
+        "NONE" => Some(0),
+        "FIXEDPOS" => Some(1),
+        "FIXEDHEIGHT" => Some(2),
+        "DOPPLER_VELOCITY" => Some(8),
+        "SINGLE" => Some(16),
+        "PSRDIFF" => Some(17),
+        "WAAS" => Some(18),
+        "PROPAGATED" => Some(19),
+        "OMNISTAR" => Some(20),
+        "L1_FLOAT" => Some(32),
+        "IONOFREE_FLOAT" => Some(33),
+        "NARROW_FLOAT" => Some(34),
+        "L1_INT" => Some(48),
+        "NARROW_INT" => Some(50),
+        "OMNISTAR_HP" => Some(64),
+        "OMNISTAR_XP" => Some(65),
+        "PPP_CONVERGING" => Some(68),
+        "PPP" => Some(69),
+        "OPERATIONAL" => Some(70),
+        "WARNING" => Some(71),
+        "OUT_OF_BOUNDS" => Some(72),
+        "PPP_BASIC_CONVERGING" => Some(77),
+        "PPP_BASIC" => Some(78),
+        _ => None,
+    }
+}
+
+/// Convert an ASCII clock model status name into its numeric value
+pub fn clock_status(name: &str) -> Option<u32> {
+    match name {
+        "VALID" => Some(0),
+        "CONVERGING" => Some(1),
+        "ITERATING" => Some(2),
+        "INVALID" => Some(3),
+        "ERROR" => Some(4),
+        _ => None,
+    }
+}
+
+/// Convert an ASCII UTC status name into its numeric value
+pub fn utc_status(name: &str) -> Option<u32> {
+    match name {
+        "INVALID" => Some(0),
+        "VALID" => Some(1),
+        "WARNING" => Some(2),
+        _ => None,
+    }
+}
@@ -0,0 +1,66 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+
+// Port identifiers, parity, handshaking, echo and break detection values
+// used by the COM command
+const COM_PORT_THISPORT: u32 = 6;
+const COM_PARITY_NONE: u32 = 0;
+const COM_DATA_BITS: u32 = 8;
+const COM_STOP_BITS: u32 = 1;
+const COM_HANDSHAKE_NONE: u32 = 0;
+const COM_ECHO_OFF: u32 = 0;
+const COM_BREAK_ON: u32 = 1;
+
+/// Serial data rates supported by both the OEM6 and the local serial port
+pub const SUPPORTED_BAUD_RATES: [u32; 5] = [9600, 19200, 38400, 57600, 115200];
+
+pub struct ComCmd {
+    hdr: Header,
+    baud: u32,
+}
+
+impl ComCmd {
+    pub fn new(baud: u32) -> Self {
+        ComCmd {
+            hdr: Header::new(MessageID::Com, 32),
+            baud,
+        }
+    }
+}
+
+impl Message for ComCmd {
+    fn serialize(&self) -> Vec<u8> {
+        let mut vec = vec![];
+
+        //Add header
+        vec.append(&mut self.hdr.serialize());
+
+        // Add COM message. Only the data rate of the port we're talking on is changed,
+        // the rest of the settings match the ones used by the local port
+        vec.write_u32::<LittleEndian>(COM_PORT_THISPORT).unwrap();
+        vec.write_u32::<LittleEndian>(self.baud).unwrap();
+        vec.write_u32::<LittleEndian>(COM_PARITY_NONE).unwrap();
+        vec.write_u32::<LittleEndian>(COM_DATA_BITS).unwrap();
+        vec.write_u32::<LittleEndian>(COM_STOP_BITS).unwrap();
+        vec.write_u32::<LittleEndian>(COM_HANDSHAKE_NONE).unwrap();
+        vec.write_u32::<LittleEndian>(COM_ECHO_OFF).unwrap();
+        vec.write_u32::<LittleEndian>(COM_BREAK_ON).unwrap();
+
+        vec
+    }
+}
@@ -0,0 +1,45 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+
+pub struct ECutoffCmd {
+    hdr: Header,
+    angle: f32,
+}
+
+impl ECutoffCmd {
+    pub fn new(angle: f32) -> Self {
+        ECutoffCmd {
+            hdr: Header::new(MessageID::ECutoff, 4),
+            angle,
+        }
+    }
+}
+
+impl Message for ECutoffCmd {
+    fn serialize(&self) -> Vec<u8> {
+        let mut vec = vec![];
+
+        //Add header
+        vec.append(&mut self.hdr.serialize());
+
+        // Add ECutoff message
+        vec.write_f32::<LittleEndian>(self.angle).unwrap();
+
+        vec
+    }
+}
@@ -0,0 +1,118 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+
+pub struct LockoutSystemCmd {
+    hdr: Header,
+    system: Constellation,
+}
+
+impl LockoutSystemCmd {
+    pub fn new(system: Constellation) -> Self {
+        LockoutSystemCmd {
+            hdr: Header::new(MessageID::LockoutSystem, 4),
+            system,
+        }
+    }
+}
+
+impl Message for LockoutSystemCmd {
+    fn serialize(&self) -> Vec<u8> {
+        let mut vec = vec![];
+
+        //Add header
+        vec.append(&mut self.hdr.serialize());
+
+        // Add LockoutSystem message
+        vec.write_u32::<LittleEndian>(self.system as u32).unwrap();
+
+        vec
+    }
+}
+
+pub struct UnlockoutSystemCmd {
+    hdr: Header,
+    system: Constellation,
+}
+
+impl UnlockoutSystemCmd {
+    pub fn new(system: Constellation) -> Self {
+        UnlockoutSystemCmd {
+            hdr: Header::new(MessageID::UnlockoutSystem, 4),
+            system,
+        }
+    }
+}
+
+impl Message for UnlockoutSystemCmd {
+    fn serialize(&self) -> Vec<u8> {
+        let mut vec = vec![];
+
+        //Add header
+        vec.append(&mut self.hdr.serialize());
+
+        // Add UnlockoutSystem message
+        vec.write_u32::<LittleEndian>(self.system as u32).unwrap();
+
+        vec
+    }
+}
+
+/// Satellite systems which can be locked out of (or restored to) the solution
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Constellation {
+    /// Global Positioning System
+    Gps = 0,
+    /// GLONASS
+    Glonass = 1,
+    /// Satellite-Based Augmentation System
+    Sbas = 2,
+    /// Galileo
+    Galileo = 5,
+    /// BeiDou
+    Beidou = 6,
+    /// Quasi-Zenith Satellite System
+    Qzss = 7,
+}
+
+impl Constellation {
+    /// All of the satellite systems which can be selected
+    pub const ALL: [Constellation; 6] = [
+        Constellation::Gps,
+        Constellation::Glonass,
+        Constellation::Sbas,
+        Constellation::Galileo,
+        Constellation::Beidou,
+        Constellation::Qzss,
+    ];
+
+    /// Decode the satellite system from a channel tracking status word
+    /// (as reported in the RANGE and TRACKSTAT logs)
+    ///
+    /// Returns `None` if the system is not one of the known constellations
+    pub fn from_tracking_status(status: u32) -> Option<Constellation> {
+        match (status >> 16) & 0x7 {
+            0 => Some(Constellation::Gps),
+            1 => Some(Constellation::Glonass),
+            2 => Some(Constellation::Sbas),
+            3 => Some(Constellation::Galileo),
+            4 => Some(Constellation::Beidou),
+            5 => Some(Constellation::Qzss),
+            _ => None,
+        }
+    }
+}
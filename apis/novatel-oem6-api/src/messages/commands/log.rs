@@ -21,7 +21,7 @@ pub struct LogCmd {
     hdr: Header,
     port: Port,
     msg_id: MessageID,
-    msg_type: u8,
+    format: LogFormat,
     trigger: LogTrigger,
     period: f64,
     offset: f64,
@@ -33,6 +33,7 @@ impl LogCmd {
     pub fn new(
         port: Port,
         msg_id: MessageID,
+        format: LogFormat,
         trigger: LogTrigger,
         period: f64,
         offset: f64,
@@ -42,7 +43,7 @@ impl LogCmd {
             hdr: Header::new(MessageID::Log, 32),
             port,
             msg_id,
-            msg_type: 0,
+            format,
             trigger,
             period,
             offset,
@@ -62,7 +63,7 @@ impl Message for LogCmd {
         // Add LOG message
         vec.write_u32::<LittleEndian>(self.port as u32).unwrap();
         vec.write_u16::<LittleEndian>(self.msg_id as u16).unwrap();
-        vec.push(self.msg_type);
+        vec.push(self.format as u8);
         vec.push(0x00); // Reserved byte
         vec.write_u32::<LittleEndian>(self.trigger as u32).unwrap();
         vec.write_f64::<LittleEndian>(self.period).unwrap();
@@ -85,26 +86,39 @@ impl Message for LogCmd {
         let mut port = {
             let from = match self.port {
                 Port::COM1 => "COM1 ",
+                Port::COM2 => "COM2 ",
+                Port::COM3 => "COM3 ",
                 Port::ThisPort => "THISPORT ",
             };
             String::from(from).into_bytes()
         };
 
         let mut message = {
-            let from = match self.msg_id {
-                MessageID::BestXYZ => "BESTXYZB ",
-                MessageID::Log => "LOG ",
-                MessageID::RxStatusEvent => "RXSTATUSEVENT ",
-                MessageID::Unlog => "UNLOG ",
-                MessageID::UnlogAll => "UNLOGALL ",
-                MessageID::Version => "VERSION ",
-                _ => "UNKNOWN ",
-            };
-            String::from(from).into_bytes()
+            let suffix = self.format.suffix();
+            match self.msg_id {
+                MessageID::BestXYZ => format!("BESTXYZ{} ", suffix),
+                MessageID::BestVel => format!("BESTVEL{} ", suffix),
+                MessageID::Time => format!("TIME{} ", suffix),
+                MessageID::PsrDop => format!("PSRDOP{} ", suffix),
+                MessageID::RxConfig => format!("RXCONFIG{} ", suffix),
+                MessageID::Mark1Time => format!("MARK1TIME{} ", suffix),
+                MessageID::Mark2Time => format!("MARK2TIME{} ", suffix),
+                MessageID::HwMonitor => format!("HWMONITOR{} ", suffix),
+                MessageID::Range => format!("RANGE{} ", suffix),
+                MessageID::TrackStat => format!("TRACKSTAT{} ", suffix),
+                MessageID::Log => String::from("LOG "),
+                MessageID::RxStatusEvent => String::from("RXSTATUSEVENT "),
+                MessageID::Unlog => String::from("UNLOG "),
+                MessageID::UnlogAll => String::from("UNLOGALL "),
+                MessageID::Version => String::from("VERSION "),
+                _ => String::from("UNKNOWN "),
+            }
+            .into_bytes()
         };
 
         let mut trigger = {
             let from = match self.trigger {
+                LogTrigger::OnNew => "ONNEW ",
                 LogTrigger::OnChanged => "ONCHANGED ",
                 LogTrigger::OnTime => "ONTIME ",
                 LogTrigger::Once => "ONCE ",
@@ -139,7 +153,33 @@ impl Message for LogCmd {
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum LogTrigger {
+    OnNew = 0,
     OnChanged = 1,
     OnTime = 2,
     Once = 4,
 }
+
+/// Encoding format of generated log messages
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LogFormat {
+    /// Binary log messages
+    Binary = 0x00,
+    /// ASCII log messages
+    Ascii = 0x20,
+}
+
+impl Default for LogFormat {
+    fn default() -> LogFormat {
+        LogFormat::Binary
+    }
+}
+
+impl LogFormat {
+    /// Suffix appended to a log name to request this format (ex. "BESTXYZB")
+    pub fn suffix(self) -> &'static str {
+        match self {
+            LogFormat::Binary => "B",
+            LogFormat::Ascii => "A",
+        }
+    }
+}
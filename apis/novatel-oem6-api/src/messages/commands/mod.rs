@@ -17,11 +17,25 @@
 use super::*;
 use byteorder::LittleEndian;
 
+mod com;
+mod ecutoff;
+mod lockout_system;
 mod log;
+mod reset;
+mod save_config;
+mod set_approx_pos;
+mod set_approx_time;
 mod unlog;
 mod unlog_all;
 
+pub use self::com::*;
+pub use self::ecutoff::*;
+pub use self::lockout_system::*;
 pub use self::log::*;
+pub use self::reset::*;
+pub use self::save_config::*;
+pub use self::set_approx_pos::*;
+pub use self::set_approx_time::*;
 pub use self::unlog::*;
 pub use self::unlog_all::*;
 
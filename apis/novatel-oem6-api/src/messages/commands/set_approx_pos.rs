@@ -0,0 +1,51 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+
+pub struct SetApproxPosCmd {
+    hdr: Header,
+    lat: f64,
+    lon: f64,
+    height: f64,
+}
+
+impl SetApproxPosCmd {
+    pub fn new(lat: f64, lon: f64, height: f64) -> Self {
+        SetApproxPosCmd {
+            hdr: Header::new(MessageID::SetApproxPos, 24),
+            lat,
+            lon,
+            height,
+        }
+    }
+}
+
+impl Message for SetApproxPosCmd {
+    fn serialize(&self) -> Vec<u8> {
+        let mut vec = vec![];
+
+        //Add header
+        vec.append(&mut self.hdr.serialize());
+
+        // Add SetApproxPos message
+        vec.write_f64::<LittleEndian>(self.lat).unwrap();
+        vec.write_f64::<LittleEndian>(self.lon).unwrap();
+        vec.write_f64::<LittleEndian>(self.height).unwrap();
+
+        vec
+    }
+}
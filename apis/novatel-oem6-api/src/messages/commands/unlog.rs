@@ -63,6 +63,8 @@ impl Message for UnlogCmd {
         let mut port = {
             let from = match self.port {
                 Port::COM1 => "COM1 ",
+                Port::COM2 => "COM2 ",
+                Port::COM3 => "COM3 ",
                 Port::ThisPort => "THISPORT ",
             };
             String::from(from).into_bytes()
@@ -71,6 +73,15 @@ impl Message for UnlogCmd {
         let mut message = {
             let from = match self.msg_id {
                 MessageID::BestXYZ => "BESTXYZB ",
+                MessageID::BestVel => "BESTVELB ",
+                MessageID::Time => "TIMEB ",
+                MessageID::PsrDop => "PSRDOPB ",
+                MessageID::RxConfig => "RXCONFIGB ",
+                MessageID::Mark1Time => "MARK1TIMEB ",
+                MessageID::Mark2Time => "MARK2TIMEB ",
+                MessageID::HwMonitor => "HWMONITORB ",
+                MessageID::Range => "RANGEB ",
+                MessageID::TrackStat => "TRACKSTATB ",
                 MessageID::RxStatusEvent => "RXSTATUSEVENT ",
                 MessageID::Version => "VERSION ",
                 _ => "UNKNOWN ",
@@ -0,0 +1,124 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+use nom::*;
+
+/// Log message containing the best available velocity
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct BestVelLog {
+    /// Current status of receiver
+    pub recv_status: ReceiverStatusFlags,
+    /// Validity of the time information
+    pub time_status: u8,
+    /// GPS reference week
+    pub week: u16,
+    /// Milliseconds into GPS reference week
+    pub ms: i32,
+    /// Velocity solution status
+    pub sol_status: u32,
+    /// Velocity type
+    pub vel_type: u32,
+    /// Latency of the velocity time tag, in seconds, with respect to the log's time
+    pub latency: f32,
+    /// Differential age (seconds)
+    pub diff_age: f32,
+    /// Horizontal speed over ground (m/s)
+    pub hor_speed: f64,
+    /// Actual direction of motion over ground with respect to True North (degrees)
+    pub track: f64,
+    /// Vertical speed (m/s). Positive values indicate increasing altitude
+    pub vert_speed: f64,
+}
+
+impl BestVelLog {
+    /// Convert a raw data buffer into a useable struct
+    pub fn new(
+        recv_status: ReceiverStatusFlags,
+        time_status: u8,
+        week: u16,
+        ms: i32,
+        raw: &[u8],
+    ) -> Option<Self> {
+        let mut log = match parse_best_vel(raw) {
+            Ok(conv) => conv.1,
+            _ => return None,
+        };
+
+        log.recv_status = recv_status;
+        log.time_status = time_status;
+        log.week = week;
+        log.ms = ms;
+
+        Some(log)
+    }
+
+    /// Convert the body of an ASCII log message into a useable struct
+    pub fn from_ascii(
+        recv_status: ReceiverStatusFlags,
+        time_status: u8,
+        week: u16,
+        ms: i32,
+        raw: &[u8],
+    ) -> Option<Self> {
+        let fields = ascii::ascii_fields(raw)?;
+
+        if fields.len() != 8 {
+            return None;
+        }
+
+        Some(BestVelLog {
+            recv_status,
+            time_status,
+            week,
+            ms,
+            sol_status: ascii::solution_status(fields[0])?,
+            vel_type: ascii::pos_vel_type(fields[1])?,
+            latency: ascii::field(&fields, 2)?,
+            diff_age: ascii::field(&fields, 3)?,
+            hor_speed: ascii::field(&fields, 4)?,
+            track: ascii::field(&fields, 5)?,
+            vert_speed: ascii::field(&fields, 6)?,
+        })
+    }
+}
+
+named!(parse_best_vel(&[u8]) -> BestVelLog,
+    do_parse!(
+        sol_status: le_u32 >>
+        vel_type: le_u32 >>
+        latency: le_f32 >>
+        diff_age: le_f32 >>
+        hor_speed: le_f64 >>
+        track: le_f64 >>
+        vert_speed: le_f64 >>
+        le_f32 >>
+        (BestVelLog {
+            recv_status: ReceiverStatusFlags::empty(),
+            time_status: 0,
+            week: 0,
+            ms: 0,
+            sol_status,
+            vel_type,
+            latency,
+            diff_age,
+            hor_speed,
+            track,
+            vert_speed,
+            }
+        )
+    )
+);
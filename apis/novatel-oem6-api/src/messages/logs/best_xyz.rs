@@ -28,6 +28,15 @@ pub struct BestXYZLog {
     pub week: u16,
     /// Milliseconds into GPS reference week
     pub ms: i32,
+    /// Address of the receiver port which the log was output on. See [`port_name`]
+    ///
+    /// [`port_name`]: fn.port_name.html
+    pub port_addr: u8,
+    /// Sequence number from the log header. Counts down to 0 across a set of related
+    /// logs output together, and is 0 for logs output on their own
+    pub seq: u16,
+    /// Time the receiver's processor was idle, in units of 0.5% (0-200)
+    pub idle_time: u8,
     /// Position solution status
     pub pos_status: u32,
     /// Position type
@@ -89,6 +98,66 @@ impl BestXYZLog {
 
         Some(log)
     }
+
+    /// Convert the body of an ASCII log message into a useable struct
+    pub fn from_ascii(
+        recv_status: ReceiverStatusFlags,
+        time_status: u8,
+        week: u16,
+        ms: i32,
+        raw: &[u8],
+    ) -> Option<Self> {
+        let fields = ascii::ascii_fields(raw)?;
+
+        if fields.len() != 28 {
+            return None;
+        }
+
+        Some(BestXYZLog {
+            recv_status,
+            time_status,
+            week,
+            ms,
+            port_addr: 0,
+            seq: 0,
+            idle_time: 0,
+            pos_status: ascii::solution_status(fields[0])?,
+            pos_type: ascii::pos_vel_type(fields[1])?,
+            position: [
+                ascii::field(&fields, 2)?,
+                ascii::field(&fields, 3)?,
+                ascii::field(&fields, 4)?,
+            ],
+            pos_deviation: [
+                ascii::field(&fields, 5)?,
+                ascii::field(&fields, 6)?,
+                ascii::field(&fields, 7)?,
+            ],
+            vel_status: ascii::solution_status(fields[8])?,
+            vel_type: ascii::pos_vel_type(fields[9])?,
+            velocity: [
+                ascii::field(&fields, 10)?,
+                ascii::field(&fields, 11)?,
+                ascii::field(&fields, 12)?,
+            ],
+            vel_deviation: [
+                ascii::field(&fields, 13)?,
+                ascii::field(&fields, 14)?,
+                ascii::field(&fields, 15)?,
+            ],
+            station_id: fields[16].trim_matches('"').to_owned(),
+            vel_time_latency: ascii::field(&fields, 17)?,
+            diff_age: ascii::field(&fields, 18)?,
+            sol_age: ascii::field(&fields, 19)?,
+            num_sats: ascii::field(&fields, 20)?,
+            num_sat_vehicles: ascii::field(&fields, 21)?,
+            num_gg_l1: ascii::field(&fields, 22)?,
+            num_multi_sats: ascii::field(&fields, 23)?,
+            ext_sol_stat: ascii::hex_field(&fields, 25)? as u8,
+            gal_beidou_sig: ascii::hex_field(&fields, 26)? as u8,
+            gps_glonass_sig: ascii::hex_field(&fields, 27)? as u8,
+        })
+    }
 }
 
 #[cfg(not(feature = "nos3"))]
@@ -127,6 +196,9 @@ named!(parse_bestxyz(&[u8]) -> BestXYZLog,
             time_status: 0,
             week: 0,
             ms: 0,
+            port_addr: 0,
+            seq: 0,
+            idle_time: 0,
             pos_status,
             pos_type,
             position: [pos_x, pos_y, pos_z],
@@ -187,6 +259,9 @@ named!(parse_bestxyz(&[u8]) -> BestXYZLog,
             time_status: 0,
             week: 0,
             ms: 0,
+            port_addr: 0,
+            seq: 0,
+            idle_time: 0,
             pos_status,
             pos_type,
             position: [pos_x, pos_y, pos_z],
@@ -0,0 +1,125 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+use nom::*;
+
+/// HwMonitor reading type: Temperature (degrees Celsius)
+pub const HW_TEMPERATURE: u8 = 0x01;
+/// HwMonitor reading type: Antenna current (Amps)
+pub const HW_ANTENNA_CURRENT: u8 = 0x02;
+/// HwMonitor reading type: Digital core voltage (Volts)
+pub const HW_CORE_VOLTAGE: u8 = 0x06;
+/// HwMonitor reading type: Antenna voltage (Volts)
+pub const HW_ANTENNA_VOLTAGE: u8 = 0x07;
+/// HwMonitor reading type: Digital I/O voltage (Volts)
+pub const HW_IO_VOLTAGE: u8 = 0x08;
+/// HwMonitor reading type: Regulated supply voltage (Volts)
+pub const HW_SUPPLY_VOLTAGE: u8 = 0x09;
+
+/// A single hardware monitor measurement
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct HwMeasurement {
+    /// Measured value, already in the physical units of the reading type
+    pub reading: f32,
+    /// Raw status word. Bits 0-7 contain the boundary limit status
+    /// and bits 8-15 contain the reading type
+    pub status: u32,
+}
+
+impl HwMeasurement {
+    /// Type of the reading (ex. [`HW_TEMPERATURE`])
+    ///
+    /// [`HW_TEMPERATURE`]: constant.HW_TEMPERATURE.html
+    pub fn reading_type(&self) -> u8 {
+        (self.status >> 8) as u8
+    }
+
+    /// Whether the reading is within its acceptable range
+    /// (0 = acceptable, 1 = low warning, 2 = low error, 3 = high warning, 4 = high error)
+    pub fn limit_status(&self) -> u8 {
+        self.status as u8
+    }
+}
+
+/// Log message containing the receiver's hardware monitor readings
+/// (temperature, antenna current, voltages)
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct HwMonitorLog {
+    /// Current status of receiver
+    pub recv_status: ReceiverStatusFlags,
+    /// Validity of the time information
+    pub time_status: u8,
+    /// GPS reference week
+    pub week: u16,
+    /// Milliseconds into GPS reference week
+    pub ms: i32,
+    /// Measurements reported by the receiver
+    pub measurements: Vec<HwMeasurement>,
+}
+
+impl HwMonitorLog {
+    /// Convert a raw data buffer into a useable struct
+    pub fn new(
+        recv_status: ReceiverStatusFlags,
+        time_status: u8,
+        week: u16,
+        ms: i32,
+        raw: &[u8],
+    ) -> Option<Self> {
+        let mut log = match parse_hw_monitor(raw) {
+            Ok(conv) => conv.1,
+            _ => return None,
+        };
+
+        log.recv_status = recv_status;
+        log.time_status = time_status;
+        log.week = week;
+        log.ms = ms;
+
+        Some(log)
+    }
+
+    /// Get the first reading of the requested type, if the receiver reported one
+    pub fn reading(&self, reading_type: u8) -> Option<f32> {
+        self.measurements
+            .iter()
+            .find(|measurement| measurement.reading_type() == reading_type)
+            .map(|measurement| measurement.reading)
+    }
+}
+
+named!(parse_hw_monitor(&[u8]) -> HwMonitorLog,
+    do_parse!(
+        num_measurements: le_u32 >>
+        measurements: count!(
+            do_parse!(
+                reading: le_f32 >>
+                status: le_u32 >>
+                (HwMeasurement { reading, status })
+            ),
+            num_measurements as usize
+        ) >>
+        (HwMonitorLog {
+            recv_status: ReceiverStatusFlags::empty(),
+            time_status: 0,
+            week: 0,
+            ms: 0,
+            measurements,
+            }
+        )
+    )
+);
@@ -0,0 +1,95 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+use nom::*;
+
+/// Log message containing the time of an event on one of the receiver's mark inputs
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct MarkTimeLog {
+    /// Current status of receiver
+    pub recv_status: ReceiverStatusFlags,
+    /// Validity of the time information
+    pub time_status: u8,
+    /// GPS reference week
+    pub week: u16,
+    /// Milliseconds into GPS reference week
+    pub ms: i32,
+    /// Mark input which generated the event (1 for MARK1TIME, 2 for MARK2TIME)
+    pub event_id: u8,
+    /// GPS reference week of the mark event
+    pub mark_week: u32,
+    /// Seconds into the GPS reference week of the mark event
+    pub seconds: f64,
+    /// Receiver clock offset, in seconds, from GPS system time
+    pub offset: f64,
+    /// Receiver clock offset standard deviation (seconds)
+    pub offset_std: f64,
+    /// Offset, in seconds, of GPS system time from UTC time
+    pub utc_offset: f64,
+    /// Clock model status
+    pub clock_status: u32,
+}
+
+impl MarkTimeLog {
+    /// Convert a raw data buffer into a useable struct
+    pub fn new(
+        event_id: u8,
+        recv_status: ReceiverStatusFlags,
+        time_status: u8,
+        week: u16,
+        ms: i32,
+        raw: &[u8],
+    ) -> Option<Self> {
+        let mut log = match parse_mark_time(raw) {
+            Ok(conv) => conv.1,
+            _ => return None,
+        };
+
+        log.event_id = event_id;
+        log.recv_status = recv_status;
+        log.time_status = time_status;
+        log.week = week;
+        log.ms = ms;
+
+        Some(log)
+    }
+}
+
+named!(parse_mark_time(&[u8]) -> MarkTimeLog,
+    do_parse!(
+        mark_week: le_u32 >>
+        seconds: le_f64 >>
+        offset: le_f64 >>
+        offset_std: le_f64 >>
+        utc_offset: le_f64 >>
+        clock_status: le_u32 >>
+        (MarkTimeLog {
+            recv_status: ReceiverStatusFlags::empty(),
+            time_status: 0,
+            week: 0,
+            ms: 0,
+            event_id: 0,
+            mark_week,
+            seconds,
+            offset,
+            offset_std,
+            utc_offset,
+            clock_status,
+            }
+        )
+    )
+);
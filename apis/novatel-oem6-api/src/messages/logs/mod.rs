@@ -14,27 +14,77 @@
 // limitations under the License.
 //
 
+mod best_vel;
 mod best_xyz;
+mod hw_monitor;
+mod mark_time;
+mod psr_dop;
+mod range;
+mod rx_config;
 mod rxstatusevent;
+mod time;
+mod track_stat;
 mod version;
 
+pub use self::best_vel::*;
 pub use self::best_xyz::*;
+pub use self::hw_monitor::*;
+pub use self::mark_time::*;
+pub use self::psr_dop::*;
+pub use self::range::*;
+pub use self::rx_config::*;
 pub use self::rxstatusevent::*;
+pub use self::time::*;
+pub use self::track_stat::*;
 pub use self::version::*;
 use super::*;
 
 /// Supported log messages
 #[derive(Clone, Debug, PartialEq)]
 pub enum Log {
+    /// Best available velocity
+    BestVel(BestVelLog),
     /// Best available position and velocity in ECEF coordinates
     BestXYZ(BestXYZLog),
+    /// Receiver hardware monitor readings
+    HwMonitor(HwMonitorLog),
+    /// Time of an event on one of the mark inputs
+    MarkTime(MarkTimeLog),
+    /// Dilution of precision information
+    PsrDop(PsrDopLog),
+    /// Raw satellite range measurements
+    Range(RangeLog),
+    /// Configuration command currently in effect
+    RxConfig(RxConfigLog),
     /// Event and/or error message
     RxStatusEvent(RxStatusEventLog),
+    /// Clock model and UTC offset information
+    Time(TimeLog),
+    /// Tracking status of each receiver channel
+    TrackStat(TrackStatLog),
     /// System version information
     Version(VersionLog),
 }
 
 impl Log {
+    /// Message ID of the log message this log was parsed from
+    pub fn id(&self) -> MessageID {
+        match self {
+            Log::BestVel(_) => MessageID::BestVel,
+            Log::BestXYZ(_) => MessageID::BestXYZ,
+            Log::HwMonitor(_) => MessageID::HwMonitor,
+            Log::MarkTime(log) if log.event_id == 2 => MessageID::Mark2Time,
+            Log::MarkTime(_) => MessageID::Mark1Time,
+            Log::PsrDop(_) => MessageID::PsrDop,
+            Log::Range(_) => MessageID::Range,
+            Log::RxConfig(_) => MessageID::RxConfig,
+            Log::RxStatusEvent(_) => MessageID::RxStatusEvent,
+            Log::Time(_) => MessageID::Time,
+            Log::TrackStat(_) => MessageID::TrackStat,
+            Log::Version(_) => MessageID::Version,
+        }
+    }
+
     /// Convert a raw data buffer into a useable struct
     pub fn new(
         id: MessageID,
@@ -45,17 +95,72 @@ impl Log {
         raw: Vec<u8>,
     ) -> Option<Log> {
         match id {
+            MessageID::BestVel => {
+                BestVelLog::new(recv_status, time_status, week, ms, &raw).map(Log::BestVel)
+            }
             MessageID::BestXYZ => {
                 BestXYZLog::new(recv_status, time_status, week, ms, &raw).map(Log::BestXYZ)
             }
+            MessageID::HwMonitor => {
+                HwMonitorLog::new(recv_status, time_status, week, ms, &raw).map(Log::HwMonitor)
+            }
+            MessageID::Mark1Time => {
+                MarkTimeLog::new(1, recv_status, time_status, week, ms, &raw).map(Log::MarkTime)
+            }
+            MessageID::Mark2Time => {
+                MarkTimeLog::new(2, recv_status, time_status, week, ms, &raw).map(Log::MarkTime)
+            }
+            MessageID::PsrDop => {
+                PsrDopLog::new(recv_status, time_status, week, ms, &raw).map(Log::PsrDop)
+            }
+            MessageID::Range => {
+                RangeLog::new(recv_status, time_status, week, ms, &raw).map(Log::Range)
+            }
+            MessageID::RxConfig => {
+                RxConfigLog::new(recv_status, time_status, week, ms, &raw).map(Log::RxConfig)
+            }
             MessageID::RxStatusEvent => {
                 RxStatusEventLog::new(recv_status, time_status, week, ms, &raw)
                     .map(Log::RxStatusEvent)
             }
+            MessageID::Time => {
+                TimeLog::new(recv_status, time_status, week, ms, &raw).map(Log::Time)
+            }
+            MessageID::TrackStat => {
+                TrackStatLog::new(recv_status, time_status, week, ms, &raw).map(Log::TrackStat)
+            }
             MessageID::Version => {
                 VersionLog::new(recv_status, time_status, week, ms, raw).map(Log::Version)
             }
             _ => None,
         }
     }
+
+    /// Convert the body of an ASCII log message into a useable struct
+    ///
+    /// ASCII logs are only supported for BestVel, BestXYZ, PsrDop and Time messages
+    pub fn from_ascii(
+        id: MessageID,
+        recv_status: ReceiverStatusFlags,
+        time_status: u8,
+        week: u16,
+        ms: i32,
+        raw: &[u8],
+    ) -> Option<Log> {
+        match id {
+            MessageID::BestVel => {
+                BestVelLog::from_ascii(recv_status, time_status, week, ms, raw).map(Log::BestVel)
+            }
+            MessageID::BestXYZ => {
+                BestXYZLog::from_ascii(recv_status, time_status, week, ms, raw).map(Log::BestXYZ)
+            }
+            MessageID::PsrDop => {
+                PsrDopLog::from_ascii(recv_status, time_status, week, ms, raw).map(Log::PsrDop)
+            }
+            MessageID::Time => {
+                TimeLog::from_ascii(recv_status, time_status, week, ms, raw).map(Log::Time)
+            }
+            _ => None,
+        }
+    }
 }
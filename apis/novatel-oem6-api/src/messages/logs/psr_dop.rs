@@ -0,0 +1,131 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+use nom::*;
+
+/// Log message containing the dilution of precision values of the current
+/// pseudorange position solution
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct PsrDopLog {
+    /// Current status of receiver
+    pub recv_status: ReceiverStatusFlags,
+    /// Validity of the time information
+    pub time_status: u8,
+    /// GPS reference week
+    pub week: u16,
+    /// Milliseconds into GPS reference week
+    pub ms: i32,
+    /// Geometric dilution of precision
+    pub gdop: f32,
+    /// Position dilution of precision
+    pub pdop: f32,
+    /// Horizontal dilution of precision
+    pub hdop: f32,
+    /// Horizontal position and time dilution of precision
+    pub htdop: f32,
+    /// Time dilution of precision
+    pub tdop: f32,
+    /// Elevation cut-off angle (degrees)
+    pub cutoff: f32,
+    /// Satellite PRNs used in the solution
+    pub prns: Vec<u32>,
+}
+
+impl PsrDopLog {
+    /// Convert a raw data buffer into a useable struct
+    pub fn new(
+        recv_status: ReceiverStatusFlags,
+        time_status: u8,
+        week: u16,
+        ms: i32,
+        raw: &[u8],
+    ) -> Option<Self> {
+        let mut log = match parse_psr_dop(raw) {
+            Ok(conv) => conv.1,
+            _ => return None,
+        };
+
+        log.recv_status = recv_status;
+        log.time_status = time_status;
+        log.week = week;
+        log.ms = ms;
+
+        Some(log)
+    }
+
+    /// Convert the body of an ASCII log message into a useable struct
+    pub fn from_ascii(
+        recv_status: ReceiverStatusFlags,
+        time_status: u8,
+        week: u16,
+        ms: i32,
+        raw: &[u8],
+    ) -> Option<Self> {
+        let fields = ascii::ascii_fields(raw)?;
+
+        let num_prns: usize = ascii::field(&fields, 6)?;
+        if fields.len() != 7 + num_prns {
+            return None;
+        }
+
+        let mut prns = vec![];
+        for index in 7..fields.len() {
+            prns.push(ascii::field(&fields, index)?);
+        }
+
+        Some(PsrDopLog {
+            recv_status,
+            time_status,
+            week,
+            ms,
+            gdop: ascii::field(&fields, 0)?,
+            pdop: ascii::field(&fields, 1)?,
+            hdop: ascii::field(&fields, 2)?,
+            htdop: ascii::field(&fields, 3)?,
+            tdop: ascii::field(&fields, 4)?,
+            cutoff: ascii::field(&fields, 5)?,
+            prns,
+        })
+    }
+}
+
+named!(parse_psr_dop(&[u8]) -> PsrDopLog,
+    do_parse!(
+        gdop: le_f32 >>
+        pdop: le_f32 >>
+        hdop: le_f32 >>
+        htdop: le_f32 >>
+        tdop: le_f32 >>
+        cutoff: le_f32 >>
+        num_prns: le_u32 >>
+        prns: count!(le_u32, num_prns as usize) >>
+        (PsrDopLog {
+            recv_status: ReceiverStatusFlags::empty(),
+            time_status: 0,
+            week: 0,
+            ms: 0,
+            gdop,
+            pdop,
+            hdop,
+            htdop,
+            tdop,
+            cutoff,
+            prns,
+            }
+        )
+    )
+);
@@ -0,0 +1,134 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+use nom::*;
+
+/// Raw observation for a single tracked satellite signal
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct RangeObservation {
+    /// Satellite PRN number (or GLONASS slot number)
+    pub prn: u16,
+    /// GLONASS frequency channel number + 7. Zero for other systems
+    pub glofreq: u16,
+    /// Pseudorange measurement (meters)
+    pub psr: f64,
+    /// Pseudorange measurement standard deviation (meters)
+    pub psr_std: f32,
+    /// Carrier phase, in cycles (accumulated Doppler range)
+    pub adr: f64,
+    /// Estimated carrier phase standard deviation (cycles)
+    pub adr_std: f32,
+    /// Instantaneous carrier Doppler frequency (Hz)
+    pub doppler: f32,
+    /// Carrier to noise density ratio (dB-Hz)
+    pub cno: f32,
+    /// Number of seconds of continuous tracking (no cycle slipping)
+    pub lock_time: f32,
+    /// Tracking status word (signal type, satellite system, phase lock, etc)
+    pub tracking_status: u32,
+}
+
+impl RangeObservation {
+    /// Satellite system of the signal, decoded from the tracking status word
+    ///
+    /// Returns `None` for systems not covered by [`Constellation`]
+    ///
+    /// [`Constellation`]: enum.Constellation.html
+    pub fn system(&self) -> Option<Constellation> {
+        Constellation::from_tracking_status(self.tracking_status)
+    }
+}
+
+/// Log message containing the raw channel measurements for each satellite
+/// signal currently being tracked
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct RangeLog {
+    /// Current status of receiver
+    pub recv_status: ReceiverStatusFlags,
+    /// Validity of the time information
+    pub time_status: u8,
+    /// GPS reference week
+    pub week: u16,
+    /// Milliseconds into GPS reference week
+    pub ms: i32,
+    /// Observations reported by the receiver
+    pub observations: Vec<RangeObservation>,
+}
+
+impl RangeLog {
+    /// Convert a raw data buffer into a useable struct
+    pub fn new(
+        recv_status: ReceiverStatusFlags,
+        time_status: u8,
+        week: u16,
+        ms: i32,
+        raw: &[u8],
+    ) -> Option<Self> {
+        let mut log = match parse_range(raw) {
+            Ok(conv) => conv.1,
+            _ => return None,
+        };
+
+        log.recv_status = recv_status;
+        log.time_status = time_status;
+        log.week = week;
+        log.ms = ms;
+
+        Some(log)
+    }
+}
+
+named!(parse_range(&[u8]) -> RangeLog,
+    do_parse!(
+        num_obs: le_u32 >>
+        observations: count!(
+            do_parse!(
+                prn: le_u16 >>
+                glofreq: le_u16 >>
+                psr: le_f64 >>
+                psr_std: le_f32 >>
+                adr: le_f64 >>
+                adr_std: le_f32 >>
+                doppler: le_f32 >>
+                cno: le_f32 >>
+                lock_time: le_f32 >>
+                tracking_status: le_u32 >>
+                (RangeObservation {
+                    prn,
+                    glofreq,
+                    psr,
+                    psr_std,
+                    adr,
+                    adr_std,
+                    doppler,
+                    cno,
+                    lock_time,
+                    tracking_status,
+                })
+            ),
+            num_obs as usize
+        ) >>
+        (RangeLog {
+            recv_status: ReceiverStatusFlags::empty(),
+            time_status: 0,
+            week: 0,
+            ms: 0,
+            observations,
+            }
+        )
+    )
+);
@@ -0,0 +1,131 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+use nom::*;
+
+/// Log message containing a single configuration command currently in effect
+/// on the receiver
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct RxConfigLog {
+    /// Current status of receiver
+    pub recv_status: ReceiverStatusFlags,
+    /// Validity of the time information
+    pub time_status: u8,
+    /// GPS reference week
+    pub week: u16,
+    /// Milliseconds into GPS reference week
+    pub ms: i32,
+    /// Message ID of the embedded configuration command
+    pub cmd_id: MessageID,
+    /// Body of the embedded configuration command
+    pub cmd: Vec<u8>,
+}
+
+impl RxConfigLog {
+    /// Convert a raw data buffer into a useable struct
+    pub fn new(
+        recv_status: ReceiverStatusFlags,
+        time_status: u8,
+        week: u16,
+        ms: i32,
+        raw: &[u8],
+    ) -> Option<Self> {
+        let cmd_hdr = Header::parse(raw)?;
+
+        let start = usize::from(cmd_hdr.hdr_len);
+        let end = start + usize::from(cmd_hdr.msg_len);
+        if raw.len() < end {
+            return None;
+        }
+
+        Some(RxConfigLog {
+            recv_status,
+            time_status,
+            week,
+            ms,
+            cmd_id: cmd_hdr.msg_id,
+            cmd: raw[start..end].to_vec(),
+        })
+    }
+
+    /// Decode the embedded configuration command as a LOG request.
+    /// Returns `None` if the embedded command is some other type of command
+    pub fn log_request(&self) -> Option<LogRequest> {
+        if self.cmd_id != MessageID::Log {
+            return None;
+        }
+
+        match parse_log_request(&self.cmd) {
+            Ok(conv) => Some(conv.1),
+            _ => None,
+        }
+    }
+}
+
+/// Log request which is currently active on the receiver
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct LogRequest {
+    /// Port the log is being output on
+    pub port: u32,
+    /// ID of the message being logged
+    pub msg_id: u16,
+    /// Message type of the log
+    pub msg_type: u8,
+    /// Log trigger. 0 = OnNew, 1 = OnChanged, 2 = OnTime, 3 = OnNext, 4 = Once, 5 = OnMark
+    pub trigger: u32,
+    /// Interval, in seconds, at which the log is being generated
+    pub period: f64,
+    /// Offset of the interval, in seconds
+    pub offset: f64,
+    /// Whether the log is protected from UNLOGALL requests
+    pub hold: bool,
+}
+
+impl LogRequest {
+    /// Receiver's name for the port the log is being output on (ex. "COM2")
+    ///
+    /// Returns "UNKNOWN" for ports which don't have a one-byte port address
+    pub fn port_name(&self) -> String {
+        if self.port > u32::from(u8::MAX) {
+            return "UNKNOWN".to_owned();
+        }
+        port_name(self.port as u8)
+    }
+}
+
+named!(parse_log_request(&[u8]) -> LogRequest,
+    do_parse!(
+        port: le_u32 >>
+        msg_id: le_u16 >>
+        msg_type: le_u8 >>
+        le_u8 >>
+        trigger: le_u32 >>
+        period: le_f64 >>
+        offset: le_f64 >>
+        hold: le_u32 >>
+        (LogRequest {
+            port,
+            msg_id,
+            msg_type,
+            trigger,
+            period,
+            offset,
+            hold: hold != 0,
+            }
+        )
+    )
+);
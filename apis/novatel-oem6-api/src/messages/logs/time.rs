@@ -0,0 +1,143 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+use nom::*;
+
+/// Log message containing clock model and UTC offset information
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct TimeLog {
+    /// Current status of receiver
+    pub recv_status: ReceiverStatusFlags,
+    /// Validity of the time information
+    pub time_status: u8,
+    /// GPS reference week
+    pub week: u16,
+    /// Milliseconds into GPS reference week
+    pub ms: i32,
+    /// Clock model status
+    pub clock_status: u32,
+    /// Receiver clock offset, in seconds, from GPS system time
+    pub offset: f64,
+    /// Receiver clock offset standard deviation (seconds)
+    pub offset_std: f64,
+    /// Offset, in seconds, of GPS system time from UTC time
+    pub utc_offset: f64,
+    /// UTC year
+    pub utc_year: u32,
+    /// UTC month (0-12). A value of 0 indicates that UTC time is unknown
+    pub utc_month: u8,
+    /// UTC day (0-31). A value of 0 indicates that UTC time is unknown
+    pub utc_day: u8,
+    /// UTC hour (0-23)
+    pub utc_hour: u8,
+    /// UTC minute (0-59)
+    pub utc_min: u8,
+    /// UTC milliseconds (0-60999)
+    pub utc_ms: u32,
+    /// UTC status
+    pub utc_status: u32,
+}
+
+impl TimeLog {
+    /// Convert a raw data buffer into a useable struct
+    pub fn new(
+        recv_status: ReceiverStatusFlags,
+        time_status: u8,
+        week: u16,
+        ms: i32,
+        raw: &[u8],
+    ) -> Option<Self> {
+        let mut log = match parse_time(raw) {
+            Ok(conv) => conv.1,
+            _ => return None,
+        };
+
+        log.recv_status = recv_status;
+        log.time_status = time_status;
+        log.week = week;
+        log.ms = ms;
+
+        Some(log)
+    }
+
+    /// Convert the body of an ASCII log message into a useable struct
+    pub fn from_ascii(
+        recv_status: ReceiverStatusFlags,
+        time_status: u8,
+        week: u16,
+        ms: i32,
+        raw: &[u8],
+    ) -> Option<Self> {
+        let fields = ascii::ascii_fields(raw)?;
+
+        if fields.len() != 11 {
+            return None;
+        }
+
+        Some(TimeLog {
+            recv_status,
+            time_status,
+            week,
+            ms,
+            clock_status: ascii::clock_status(fields[0])?,
+            offset: ascii::field(&fields, 1)?,
+            offset_std: ascii::field(&fields, 2)?,
+            utc_offset: ascii::field(&fields, 3)?,
+            utc_year: ascii::field(&fields, 4)?,
+            utc_month: ascii::field(&fields, 5)?,
+            utc_day: ascii::field(&fields, 6)?,
+            utc_hour: ascii::field(&fields, 7)?,
+            utc_min: ascii::field(&fields, 8)?,
+            utc_ms: ascii::field(&fields, 9)?,
+            utc_status: ascii::utc_status(fields[10])?,
+        })
+    }
+}
+
+named!(parse_time(&[u8]) -> TimeLog,
+    do_parse!(
+        clock_status: le_u32 >>
+        offset: le_f64 >>
+        offset_std: le_f64 >>
+        utc_offset: le_f64 >>
+        utc_year: le_u32 >>
+        utc_month: le_u8 >>
+        utc_day: le_u8 >>
+        utc_hour: le_u8 >>
+        utc_min: le_u8 >>
+        utc_ms: le_u32 >>
+        utc_status: le_u32 >>
+        (TimeLog {
+            recv_status: ReceiverStatusFlags::empty(),
+            time_status: 0,
+            week: 0,
+            ms: 0,
+            clock_status,
+            offset,
+            offset_std,
+            utc_offset,
+            utc_year,
+            utc_month,
+            utc_day,
+            utc_hour,
+            utc_min,
+            utc_ms,
+            utc_status,
+            }
+        )
+    )
+);
@@ -0,0 +1,216 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+use nom::*;
+
+/// Tracking status of a single receiver channel
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct TrackStatChannel {
+    /// Satellite PRN number (or GLONASS slot number). Zero if the channel is idle
+    pub prn: u16,
+    /// GLONASS frequency channel number + 7. Zero for other systems
+    pub glofreq: u16,
+    /// Channel tracking status word (tracking state, satellite system, signal type, etc)
+    pub tracking_status: u32,
+    /// Pseudorange (meters)
+    pub psr: f64,
+    /// Doppler frequency (Hz)
+    pub doppler: f32,
+    /// Carrier to noise density ratio (dB-Hz)
+    pub cno: f32,
+    /// Number of seconds of continuous tracking (no cycle slipping)
+    pub lock_time: f32,
+    /// Pseudorange residual from the position solution (meters)
+    pub psr_residual: f32,
+    /// Reason the observation was rejected from the solution. Zero if it was used
+    pub reject: u32,
+    /// Pseudorange weighting in the position solution
+    pub psr_weight: f32,
+}
+
+impl TrackStatChannel {
+    /// Satellite system of the signal being tracked, decoded from the tracking status word
+    ///
+    /// Returns `None` for systems not covered by [`Constellation`]
+    ///
+    /// [`Constellation`]: enum.Constellation.html
+    pub fn system(&self) -> Option<Constellation> {
+        Constellation::from_tracking_status(self.tracking_status)
+    }
+
+    /// Tracking state of the channel, decoded from the tracking status word
+    ///
+    /// Returns `None` for states not covered by [`TrackingState`]
+    ///
+    /// [`TrackingState`]: enum.TrackingState.html
+    pub fn tracking_state(&self) -> Option<TrackingState> {
+        TrackingState::from_u32(self.tracking_status & TRACKING_STATE_MASK)
+    }
+
+    /// Whether the channel is tracking a signal
+    pub fn is_tracking(&self) -> bool {
+        self.prn != 0 && self.tracking_status & TRACKING_STATE_MASK != 0
+    }
+
+    /// Whether the signal is being used in the position solution
+    pub fn used_in_solution(&self) -> bool {
+        self.is_tracking() && self.reject == REJECT_GOOD
+    }
+}
+
+/// Tracking state of a receiver channel
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TrackingState {
+    /// The channel is not tracking a signal
+    Idle = 0,
+    /// Searching for a signal across the sky
+    SkySearch = 1,
+    /// Wide frequency band pull-in
+    WideFrequencyPullIn = 2,
+    /// Narrow frequency band pull-in
+    NarrowFrequencyPullIn = 3,
+    /// Phase lock loop. The signal is fully tracked
+    PhaseLockLoop = 4,
+    /// Channel steering
+    ChannelSteering = 6,
+    /// Frequency lock loop
+    FrequencyLockLoop = 7,
+    /// Channel alignment
+    ChannelAlignment = 9,
+    /// Code search
+    CodeSearch = 10,
+    /// Aided phase lock loop
+    AidedPhaseLockLoop = 11,
+}
+
+impl TrackingState {
+    /// Convert the tracking state bits of a channel tracking status word into a
+    /// `TrackingState` value
+    ///
+    /// Returns `None` if the value does not correspond to a known state
+    pub fn from_u32(state: u32) -> Option<TrackingState> {
+        match state {
+            0 => Some(TrackingState::Idle),
+            1 => Some(TrackingState::SkySearch),
+            2 => Some(TrackingState::WideFrequencyPullIn),
+            3 => Some(TrackingState::NarrowFrequencyPullIn),
+            4 => Some(TrackingState::PhaseLockLoop),
+            6 => Some(TrackingState::ChannelSteering),
+            7 => Some(TrackingState::FrequencyLockLoop),
+            9 => Some(TrackingState::ChannelAlignment),
+            10 => Some(TrackingState::CodeSearch),
+            11 => Some(TrackingState::AidedPhaseLockLoop),
+            _ => None,
+        }
+    }
+}
+
+// Bits of the channel tracking status word containing the tracking state. Zero is idle
+const TRACKING_STATE_MASK: u32 = 0x1F;
+// Observation status indicating that the observation is good
+const REJECT_GOOD: u32 = 0;
+
+/// Log message containing the tracking status of every receiver channel
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct TrackStatLog {
+    /// Current status of receiver
+    pub recv_status: ReceiverStatusFlags,
+    /// Validity of the time information
+    pub time_status: u8,
+    /// GPS reference week
+    pub week: u16,
+    /// Milliseconds into GPS reference week
+    pub ms: i32,
+    /// Position solution status
+    pub sol_status: u32,
+    /// Position type
+    pub pos_type: u32,
+    /// Tracking elevation cutoff angle (degrees)
+    pub cutoff: f32,
+    /// Status of each receiver channel, including idle channels
+    pub channels: Vec<TrackStatChannel>,
+}
+
+impl TrackStatLog {
+    /// Convert a raw data buffer into a useable struct
+    pub fn new(
+        recv_status: ReceiverStatusFlags,
+        time_status: u8,
+        week: u16,
+        ms: i32,
+        raw: &[u8],
+    ) -> Option<Self> {
+        let mut log = match parse_track_stat(raw) {
+            Ok(conv) => conv.1,
+            _ => return None,
+        };
+
+        log.recv_status = recv_status;
+        log.time_status = time_status;
+        log.week = week;
+        log.ms = ms;
+
+        Some(log)
+    }
+}
+
+named!(parse_track_stat(&[u8]) -> TrackStatLog,
+    do_parse!(
+        sol_status: le_u32 >>
+        pos_type: le_u32 >>
+        cutoff: le_f32 >>
+        num_chans: le_u32 >>
+        channels: count!(
+            do_parse!(
+                prn: le_u16 >>
+                glofreq: le_u16 >>
+                tracking_status: le_u32 >>
+                psr: le_f64 >>
+                doppler: le_f32 >>
+                cno: le_f32 >>
+                lock_time: le_f32 >>
+                psr_residual: le_f32 >>
+                reject: le_u32 >>
+                psr_weight: le_f32 >>
+                (TrackStatChannel {
+                    prn,
+                    glofreq,
+                    tracking_status,
+                    psr,
+                    doppler,
+                    cno,
+                    lock_time,
+                    psr_residual,
+                    reject,
+                    psr_weight,
+                })
+            ),
+            num_chans as usize
+        ) >>
+        (TrackStatLog {
+            recv_status: ReceiverStatusFlags::empty(),
+            time_status: 0,
+            week: 0,
+            ms: 0,
+            sol_status,
+            pos_type,
+            cutoff,
+            channels,
+            }
+        )
+    )
+);
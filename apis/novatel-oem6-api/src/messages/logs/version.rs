@@ -16,6 +16,7 @@
 
 use super::*;
 use nom::*;
+use std::str;
 
 const COMPONENT_SIZE: usize = 108;
 
@@ -32,7 +33,8 @@ pub struct VersionLog {
     pub ms: i32,
     /// Number of components present in this structure
     pub num_components: u32,
-    /// Version information for each component present in the system
+    /// Version information for each component present in the system.
+    /// Malformed components are included, with their `valid` field set to false
     pub components: Vec<Component>,
 }
 
@@ -62,9 +64,7 @@ impl VersionLog {
         };
 
         for elem in raw_comp.chunks(COMPONENT_SIZE) {
-            if let Ok(conv) = parse_component(elem) {
-                log.components.push(conv.1);
-            }
+            log.components.push(parse_component(elem));
         }
 
         Some(log)
@@ -90,35 +90,56 @@ pub struct Component {
     pub compile_date: String,
     /// Software compilation time stamp
     pub compile_time: String,
+    /// Whether every field was read successfully. Invalid components are still reported, with
+    /// any fields which were cut off left incomplete or empty
+    pub valid: bool,
 }
 
-named!(parse_component(&[u8]) -> Component,
-    do_parse!(
-        comp_type: le_u32 >>
-        model: take!(16) >>
-        serial_num: take!(16) >>
-        hw_version: take!(16) >>
-        sw_version: take!(16) >>
-        boot_version: take!(16) >>
-        compile_date: take!(12) >>
-        compile_time: take!(12) >>
-        (Component {
-            comp_type,
-            model: String::from_utf8_lossy(model)
-                            .trim_end_matches('\u{0}').to_owned(),
-            serial_num: String::from_utf8_lossy(serial_num)
-                            .trim_end_matches('\u{0}').to_owned(),
-            hw_version: String::from_utf8_lossy(hw_version)
-                            .trim_end_matches('\u{0}').to_owned(),
-            sw_version: String::from_utf8_lossy(sw_version)
-                            .trim_end_matches('\u{0}').to_owned(),
-            boot_version: String::from_utf8_lossy(boot_version)
-                            .trim_end_matches('\u{0}').to_owned(),
-            compile_date: String::from_utf8_lossy(compile_date)
-                            .trim_end_matches('\u{0}').to_owned(),
-            compile_time: String::from_utf8_lossy(compile_time)
-                            .trim_end_matches('\u{0}').to_owned(),
-            }
-        )
-    )
-);
+// Convert a single component. Parsing is best-effort, so that one malformed component
+// doesn't prevent the rest of the log from being used: fields which are cut off are read
+// as far as possible (or left empty), strings which aren't valid UTF-8 are converted lossily,
+// and either case marks the component as invalid
+fn parse_component(raw: &[u8]) -> Component {
+    let mut rest = raw;
+    let mut take = |len: usize| {
+        let (field, remaining) = rest.split_at(len.min(rest.len()));
+        rest = remaining;
+        field
+    };
+
+    let comp_type = take(4);
+    let fields = [
+        take(16), // Model
+        take(16), // Serial number
+        take(16), // Hardware version
+        take(16), // Software version
+        take(16), // Boot version
+        take(12), // Compile date
+        take(12), // Compile time
+    ];
+
+    let valid =
+        raw.len() == COMPONENT_SIZE && fields.iter().all(|field| str::from_utf8(field).is_ok());
+
+    let [model, serial_num, hw_version, sw_version, boot_version, compile_date, compile_time] =
+        fields.map(|field| {
+            String::from_utf8_lossy(field)
+                .trim_end_matches('\u{0}')
+                .to_owned()
+        });
+
+    Component {
+        comp_type: match le_u32(comp_type) {
+            Ok(conv) => conv.1,
+            Err(_) => 0,
+        },
+        model,
+        serial_num,
+        hw_version,
+        sw_version,
+        boot_version,
+        compile_date,
+        compile_time,
+        valid,
+    }
+}
@@ -14,6 +14,7 @@
 // limitations under the License.
 //
 
+pub mod ascii;
 pub mod commands;
 pub mod logs;
 
@@ -25,22 +26,60 @@ use nom::*;
 
 pub const SYNC: [u8; 3] = [0xAA, 0x44, 0x12];
 pub const HDR_LEN: u8 = 28;
+/// Message type bits which indicate the message format
+pub const FORMAT_MASK: u8 = 0x60;
 
 /// Supported message types
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum MessageID {
     /// Log command message
     Log = 1,
+    /// Serial port configuration command message
+    Com = 4,
+    /// Reset command message
+    Reset = 18,
+    /// Save configuration command message
+    SaveConfig = 19,
+    /// Factory reset command message
+    FReset = 20,
     /// Unlog command message
     Unlog = 36,
     /// Unlog all command message
     UnlogAll = 38,
     /// Version data log
     Version = 37,
+    /// Satellite range measurement (raw observation) data log
+    Range = 43,
+    /// Elevation cutoff angle command message
+    ECutoff = 50,
+    /// Channel tracking status data log
+    TrackStat = 83,
     /// RX status event data log
     RxStatusEvent = 94,
+    /// Receiver configuration data log
+    RxConfig = 128,
+    /// Best available velocity data log
+    BestVel = 99,
+    /// Clock model and UTC offset data log
+    Time = 101,
+    /// Approximate GPS time command message
+    SetApproxTime = 102,
+    /// Pseudorange dilution of precision data log
+    PsrDop = 174,
+    /// Time of an event on the Mark 1 input
+    Mark1Time = 231,
     /// Best XYZ position/velocity data log
     BestXYZ = 241,
+    /// Approximate position command message
+    SetApproxPos = 377,
+    /// Time of an event on the Mark 2 input
+    Mark2Time = 616,
+    /// Satellite system lockout command message
+    LockoutSystem = 871,
+    /// Satellite system lockout removal command message
+    UnlockoutSystem = 908,
+    /// Receiver hardware monitor data log
+    HwMonitor = 963,
     /// Catch-all value for received messages with an unknown ID
     Unknown,
 }
@@ -55,11 +94,29 @@ impl From<u16> for MessageID {
     fn from(t: u16) -> MessageID {
         match t {
             1 => MessageID::Log,
+            4 => MessageID::Com,
+            18 => MessageID::Reset,
+            19 => MessageID::SaveConfig,
+            20 => MessageID::FReset,
             36 => MessageID::Unlog,
             37 => MessageID::Version,
             38 => MessageID::UnlogAll,
+            43 => MessageID::Range,
+            50 => MessageID::ECutoff,
+            83 => MessageID::TrackStat,
             94 => MessageID::RxStatusEvent,
+            99 => MessageID::BestVel,
+            101 => MessageID::Time,
+            102 => MessageID::SetApproxTime,
+            128 => MessageID::RxConfig,
+            174 => MessageID::PsrDop,
+            231 => MessageID::Mark1Time,
             241 => MessageID::BestXYZ,
+            377 => MessageID::SetApproxPos,
+            616 => MessageID::Mark2Time,
+            871 => MessageID::LockoutSystem,
+            908 => MessageID::UnlockoutSystem,
+            963 => MessageID::HwMonitor,
             _ => MessageID::Unknown,
         }
     }
@@ -137,10 +194,69 @@ impl Message for Header {
 /// Device communication ports
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Port {
+    /// Serial port COM1
     COM1 = 32,
+    /// Serial port COM2
+    COM2 = 64,
+    /// Serial port COM3
+    COM3 = 96,
+    /// Whichever port the command was received on
     ThisPort = 192,
 }
 
+/// Ports which logs can be requested on with [`request_log_on_port`]
+///
+/// `ThisPort` is excluded, since the receiver reports logs requested on it against
+/// the physical port the request arrived on
+///
+/// [`request_log_on_port`]: struct.OEM6.html#method.request_log_on_port
+pub const SUPPORTED_LOG_PORTS: [Port; 3] = [Port::COM1, Port::COM2, Port::COM3];
+
+// Names of the ports which a log can be output on. The lower five bits of a port address
+// select one of the port's virtual ports
+const PORT_NAMES: [(u8, &str); 6] = [
+    (0x20, "COM1"),
+    (0x40, "COM2"),
+    (0x60, "COM3"),
+    (0xA0, "SPECIAL"),
+    (0xC0, "THISPORT"),
+    (0xE0, "FILE"),
+];
+
+/// Convert the port address from a log message header into the receiver's name for the port
+/// (ex. "COM1", or "COM1_3" for virtual port 3)
+///
+/// Returns "UNKNOWN" for addresses which don't identify a single port
+pub fn port_name(addr: u8) -> String {
+    let virtual_port = addr & 0x1F;
+
+    match PORT_NAMES.iter().find(|(base, _)| *base == addr & 0xE0) {
+        Some((_, name)) if virtual_port == 0 => (*name).to_owned(),
+        Some((_, name)) => format!("{}_{}", name, virtual_port),
+        None => "UNKNOWN".to_owned(),
+    }
+}
+
+/// Convert a port name from an ASCII log header into its port address.
+/// The inverse of [`port_name`]
+///
+/// [`port_name`]: fn.port_name.html
+pub fn port_address(name: &str) -> Option<u8> {
+    let (base, virtual_port) = match name.rfind('_') {
+        Some(index) => (&name[..index], name[(index + 1)..].parse::<u8>().ok()?),
+        None => (name, 0),
+    };
+
+    if virtual_port > 0x1F {
+        return None;
+    }
+
+    PORT_NAMES
+        .iter()
+        .find(|(_, port)| *port == base)
+        .map(|(addr, _)| addr | virtual_port)
+}
+
 named!(parse_header(&[u8]) -> Header,
     do_parse!(
         sync1: le_u8 >>
@@ -260,4 +376,93 @@ impl ReceiverStatusFlags {
             .map(|x| x.to_string())
             .collect()
     }
+
+    /// Convert any error-class flags which are present into human-readable descriptions
+    /// of the problem.
+    ///
+    /// Informational flags (for example, `POSITION_FIXED` or `AUX1_STATUS_EVENT`) are
+    /// not included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use novatel_oem6_api::*;
+    ///
+    /// let flags = ReceiverStatusFlags::ANTENNA_OPEN |
+    ///     ReceiverStatusFlags::POSITION_SOLUTION_INVALID;
+    ///
+    /// assert_eq!(
+    ///     flags.error_descriptions(),
+    ///     vec!["Antenna open circuit. Check the antenna connection"]
+    /// );
+    /// ```
+    ///
+    pub fn error_descriptions(self) -> Vec<String> {
+        STATUS_ERROR_DESCRIPTIONS
+            .iter()
+            .filter(|(flag, _)| self.contains(*flag))
+            .map(|(_, desc)| (*desc).to_owned())
+            .collect()
+    }
 }
+
+// Descriptions of the receiver status flags which indicate a problem, in bit order
+const STATUS_ERROR_DESCRIPTIONS: [(ReceiverStatusFlags, &str); 15] = [
+    (
+        ReceiverStatusFlags::ERROR_PRESENT,
+        "Receiver error present. Check the RXSTATUS log for details",
+    ),
+    (
+        ReceiverStatusFlags::TEMPERATURE_WARNING,
+        "Receiver temperature is outside of its operating range",
+    ),
+    (
+        ReceiverStatusFlags::VOLTAGE_SUPPLY_WARNING,
+        "Receiver supply voltage is outside of its operating range",
+    ),
+    (
+        ReceiverStatusFlags::ANTENNA_NOT_POWERED,
+        "Antenna is not powered",
+    ),
+    (ReceiverStatusFlags::LNA_FAILURE, "Antenna LNA failure"),
+    (
+        ReceiverStatusFlags::ANTENNA_OPEN,
+        "Antenna open circuit. Check the antenna connection",
+    ),
+    (
+        ReceiverStatusFlags::ANTENNA_SHORTENED,
+        "Antenna short circuit. Check the antenna connection",
+    ),
+    (
+        ReceiverStatusFlags::CPU_OVERLOAD,
+        "Receiver CPU overloaded. Reduce the number of requested logs",
+    ),
+    (
+        ReceiverStatusFlags::COM1_BUFFER_OVERRUN,
+        "COM1 transmit buffer overrun. Log data has been lost",
+    ),
+    (
+        ReceiverStatusFlags::COM2_BUFFER_OVERRUN,
+        "COM2 transmit buffer overrun. Log data has been lost",
+    ),
+    (
+        ReceiverStatusFlags::COM3_BUFFER_OVERRUN,
+        "COM3 transmit buffer overrun. Log data has been lost",
+    ),
+    (
+        ReceiverStatusFlags::LINK_OVERRUN,
+        "Link buffer overrun. Log data has been lost",
+    ),
+    (
+        ReceiverStatusFlags::AUX_TRANSMIT_OVERRUN,
+        "Auxiliary transmit buffer overrun. Log data has been lost",
+    ),
+    (
+        ReceiverStatusFlags::AGC_OUT_OF_RANGE,
+        "RF input overload: AGC out of range. Check for interference or a faulty antenna",
+    ),
+    (
+        ReceiverStatusFlags::SOFTWARE_RESOURCE_WARNING,
+        "Receiver software resource limit reached",
+    ),
+];
@@ -22,7 +22,7 @@ use failure::Fail;
 use rust_uart::UartError;
 use rust_uart::*;
 use std::sync::mpsc::{Receiver, RecvTimeoutError, SyncSender, TrySendError};
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, MutexGuard};
 use std::time::Duration;
 
 const CHAR_SIZE: serial::CharSize = serial::Bits8;
@@ -37,12 +37,18 @@ const TIMEOUT: Duration = Duration::from_millis(60);
 /// or a log message. The function will detect the type and then forward
 /// the message to the appropriate channel receiver.
 ///
+/// If reading from the connection fails, the thread keeps retrying rather than exiting,
+/// so that a recovered link, or a connection reopened with [`OEM6::set_connection`],
+/// is picked up automatically.
+///
 /// # Arguments
 ///
 /// * rx_conn - UART connection stream to use for communication
 /// * log_send - SyncSender for forwarding received log messages
 /// * response_send - SyncSender for forwarding recieved response messages
 ///
+/// [`OEM6::set_connection`]: struct.OEM6.html#method.set_connection
+///
 /// # Examples
 ///
 /// ```
@@ -99,7 +105,10 @@ pub fn read_thread(
                         cause: ::std::io::ErrorKind::TimedOut,
                         ..
                     } => continue,
-                    _ => panic!("{}", err),
+                    _ => {
+                        wait_for_link(conn);
+                        continue;
+                    }
                 },
             };
 
@@ -144,6 +153,41 @@ pub fn read_thread(
                 continue;
             }
 
+            // If message is an ASCII log message (starts with "#")
+            if message[0] == ascii::ASCII_SYNC {
+                // Read the rest of the line byte by byte
+                let mut complete = false;
+                while let Ok(mut ascii_char) = conn.read(1, TIMEOUT) {
+                    message.append(&mut ascii_char);
+                    if message[message.len() - 1] == b'\n' {
+                        complete = true;
+                        break;
+                    }
+                }
+
+                // Verify the CRC and convert the header fields
+                let (hdr, body) = match ascii::parse_ascii_log(&message) {
+                    Some(v) if complete => v,
+                    _ => continue,
+                };
+
+                log_send
+                    .try_send((hdr, body))
+                    .or_else::<TrySendError<(Header, Vec<u8>)>, _>(|err| match err {
+                        TrySendError::Full(_) => Ok(()),
+                        TrySendError::Disconnected(_) => {
+                            if response_err {
+                                panic!("Both message receivers have disconnected")
+                            }
+                            log_err = true;
+                            Ok(())
+                        }
+                    })
+                    .unwrap();
+
+                continue;
+            }
+
             if message != SYNC {
                 continue;
             }
@@ -156,7 +200,10 @@ pub fn read_thread(
                         cause: ::std::io::ErrorKind::TimedOut,
                         ..
                     } => continue,
-                    _ => panic!("{}", err),
+                    _ => {
+                        wait_for_link(conn);
+                        continue;
+                    }
                 },
             };
             message.append(&mut hdr);
@@ -176,7 +223,10 @@ pub fn read_thread(
                         cause: ::std::io::ErrorKind::TimedOut,
                         ..
                     } => continue,
-                    _ => panic!("{}", err),
+                    _ => {
+                        wait_for_link(conn);
+                        continue;
+                    }
                 },
             };
             message.append(&mut body);
@@ -232,6 +282,12 @@ pub fn read_thread(
     }
 }
 
+// Release a failed connection so that it can be replaced, and give the link time to recover
+fn wait_for_link(conn: MutexGuard<Connection>) {
+    drop(conn);
+    ::std::thread::sleep(Duration::from_millis(250));
+}
+
 /// Structure for OEM6 device instance
 #[derive(Clone)]
 #[allow(clippy::type_complexity)]
@@ -287,22 +343,37 @@ impl OEM6 {
         response_recv: Receiver<(Header, Vec<u8>)>,
         response_abbrv_recv: Receiver<Vec<u8>>,
     ) -> OEMResult<OEM6> {
-        let settings = serial::PortSettings {
-            baud_rate,
-            char_size: CHAR_SIZE,
-            parity: PARITY,
-            stop_bits: STOP_BITS,
-            flow_control: FLOW_CONTROL,
-        };
-
-        let conn = Arc::new(Mutex::new(Connection::from_path(bus, settings, TIMEOUT)?));
+        Ok(OEM6::from_connection(
+            OEM6::open_port(bus, baud_rate)?,
+            log_recv,
+            response_recv,
+            response_abbrv_recv,
+        ))
+    }
 
-        Ok(OEM6 {
-            conn,
+    /// Constructor for an OEM6 structure which uses an already open connection,
+    /// such as a [`ReplaySource`]
+    ///
+    /// # Arguments
+    ///
+    /// * conn - Connection to the device
+    /// * log_recv - Receiver for log messages sent by read thread
+    /// * response_recv - Receiver for response messages sent by read thread
+    /// * response_abbrv_recv - Receiver for abbreviated response messages sent by read thread
+    ///
+    /// [`ReplaySource`]: struct.ReplaySource.html
+    pub fn from_connection(
+        conn: Connection,
+        log_recv: Receiver<(Header, Vec<u8>)>,
+        response_recv: Receiver<(Header, Vec<u8>)>,
+        response_abbrv_recv: Receiver<Vec<u8>>,
+    ) -> OEM6 {
+        OEM6 {
+            conn: Arc::new(Mutex::new(conn)),
             log_recv: Arc::new(Mutex::new(log_recv)),
             response_recv: Arc::new(Mutex::new(response_recv)),
             response_abbrv_recv: Arc::new(Mutex::new(response_abbrv_recv)),
-        })
+        }
     }
 
     /// Request the system version information
@@ -364,6 +435,7 @@ impl OEM6 {
         let request = LogCmd::new(
             Port::COM1,
             MessageID::Version,
+            LogFormat::Binary,
             LogTrigger::Once,
             0.0,
             0.0,
@@ -430,29 +502,23 @@ impl OEM6 {
     /// [`unlog_all`]: method.unlog_all.html
     /// [`OEMError`]: enum.OEMError.html
     pub fn request_position(&self, interval: f64, offset: f64, hold: bool) -> OEMResult<()> {
-        let trigger = if interval == 0.0 {
-            LogTrigger::Once
-        } else {
-            LogTrigger::OnTime
-        };
-
-        let request = LogCmd::new(
-            Port::COM1,
+        self.request_log(
             MessageID::BestXYZ,
-            trigger,
+            LogFormat::Binary,
             interval,
             offset,
             hold,
-        );
-
-        self.send_message(&request)
-            .and_then(|_| self.get_response(MessageID::Log))
+        )
     }
 
-    /// Request that the device send error messages as they occur
+    /// Request BestVel velocity log/s from the device
+    ///
+    /// Note: Subsequent [`get_log()`] calls are required to fetch the information
     ///
     /// # Arguments
     ///
+    /// * interval - Frequency, in seconds, at which the OEM6 should emit velocity log messages
+    /// * offset - Offset, in seconds, of the message emit frequency
     /// * hold - Whether the [`unlog_all`] command should be able to apply to this log. A value
     ///          of `true` will prevent [`unlog_all`] from applying to this log.
     ///
@@ -473,33 +539,37 @@ impl OEM6 {
     /// # let (response_send, response_recv) = sync_channel(5);
     /// # let (response_abbrv_send, response_abbrv_recv) = sync_channel(5);
     /// let oem = OEM6::new(bus, BaudRate::Baud9600, log_recv, response_recv, response_abbrv_recv).unwrap();
+    /// let rx_conn = oem.conn.clone();
+    /// thread::spawn(move || read_thread(&rx_conn, &log_send, &response_send, &response_abbrv_send));
     ///
-    /// oem.request_errors(false)?;
+    /// oem.request_velocity(1.0, 0.0, false)?;
     /// # Ok(())
     /// # }
     /// ```
     ///
+    /// [`get_log()`]: method.get_log.html
     /// [`unlog_all`]: method.unlog_all.html
     /// [`OEMError`]: enum.OEMError.html
-    pub fn request_errors(&self, hold: bool) -> OEMResult<()> {
-        let request = LogCmd::new(
-            Port::COM1,
-            MessageID::RxStatusEvent,
-            LogTrigger::OnChanged,
-            0.0,
-            0.0,
+    pub fn request_velocity(&self, interval: f64, offset: f64, hold: bool) -> OEMResult<()> {
+        self.request_log(
+            MessageID::BestVel,
+            LogFormat::Binary,
+            interval,
+            offset,
             hold,
-        );
-
-        self.send_message(&request)
-            .and_then(|_| self.get_response(MessageID::Log))
+        )
     }
 
-    /// Request that automatic logging for a particular log type be stopped
+    /// Request Time clock model log/s from the device
+    ///
+    /// Note: Subsequent [`get_log()`] calls are required to fetch the information
     ///
     /// # Arguments
     ///
-    /// * id - Message ID which should no longer be logged
+    /// * interval - Frequency, in seconds, at which the OEM6 should emit time log messages
+    /// * offset - Offset, in seconds, of the message emit frequency
+    /// * hold - Whether the [`unlog_all`] command should be able to apply to this log. A value
+    ///          of `true` will prevent [`unlog_all`] from applying to this log.
     ///
     /// # Errors
     ///
@@ -518,26 +588,31 @@ impl OEM6 {
     /// # let (response_send, response_recv) = sync_channel(5);
     /// # let (response_abbrv_send, response_abbrv_recv) = sync_channel(5);
     /// let oem = OEM6::new(bus, BaudRate::Baud9600, log_recv, response_recv, response_abbrv_recv).unwrap();
+    /// let rx_conn = oem.conn.clone();
+    /// thread::spawn(move || read_thread(&rx_conn, &log_send, &response_send, &response_abbrv_send));
     ///
-    /// oem.request_unlog(MessageID::BestXYZ)?;
+    /// oem.request_time(1.0, 0.0, false)?;
     /// # Ok(())
     /// # }
     /// ```
     ///
+    /// [`get_log()`]: method.get_log.html
+    /// [`unlog_all`]: method.unlog_all.html
     /// [`OEMError`]: enum.OEMError.html
-    pub fn request_unlog(&self, id: MessageID) -> OEMResult<()> {
-        let request = UnlogCmd::new(Port::COM1, id);
-
-        self.send_message(&request)
-            .and_then(|_| self.get_response(MessageID::Unlog))
+    pub fn request_time(&self, interval: f64, offset: f64, hold: bool) -> OEMResult<()> {
+        self.request_log(MessageID::Time, LogFormat::Binary, interval, offset, hold)
     }
 
-    /// Request that all automatic logging be stopped
+    /// Request PsrDop dilution of precision log/s from the device
+    ///
+    /// Note: Subsequent [`get_log()`] calls are required to fetch the information
     ///
     /// # Arguments
     ///
-    /// * clear_holds - Specifies whether log messages which were set with the `hold` option
-    ///                 should also be unlogged
+    /// * interval - Frequency, in seconds, at which the OEM6 should emit DOP log messages
+    /// * offset - Offset, in seconds, of the message emit frequency
+    /// * hold - Whether the [`unlog_all`] command should be able to apply to this log. A value
+    ///          of `true` will prevent [`unlog_all`] from applying to this log.
     ///
     /// # Errors
     ///
@@ -556,16 +631,39 @@ impl OEM6 {
     /// # let (response_send, response_recv) = sync_channel(5);
     /// # let (response_abbrv_send, response_abbrv_recv) = sync_channel(5);
     /// let oem = OEM6::new(bus, BaudRate::Baud9600, log_recv, response_recv, response_abbrv_recv).unwrap();
+    /// let rx_conn = oem.conn.clone();
+    /// thread::spawn(move || read_thread(&rx_conn, &log_send, &response_send, &response_abbrv_send));
     ///
-    /// // Request position information every second and prevent unlogging with unlog_all()
-    /// oem.request_position(1.0, 0.0, true)?;
-    ///
-    /// // Unlog everything. Previous request for position information will remain intact
-    /// oem.request_unlog_all(false)?;
+    /// oem.request_dop(1.0, 0.0, false)?;
     /// # Ok(())
     /// # }
     /// ```
     ///
+    /// [`get_log()`]: method.get_log.html
+    /// [`unlog_all`]: method.unlog_all.html
+    /// [`OEMError`]: enum.OEMError.html
+    pub fn request_dop(&self, interval: f64, offset: f64, hold: bool) -> OEMResult<()> {
+        self.request_log(MessageID::PsrDop, LogFormat::Binary, interval, offset, hold)
+    }
+
+    /// Request HwMonitor hardware monitor log/s (temperature, antenna current, voltages)
+    /// from the device
+    ///
+    /// Note: Subsequent [`get_log()`] calls are required to fetch the information
+    ///
+    /// # Arguments
+    ///
+    /// * interval - Frequency, in seconds, at which the OEM6 should emit hardware monitor log messages
+    /// * offset - Offset, in seconds, of the message emit frequency
+    /// * hold - Whether the [`unlog_all`] command should be able to apply to this log. A value
+    ///          of `true` will prevent [`unlog_all`] from applying to this log.
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`OEMError`] variant will be returned.
+    ///
+    /// # Examples
+    ///
     /// ```
     /// # use novatel_oem6_api::*;
     /// # use std::thread;
@@ -577,32 +675,40 @@ impl OEM6 {
     /// # let (response_send, response_recv) = sync_channel(5);
     /// # let (response_abbrv_send, response_abbrv_recv) = sync_channel(5);
     /// let oem = OEM6::new(bus, BaudRate::Baud9600, log_recv, response_recv, response_abbrv_recv).unwrap();
+    /// let rx_conn = oem.conn.clone();
+    /// thread::spawn(move || read_thread(&rx_conn, &log_send, &response_send, &response_abbrv_send));
     ///
-    /// // Request position information every second and prevent unlogging with unlog_all()
-    /// oem.request_position(1.0, 0.0, true)?;
-    ///
-    /// // Unlog everything. This will override the position request's `hold=true` value and
-    /// // also unlog that request
-    /// oem.request_unlog_all(true)?;
+    /// oem.request_hw_monitor(1.0, 0.0, false)?;
     /// # Ok(())
     /// # }
     /// ```
     ///
+    /// [`get_log()`]: method.get_log.html
+    /// [`unlog_all`]: method.unlog_all.html
     /// [`OEMError`]: enum.OEMError.html
-    pub fn request_unlog_all(&self, clear_holds: bool) -> OEMResult<()> {
-        let request = UnlogAllCmd::new(Port::COM1 as u32, clear_holds);
-
-        self.send_message(&request)
-            .and_then(|_| self.get_response(MessageID::UnlogAll))
+    pub fn request_hw_monitor(&self, interval: f64, offset: f64, hold: bool) -> OEMResult<()> {
+        self.request_log(
+            MessageID::HwMonitor,
+            LogFormat::Binary,
+            interval,
+            offset,
+            hold,
+        )
     }
 
-    /// Directly send a message without formatting or checksum calculation
+    /// Request Range raw observation log/s (pseudorange, carrier phase, Doppler) from the device
     ///
-    /// Note: The message will not be verified by checking for a command response
+    /// The log is always requested in binary format, since it contains an entry for every
+    /// tracked signal and can be large.
+    ///
+    /// Note: Subsequent [`get_log()`] calls are required to fetch the information
     ///
     /// # Arguments
     ///
-    /// * msg - Message to send
+    /// * interval - Frequency, in seconds, at which the OEM6 should emit range log messages
+    /// * offset - Offset, in seconds, of the message emit frequency
+    /// * hold - Whether the [`unlog_all`] command should be able to apply to this log. A value
+    ///          of `true` will prevent [`unlog_all`] from applying to this log.
     ///
     /// # Errors
     ///
@@ -621,74 +727,1078 @@ impl OEM6 {
     /// # let (response_send, response_recv) = sync_channel(5);
     /// # let (response_abbrv_send, response_abbrv_recv) = sync_channel(5);
     /// let oem = OEM6::new(bus, BaudRate::Baud9600, log_recv, response_recv, response_abbrv_recv).unwrap();
+    /// let rx_conn = oem.conn.clone();
+    /// thread::spawn(move || read_thread(&rx_conn, &log_send, &response_send, &response_abbrv_send));
     ///
-    /// let packet: [u8; 6] = [0, 1, 2, 3, 4, 5];
-    /// oem.passthrough(&packet)?;
+    /// oem.request_range(1.0, 0.0, false)?;
     /// # Ok(())
     /// # }
     /// ```
     ///
-    ///
+    /// [`get_log()`]: method.get_log.html
+    /// [`unlog_all`]: method.unlog_all.html
     /// [`OEMError`]: enum.OEMError.html
-    pub fn passthrough(&self, msg: &[u8]) -> OEMResult<()> {
-        // If the mutex has been poisoned by the read thread,
-        // go ahead and attempt the write anyways, to try to
-        // preserve functionality, but inform the caller afterwards
-        match self.conn.lock() {
-            Ok(conn) => conn.write(msg).map_err(|err| err.into()),
-            Err(conn) => conn
-                .into_inner()
-                .write(msg)
-                .map_err(|err| err.into())
-                .and(Err(OEMError::ThreadCommError)),
-        }
-    }
-
-    #[cfg(not(feature = "nos3"))]
-    fn send_message<T: Message>(&self, msg: &T) -> OEMResult<()> {
-        let mut raw = msg.serialize();
-
-        // Get the calculated CRC
-        let crc = calc_crc(&raw);
-        raw.write_u32::<LittleEndian>(crc).unwrap();
-
-        // If the mutex has been poisoned by the read thread,
-        // go ahead and attempt the write anyways, to try to
-        // preserve functionality, but inform the caller afterwards
-        match self.conn.lock() {
-            Ok(conn) => conn.write(raw.as_slice()).map_err(|err| err.into()),
-            Err(conn) => conn
-                .into_inner()
-                .write(raw.as_slice())
-                .map_err(|err| err.into())
-                .and(Err(OEMError::ThreadCommError)),
-        }
+    pub fn request_range(&self, interval: f64, offset: f64, hold: bool) -> OEMResult<()> {
+        self.request_log(MessageID::Range, LogFormat::Binary, interval, offset, hold)
     }
 
-    #[cfg(feature = "nos3")]
-    fn send_message<T: Message>(&self, msg: &T) -> OEMResult<()> {
-        let raw = msg.serialize();
-
-        // If the mutex has been poisoned by the read thread,
-        // go ahead and attempt the write anyways, to try to
-        // preserve functionality, but inform the caller afterwards
-        match self.conn.lock() {
-            Ok(conn) => conn.write(raw.as_slice()).map_err(|err| err.into()),
-            Err(conn) => conn
-                .into_inner()
-                .write(raw.as_slice())
-                .map_err(|err| err.into())
-                .and(Err(OEMError::ThreadCommError)),
-        }
+    /// Request TrackStat channel tracking status log/s from the device
+    ///
+    /// The log is always requested in binary format, since it contains an entry for every
+    /// receiver channel and can be large.
+    ///
+    /// Note: Subsequent [`get_log()`] calls are required to fetch the information
+    ///
+    /// # Arguments
+    ///
+    /// * interval - Frequency, in seconds, at which the OEM6 should emit trackstat log messages
+    /// * offset - Offset, in seconds, of the message emit frequency
+    /// * hold - Whether the [`unlog_all`] command should be able to apply to this log. A value
+    ///          of `true` will prevent [`unlog_all`] from applying to this log.
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`OEMError`] variant will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use novatel_oem6_api::*;
+    /// # use std::thread;
+    /// # use std::sync::mpsc::sync_channel;
+    ///
+    /// # fn func() -> OEMResult<()> {
+    /// # let bus = "/dev/ttyS5";
+    /// # let (log_send, log_recv) = sync_channel(5);
+    /// # let (response_send, response_recv) = sync_channel(5);
+    /// # let (response_abbrv_send, response_abbrv_recv) = sync_channel(5);
+    /// let oem = OEM6::new(bus, BaudRate::Baud9600, log_recv, response_recv, response_abbrv_recv).unwrap();
+    /// let rx_conn = oem.conn.clone();
+    /// thread::spawn(move || read_thread(&rx_conn, &log_send, &response_send, &response_abbrv_send));
+    ///
+    /// oem.request_track_stat(1.0, 0.0, false)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`get_log()`]: method.get_log.html
+    /// [`unlog_all`]: method.unlog_all.html
+    /// [`OEMError`]: enum.OEMError.html
+    pub fn request_track_stat(&self, interval: f64, offset: f64, hold: bool) -> OEMResult<()> {
+        self.request_log(
+            MessageID::TrackStat,
+            LogFormat::Binary,
+            interval,
+            offset,
+            hold,
+        )
     }
 
-    #[cfg(not(feature = "nos3"))]
-    fn get_response(&self, id: MessageID) -> OEMResult<()> {
-        let (hdr, body) = self
-            .response_recv
-            .lock()
-            .map_err(|_| OEMError::MutexError)?
-            .recv_timeout(Duration::from_millis(500))
+    /// Request log/s of the given type and format from the device
+    ///
+    /// Note: Subsequent [`get_log()`] calls are required to fetch the information.
+    /// ASCII logs are supported for the BestVel, BestXYZ, Time and PsrDop messages
+    ///
+    /// # Arguments
+    ///
+    /// * id - Log message which should be generated
+    /// * format - Format in which the OEM6 should emit the log messages
+    /// * interval - Frequency, in seconds, at which the OEM6 should emit log messages.
+    ///              A value of `0.0` will request a single log message
+    /// * offset - Offset, in seconds, of the message emit frequency
+    /// * hold - Whether the [`unlog_all`] command should be able to apply to this log. A value
+    ///          of `true` will prevent [`unlog_all`] from applying to this log.
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`OEMError`] variant will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use novatel_oem6_api::*;
+    /// # use std::thread;
+    /// # use std::sync::mpsc::sync_channel;
+    ///
+    /// # fn func() -> OEMResult<()> {
+    /// # let bus = "/dev/ttyS5";
+    /// # let (log_send, log_recv) = sync_channel(5);
+    /// # let (response_send, response_recv) = sync_channel(5);
+    /// # let (response_abbrv_send, response_abbrv_recv) = sync_channel(5);
+    ///
+    /// let oem = OEM6::new(bus, BaudRate::Baud9600, log_recv, response_recv, response_abbrv_recv).unwrap();
+    /// let rx_conn = oem.conn.clone();
+    /// thread::spawn(move || read_thread(&rx_conn, &log_send, &response_send, &response_abbrv_send));
+    ///
+    /// oem.request_log(MessageID::Time, LogFormat::Ascii, 1.0, 0.0, false)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`get_log()`]: method.get_log.html
+    /// [`unlog_all`]: method.unlog_all.html
+    /// [`OEMError`]: enum.OEMError.html
+    pub fn request_log(
+        &self,
+        id: MessageID,
+        format: LogFormat,
+        interval: f64,
+        offset: f64,
+        hold: bool,
+    ) -> OEMResult<()> {
+        let trigger = if interval == 0.0 {
+            LogTrigger::Once
+        } else {
+            LogTrigger::OnTime
+        };
+
+        let request = LogCmd::new(Port::COM1, id, format, trigger, interval, offset, hold);
+
+        self.send_message(&request)
+            .and_then(|_| self.get_response(MessageID::Log))
+    }
+
+    /// Request log/s of the given type and format be output on a specific port
+    ///
+    /// Logs requested with this function are output in addition to any logs already being
+    /// generated on other ports. The RXSTATUSEVENT and MARK1TIME/MARK2TIME logs are requested
+    /// as event logs, in which case `interval` and `offset` are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * port - Port the OEM6 should emit the log messages on. Should be one of
+    ///          [`SUPPORTED_LOG_PORTS`]
+    /// * id - Log message which should be generated
+    /// * format - Format in which the OEM6 should emit the log messages
+    /// * interval - Frequency, in seconds, at which the OEM6 should emit log messages.
+    ///              A value of `0.0` will request a single log message
+    /// * offset - Offset, in seconds, of the message emit frequency
+    /// * hold - Whether the [`unlog_all`] command should be able to apply to this log. A value
+    ///          of `true` will prevent [`unlog_all`] from applying to this log.
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`OEMError`] variant will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use novatel_oem6_api::*;
+    /// # use std::thread;
+    /// # use std::sync::mpsc::sync_channel;
+    ///
+    /// # fn func() -> OEMResult<()> {
+    /// # let bus = "/dev/ttyS5";
+    /// # let (log_send, log_recv) = sync_channel(5);
+    /// # let (response_send, response_recv) = sync_channel(5);
+    /// # let (response_abbrv_send, response_abbrv_recv) = sync_channel(5);
+    /// let oem = OEM6::new(bus, BaudRate::Baud9600, log_recv, response_recv, response_abbrv_recv).unwrap();
+    ///
+    /// oem.request_log_on_port(Port::COM2, MessageID::BestXYZ, LogFormat::Binary, 1.0, 0.0, false)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`SUPPORTED_LOG_PORTS`]: constant.SUPPORTED_LOG_PORTS.html
+    /// [`unlog_all`]: method.unlog_all.html
+    /// [`OEMError`]: enum.OEMError.html
+    pub fn request_log_on_port(
+        &self,
+        port: Port,
+        id: MessageID,
+        format: LogFormat,
+        interval: f64,
+        offset: f64,
+        hold: bool,
+    ) -> OEMResult<()> {
+        let (trigger, interval, offset) = match id {
+            MessageID::RxStatusEvent => (LogTrigger::OnChanged, 0.0, 0.0),
+            MessageID::Mark1Time | MessageID::Mark2Time => (LogTrigger::OnNew, 0.0, 0.0),
+            _ if interval == 0.0 => (LogTrigger::Once, interval, offset),
+            _ => (LogTrigger::OnTime, interval, offset),
+        };
+
+        let request = LogCmd::new(port, id, format, trigger, interval, offset, hold);
+
+        self.send_message(&request)
+            .and_then(|_| self.get_response(MessageID::Log))
+    }
+
+    /// Request the list of configuration commands currently in effect on the device
+    ///
+    /// Note: Subsequent [`get_log()`] calls are required to fetch the information.
+    /// The device will send one RXCONFIG log message for each configuration command
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`OEMError`] variant will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use novatel_oem6_api::*;
+    /// # use std::thread;
+    /// # use std::sync::mpsc::sync_channel;
+    ///
+    /// # fn func() -> OEMResult<()> {
+    /// # let bus = "/dev/ttyS5";
+    /// # let (log_send, log_recv) = sync_channel(5);
+    /// # let (response_send, response_recv) = sync_channel(5);
+    /// # let (response_abbrv_send, response_abbrv_recv) = sync_channel(5);
+    /// let oem = OEM6::new(bus, BaudRate::Baud9600, log_recv, response_recv, response_abbrv_recv).unwrap();
+    /// let rx_conn = oem.conn.clone();
+    /// thread::spawn(move || read_thread(&rx_conn, &log_send, &response_send, &response_abbrv_send));
+    ///
+    /// oem.request_rxconfig()?;
+    ///
+    /// while let Ok(Log::RxConfig(log)) = oem.get_log() {
+    ///     if let Some(request) = log.log_request() {
+    ///         println!("Message {} every {} sec", request.msg_id, request.period);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`get_log()`]: method.get_log.html
+    /// [`OEMError`]: enum.OEMError.html
+    pub fn request_rxconfig(&self) -> OEMResult<()> {
+        let request = LogCmd::new(
+            Port::COM1,
+            MessageID::RxConfig,
+            LogFormat::Binary,
+            LogTrigger::Once,
+            0.0,
+            0.0,
+            false,
+        );
+
+        self.send_message(&request)
+            .and_then(|_| self.get_response(MessageID::Log))
+    }
+
+    /// Request that the device send error messages as they occur
+    ///
+    /// # Arguments
+    ///
+    /// * hold - Whether the [`unlog_all`] command should be able to apply to this log. A value
+    ///          of `true` will prevent [`unlog_all`] from applying to this log.
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`OEMError`] variant will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use novatel_oem6_api::*;
+    /// # use std::thread;
+    /// # use std::sync::mpsc::sync_channel;
+    ///
+    /// # fn func() -> OEMResult<()> {
+    /// # let bus = "/dev/ttyS5";
+    /// # let (log_send, log_recv) = sync_channel(5);
+    /// # let (response_send, response_recv) = sync_channel(5);
+    /// # let (response_abbrv_send, response_abbrv_recv) = sync_channel(5);
+    /// let oem = OEM6::new(bus, BaudRate::Baud9600, log_recv, response_recv, response_abbrv_recv).unwrap();
+    ///
+    /// oem.request_errors(false)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`unlog_all`]: method.unlog_all.html
+    /// [`OEMError`]: enum.OEMError.html
+    pub fn request_errors(&self, hold: bool) -> OEMResult<()> {
+        let request = LogCmd::new(
+            Port::COM1,
+            MessageID::RxStatusEvent,
+            LogFormat::Binary,
+            LogTrigger::OnChanged,
+            0.0,
+            0.0,
+            hold,
+        );
+
+        self.send_message(&request)
+            .and_then(|_| self.get_response(MessageID::Log))
+    }
+
+    /// Request that the device send MARK1TIME and MARK2TIME logs as external events occur
+    ///
+    /// Note: Subsequent [`get_log()`] calls are required to fetch the information.
+    /// Events from both mark inputs are returned as [`Log::MarkTime`] entries, with the
+    /// originating input recorded in the `event_id` field
+    ///
+    /// # Arguments
+    ///
+    /// * hold - Whether the [`unlog_all`] command should be able to apply to these logs. A value
+    ///          of `true` will prevent [`unlog_all`] from applying to these logs.
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`OEMError`] variant will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use novatel_oem6_api::*;
+    /// # use std::thread;
+    /// # use std::sync::mpsc::sync_channel;
+    ///
+    /// # fn func() -> OEMResult<()> {
+    /// # let bus = "/dev/ttyS5";
+    /// # let (log_send, log_recv) = sync_channel(5);
+    /// # let (response_send, response_recv) = sync_channel(5);
+    /// # let (response_abbrv_send, response_abbrv_recv) = sync_channel(5);
+    /// let oem = OEM6::new(bus, BaudRate::Baud9600, log_recv, response_recv, response_abbrv_recv).unwrap();
+    ///
+    /// oem.request_mark_time(false)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`get_log()`]: method.get_log.html
+    /// [`Log::MarkTime`]: enum.Log.html#variant.MarkTime
+    /// [`unlog_all`]: method.unlog_all.html
+    /// [`OEMError`]: enum.OEMError.html
+    pub fn request_mark_time(&self, hold: bool) -> OEMResult<()> {
+        for id in &[MessageID::Mark1Time, MessageID::Mark2Time] {
+            let request = LogCmd::new(
+                Port::COM1,
+                *id,
+                LogFormat::Binary,
+                LogTrigger::OnNew,
+                0.0,
+                0.0,
+                hold,
+            );
+
+            self.send_message(&request)
+                .and_then(|_| self.get_response(MessageID::Log))?;
+        }
+
+        Ok(())
+    }
+
+    /// Request that automatic logging for a particular log type be stopped
+    ///
+    /// # Arguments
+    ///
+    /// * id - Message ID which should no longer be logged
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`OEMError`] variant will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use novatel_oem6_api::*;
+    /// # use std::thread;
+    /// # use std::sync::mpsc::sync_channel;
+    ///
+    /// # fn func() -> OEMResult<()> {
+    /// # let bus = "/dev/ttyS5";
+    /// # let (log_send, log_recv) = sync_channel(5);
+    /// # let (response_send, response_recv) = sync_channel(5);
+    /// # let (response_abbrv_send, response_abbrv_recv) = sync_channel(5);
+    /// let oem = OEM6::new(bus, BaudRate::Baud9600, log_recv, response_recv, response_abbrv_recv).unwrap();
+    ///
+    /// oem.request_unlog(MessageID::BestXYZ)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`OEMError`]: enum.OEMError.html
+    pub fn request_unlog(&self, id: MessageID) -> OEMResult<()> {
+        let request = UnlogCmd::new(Port::COM1, id);
+
+        self.send_message(&request)
+            .and_then(|_| self.get_response(MessageID::Unlog))
+    }
+
+    /// Request that automatic logging for a particular log type be stopped on a specific port
+    ///
+    /// Output of the same log on other ports is unaffected
+    ///
+    /// # Arguments
+    ///
+    /// * port - Port the OEM6 should stop emitting the log messages on. Should be one of
+    ///          [`SUPPORTED_LOG_PORTS`]
+    /// * id - Message ID which should no longer be logged
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`OEMError`] variant will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use novatel_oem6_api::*;
+    /// # use std::thread;
+    /// # use std::sync::mpsc::sync_channel;
+    ///
+    /// # fn func() -> OEMResult<()> {
+    /// # let bus = "/dev/ttyS5";
+    /// # let (log_send, log_recv) = sync_channel(5);
+    /// # let (response_send, response_recv) = sync_channel(5);
+    /// # let (response_abbrv_send, response_abbrv_recv) = sync_channel(5);
+    /// let oem = OEM6::new(bus, BaudRate::Baud9600, log_recv, response_recv, response_abbrv_recv).unwrap();
+    ///
+    /// oem.request_unlog_on_port(Port::COM2, MessageID::BestXYZ)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`SUPPORTED_LOG_PORTS`]: constant.SUPPORTED_LOG_PORTS.html
+    /// [`OEMError`]: enum.OEMError.html
+    pub fn request_unlog_on_port(&self, port: Port, id: MessageID) -> OEMResult<()> {
+        let request = UnlogCmd::new(port, id);
+
+        self.send_message(&request)
+            .and_then(|_| self.get_response(MessageID::Unlog))
+    }
+
+    /// Request that all automatic logging be stopped
+    ///
+    /// # Arguments
+    ///
+    /// * clear_holds - Specifies whether log messages which were set with the `hold` option
+    ///                 should also be unlogged
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`OEMError`] variant will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use novatel_oem6_api::*;
+    /// # use std::thread;
+    /// # use std::sync::mpsc::sync_channel;
+    ///
+    /// # fn func() -> OEMResult<()> {
+    /// # let bus = "/dev/ttyS5";
+    /// # let (log_send, log_recv) = sync_channel(5);
+    /// # let (response_send, response_recv) = sync_channel(5);
+    /// # let (response_abbrv_send, response_abbrv_recv) = sync_channel(5);
+    /// let oem = OEM6::new(bus, BaudRate::Baud9600, log_recv, response_recv, response_abbrv_recv).unwrap();
+    ///
+    /// // Request position information every second and prevent unlogging with unlog_all()
+    /// oem.request_position(1.0, 0.0, true)?;
+    ///
+    /// // Unlog everything. Previous request for position information will remain intact
+    /// oem.request_unlog_all(false)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ```
+    /// # use novatel_oem6_api::*;
+    /// # use std::thread;
+    /// # use std::sync::mpsc::sync_channel;
+    ///
+    /// # fn func() -> OEMResult<()> {
+    /// # let bus = "/dev/ttyS5";
+    /// # let (log_send, log_recv) = sync_channel(5);
+    /// # let (response_send, response_recv) = sync_channel(5);
+    /// # let (response_abbrv_send, response_abbrv_recv) = sync_channel(5);
+    /// let oem = OEM6::new(bus, BaudRate::Baud9600, log_recv, response_recv, response_abbrv_recv).unwrap();
+    ///
+    /// // Request position information every second and prevent unlogging with unlog_all()
+    /// oem.request_position(1.0, 0.0, true)?;
+    ///
+    /// // Unlog everything. This will override the position request's `hold=true` value and
+    /// // also unlog that request
+    /// oem.request_unlog_all(true)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`OEMError`]: enum.OEMError.html
+    pub fn request_unlog_all(&self, clear_holds: bool) -> OEMResult<()> {
+        let request = UnlogAllCmd::new(Port::COM1 as u32, clear_holds);
+
+        self.send_message(&request)
+            .and_then(|_| self.get_response(MessageID::UnlogAll))
+    }
+
+    /// Request that the device perform a hardware reset
+    ///
+    /// Saved configuration and satellite data are retained through the reset
+    ///
+    /// # Arguments
+    ///
+    /// * delay - Number of seconds the device should wait before resetting
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`OEMError`] variant will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use novatel_oem6_api::*;
+    /// # use std::thread;
+    /// # use std::sync::mpsc::sync_channel;
+    ///
+    /// # fn func() -> OEMResult<()> {
+    /// # let bus = "/dev/ttyS5";
+    /// # let (log_send, log_recv) = sync_channel(5);
+    /// # let (response_send, response_recv) = sync_channel(5);
+    /// # let (response_abbrv_send, response_abbrv_recv) = sync_channel(5);
+    /// let oem = OEM6::new(bus, BaudRate::Baud9600, log_recv, response_recv, response_abbrv_recv).unwrap();
+    ///
+    /// oem.request_reset(0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`OEMError`]: enum.OEMError.html
+    pub fn request_reset(&self, delay: u32) -> OEMResult<()> {
+        let request = ResetCmd::new(delay);
+
+        self.send_message(&request)
+            .and_then(|_| self.get_response(MessageID::Reset))
+    }
+
+    /// Request that the device clear data from its non-volatile memory and then reset
+    ///
+    /// Note: [`FResetTarget::Standard`] clears all saved configuration and returns the
+    /// device to its factory default settings
+    ///
+    /// # Arguments
+    ///
+    /// * target - The data which should be cleared
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`OEMError`] variant will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use novatel_oem6_api::*;
+    /// # use std::thread;
+    /// # use std::sync::mpsc::sync_channel;
+    ///
+    /// # fn func() -> OEMResult<()> {
+    /// # let bus = "/dev/ttyS5";
+    /// # let (log_send, log_recv) = sync_channel(5);
+    /// # let (response_send, response_recv) = sync_channel(5);
+    /// # let (response_abbrv_send, response_abbrv_recv) = sync_channel(5);
+    /// let oem = OEM6::new(bus, BaudRate::Baud9600, log_recv, response_recv, response_abbrv_recv).unwrap();
+    ///
+    /// // Clear the stored ephemeris data, forcing it to be re-collected
+    /// oem.request_freset(FResetTarget::GpsEphemeris)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`FResetTarget::Standard`]: enum.FResetTarget.html#variant.Standard
+    /// [`OEMError`]: enum.OEMError.html
+    pub fn request_freset(&self, target: FResetTarget) -> OEMResult<()> {
+        let request = FResetCmd::new(target);
+
+        self.send_message(&request)
+            .and_then(|_| self.get_response(MessageID::FReset))
+    }
+
+    /// Request that the device save its current configuration, including all requested logs,
+    /// to non-volatile memory, so that it is restored after the device is reset or power cycled
+    ///
+    /// Note: The non-volatile memory supports a limited number of write cycles, so this should
+    /// only be called after the configuration has actually changed. A factory reset
+    /// ([`FResetTarget::Standard`]) will clear the saved configuration
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`OEMError`] variant will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use novatel_oem6_api::*;
+    /// # use std::thread;
+    /// # use std::sync::mpsc::sync_channel;
+    ///
+    /// # fn func() -> OEMResult<()> {
+    /// # let bus = "/dev/ttyS5";
+    /// # let (log_send, log_recv) = sync_channel(5);
+    /// # let (response_send, response_recv) = sync_channel(5);
+    /// # let (response_abbrv_send, response_abbrv_recv) = sync_channel(5);
+    /// let oem = OEM6::new(bus, BaudRate::Baud9600, log_recv, response_recv, response_abbrv_recv).unwrap();
+    ///
+    /// oem.request_position(1.0, 0.0, false)?;
+    /// oem.request_save_config()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`FResetTarget::Standard`]: enum.FResetTarget.html#variant.Standard
+    /// [`OEMError`]: enum.OEMError.html
+    pub fn request_save_config(&self) -> OEMResult<()> {
+        let request = SaveConfigCmd::new();
+
+        self.send_message(&request)
+            .and_then(|_| self.get_response(MessageID::SaveConfig))
+    }
+
+    /// Set the elevation cutoff angle. Satellites below this angle are not tracked
+    ///
+    /// # Arguments
+    ///
+    /// * angle - Elevation cutoff angle, in degrees, relative to the antenna's horizon
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`OEMError`] variant will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use novatel_oem6_api::*;
+    /// # use std::thread;
+    /// # use std::sync::mpsc::sync_channel;
+    ///
+    /// # fn func() -> OEMResult<()> {
+    /// # let bus = "/dev/ttyS5";
+    /// # let (log_send, log_recv) = sync_channel(5);
+    /// # let (response_send, response_recv) = sync_channel(5);
+    /// # let (response_abbrv_send, response_abbrv_recv) = sync_channel(5);
+    /// let oem = OEM6::new(bus, BaudRate::Baud9600, log_recv, response_recv, response_abbrv_recv).unwrap();
+    ///
+    /// oem.request_elevation_cutoff(10.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`OEMError`]: enum.OEMError.html
+    pub fn request_elevation_cutoff(&self, angle: f32) -> OEMResult<()> {
+        let request = ECutoffCmd::new(angle);
+
+        self.send_message(&request)
+            .and_then(|_| self.get_response(MessageID::ECutoff))
+    }
+
+    /// Request that a satellite system be excluded from tracking and from the solution
+    ///
+    /// # Arguments
+    ///
+    /// * system - Satellite system to lock out
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`OEMError`] variant will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use novatel_oem6_api::*;
+    /// # use std::thread;
+    /// # use std::sync::mpsc::sync_channel;
+    ///
+    /// # fn func() -> OEMResult<()> {
+    /// # let bus = "/dev/ttyS5";
+    /// # let (log_send, log_recv) = sync_channel(5);
+    /// # let (response_send, response_recv) = sync_channel(5);
+    /// # let (response_abbrv_send, response_abbrv_recv) = sync_channel(5);
+    /// let oem = OEM6::new(bus, BaudRate::Baud9600, log_recv, response_recv, response_abbrv_recv).unwrap();
+    ///
+    /// oem.request_lockout_system(Constellation::Glonass)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`OEMError`]: enum.OEMError.html
+    pub fn request_lockout_system(&self, system: Constellation) -> OEMResult<()> {
+        let request = LockoutSystemCmd::new(system);
+
+        self.send_message(&request)
+            .and_then(|_| self.get_response(MessageID::LockoutSystem))
+    }
+
+    /// Request that a previously locked out satellite system be tracked and used again
+    ///
+    /// # Arguments
+    ///
+    /// * system - Satellite system to restore
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`OEMError`] variant will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use novatel_oem6_api::*;
+    /// # use std::thread;
+    /// # use std::sync::mpsc::sync_channel;
+    ///
+    /// # fn func() -> OEMResult<()> {
+    /// # let bus = "/dev/ttyS5";
+    /// # let (log_send, log_recv) = sync_channel(5);
+    /// # let (response_send, response_recv) = sync_channel(5);
+    /// # let (response_abbrv_send, response_abbrv_recv) = sync_channel(5);
+    /// let oem = OEM6::new(bus, BaudRate::Baud9600, log_recv, response_recv, response_abbrv_recv).unwrap();
+    ///
+    /// oem.request_unlockout_system(Constellation::Glonass)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`OEMError`]: enum.OEMError.html
+    pub fn request_unlockout_system(&self, system: Constellation) -> OEMResult<()> {
+        let request = UnlockoutSystemCmd::new(system);
+
+        self.send_message(&request)
+            .and_then(|_| self.get_response(MessageID::UnlockoutSystem))
+    }
+
+    /// Give the OEM6 an approximate position to speed up the time to first fix
+    ///
+    /// The position is only used until the receiver computes its own solution
+    ///
+    /// # Arguments
+    ///
+    /// * lat - Approximate latitude, in degrees
+    /// * lon - Approximate longitude, in degrees
+    /// * height - Approximate height above mean sea level, in meters
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`OEMError`] variant will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use novatel_oem6_api::*;
+    /// # use std::thread;
+    /// # use std::sync::mpsc::sync_channel;
+    ///
+    /// # fn func() -> OEMResult<()> {
+    /// # let bus = "/dev/ttyS5";
+    /// # let (log_send, log_recv) = sync_channel(5);
+    /// # let (response_send, response_recv) = sync_channel(5);
+    /// # let (response_abbrv_send, response_abbrv_recv) = sync_channel(5);
+    /// let oem = OEM6::new(bus, BaudRate::Baud9600, log_recv, response_recv, response_abbrv_recv).unwrap();
+    ///
+    /// oem.request_approx_position(51.116, -114.038, 1048.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`OEMError`]: enum.OEMError.html
+    pub fn request_approx_position(&self, lat: f64, lon: f64, height: f64) -> OEMResult<()> {
+        let request = SetApproxPosCmd::new(lat, lon, height);
+
+        self.send_message(&request)
+            .and_then(|_| self.get_response(MessageID::SetApproxPos))
+    }
+
+    /// Give the OEM6 an approximate GPS time to speed up the time to first fix
+    ///
+    /// The time is ignored if the receiver has already established its own time
+    ///
+    /// # Arguments
+    ///
+    /// * week - GPS reference week
+    /// * seconds - Seconds into the GPS reference week
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`OEMError`] variant will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use novatel_oem6_api::*;
+    /// # use std::thread;
+    /// # use std::sync::mpsc::sync_channel;
+    ///
+    /// # fn func() -> OEMResult<()> {
+    /// # let bus = "/dev/ttyS5";
+    /// # let (log_send, log_recv) = sync_channel(5);
+    /// # let (response_send, response_recv) = sync_channel(5);
+    /// # let (response_abbrv_send, response_abbrv_recv) = sync_channel(5);
+    /// let oem = OEM6::new(bus, BaudRate::Baud9600, log_recv, response_recv, response_abbrv_recv).unwrap();
+    ///
+    /// oem.request_approx_time(1930, 428637.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`OEMError`]: enum.OEMError.html
+    pub fn request_approx_time(&self, week: u32, seconds: f64) -> OEMResult<()> {
+        let request = SetApproxTimeCmd::new(week, seconds);
+
+        self.send_message(&request)
+            .and_then(|_| self.get_response(MessageID::SetApproxTime))
+    }
+
+    /// Request that the OEM6 change the data rate of the serial port being used to talk to it
+    ///
+    /// The OEM6 switches to the new rate once it has acknowledged the command, so the local
+    /// port will need to be reopened at the same rate (see [`open_port()`] and
+    /// [`set_connection()`]) before any further communication can happen
+    ///
+    /// # Arguments
+    ///
+    /// * baud - New data rate. Should be one of [`SUPPORTED_BAUD_RATES`]
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`OEMError`] variant will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use novatel_oem6_api::*;
+    /// # use std::thread;
+    /// # use std::sync::mpsc::sync_channel;
+    ///
+    /// # fn func() -> OEMResult<()> {
+    /// # let bus = "/dev/ttyS5";
+    /// # let (log_send, log_recv) = sync_channel(5);
+    /// # let (response_send, response_recv) = sync_channel(5);
+    /// # let (response_abbrv_send, response_abbrv_recv) = sync_channel(5);
+    /// let oem = OEM6::new(bus, BaudRate::Baud9600, log_recv, response_recv, response_abbrv_recv).unwrap();
+    ///
+    /// oem.request_baud_rate(115200)?;
+    /// oem.set_connection(OEM6::open_port(bus, BaudRate::Baud115200)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`open_port()`]: #method.open_port
+    /// [`set_connection()`]: #method.set_connection
+    /// [`SUPPORTED_BAUD_RATES`]: constant.SUPPORTED_BAUD_RATES.html
+    /// [`OEMError`]: enum.OEMError.html
+    pub fn request_baud_rate(&self, baud: u32) -> OEMResult<()> {
+        let request = ComCmd::new(baud);
+
+        self.send_message(&request)
+            .and_then(|_| self.get_response(MessageID::Com))
+    }
+
+    /// Request that the OEM6 change the data rate of the serial port being used to talk to it,
+    /// without waiting for the command response
+    ///
+    /// This is intended for moving the OEM6 back to a rate which the local port is about to be
+    /// reopened at, where the response would arrive at a rate which can't be read anymore.
+    /// Otherwise, [`request_baud_rate()`] should be used
+    ///
+    /// # Arguments
+    ///
+    /// * baud - New data rate. Should be one of [`SUPPORTED_BAUD_RATES`]
+    ///
+    /// # Errors
+    ///
+    /// If the command cannot be sent, an [`OEMError`] variant will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use novatel_oem6_api::*;
+    /// # use std::thread;
+    /// # use std::sync::mpsc::sync_channel;
+    ///
+    /// # fn func() -> OEMResult<()> {
+    /// # let bus = "/dev/ttyS5";
+    /// # let (log_send, log_recv) = sync_channel(5);
+    /// # let (response_send, response_recv) = sync_channel(5);
+    /// # let (response_abbrv_send, response_abbrv_recv) = sync_channel(5);
+    /// let oem = OEM6::new(bus, BaudRate::Baud115200, log_recv, response_recv, response_abbrv_recv).unwrap();
+    ///
+    /// oem.send_baud_rate(9600)?;
+    /// oem.set_connection(OEM6::open_port(bus, BaudRate::Baud9600)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`request_baud_rate()`]: #method.request_baud_rate
+    /// [`SUPPORTED_BAUD_RATES`]: constant.SUPPORTED_BAUD_RATES.html
+    /// [`OEMError`]: enum.OEMError.html
+    pub fn send_baud_rate(&self, baud: u32) -> OEMResult<()> {
+        self.send_message(&ComCmd::new(baud))
+    }
+
+    /// Open a serial connection to an OEM6 using the settings the device expects
+    ///
+    /// # Arguments
+    ///
+    /// * bus - Serial bus to use for communication
+    /// * baud_rate - Communication data rate
+    ///
+    /// # Errors
+    ///
+    /// If the port cannot be opened, an [`OEMError::UartError`] will be returned.
+    ///
+    /// [`OEMError::UartError`]: enum.OEMError.html#variant.UartError
+    pub fn open_port(bus: &str, baud_rate: serial::BaudRate) -> OEMResult<Connection> {
+        let settings = serial::PortSettings {
+            baud_rate,
+            char_size: CHAR_SIZE,
+            parity: PARITY,
+            stop_bits: STOP_BITS,
+            flow_control: FLOW_CONTROL,
+        };
+
+        Ok(Connection::from_path(bus, settings, TIMEOUT)?)
+    }
+
+    /// Replace the connection used to talk to the OEM6, for example after changing its data
+    /// rate. The read thread will pick up the new connection the next time it reads
+    ///
+    /// # Arguments
+    ///
+    /// * conn - New connection to the device
+    pub fn set_connection(&self, conn: Connection) {
+        // A poisoned mutex still holds a usable connection, so replace it anyways
+        let mut current = self.conn.lock().unwrap_or_else(|err| err.into_inner());
+        *current = conn;
+    }
+
+    /// Directly send a message without formatting or checksum calculation
+    ///
+    /// Note: The message will not be verified by checking for a command response
+    ///
+    /// # Arguments
+    ///
+    /// * msg - Message to send
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters any errors, an [`OEMError`] variant will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use novatel_oem6_api::*;
+    /// # use std::thread;
+    /// # use std::sync::mpsc::sync_channel;
+    ///
+    /// # fn func() -> OEMResult<()> {
+    /// # let bus = "/dev/ttyS5";
+    /// # let (log_send, log_recv) = sync_channel(5);
+    /// # let (response_send, response_recv) = sync_channel(5);
+    /// # let (response_abbrv_send, response_abbrv_recv) = sync_channel(5);
+    /// let oem = OEM6::new(bus, BaudRate::Baud9600, log_recv, response_recv, response_abbrv_recv).unwrap();
+    ///
+    /// let packet: [u8; 6] = [0, 1, 2, 3, 4, 5];
+    /// oem.passthrough(&packet)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    ///
+    /// [`OEMError`]: enum.OEMError.html
+    pub fn passthrough(&self, msg: &[u8]) -> OEMResult<()> {
+        // If the mutex has been poisoned by the read thread,
+        // go ahead and attempt the write anyways, to try to
+        // preserve functionality, but inform the caller afterwards
+        match self.conn.lock() {
+            Ok(conn) => conn.write(msg).map_err(|err| err.into()),
+            Err(conn) => conn
+                .into_inner()
+                .write(msg)
+                .map_err(|err| err.into())
+                .and(Err(OEMError::ThreadCommError)),
+        }
+    }
+
+    /// Discard any command responses which have been received but not yet consumed
+    ///
+    /// Responses to requests which timed out or were abandoned can otherwise be mistaken for
+    /// the response to the next command. Any request which is currently waiting for its
+    /// response will be allowed to finish first
+    ///
+    /// # Errors
+    ///
+    /// If the response receivers have been poisoned, `OEMError::MutexError` will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use novatel_oem6_api::*;
+    /// # use std::thread;
+    /// # use std::sync::mpsc::sync_channel;
+    ///
+    /// # fn func() -> OEMResult<()> {
+    /// # let bus = "/dev/ttyS5";
+    /// # let (log_send, log_recv) = sync_channel(5);
+    /// # let (response_send, response_recv) = sync_channel(5);
+    /// # let (response_abbrv_send, response_abbrv_recv) = sync_channel(5);
+    /// let oem = OEM6::new(bus, BaudRate::Baud9600, log_recv, response_recv, response_abbrv_recv).unwrap();
+    ///
+    /// oem.flush_responses()?;
+    /// oem.request_unlog_all(true)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn flush_responses(&self) -> OEMResult<()> {
+        let response_recv = self
+            .response_recv
+            .lock()
+            .map_err(|_| OEMError::MutexError)?;
+        while response_recv.try_recv().is_ok() {}
+
+        let response_abbrv_recv = self
+            .response_abbrv_recv
+            .lock()
+            .map_err(|_| OEMError::MutexError)?;
+        while response_abbrv_recv.try_recv().is_ok() {}
+
+        Ok(())
+    }
+
+    #[cfg(not(feature = "nos3"))]
+    fn send_message<T: Message>(&self, msg: &T) -> OEMResult<()> {
+        let mut raw = msg.serialize();
+
+        // Get the calculated CRC
+        let crc = calc_crc(&raw);
+        raw.write_u32::<LittleEndian>(crc).unwrap();
+
+        // If the mutex has been poisoned by the read thread,
+        // go ahead and attempt the write anyways, to try to
+        // preserve functionality, but inform the caller afterwards
+        match self.conn.lock() {
+            Ok(conn) => conn.write(raw.as_slice()).map_err(|err| err.into()),
+            Err(conn) => conn
+                .into_inner()
+                .write(raw.as_slice())
+                .map_err(|err| err.into())
+                .and(Err(OEMError::ThreadCommError)),
+        }
+    }
+
+    #[cfg(feature = "nos3")]
+    fn send_message<T: Message>(&self, msg: &T) -> OEMResult<()> {
+        let raw = msg.serialize();
+
+        // If the mutex has been poisoned by the read thread,
+        // go ahead and attempt the write anyways, to try to
+        // preserve functionality, but inform the caller afterwards
+        match self.conn.lock() {
+            Ok(conn) => conn.write(raw.as_slice()).map_err(|err| err.into()),
+            Err(conn) => conn
+                .into_inner()
+                .write(raw.as_slice())
+                .map_err(|err| err.into())
+                .and(Err(OEMError::ThreadCommError)),
+        }
+    }
+
+    #[cfg(not(feature = "nos3"))]
+    fn get_response(&self, id: MessageID) -> OEMResult<()> {
+        let (hdr, body) = self
+            .response_recv
+            .lock()
+            .map_err(|_| OEMError::MutexError)?
+            .recv_timeout(Duration::from_millis(500))
             .map_err(|_| OEMError::NoResponse)?;
 
         // Make sure we got specifically a response message
@@ -816,14 +1926,34 @@ impl OEM6 {
                 continue;
             }
 
-            match Log::new(
-                hdr.msg_id,
-                hdr.recv_status,
-                hdr.time_status,
-                hdr.week,
-                hdr.ms,
-                body,
-            ) {
+            let log = if hdr.msg_type & FORMAT_MASK == LogFormat::Ascii as u8 {
+                Log::from_ascii(
+                    hdr.msg_id,
+                    hdr.recv_status,
+                    hdr.time_status,
+                    hdr.week,
+                    hdr.ms,
+                    &body,
+                )
+            } else {
+                Log::new(
+                    hdr.msg_id,
+                    hdr.recv_status,
+                    hdr.time_status,
+                    hdr.week,
+                    hdr.ms,
+                    body,
+                )
+            };
+
+            match log {
+                Some(Log::BestXYZ(mut v)) => {
+                    // Diagnostic header fields are only kept for position logs
+                    v.port_addr = hdr.port_addr;
+                    v.seq = hdr.seq;
+                    v.idle_time = hdr.idle_time;
+                    return Ok(Log::BestXYZ(v));
+                }
                 Some(v) => return Ok(v),
                 None => {
                     continue;
@@ -0,0 +1,123 @@
+/*
+ * Copyright (C) 2018 Kubos Corporation
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use rust_uart::{Stream, UartError, UartResult};
+use std::cell::RefCell;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::time::Duration;
+
+/// A transport which plays back previously captured OEM6 output instead of talking to a device
+///
+/// The data is handed to the [`read_thread`] in exactly the same way as data read from a live
+/// serial port, so logs are parsed and routed as they would be in flight. This makes it possible
+/// to reproduce field issues from a capture of the raw bytes received from the OEM6.
+///
+/// Once the capture has been consumed, or if the remaining bytes are too short to satisfy a
+/// read, reads time out the same way an idle serial port would. Any commands written to the
+/// source are discarded, so requests which wait for a response will fail with
+/// `OEMError::NoResponse`.
+///
+/// # Examples
+///
+/// ```
+/// use novatel_oem6_api::*;
+/// use std::sync::mpsc::sync_channel;
+/// use std::thread;
+///
+/// # fn func() -> OEMResult<()> {
+/// let (log_send, log_recv) = sync_channel(5);
+/// let (response_send, response_recv) = sync_channel(5);
+/// let (response_abbrv_send, response_abbrv_recv) = sync_channel(5);
+///
+/// let source = ReplaySource::from_file("/home/kubos/oem6-capture.bin")?;
+///
+/// let oem = OEM6::from_connection(
+///     Connection::new(Box::new(source)),
+///     log_recv,
+///     response_recv,
+///     response_abbrv_recv,
+/// );
+///
+/// let rx_conn = oem.conn.clone();
+/// thread::spawn(move || read_thread(&rx_conn, &log_send, &response_send, &response_abbrv_send));
+///
+/// let log = oem.get_log()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`read_thread`]: fn.read_thread.html
+pub struct ReplaySource {
+    data: Vec<u8>,
+    position: RefCell<usize>,
+}
+
+impl ReplaySource {
+    /// Create a replay source from captured bytes
+    ///
+    /// # Arguments
+    ///
+    /// * data - Raw bytes, as received from the OEM6
+    pub fn new(data: Vec<u8>) -> Self {
+        ReplaySource {
+            data,
+            position: RefCell::new(0),
+        }
+    }
+
+    /// Create a replay source from a file containing captured bytes
+    ///
+    /// # Arguments
+    ///
+    /// * path - Path to the capture file
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be read, a `UartError::IoError` will be returned.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> UartResult<Self> {
+        Ok(ReplaySource::new(fs::read(path)?))
+    }
+
+    /// Number of captured bytes which haven't been read yet
+    pub fn remaining(&self) -> usize {
+        self.data.len() - *self.position.borrow()
+    }
+}
+
+impl Stream for ReplaySource {
+    // There's no device to receive commands, so they're dropped
+    fn write(&self, _data: &[u8]) -> UartResult<()> {
+        Ok(())
+    }
+
+    fn read(&self, len: usize, _timeout: Duration) -> UartResult<Vec<u8>> {
+        let mut position = self.position.borrow_mut();
+
+        if self.data.len() - *position < len {
+            return Err(UartError::IoError {
+                cause: ErrorKind::TimedOut,
+                description: "End of replay data".to_owned(),
+            });
+        }
+
+        let data = self.data[*position..*position + len].to_vec();
+        *position += len;
+
+        Ok(data)
+    }
+}
@@ -0,0 +1,258 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+
+#[test]
+fn test_request_log_binary() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0xF1, 0x0, 0x0,
+        0x0, 0x2, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xF0, 0x3F, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xB9, 0x54, 0x12, 0x6F,
+    ]);
+
+    mock.read.set_output(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x80, 0x20, 0x6, 0x0, 0x0, 0x0, 0xFF, 0x78, 0xD1, 0xB,
+        0x6, 0x67, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0xFB, 0xFD, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x4F,
+        0x4B, 0x10, 0x9D, 0x33, 0xB1,
+    ]);
+
+    let oem = mock_new!(mock);
+
+    assert_eq!(
+        oem.request_log(MessageID::BestXYZ, LogFormat::Binary, 1.0, 0.0, false),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_request_log_ascii() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0xF1, 0x0,
+        0x20, 0x0, 0x2, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xF0, 0x3F, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x8, 0xE4, 0xC7, 0xE0,
+    ]);
+
+    mock.read.set_output(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x80, 0x20, 0x6, 0x0, 0x0, 0x0, 0xFF, 0x78, 0xD1, 0xB,
+        0x6, 0x67, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0xFB, 0xFD, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x4F,
+        0x4B, 0x10, 0x9D, 0x33, 0xB1,
+    ]);
+
+    let oem = mock_new!(mock);
+
+    assert_eq!(
+        oem.request_log(MessageID::BestXYZ, LogFormat::Ascii, 1.0, 0.0, false),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_request_log_ascii_once_hold() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0x65, 0x0,
+        0x20, 0x0, 0x4, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0xA4, 0x1E, 0x25, 0x66,
+    ]);
+
+    mock.read.set_output(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x80, 0x20, 0x6, 0x0, 0x0, 0x0, 0xFF, 0x78, 0xD1, 0xB,
+        0x6, 0x67, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0xFB, 0xFD, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x4F,
+        0x4B, 0x10, 0x9D, 0x33, 0xB1,
+    ]);
+
+    let oem = mock_new!(mock);
+
+    assert_eq!(
+        oem.request_log(MessageID::Time, LogFormat::Ascii, 0.0, 0.0, true),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_get_position_ascii() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(
+        b"#BESTXYZA,COM1,0,62.0,FINESTEERING,3025,164195.000,00480000,d821,13307;\
+        SOL_COMPUTED,PSRDIFF,-1634531.5683,-3664618.0326,4942496.3270,0.0099,0.0219,0.0115,\
+        SOL_COMPUTED,PSRDIFF,0.0011,-0.0049,-0.0001,0.0199,0.0439,0.0230,\
+        \"AAAA\",0.250,1.000,0.000,12,11,11,11,0,01,0,33*615c1f1c\r\n"
+            .to_vec(),
+    );
+
+    let oem = mock_new!(mock);
+
+    let expected: Log = Log::BestXYZ(BestXYZLog {
+        recv_status: ReceiverStatusFlags::CLOCK_MODEL_INVALID
+            | ReceiverStatusFlags::POSITION_SOLUTION_INVALID,
+        time_status: 180,
+        week: 3025,
+        ms: 164195000,
+        port_addr: 0x20,
+        seq: 0,
+        idle_time: 124,
+        pos_status: 0,
+        pos_type: 17,
+        position: [-1634531.5683, -3664618.0326, 4942496.3270],
+        pos_deviation: [0.0099, 0.0219, 0.0115],
+        vel_status: 0,
+        vel_type: 17,
+        velocity: [0.0011, -0.0049, -0.0001],
+        vel_deviation: [0.0199, 0.0439, 0.0230],
+        station_id: "AAAA".to_owned(),
+        vel_time_latency: 0.25,
+        diff_age: 1.0,
+        sol_age: 0.0,
+        num_sats: 12,
+        num_sat_vehicles: 11,
+        num_gg_l1: 11,
+        num_multi_sats: 11,
+        ext_sol_stat: 1,
+        gal_beidou_sig: 0,
+        gps_glonass_sig: 0x33,
+    });
+
+    assert_eq!(oem.get_log().unwrap(), expected);
+}
+
+#[test]
+fn test_get_time_ascii() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(
+        b"#TIMEA,COM1,0,50.5,FINESTEERING,3025,164195.000,00480000,9924,13307;\
+        VALID,-1.500000000e-08,2.500000000e-09,-18.00000000000,2018,10,15,12,30,15000,\
+        VALID*c38b8d19\r\n"
+            .to_vec(),
+    );
+
+    let oem = mock_new!(mock);
+
+    let expected: Log = Log::Time(TimeLog {
+        recv_status: ReceiverStatusFlags::CLOCK_MODEL_INVALID
+            | ReceiverStatusFlags::POSITION_SOLUTION_INVALID,
+        time_status: 180,
+        week: 3025,
+        ms: 164195000,
+        clock_status: 0,
+        offset: -1.5e-8,
+        offset_std: 2.5e-9,
+        utc_offset: -18.0,
+        utc_year: 2018,
+        utc_month: 10,
+        utc_day: 15,
+        utc_hour: 12,
+        utc_min: 30,
+        utc_ms: 15000,
+        utc_status: 1,
+    });
+
+    assert_eq!(oem.get_log().unwrap(), expected);
+}
+
+#[test]
+fn test_get_velocity_ascii() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(
+        b"#BESTVELA,COM1,0,50.5,FINESTEERING,3025,164195.000,00480000,9924,13307;\
+        SOL_COMPUTED,DOPPLER_VELOCITY,0.250,1.000,1.5000,90.000000,-0.5000,0.0*f481e191\r\n"
+            .to_vec(),
+    );
+
+    let oem = mock_new!(mock);
+
+    let expected: Log = Log::BestVel(BestVelLog {
+        recv_status: ReceiverStatusFlags::CLOCK_MODEL_INVALID
+            | ReceiverStatusFlags::POSITION_SOLUTION_INVALID,
+        time_status: 180,
+        week: 3025,
+        ms: 164195000,
+        sol_status: 0,
+        vel_type: 8,
+        latency: 0.25,
+        diff_age: 1.0,
+        hor_speed: 1.5,
+        track: 90.0,
+        vert_speed: -0.5,
+    });
+
+    assert_eq!(oem.get_log().unwrap(), expected);
+}
+
+#[test]
+fn test_get_dop_ascii() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(
+        b"#PSRDOPA,COM1,0,50.5,FINESTEERING,3025,164195.000,00480000,768f,13307;\
+        1.5000,1.2500,0.7500,1.0000,0.5000,5.0,3,3,5,12*b8bbbbdc\r\n"
+            .to_vec(),
+    );
+
+    let oem = mock_new!(mock);
+
+    let expected: Log = Log::PsrDop(PsrDopLog {
+        recv_status: ReceiverStatusFlags::CLOCK_MODEL_INVALID
+            | ReceiverStatusFlags::POSITION_SOLUTION_INVALID,
+        time_status: 180,
+        week: 3025,
+        ms: 164195000,
+        gdop: 1.5,
+        pdop: 1.25,
+        hdop: 0.75,
+        htdop: 1.0,
+        tdop: 0.5,
+        cutoff: 5.0,
+        prns: vec![3, 5, 12],
+    });
+
+    assert_eq!(oem.get_log().unwrap(), expected);
+}
+
+#[test]
+fn test_get_ascii_bad_crc() {
+    let mut mock = MockStream::default();
+
+    // The corrupted position log should be skipped in favor of the following time log
+    mock.read.set_output(
+        b"#BESTXYZA,COM1,0,62.0,FINESTEERING,3025,164195.000,00480000,d821,13307;\
+        SOL_COMPUTED,PSRDIFF,-1634531.5683,-3664618.0326,4942496.3270,0.0099,0.0219,0.0115,\
+        SOL_COMPUTED,PSRDIFF,0.0011,-0.0049,-0.0001,0.0199,0.0439,0.0230,\
+        \"AAAA\",0.250,1.000,0.000,12,11,11,11,0,01,0,33*615c101c\r\n\
+        #TIMEA,COM1,0,50.5,FINESTEERING,3025,164195.000,00480000,9924,13307;\
+        VALID,-1.500000000e-08,2.500000000e-09,-18.00000000000,2018,10,15,12,30,15000,\
+        VALID*c38b8d19\r\n"
+            .to_vec(),
+    );
+
+    let oem = mock_new!(mock);
+
+    match oem.get_log().unwrap() {
+        Log::Time(_) => {}
+        other => panic!("Unexpected log: {:?}", other),
+    }
+}
@@ -0,0 +1,104 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+
+#[test]
+fn test_request_baud_rate() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x4, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x6, 0x0, 0x0, 0x0, 0x0, 0xC2, 0x1,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x8, 0x0, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0xED, 0x67, 0x4B, 0xF1,
+    ]);
+
+    mock.read.set_output(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x4, 0x0, 0x80, 0x20, 0x6, 0x0, 0x0, 0x0, 0xFF, 0x78, 0xD1, 0xB,
+        0x6, 0x67, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0xFB, 0xFD, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x4F,
+        0x4B, 0x38, 0xF2, 0xD0, 0x7C,
+    ]);
+
+    let oem = mock_new!(mock);
+
+    assert_eq!(oem.request_baud_rate(115_200), Ok(()));
+}
+
+#[test]
+fn test_request_baud_rate_fail() {
+    let mock = MockStream::default();
+
+    let oem = mock_new!(mock);
+
+    assert_eq!(
+        oem.request_baud_rate(115_200).unwrap_err(),
+        OEMError::UartError {
+            cause: UartError::GenericError,
+        }
+    );
+}
+
+#[test]
+fn test_send_baud_rate() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x4, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x6, 0x0, 0x0, 0x0, 0x80, 0x25, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x8, 0x0, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0xA8, 0xA4, 0x1, 0x9E,
+    ]);
+
+    let tracker = mock.tracker();
+
+    let oem = mock_new!(mock);
+
+    // No response is expected
+    assert_eq!(oem.send_baud_rate(9600), Ok(()));
+
+    tracker.assert_consumed();
+}
+
+#[test]
+fn test_set_connection() {
+    // The original connection doesn't expect any writes
+    let mock = MockStream::default();
+
+    let oem = mock_new!(mock);
+
+    let mut new_mock = MockStream::default();
+
+    new_mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x4, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x6, 0x0, 0x0, 0x0, 0x80, 0x25, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x8, 0x0, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0xA8, 0xA4, 0x1, 0x9E,
+    ]);
+
+    new_mock.read.set_output(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x4, 0x0, 0x80, 0x20, 0x6, 0x0, 0x0, 0x0, 0xFF, 0x78, 0xD1, 0xB,
+        0x6, 0x67, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0xFB, 0xFD, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x4F,
+        0x4B, 0x38, 0xF2, 0xD0, 0x7C,
+    ]);
+
+    oem.set_connection(Connection {
+        stream: Box::new(new_mock),
+    });
+
+    // Both the write and the response go through the new connection
+    assert_eq!(oem.request_baud_rate(9600), Ok(()));
+}
@@ -0,0 +1,71 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+
+#[test]
+fn test_request_dop_ontime() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0xAE, 0x0, 0x0,
+        0x0, 0x2, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xF0, 0x3F, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x92, 0xD9, 0x94, 0x10,
+    ]);
+
+    mock.read.set_output(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x80, 0x20, 0x6, 0x0, 0x0, 0x0, 0xFF, 0x78, 0xD1, 0xB,
+        0x6, 0x67, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0xFB, 0xFD, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x4F,
+        0x4B, 0x10, 0x9D, 0x33, 0xB1,
+    ]);
+
+    let oem = mock_new!(mock);
+
+    assert_eq!(oem.request_dop(1.0, 0.0, false), Ok(()));
+}
+
+#[test]
+fn test_get_dop() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0xAE, 0x0, 0x0, 0x20, 0x28, 0x0, 0x0, 0x0, 0x7C, 0x78, 0xD1, 0xB,
+        0xB8, 0x6A, 0xC9, 0x9, 0x0, 0x0, 0x48, 0x0, 0x0, 0x0, 0xFA, 0x33, 0x0, 0x0, 0x20, 0x40,
+        0x0, 0x0, 0x0, 0x40, 0x0, 0x0, 0xA0, 0x3F, 0x0, 0x0, 0xC0, 0x3F, 0x0, 0x0, 0x80, 0x3F, 0x0,
+        0x0, 0xA0, 0x40, 0x3, 0x0, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0xC, 0x0, 0x0, 0x0, 0x17, 0x0,
+        0x0, 0x0, 0x83, 0xE4, 0xBA, 0xEA,
+    ]);
+
+    let oem = mock_new!(mock);
+
+    let expected: Log = Log::PsrDop(PsrDopLog {
+        recv_status: ReceiverStatusFlags::CLOCK_MODEL_INVALID
+            | ReceiverStatusFlags::POSITION_SOLUTION_INVALID,
+        time_status: 120,
+        week: 3025,
+        ms: 164195000,
+        gdop: 2.5,
+        pdop: 2.0,
+        hdop: 1.25,
+        htdop: 1.5,
+        tdop: 1.0,
+        cutoff: 5.0,
+        prns: vec![1, 12, 23],
+    });
+
+    assert_eq!(oem.get_log().unwrap(), expected);
+}
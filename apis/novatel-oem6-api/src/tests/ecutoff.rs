@@ -0,0 +1,73 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+
+#[test]
+fn test_request_elevation_cutoff() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x32, 0x0, 0x0, 0xC0, 0x4, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x41, 0xC2, 0x31,
+        0xAD, 0x8D,
+    ]);
+
+    mock.read.set_output(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x32, 0x0, 0x80, 0x20, 0x6, 0x0, 0x0, 0x0, 0xFF, 0x78, 0xD1, 0xB,
+        0x6, 0x67, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0xFB, 0xFD, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x4F,
+        0x4B, 0xE, 0xFC, 0xA6, 0x64,
+    ]);
+
+    let oem = mock_new!(mock);
+
+    assert_eq!(oem.request_elevation_cutoff(10.0), Ok(()));
+}
+
+#[test]
+fn test_request_elevation_cutoff_zero() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x32, 0x0, 0x0, 0xC0, 0x4, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x66, 0x64,
+        0xF2, 0x19,
+    ]);
+
+    mock.read.set_output(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x32, 0x0, 0x80, 0x20, 0x6, 0x0, 0x0, 0x0, 0xFF, 0x78, 0xD1, 0xB,
+        0x6, 0x67, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0xFB, 0xFD, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x4F,
+        0x4B, 0xE, 0xFC, 0xA6, 0x64,
+    ]);
+
+    let oem = mock_new!(mock);
+
+    assert_eq!(oem.request_elevation_cutoff(0.0), Ok(()));
+}
+
+#[test]
+fn test_request_elevation_cutoff_fail() {
+    let mock = MockStream::default();
+
+    let oem = mock_new!(mock);
+
+    assert_eq!(
+        oem.request_elevation_cutoff(10.0).unwrap_err(),
+        OEMError::UartError {
+            cause: UartError::GenericError,
+        }
+    );
+}
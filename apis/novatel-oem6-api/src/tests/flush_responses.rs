@@ -0,0 +1,54 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+use std::time::Duration;
+
+#[test]
+fn test_flush_responses() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x13, 0x0, 0x0, 0xC0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xD0, 0x17, 0xF9, 0xF0,
+    ]);
+
+    // Leftover response from an earlier unlog request
+    mock.read.set_output(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x24, 0x0, 0x80, 0x20, 0x6, 0x0, 0x0, 0x0, 0xFF, 0x78, 0xD1, 0xB,
+        0x85, 0x6F, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0xFB, 0xFD, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x4F,
+        0x4B, 0x41, 0xFE, 0xF5, 0x3F,
+    ]);
+
+    let oem = mock_new!(mock);
+
+    // Give the read thread time to queue the response
+    thread::sleep(Duration::from_millis(100));
+
+    assert_eq!(oem.flush_responses(), Ok(()));
+
+    // The stale response is gone, so it can't be mistaken for this one
+    assert_eq!(oem.request_save_config(), Err(OEMError::NoResponse));
+}
+
+#[test]
+fn test_flush_responses_empty() {
+    let mock = MockStream::default();
+
+    let oem = mock_new!(mock);
+
+    assert_eq!(oem.flush_responses(), Ok(()));
+}
@@ -0,0 +1,104 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+
+#[test]
+fn test_request_hw_monitor_ontime() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0xC3, 0x3, 0x0,
+        0x0, 0x2, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xF0, 0x3F, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x30, 0xF1, 0xD, 0xF2,
+    ]);
+
+    mock.read.set_output(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x80, 0x20, 0x6, 0x0, 0x0, 0x0, 0xFF, 0x78, 0xD1, 0xB,
+        0x6, 0x67, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0xFB, 0xFD, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x4F,
+        0x4B, 0x10, 0x9D, 0x33, 0xB1,
+    ]);
+
+    let oem = mock_new!(mock);
+
+    assert_eq!(oem.request_hw_monitor(1.0, 0.0, false), Ok(()));
+}
+
+#[test]
+fn test_get_hw_monitor() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0xC3, 0x3, 0x0, 0x20, 0x24, 0x0, 0x0, 0x0, 0x0, 0x78, 0xD1, 0xB,
+        0xB8, 0x6A, 0xC9, 0x9, 0x0, 0x0, 0x48, 0x0, 0x0, 0x0, 0x0, 0x0, 0x4, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x2A, 0x42, 0x0, 0x1, 0x0, 0x0, 0x0, 0x0, 0x80, 0x3D, 0x0, 0x2, 0x0, 0x0, 0x0, 0x0,
+        0xA0, 0x3F, 0x0, 0x6, 0x0, 0x0, 0x0, 0x0, 0xA0, 0x40, 0x0, 0x7, 0x0, 0x0, 0x3B, 0x1A, 0x26,
+        0x9C,
+    ]);
+
+    let oem = mock_new!(mock);
+
+    let expected: Log = Log::HwMonitor(HwMonitorLog {
+        recv_status: ReceiverStatusFlags::CLOCK_MODEL_INVALID
+            | ReceiverStatusFlags::POSITION_SOLUTION_INVALID,
+        time_status: 120,
+        week: 3025,
+        ms: 164195000,
+        measurements: vec![
+            HwMeasurement {
+                reading: 42.5,
+                status: 0x100,
+            },
+            HwMeasurement {
+                reading: 0.0625,
+                status: 0x200,
+            },
+            HwMeasurement {
+                reading: 1.25,
+                status: 0x600,
+            },
+            HwMeasurement {
+                reading: 5.0,
+                status: 0x700,
+            },
+        ],
+    });
+
+    assert_eq!(oem.get_log().unwrap(), expected);
+}
+
+#[test]
+fn test_hw_monitor_reading() {
+    let log = HwMonitorLog {
+        measurements: vec![
+            HwMeasurement {
+                reading: 42.5,
+                status: 0x103,
+            },
+            HwMeasurement {
+                reading: 1.25,
+                status: 0x600,
+            },
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(log.reading(HW_TEMPERATURE), Some(42.5));
+    assert_eq!(log.reading(HW_CORE_VOLTAGE), Some(1.25));
+    assert_eq!(log.reading(HW_ANTENNA_CURRENT), None);
+    assert_eq!(log.measurements[0].limit_status(), 3);
+}
@@ -0,0 +1,104 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+
+#[test]
+fn test_request_log_on_port() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x40, 0x0, 0x0, 0x0, 0xF1, 0x0, 0x0,
+        0x0, 0x2, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xF0, 0x3F, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xCF, 0x32, 0xC5, 0x20,
+    ]);
+
+    mock.read.set_output(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x80, 0x20, 0x6, 0x0, 0x0, 0x0, 0xFF, 0x78, 0xD1, 0xB,
+        0x6, 0x67, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0xFB, 0xFD, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x4F,
+        0x4B, 0x10, 0x9D, 0x33, 0xB1,
+    ]);
+
+    let oem = mock_new!(mock);
+
+    assert_eq!(
+        oem.request_log_on_port(
+            Port::COM2,
+            MessageID::BestXYZ,
+            LogFormat::Binary,
+            1.0,
+            0.0,
+            false
+        ),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_request_log_on_port_event() {
+    let mut mock = MockStream::default();
+
+    // Event logs ignore the requested interval and offset
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x60, 0x0, 0x0, 0x0, 0x5E, 0x0, 0x0,
+        0x0, 0x1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x76, 0x73, 0x27, 0x56,
+    ]);
+
+    mock.read.set_output(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x80, 0x20, 0x6, 0x0, 0x0, 0x0, 0xFF, 0x78, 0xD1, 0xB,
+        0x6, 0x67, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0xFB, 0xFD, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x4F,
+        0x4B, 0x10, 0x9D, 0x33, 0xB1,
+    ]);
+
+    let oem = mock_new!(mock);
+
+    assert_eq!(
+        oem.request_log_on_port(
+            Port::COM3,
+            MessageID::RxStatusEvent,
+            LogFormat::Binary,
+            5.0,
+            1.0,
+            true
+        ),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_request_log_on_port_fail() {
+    let mock = MockStream::default();
+
+    let oem = mock_new!(mock);
+
+    assert_eq!(
+        oem.request_log_on_port(
+            Port::COM2,
+            MessageID::BestXYZ,
+            LogFormat::Binary,
+            1.0,
+            0.0,
+            false
+        )
+        .unwrap_err(),
+        OEMError::UartError {
+            cause: UartError::GenericError,
+        }
+    );
+}
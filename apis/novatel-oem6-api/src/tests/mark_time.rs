@@ -0,0 +1,111 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+
+#[test]
+fn test_request_mark_time() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0xE7, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x95, 0x23, 0xDF, 0xE1,
+    ]);
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0x68, 0x2, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x2C, 0x80, 0x9F, 0xEF,
+    ]);
+
+    mock.read.set_output(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x80, 0x20, 0x6, 0x0, 0x0, 0x0, 0xFF, 0x78, 0xD1, 0xB,
+        0x6, 0x67, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0xFB, 0xFD, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x4F,
+        0x4B, 0x10, 0x9D, 0x33, 0xB1, 0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x80, 0x20, 0x6, 0x0, 0x0,
+        0x0, 0xFF, 0x78, 0xD1, 0xB, 0x6, 0x67, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0xFB, 0xFD, 0x0, 0x0,
+        0x1, 0x0, 0x0, 0x0, 0x4F, 0x4B, 0x10, 0x9D, 0x33, 0xB1,
+    ]);
+
+    let oem = mock_new!(mock);
+
+    assert_eq!(oem.request_mark_time(false), Ok(()));
+}
+
+#[test]
+fn test_get_mark1_time() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0xE7, 0x0, 0x0, 0x20, 0x2C, 0x0, 0x0, 0x0, 0x48, 0xB4, 0xD1, 0x7,
+        0x9D, 0x40, 0x96, 0x18, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xFA, 0x33, 0xD1, 0x7, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x80, 0x50, 0x2D, 0x19, 0x41, 0xDF, 0x41, 0x3A, 0xDC, 0x11, 0xC5, 0x19, 0xBE,
+        0x4, 0x54, 0xEF, 0xD7, 0xB7, 0xDC, 0xEC, 0x3D, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x32, 0xC0,
+        0x0, 0x0, 0x0, 0x0, 0x7F, 0x82, 0x90, 0x98,
+    ]);
+
+    let oem = mock_new!(mock);
+
+    let expected: Log = Log::MarkTime(MarkTimeLog {
+        recv_status: ReceiverStatusFlags::empty(),
+        time_status: 180,
+        week: 2001,
+        ms: 412500125,
+        event_id: 1,
+        mark_week: 2001,
+        seconds: 412500.125,
+        offset: -1.5e-9,
+        offset_std: 2.1e-10,
+        utc_offset: -18.0,
+        clock_status: 0,
+    });
+
+    assert_eq!(oem.get_log().unwrap(), expected);
+}
+
+#[test]
+fn test_get_mark2_time() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x68, 0x2, 0x0, 0x20, 0x2C, 0x0, 0x0, 0x0, 0x48, 0xB4, 0xD1, 0x7,
+        0xFC, 0x45, 0x96, 0x18, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xFA, 0x33, 0xD1, 0x7, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x56, 0x2D, 0x19, 0x41, 0xDF, 0x41, 0x3A, 0xDC, 0x11, 0xC5, 0x19, 0xBE, 0x4,
+        0x54, 0xEF, 0xD7, 0xB7, 0xDC, 0xEC, 0x3D, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x32, 0xC0, 0x0,
+        0x0, 0x0, 0x0, 0x2A, 0x4E, 0x1E, 0x59,
+    ]);
+
+    let oem = mock_new!(mock);
+
+    let expected: Log = Log::MarkTime(MarkTimeLog {
+        recv_status: ReceiverStatusFlags::empty(),
+        time_status: 180,
+        week: 2001,
+        ms: 412501500,
+        event_id: 2,
+        mark_week: 2001,
+        seconds: 412501.5,
+        offset: -1.5e-9,
+        offset_std: 2.1e-10,
+        utc_offset: -18.0,
+        clock_status: 0,
+    });
+
+    let log = oem.get_log().unwrap();
+    assert_eq!(log.id(), MessageID::Mark2Time);
+    assert_eq!(log, expected);
+}
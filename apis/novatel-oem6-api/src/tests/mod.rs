@@ -90,7 +90,26 @@ fn test_passthrough_good() {
     assert_eq!(oem.passthrough(&packet), Ok(()));
 }
 
+mod ascii;
+mod com;
+mod dop;
+mod ecutoff;
 mod errors;
+mod flush_responses;
+mod hw_monitor;
+mod lockout_system;
+mod log_port;
+mod mark_time;
 mod position;
+mod range;
+mod replay;
+mod reset;
+mod rx_config;
+mod save_config;
+mod set_approx;
+mod status;
+mod time;
+mod track_stat;
 mod unlog;
+mod velocity;
 mod version;
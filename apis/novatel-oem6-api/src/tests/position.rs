@@ -129,6 +129,9 @@ fn test_get_position() {
         time_status: 120,
         week: 3025,
         ms: 164195000,
+        port_addr: 0x20,
+        seq: 0,
+        idle_time: 124,
         pos_status: 1,
         pos_type: 0,
         position: [0.0, 0.0, 0.0],
@@ -152,3 +155,95 @@ fn test_get_position() {
 
     assert_eq!(oem.get_log().unwrap(), expected);
 }
+
+#[test]
+fn test_get_position_differential() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0xF1, 0x0, 0x0, 0x20, 0x70, 0x0, 0x0, 0x0, 0x7C, 0xB4, 0xD1, 0xB,
+        0xB8, 0x6A, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xFA, 0x33, 0x0, 0x0, 0x0, 0x0, 0x11,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x80, 0xE3, 0xF0, 0x38, 0xC1, 0x0, 0x0, 0x0, 0x20, 0x75,
+        0xF5, 0x4B, 0xC1, 0x0, 0x0, 0x0, 0x30, 0xA8, 0xDA, 0x52, 0x41, 0x0, 0x0, 0x0, 0x3F, 0x0,
+        0x0, 0x40, 0x3F, 0x0, 0x0, 0x80, 0x3F, 0x0, 0x0, 0x0, 0x0, 0x11, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0xF8, 0x3F, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x2, 0xC0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0xE0, 0x3F, 0x0, 0x0, 0x0, 0x3E, 0x0, 0x0, 0x0, 0x3E, 0x0, 0x0, 0x80, 0x3E,
+        0x31, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x90, 0x40, 0x0, 0x0, 0x0, 0x0, 0xA,
+        0x8, 0x8, 0x8, 0x0, 0x0, 0x0, 0x3, 0x2C, 0xBB, 0x48, 0x17,
+    ]);
+
+    let oem = mock_new!(mock);
+
+    let expected: Log = Log::BestXYZ(BestXYZLog {
+        recv_status: ReceiverStatusFlags::empty(),
+        time_status: 180,
+        week: 3025,
+        ms: 164195000,
+        port_addr: 0x20,
+        seq: 0,
+        idle_time: 124,
+        pos_status: 0,
+        pos_type: 17,
+        position: [-1634531.5, -3664618.25, 4942496.75],
+        pos_deviation: [0.5, 0.75, 1.0],
+        vel_status: 0,
+        vel_type: 17,
+        velocity: [1.5, -2.25, 0.5],
+        vel_deviation: [0.125, 0.125, 0.25],
+        station_id: "1".to_owned(),
+        vel_time_latency: 0.0,
+        diff_age: 4.5,
+        sol_age: 0.0,
+        num_sats: 10,
+        num_sat_vehicles: 8,
+        num_gg_l1: 8,
+        num_multi_sats: 8,
+        ext_sol_stat: 0,
+        gal_beidou_sig: 0,
+        gps_glonass_sig: 3,
+    });
+
+    assert_eq!(oem.get_log().unwrap(), expected);
+}
+
+#[test]
+fn test_get_position_header() {
+    let mut mock = MockStream::default();
+
+    // Log output on virtual port COM2_1 with two more logs of its set to follow,
+    // while the receiver was 72.5% idle
+    mock.read.set_output(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0xF1, 0x0, 0x0, 0x41, 0x70, 0x0, 0x2, 0x0, 0x91, 0x78, 0xD1, 0xB,
+        0xB8, 0x6A, 0xC9, 0x9, 0x0, 0x0, 0x48, 0x0, 0xC1, 0xFA, 0xFA, 0x33, 0x1, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x4, 0xC3, 0x1, 0x28,
+    ]);
+
+    let oem = mock_new!(mock);
+
+    let log = match oem.get_log().unwrap() {
+        Log::BestXYZ(log) => log,
+        other => panic!("Unexpected log: {:?}", other),
+    };
+
+    assert_eq!(log.port_addr, 0x41);
+    assert_eq!(port_name(log.port_addr), "COM2_1");
+    assert_eq!(log.seq, 2);
+    assert_eq!(log.idle_time, 145);
+    assert_eq!(log.week, 3025);
+    assert_eq!(log.ms, 164195000);
+}
+
+#[test]
+fn test_port_name() {
+    assert_eq!(port_name(0x20), "COM1");
+    assert_eq!(port_name(0x5F), "COM2_31");
+    assert_eq!(port_name(0xC0), "THISPORT");
+    assert_eq!(port_name(0x01), "UNKNOWN");
+    assert_eq!(port_name(0x80), "UNKNOWN");
+}
@@ -0,0 +1,107 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+
+// RANGEB log containing two GPS observations
+static RANGE_LOG: [u8; 124] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x2B, 0x0, 0x0, 0x20, 0x5C, 0x0, 0x0, 0x0, 0x0, 0x78, 0xD1, 0xB, 0xB8,
+    0x6A, 0xC9, 0x9, 0x0, 0x0, 0x48, 0x0, 0x0, 0x0, 0x0, 0x0, 0x2, 0x0, 0x0, 0x0, 0xC, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0xE2, 0x58, 0x5B, 0x74, 0x41, 0x0, 0x0, 0x80, 0x3E, 0x0, 0x0, 0x0, 0x2E,
+    0x52, 0xBE, 0x9A, 0xC1, 0x0, 0x0, 0x0, 0x3C, 0x0, 0x50, 0x9A, 0xC4, 0x0, 0x0, 0x36, 0x42, 0x0,
+    0x48, 0x9A, 0x44, 0x4, 0x9C, 0x10, 0x8, 0x17, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x58, 0xC1, 0x5E,
+    0x76, 0x41, 0x0, 0x0, 0x0, 0x3F, 0x0, 0x0, 0x0, 0x9, 0x9B, 0x63, 0x9D, 0xC1, 0x0, 0x0, 0x80,
+    0x3C, 0x0, 0x9C, 0x12, 0x45, 0x0, 0x0, 0x19, 0x42, 0x0, 0xE0, 0xD, 0x44, 0x24, 0x9C, 0x10,
+    0x18, 0xA, 0x7F, 0x32, 0xD,
+];
+
+#[test]
+fn test_request_range_ontime() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0x2B, 0x0, 0x0,
+        0x0, 0x2, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xF0, 0x3F, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x7B, 0x5E, 0x8, 0xCD,
+    ]);
+
+    mock.read.set_output(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x80, 0x20, 0x6, 0x0, 0x0, 0x0, 0xFF, 0x78, 0xD1, 0xB,
+        0x6, 0x67, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0xFB, 0xFD, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x4F,
+        0x4B, 0x10, 0x9D, 0x33, 0xB1,
+    ]);
+
+    let oem = mock_new!(mock);
+
+    assert_eq!(oem.request_range(1.0, 0.0, false), Ok(()));
+}
+
+#[test]
+fn test_get_range() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(RANGE_LOG.to_vec());
+
+    let oem = mock_new!(mock);
+
+    let expected: Log = Log::Range(RangeLog {
+        recv_status: ReceiverStatusFlags::CLOCK_MODEL_INVALID
+            | ReceiverStatusFlags::POSITION_SOLUTION_INVALID,
+        time_status: 120,
+        week: 3025,
+        ms: 164195000,
+        observations: vec![
+            RangeObservation {
+                prn: 12,
+                glofreq: 0,
+                psr: 21345678.125,
+                psr_std: 0.25,
+                adr: -112170123.5,
+                adr_std: 0.0078125,
+                doppler: -1234.5,
+                cno: 45.5,
+                lock_time: 1234.25,
+                tracking_status: 0x08109C04,
+            },
+            RangeObservation {
+                prn: 23,
+                glofreq: 0,
+                psr: 23456789.5,
+                psr_std: 0.5,
+                adr: -123266754.25,
+                adr_std: 0.015625,
+                doppler: 2345.75,
+                cno: 38.25,
+                lock_time: 567.5,
+                tracking_status: 0x18109C24,
+            },
+        ],
+    });
+
+    assert_eq!(oem.get_log().unwrap(), expected);
+}
+
+#[test]
+fn test_range_truncated() {
+    // Drop the header, CRC, and the last few bytes of the second observation
+    let body = &RANGE_LOG[28..112];
+
+    assert_eq!(
+        RangeLog::new(ReceiverStatusFlags::empty(), 0, 0, 0, body),
+        None
+    );
+}
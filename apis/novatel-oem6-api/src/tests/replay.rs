@@ -0,0 +1,104 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+use std::env;
+use std::fs;
+use std::io::ErrorKind;
+use std::time::Duration;
+
+// Captured BestXYZ log with no position solution
+const POSITION_CAPTURE: [u8; 144] = [
+    0xAA, 0x44, 0x12, 0x1C, 0xF1, 0x0, 0x0, 0x20, 0x70, 0x0, 0x0, 0x0, 0x7C, 0x78, 0xD1, 0xB, 0xB8,
+    0x6A, 0xC9, 0x9, 0x0, 0x0, 0x48, 0x0, 0xC1, 0xFA, 0xFA, 0x33, 0x1, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x7A, 0x4C, 0xA9, 0xFD,
+];
+
+#[test]
+fn test_replay_read() {
+    let source = ReplaySource::new(vec![1, 2, 3, 4, 5, 6]);
+
+    assert_eq!(source.read(2, Duration::from_millis(0)), Ok(vec![1, 2]));
+    assert_eq!(source.read(3, Duration::from_millis(0)), Ok(vec![3, 4, 5]));
+    assert_eq!(source.remaining(), 1);
+
+    // Not enough data left, so the read looks like an idle port
+    match source.read(2, Duration::from_millis(0)) {
+        Err(UartError::IoError { cause, .. }) => assert_eq!(cause, ErrorKind::TimedOut),
+        other => panic!("Unexpected read result: {:?}", other),
+    }
+    assert_eq!(source.remaining(), 1);
+}
+
+#[test]
+fn test_replay_write_dropped() {
+    let source = ReplaySource::new(vec![]);
+
+    assert_eq!(source.write(&[0xAA, 0x44, 0x12]), Ok(()));
+}
+
+#[test]
+fn test_replay_from_file() {
+    let path = env::temp_dir().join("novatel-oem6-api-replay-test.bin");
+    fs::write(&path, &POSITION_CAPTURE[..]).unwrap();
+
+    let source = ReplaySource::from_file(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(source.remaining(), POSITION_CAPTURE.len());
+}
+
+#[test]
+fn test_replay_from_file_missing() {
+    let path = env::temp_dir().join("novatel-oem6-api-replay-missing.bin");
+
+    match ReplaySource::from_file(&path) {
+        Err(UartError::IoError { cause, .. }) => assert_eq!(cause, ErrorKind::NotFound),
+        Err(err) => panic!("Unexpected error: {:?}", err),
+        Ok(_) => panic!("Missing file was opened"),
+    }
+}
+
+#[test]
+fn test_replay_get_log() {
+    let (log_send, log_recv) = sync_channel(5);
+    let (response_send, response_recv) = sync_channel(5);
+    let (response_abbrv_send, response_abbrv_recv) = sync_channel(5);
+
+    let oem = OEM6::from_connection(
+        Connection::new(Box::new(ReplaySource::new(POSITION_CAPTURE.to_vec()))),
+        log_recv,
+        response_recv,
+        response_abbrv_recv,
+    );
+
+    let rx_conn = oem.conn.clone();
+    thread::spawn(move || read_thread(&rx_conn, &log_send, &response_send, &response_abbrv_send));
+
+    match oem.get_log().unwrap() {
+        Log::BestXYZ(log) => {
+            assert_eq!(log.week, 3025);
+            assert_eq!(log.ms, 164_195_000);
+            assert_eq!(log.pos_status, 1);
+        }
+        other => panic!("Unexpected log: {:?}", other),
+    }
+}
@@ -0,0 +1,98 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+
+#[test]
+fn test_request_rxconfig() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0x80, 0x0, 0x0,
+        0x0, 0x4, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x79, 0xAA, 0x10, 0xD7,
+    ]);
+
+    mock.read.set_output(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x80, 0x20, 0x6, 0x0, 0x0, 0x0, 0xFF, 0x78, 0xD1, 0xB,
+        0x6, 0x67, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0xFB, 0xFD, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x4F,
+        0x4B, 0x10, 0x9D, 0x33, 0xB1,
+    ]);
+
+    let oem = mock_new!(mock);
+
+    assert_eq!(oem.request_rxconfig(), Ok(()));
+}
+
+#[test]
+fn test_get_rxconfig_log() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x80, 0x0, 0x0, 0x20, 0x40, 0x0, 0x0, 0x0, 0x7C, 0x78, 0xD1, 0xB,
+        0xB8, 0x6A, 0xC9, 0x9, 0x0, 0x0, 0x48, 0x0, 0x0, 0x0, 0xFA, 0x33, 0xAA, 0x44, 0x12, 0x1C,
+        0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0xF1, 0x0, 0x0, 0x0, 0x2, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xF0, 0x3F, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0xB9, 0x54, 0x12, 0x6F, 0xF2, 0x83, 0x5A, 0x43,
+    ]);
+
+    let oem = mock_new!(mock);
+
+    let log = match oem.get_log().unwrap() {
+        Log::RxConfig(log) => log,
+        other => panic!("Unexpected log: {:?}", other),
+    };
+
+    assert_eq!(log.cmd_id, MessageID::Log);
+    assert_eq!(
+        log.log_request(),
+        Some(LogRequest {
+            port: 32,
+            msg_id: 241,
+            msg_type: 0,
+            trigger: 2,
+            period: 1.0,
+            offset: 0.0,
+            hold: false,
+        })
+    );
+    assert_eq!(log.log_request().unwrap().port_name(), "COM1");
+}
+
+#[test]
+fn test_get_rxconfig_other() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x80, 0x0, 0x0, 0x20, 0x28, 0x0, 0x0, 0x0, 0x7C, 0x78, 0xD1, 0xB,
+        0xB8, 0x6A, 0xC9, 0x9, 0x0, 0x0, 0x48, 0x0, 0x0, 0x0, 0xFA, 0x33, 0xAA, 0x44, 0x12, 0x1C,
+        0x24, 0x0, 0x0, 0xC0, 0x8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0xAE, 0x0, 0x0, 0x0, 0xF6, 0x89, 0xDE,
+        0x18, 0x64, 0x24, 0x9A, 0x10,
+    ]);
+
+    let oem = mock_new!(mock);
+
+    let log = match oem.get_log().unwrap() {
+        Log::RxConfig(log) => log,
+        other => panic!("Unexpected log: {:?}", other),
+    };
+
+    assert_eq!(log.cmd_id, MessageID::Unlog);
+    assert_eq!(log.log_request(), None);
+}
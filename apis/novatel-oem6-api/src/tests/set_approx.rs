@@ -0,0 +1,92 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+
+#[test]
+fn test_request_approx_position() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x79, 0x1, 0x0, 0xC0, 0x18, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x2, 0x2B, 0x87, 0x16, 0xD9, 0x8E,
+        0x49, 0x40, 0xDF, 0x4F, 0x8D, 0x97, 0x6E, 0x82, 0x5C, 0xC0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x60,
+        0x90, 0x40, 0x58, 0xA, 0xFD, 0xAB,
+    ]);
+
+    mock.read.set_output(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x79, 0x1, 0x80, 0x20, 0x6, 0x0, 0x0, 0x0, 0xFF, 0x78, 0xD1, 0xB,
+        0x6, 0x67, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0xFB, 0xFD, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x4F,
+        0x4B, 0x7A, 0xE8, 0xB7, 0x63,
+    ]);
+
+    let oem = mock_new!(mock);
+
+    assert_eq!(
+        oem.request_approx_position(51.116, -114.038, 1048.0),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_request_approx_position_fail() {
+    let mock = MockStream::default();
+
+    let oem = mock_new!(mock);
+
+    assert_eq!(
+        oem.request_approx_position(51.116, -114.038, 1048.0)
+            .unwrap_err(),
+        OEMError::UartError {
+            cause: UartError::GenericError,
+        }
+    );
+}
+
+#[test]
+fn test_request_approx_time() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x66, 0x0, 0x0, 0xC0, 0xC, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x8A, 0x7, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x74, 0x29, 0x1A, 0x41, 0x3D, 0x54, 0xC9, 0x94,
+    ]);
+
+    mock.read.set_output(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x66, 0x0, 0x80, 0x20, 0x6, 0x0, 0x0, 0x0, 0xFF, 0x78, 0xD1, 0xB,
+        0x6, 0x67, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0xFB, 0xFD, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x4F,
+        0x4B, 0x65, 0xBA, 0x8, 0xFD,
+    ]);
+
+    let oem = mock_new!(mock);
+
+    assert_eq!(oem.request_approx_time(1930, 428637.0), Ok(()));
+}
+
+#[test]
+fn test_request_approx_time_fail() {
+    let mock = MockStream::default();
+
+    let oem = mock_new!(mock);
+
+    assert_eq!(
+        oem.request_approx_time(1930, 428637.0).unwrap_err(),
+        OEMError::UartError {
+            cause: UartError::GenericError,
+        }
+    );
+}
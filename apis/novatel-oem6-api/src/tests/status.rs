@@ -0,0 +1,53 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+// Test decoding receiver status flags
+
+use crate::messages::ReceiverStatusFlags;
+
+#[test]
+fn error_descriptions_rf_overload() {
+    let flags = ReceiverStatusFlags::AGC_OUT_OF_RANGE;
+
+    assert_eq!(
+        flags.error_descriptions(),
+        vec!["RF input overload: AGC out of range. Check for interference or a faulty antenna"]
+    );
+}
+
+#[test]
+fn error_descriptions_multiple() {
+    let flags = ReceiverStatusFlags::TEMPERATURE_WARNING
+        | ReceiverStatusFlags::CLOCK_MODEL_INVALID
+        | ReceiverStatusFlags::COM1_BUFFER_OVERRUN;
+
+    assert_eq!(
+        flags.error_descriptions(),
+        vec![
+            "Receiver temperature is outside of its operating range",
+            "COM1 transmit buffer overrun. Log data has been lost",
+        ]
+    );
+}
+
+#[test]
+fn error_descriptions_informational() {
+    let flags = ReceiverStatusFlags::POSITION_SOLUTION_INVALID
+        | ReceiverStatusFlags::CLOCK_MODEL_INVALID
+        | ReceiverStatusFlags::AUX1_STATUS_EVENT;
+
+    assert!(flags.error_descriptions().is_empty());
+}
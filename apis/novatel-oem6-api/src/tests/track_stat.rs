@@ -0,0 +1,181 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+
+// TRACKSTATB log containing GPS (two signals from the same satellite), GLONASS and Galileo
+// channels, followed by an idle channel
+static TRACKSTAT_LOG: [u8; 248] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x53, 0x0, 0x0, 0x20, 0xD8, 0x0, 0x0, 0x0, 0x0, 0x78, 0xD1, 0xB, 0xB8,
+    0x6A, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x10, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0xA0, 0x40, 0x5, 0x0, 0x0, 0x0, 0xC, 0x0, 0x0, 0x0, 0x4, 0x9C, 0x10, 0x8, 0x0,
+    0x0, 0x0, 0xE2, 0x58, 0x5B, 0x74, 0x41, 0x0, 0x50, 0x9A, 0xC4, 0x0, 0x0, 0x36, 0x42, 0x0, 0x48,
+    0x9A, 0x44, 0x0, 0x0, 0xC0, 0x3F, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x80, 0x3E, 0xC, 0x0, 0x0, 0x0,
+    0x24, 0x9C, 0x30, 0x1, 0x0, 0x0, 0x0, 0x8, 0x59, 0x5B, 0x74, 0x41, 0x0, 0x50, 0x70, 0xC4, 0x0,
+    0x0, 0x18, 0x42, 0x0, 0x0, 0x96, 0x44, 0x0, 0x0, 0x0, 0x0, 0x11, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x2D, 0x0, 0x8, 0x0, 0x44, 0x9C, 0x11, 0x8, 0x0, 0x0, 0x0, 0xF4, 0xAB, 0xF4, 0x72, 0x41,
+    0x0, 0x28, 0xA, 0x45, 0x0, 0x0, 0x29, 0x42, 0x0, 0xC0, 0xA0, 0x43, 0x0, 0x0, 0x20, 0xC0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x3E, 0xB, 0x0, 0x0, 0x0, 0x64, 0x9C, 0x13, 0x8, 0x0, 0x0, 0x0,
+    0x2C, 0x5, 0x6E, 0x77, 0x41, 0x0, 0x0, 0x0, 0x44, 0x0, 0x0, 0x22, 0x42, 0x0, 0x0, 0xB0, 0x42,
+    0x0, 0x0, 0x0, 0x0, 0x9, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xC, 0x64, 0xE4, 0x18,
+];
+
+#[test]
+fn test_request_track_stat_ontime() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0x53, 0x0, 0x0,
+        0x0, 0x2, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xF0, 0x3F, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xA3, 0x83, 0x38, 0x80,
+    ]);
+
+    mock.read.set_output(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x80, 0x20, 0x6, 0x0, 0x0, 0x0, 0xFF, 0x78, 0xD1, 0xB,
+        0x6, 0x67, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0xFB, 0xFD, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x4F,
+        0x4B, 0x10, 0x9D, 0x33, 0xB1,
+    ]);
+
+    let oem = mock_new!(mock);
+
+    assert_eq!(oem.request_track_stat(1.0, 0.0, false), Ok(()));
+}
+
+#[test]
+fn test_get_track_stat() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(TRACKSTAT_LOG.to_vec());
+
+    let oem = mock_new!(mock);
+
+    let log = match oem.get_log().unwrap() {
+        Log::TrackStat(log) => log,
+        other => panic!("Unexpected log: {:?}", other),
+    };
+
+    assert_eq!(log.week, 3025);
+    assert_eq!(log.ms, 164195000);
+    assert_eq!(log.sol_status, 0);
+    assert_eq!(log.pos_type, 16);
+    assert_eq!(log.cutoff, 5.0);
+    assert_eq!(log.channels.len(), 5);
+    assert_eq!(
+        log.channels[2],
+        TrackStatChannel {
+            prn: 45,
+            glofreq: 8,
+            tracking_status: 0x08119C44,
+            psr: 19876543.25,
+            doppler: 2210.5,
+            cno: 42.25,
+            lock_time: 321.5,
+            psr_residual: -2.5,
+            reject: 0,
+            psr_weight: 0.125,
+        }
+    );
+}
+
+#[test]
+fn test_track_stat_mixed_constellations() {
+    let body = &TRACKSTAT_LOG[28..244];
+    let log = TrackStatLog::new(ReceiverStatusFlags::empty(), 0, 0, 0, body).unwrap();
+
+    let summary: Vec<_> = log
+        .channels
+        .iter()
+        .map(|chan| {
+            (
+                chan.prn,
+                chan.system(),
+                chan.is_tracking(),
+                chan.used_in_solution(),
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        summary,
+        vec![
+            (12, Some(Constellation::Gps), true, true),
+            (12, Some(Constellation::Gps), true, false),
+            (45, Some(Constellation::Glonass), true, true),
+            (11, Some(Constellation::Galileo), true, false),
+            (0, Some(Constellation::Gps), false, false),
+        ]
+    );
+}
+
+#[test]
+fn test_track_stat_channel_state() {
+    let body = &TRACKSTAT_LOG[28..244];
+    let log = TrackStatLog::new(ReceiverStatusFlags::empty(), 0, 0, 0, body).unwrap();
+
+    let summary: Vec<_> = log
+        .channels
+        .iter()
+        .map(|chan| (chan.prn, chan.cno, chan.tracking_state()))
+        .collect();
+
+    assert_eq!(
+        summary,
+        vec![
+            (12, 45.5, Some(TrackingState::PhaseLockLoop)),
+            (12, 38.0, Some(TrackingState::PhaseLockLoop)),
+            (45, 42.25, Some(TrackingState::PhaseLockLoop)),
+            (11, 40.5, Some(TrackingState::PhaseLockLoop)),
+            (0, 0.0, Some(TrackingState::Idle)),
+        ]
+    );
+}
+
+#[test]
+fn test_tracking_state_values() {
+    assert_eq!(TrackingState::from_u32(1), Some(TrackingState::SkySearch));
+    assert_eq!(
+        TrackingState::from_u32(11),
+        Some(TrackingState::AidedPhaseLockLoop)
+    );
+    assert_eq!(TrackingState::from_u32(5), None);
+    assert_eq!(TrackingState::from_u32(0x1F), None);
+}
+
+#[test]
+fn test_track_stat_truncated() {
+    let body = &TRACKSTAT_LOG[28..200];
+
+    assert_eq!(
+        TrackStatLog::new(ReceiverStatusFlags::empty(), 0, 0, 0, body),
+        None
+    );
+}
+
+#[test]
+fn test_tracking_status_system() {
+    assert_eq!(
+        Constellation::from_tracking_status(0x08109C04),
+        Some(Constellation::Gps)
+    );
+    assert_eq!(
+        Constellation::from_tracking_status(0x00049C04),
+        Some(Constellation::Beidou)
+    );
+    assert_eq!(Constellation::from_tracking_status(0x00079C04), None);
+}
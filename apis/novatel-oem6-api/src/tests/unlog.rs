@@ -38,6 +38,27 @@ fn test_unlog() {
     assert_eq!(oem.request_unlog(MessageID::BestXYZ), Ok(()));
 }
 
+#[test]
+fn test_unlog_velocity() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x24, 0x0, 0x0, 0xC0, 0x8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0x63, 0x0, 0x0,
+        0x0, 0x2D, 0xBF, 0xF9, 0x9C,
+    ]);
+
+    mock.read.set_output(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x24, 0x0, 0x80, 0x20, 0x6, 0x0, 0x0, 0x0, 0xFF, 0x78, 0xD1, 0xB,
+        0x85, 0x6F, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0xFB, 0xFD, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x4F,
+        0x4B, 0x41, 0xFE, 0xF5, 0x3F,
+    ]);
+
+    let oem = mock_new!(mock);
+
+    assert_eq!(oem.request_unlog(MessageID::BestVel), Ok(()));
+}
+
 #[test]
 fn test_unlog_all() {
     let mut mock = MockStream::default();
@@ -79,3 +100,27 @@ fn test_unlog_all_override() {
 
     assert_eq!(oem.request_unlog_all(true), Ok(()));
 }
+
+#[test]
+fn test_unlog_on_port() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x24, 0x0, 0x0, 0xC0, 0x8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x40, 0x0, 0x0, 0x0, 0x65, 0x0, 0x0,
+        0x0, 0x4A, 0x40, 0x79, 0x69,
+    ]);
+
+    mock.read.set_output(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x24, 0x0, 0x80, 0x20, 0x6, 0x0, 0x0, 0x0, 0xFF, 0x78, 0xD1, 0xB,
+        0x85, 0x6F, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0xFB, 0xFD, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x4F,
+        0x4B, 0x41, 0xFE, 0xF5, 0x3F,
+    ]);
+
+    let oem = mock_new!(mock);
+
+    assert_eq!(
+        oem.request_unlog_on_port(Port::COM2, MessageID::Time),
+        Ok(())
+    );
+}
@@ -147,8 +147,101 @@ fn test_get_version() {
             boot_version: "OEM060201RB0000".to_owned(),
             compile_date: "2015/Jan/28".to_owned(),
             compile_time: "15:27:29".to_owned(),
+            valid: true,
         }],
     });
 
     assert_eq!(oem.get_log().unwrap(), expected);
 }
+
+#[test]
+fn test_get_version_corrupt_component() {
+    let mut mock = MockStream::default();
+
+    // Three components, where the second has a model number which isn't valid UTF-8
+    mock.read.set_output(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x25, 0x0, 0x0, 0x20, 0x48, 0x1, 0x0, 0x0, 0x7D, 0x78, 0xD1, 0xB,
+        0x38, 0x5E, 0xC9, 0x9, 0x0, 0x0, 0x48, 0x0, 0x81, 0x36, 0xFA, 0x33, 0x3, 0x0, 0x0, 0x0,
+        0x1, 0x0, 0x0, 0x0, 0x47, 0x31, 0x53, 0x42, 0x30, 0x47, 0x54, 0x54, 0x30, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x42, 0x4A, 0x59, 0x41, 0x31, 0x35, 0x31, 0x32, 0x30, 0x30, 0x33, 0x38,
+        0x48, 0x0, 0x0, 0x0, 0x4F, 0x45, 0x4D, 0x36, 0x31, 0x35, 0x2D, 0x32, 0x2E, 0x30, 0x30, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x4F, 0x45, 0x4D, 0x30, 0x36, 0x30, 0x36, 0x30, 0x30, 0x52, 0x4E, 0x30,
+        0x30, 0x30, 0x30, 0x0, 0x4F, 0x45, 0x4D, 0x30, 0x36, 0x30, 0x32, 0x30, 0x31, 0x52, 0x42,
+        0x30, 0x30, 0x30, 0x30, 0x0, 0x32, 0x30, 0x31, 0x35, 0x2F, 0x4A, 0x61, 0x6E, 0x2F, 0x32,
+        0x38, 0x0, 0x31, 0x35, 0x3A, 0x32, 0x37, 0x3A, 0x32, 0x39, 0x0, 0x0, 0x0, 0x0, 0x4, 0x0,
+        0x0, 0x0, 0xFF, 0xFE, 0xFD, 0xFC, 0x30, 0x47, 0x54, 0x54, 0x30, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x42, 0x4A, 0x59, 0x41, 0x31, 0x35, 0x31, 0x32, 0x30, 0x30, 0x33, 0x38, 0x48,
+        0x0, 0x0, 0x0, 0x4F, 0x45, 0x4D, 0x36, 0x31, 0x35, 0x2D, 0x32, 0x2E, 0x30, 0x30, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x4F, 0x45, 0x4D, 0x30, 0x36, 0x30, 0x36, 0x30, 0x30, 0x52, 0x4E, 0x30,
+        0x30, 0x30, 0x30, 0x0, 0x4F, 0x45, 0x4D, 0x30, 0x36, 0x30, 0x32, 0x30, 0x31, 0x52, 0x42,
+        0x30, 0x30, 0x30, 0x30, 0x0, 0x32, 0x30, 0x31, 0x35, 0x2F, 0x4A, 0x61, 0x6E, 0x2F, 0x32,
+        0x38, 0x0, 0x31, 0x35, 0x3A, 0x32, 0x37, 0x3A, 0x32, 0x39, 0x0, 0x0, 0x0, 0x0, 0x8, 0x0,
+        0x0, 0x0, 0x47, 0x31, 0x53, 0x42, 0x30, 0x47, 0x54, 0x54, 0x30, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x42, 0x4A, 0x59, 0x41, 0x31, 0x35, 0x31, 0x32, 0x30, 0x30, 0x33, 0x38, 0x48,
+        0x0, 0x0, 0x0, 0x4F, 0x45, 0x4D, 0x36, 0x31, 0x35, 0x2D, 0x32, 0x2E, 0x30, 0x30, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x4F, 0x45, 0x4D, 0x30, 0x36, 0x30, 0x36, 0x30, 0x30, 0x52, 0x4E, 0x30,
+        0x30, 0x30, 0x30, 0x0, 0x4F, 0x45, 0x4D, 0x30, 0x36, 0x30, 0x32, 0x30, 0x31, 0x52, 0x42,
+        0x30, 0x30, 0x30, 0x30, 0x0, 0x32, 0x30, 0x31, 0x35, 0x2F, 0x4A, 0x61, 0x6E, 0x2F, 0x32,
+        0x38, 0x0, 0x31, 0x35, 0x3A, 0x32, 0x37, 0x3A, 0x32, 0x39, 0x0, 0x0, 0x0, 0x0, 0x3E, 0xC0,
+        0x43, 0x38,
+    ]);
+
+    let oem = mock_new!(mock);
+
+    let log = match oem.get_log().unwrap() {
+        Log::Version(log) => log,
+        other => panic!("Unexpected log: {:?}", other),
+    };
+
+    let good = Component {
+        comp_type: 1,
+        model: "G1SB0GTT0".to_owned(),
+        serial_num: "BJYA15120038H".to_owned(),
+        hw_version: "OEM615-2.00".to_owned(),
+        sw_version: "OEM060600RN0000".to_owned(),
+        boot_version: "OEM060201RB0000".to_owned(),
+        compile_date: "2015/Jan/28".to_owned(),
+        compile_time: "15:27:29".to_owned(),
+        valid: true,
+    };
+
+    assert_eq!(log.num_components, 3);
+    assert_eq!(
+        log.components,
+        vec![
+            good.clone(),
+            Component {
+                comp_type: 4,
+                model: "\u{FFFD}\u{FFFD}\u{FFFD}\u{FFFD}0GTT0".to_owned(),
+                valid: false,
+                ..good.clone()
+            },
+            Component {
+                comp_type: 8,
+                ..good
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_get_version_truncated_component() {
+    let mut raw = vec![0; 4];
+    raw[0] = 2;
+    raw.extend_from_slice(&[0x1, 0x0, 0x0, 0x0]);
+    raw.extend_from_slice(b"G1SB0GTT0\0\0\0\0\0\0\0BJYA");
+
+    let log = VersionLog::new(ReceiverStatusFlags::empty(), 120, 3025, 164191800, raw).unwrap();
+
+    assert_eq!(log.num_components, 2);
+    assert_eq!(
+        log.components,
+        vec![Component {
+            comp_type: 1,
+            model: "G1SB0GTT0".to_owned(),
+            serial_num: "BJYA".to_owned(),
+            valid: false,
+            ..Default::default()
+        }]
+    );
+}
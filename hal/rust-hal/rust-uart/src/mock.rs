@@ -20,15 +20,45 @@
 //! functions, [Double](https://github.com/DonaldWhyte/double), was written specifically
 //! for single-threaded code. Our APIs and services which utilize this crate are not
 //! guaranteed to be single-threaded, so we made the decision to create our own mock objects.
+//!
+//! Any data passed to `WriteStruct::set_input` which is never written will not, by itself,
+//! cause a test to fail. Tests which verify transmitted data should grab a [`MockTracker`]
+//! before handing the mock to the code under test, and then call `assert_consumed` once
+//! the code under test has finished. This catches commands which are shorter than expected
+//! or which were never sent at all.
+//!
+//! ```
+//! use rust_uart::*;
+//! use rust_uart::mock::*;
+//!
+//! let mut mock = MockStream::default();
+//!
+//! mock.write.set_input(vec![0, 1, 2, 3]);
+//!
+//! let tracker = mock.tracker();
+//!
+//! let connection = Connection {
+//!     stream: Box::new(mock),
+//! };
+//!
+//! connection.write(&[0, 1, 2, 3]).unwrap();
+//!
+//! tracker.assert_consumed();
+//! ```
+//!
+//! [`MockTracker`]: struct.MockTracker.html
 
 use super::*;
 use std::collections::VecDeque;
 use std::io::Cursor;
+use std::sync::{Arc, Mutex};
+
+type InputQueue = Arc<Mutex<VecDeque<Vec<u8>>>>;
 
 /// Structure containing the input data to verify and/or result to return
 /// when the MockStream's write function is called
 pub struct WriteStruct {
-    input: RefCell<VecDeque<Vec<u8>>>,
+    input: InputQueue,
     result: UartResult<()>,
 }
 
@@ -96,7 +126,7 @@ impl WriteStruct {
     /// }
     /// ```
     pub fn set_input(&mut self, input: Vec<u8>) {
-        self.input.borrow_mut().push_back(input)
+        lock_input(&self.input).push_back(input)
     }
 }
 
@@ -104,6 +134,7 @@ impl WriteStruct {
 /// when the MockStream's read function is called
 pub struct ReadStruct {
     output: Option<RefCell<Cursor<Vec<u8>>>>,
+    chunks: Option<RefCell<VecDeque<Vec<u8>>>>,
     result: UartResult<Vec<u8>>,
 }
 
@@ -181,6 +212,52 @@ impl ReadStruct {
     pub fn set_output(&mut self, output: Vec<u8>) {
         self.output = Some(RefCell::new(Cursor::new(output)))
     }
+
+    /// Set the output data as a series of chunks
+    ///
+    /// Each chunk simulates a fragment of data arriving from the UART port.
+    /// A read() call which requests more data than remains in the current chunk
+    /// will consume the following chunks until it has been satisfied, so a single
+    /// logical frame may be split across multiple chunks and a single chunk may be
+    /// split across multiple read() calls.
+    ///
+    /// Note: This will be ignored if set_output is also used
+    ///
+    /// # Arguments
+    ///
+    /// * chunks - The output fragments which future read() calls should
+    ///            retrieve data from, in order of arrival
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_uart::*;
+    /// use rust_uart::mock::*;
+    /// use std::time::Duration;
+    ///
+    /// fn test_read_good_chunked() {
+    ///     let mut mock = MockStream::default();
+    ///
+    ///     mock.read
+    ///         .set_output_chunked(vec![vec![0, 1], vec![2, 3, 4], vec![5]]);
+    ///
+    ///     let connection = Connection {
+    ///         stream: Box::new(mock),
+    ///     };
+    ///
+    ///     assert_eq!(
+    ///         connection.read(4, Duration::new(0, 0)).unwrap(),
+    ///         vec![0, 1, 2, 3]
+    ///     );
+    ///     assert_eq!(
+    ///         connection.read(2, Duration::new(0, 0)).unwrap(),
+    ///         vec![4, 5]
+    ///     );
+    /// }
+    /// ```
+    pub fn set_output_chunked(&mut self, chunks: Vec<Vec<u8>>) {
+        self.chunks = Some(RefCell::new(chunks.into_iter().collect()))
+    }
 }
 
 /// Mock object for simulating a UART data stream
@@ -191,16 +268,57 @@ pub struct MockStream {
     pub read: ReadStruct,
 }
 
+impl MockStream {
+    /// Panic if any data passed to `write.set_input` has not been written
+    ///
+    /// See [`MockTracker::assert_consumed`](struct.MockTracker.html#method.assert_consumed)
+    pub fn assert_consumed(&self) {
+        self.tracker().assert_consumed()
+    }
+
+    /// Get a handle which can be used to check the state of this mock's write queue
+    /// after the mock has been moved into a `Connection`
+    pub fn tracker(&self) -> MockTracker {
+        MockTracker {
+            input: self.write.input.clone(),
+        }
+    }
+}
+
+/// Handle for checking a `MockStream`'s write queue after the mock has been
+/// handed to the code under test
+pub struct MockTracker {
+    input: InputQueue,
+}
+
+impl MockTracker {
+    /// Panic if any data passed to `write.set_input` has not been written
+    ///
+    /// Every queued entry, including empty entries used to simulate write failures,
+    /// must have been matched by a write() call
+    pub fn assert_consumed(&self) {
+        let input = lock_input(&self.input);
+
+        assert!(
+            input.is_empty(),
+            "MockStream has {} unconsumed write input(s): {:?}",
+            input.len(),
+            input
+        );
+    }
+}
+
 impl Default for MockStream {
     fn default() -> Self {
         MockStream {
             write: WriteStruct {
                 result: Err(UartError::GenericError),
-                input: RefCell::new(VecDeque::new()),
+                input: Arc::new(Mutex::new(VecDeque::new())),
             },
             read: ReadStruct {
                 result: Err(UartError::GenericError),
                 output: None,
+                chunks: None,
             },
         }
     }
@@ -208,17 +326,15 @@ impl Default for MockStream {
 
 impl Stream for MockStream {
     fn write(&self, data: &[u8]) -> UartResult<()> {
-        if self.write.input.borrow_mut().is_empty() {
-            self.write.result.clone()
-        } else {
-            let input = self.write.input.borrow_mut().pop_front().unwrap();
-            if input.is_empty() {
-                self.write.result.clone()
-            } else {
+        let input = lock_input(&self.write.input).pop_front();
+
+        match input {
+            Some(ref input) if !input.is_empty() => {
                 //Verify input matches data
                 assert_eq!(input.as_slice(), data);
                 Ok(())
             }
+            _ => self.write.result.clone(),
         }
     }
 
@@ -233,14 +349,45 @@ impl Stream for MockStream {
                     // but, in reality, our UART stream would throw a timeout
                     // error when it was unable to read the requested number of
                     // bytes within the timeout period
-                    Err(UartError::from(std::io::Error::new(
-                        std::io::ErrorKind::TimedOut,
-                        "Operation timed out",
-                    )))
+                    Err(timeout_error())
+                }
+            }
+        } else if let Some(ref chunks) = self.read.chunks {
+            let mut chunks = chunks.borrow_mut();
+            let mut response: Vec<u8> = Vec::with_capacity(len);
+
+            // Keep pulling in fragments until we have the requested number of bytes,
+            // the same way the real UART stream reassembles partial reads
+            while response.len() < len {
+                let mut chunk = match chunks.pop_front() {
+                    Some(chunk) => chunk,
+                    None => return Err(timeout_error()),
+                };
+
+                let needed = len - response.len();
+                if chunk.len() > needed {
+                    chunks.push_front(chunk.split_off(needed));
                 }
+
+                response.append(&mut chunk);
             }
+
+            Ok(response)
         } else {
             self.read.result.clone()
         }
     }
 }
+
+// A failed assertion while the queue is locked will poison the mutex,
+// but the queue itself is still perfectly usable
+fn lock_input(input: &InputQueue) -> std::sync::MutexGuard<VecDeque<Vec<u8>>> {
+    input.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+fn timeout_error() -> UartError {
+    UartError::from(std::io::Error::new(
+        std::io::ErrorKind::TimedOut,
+        "Operation timed out",
+    ))
+}
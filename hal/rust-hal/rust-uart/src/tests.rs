@@ -242,3 +242,80 @@ fn test_read_good_multi() {
         vec![3, 4, 5]
     );
 }
+
+#[test]
+fn test_read_good_chunked() {
+    let mut mock = MockStream::default();
+
+    mock.read
+        .set_output_chunked(vec![vec![0, 1], vec![2, 3, 4], vec![5]]);
+
+    let connection = Connection {
+        stream: Box::new(mock),
+    };
+
+    assert_eq!(connection.read(1, Duration::new(0, 0)).unwrap(), vec![0]);
+    assert_eq!(
+        connection.read(4, Duration::new(0, 0)).unwrap(),
+        vec![1, 2, 3, 4]
+    );
+    assert_eq!(connection.read(1, Duration::new(0, 0)).unwrap(), vec![5]);
+}
+
+#[test]
+fn test_read_bad_chunked() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output_chunked(vec![vec![0, 1, 2], vec![3]]);
+
+    let connection = Connection {
+        stream: Box::new(mock),
+    };
+
+    assert_eq!(connection.read(2, Duration::new(0, 0)).unwrap(), vec![0, 1]);
+    assert_eq!(
+        connection.read(3, Duration::new(0, 0)).unwrap_err(),
+        UartError::IoError {
+            cause: std::io::ErrorKind::TimedOut,
+            description: "Operation timed out".to_owned(),
+        }
+    );
+}
+
+#[test]
+fn test_write_consumed() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![0, 1, 2, 3]);
+    mock.write.set_input(vec![4, 5]);
+
+    let tracker = mock.tracker();
+
+    let connection = Connection {
+        stream: Box::new(mock),
+    };
+
+    assert_eq!(connection.write(&[0, 1, 2, 3]), Ok(()));
+    assert_eq!(connection.write(&[4, 5]), Ok(()));
+
+    tracker.assert_consumed();
+}
+
+#[test]
+#[should_panic(expected = "MockStream has 1 unconsumed write input(s)")]
+fn test_write_not_consumed() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![0, 1, 2, 3]);
+    mock.write.set_input(vec![4, 5]);
+
+    let tracker = mock.tracker();
+
+    let connection = Connection {
+        stream: Box::new(mock),
+    };
+
+    assert_eq!(connection.write(&[0, 1, 2, 3]), Ok(()));
+
+    tracker.assert_consumed();
+}
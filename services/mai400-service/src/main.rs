@@ -31,6 +31,17 @@
 //!
 //! Where `ip` specifies the service's IP address, and `port` specifies the port which UDP requests should be sent to.
 //!
+//! The following optional fields may also be specified in the `[mai400-service]` section to control how many
+//! consecutive identical samples a sensor field must report before it is flagged by the `stuckFields` query.
+//! Each must be a positive number and defaults to 20 samples (five seconds of telemetry):
+//!
+//! ```toml
+//! [mai400-service]
+//! stuck_gyro = 20
+//! stuck_accel = 20
+//! stuck_mag = 20
+//! ```
+//!
 //! # Starting the Service
 //!
 //! The service should be started automatically by its init script, but may also be started manually:
@@ -266,6 +277,17 @@
 //! }
 //! ```
 //!
+//! ## Stuck Fields
+//!
+//! Get the list of sensor fields which have reported an identical value for at least
+//! as many consecutive samples as their configured threshold, indicating a likely frozen sensor
+//!
+//! ```json
+//! {
+//!     stuckFields: [String]
+//! }
+//! ```
+//!
 //! # Mutations
 //!
 //! ## Errors
@@ -351,6 +373,8 @@
 //!
 //! - command: String containing the hex values to be sent (ex. "C3").
 //!          It will be converted to a byte array before transfer.
+//!          The command must be exactly 40 bytes (80 hex characters) long,
+//!          including the sync and checksum bytes.
 //!
 //! ```json
 //! mutation {
@@ -415,25 +439,50 @@ mod schema;
 #[cfg(test)]
 mod tests;
 
-use crate::model::{ReadData, Subsystem};
+use crate::model::{ReadData, StuckThresholds, Subsystem};
 pub use crate::objects::*;
 use crate::schema::{MutationRoot, QueryRoot};
 use kubos_service::{Config, Logger, Service};
 use log::error;
-use mai400_api::MAIResult;
+use mai400_api::{MAIError, MAIResult};
 use std::sync::Arc;
 
 fn main() -> MAIResult<()> {
     Logger::init("mai400-service").unwrap();
 
+    let config = Config::new("mai400-service")
+        .map_err(|err| {
+            error!("Failed to load service config: {:?}", err);
+            err
+        })
+        .unwrap();
+
+    let defaults = StuckThresholds::default();
+    let threshold =
+        |name: &str, default: u32| match config.get(name).and_then(|val| val.as_integer()) {
+            Some(val) if val < 1 || val > i64::from(u32::max_value()) => {
+                error!(
+                    "Invalid '{}' config value {}. Must be a positive number of samples",
+                    name, val
+                );
+                Err(MAIError::GenericError)
+            }
+            Some(val) => Ok(val as u32),
+            None => Ok(default),
+        };
+    let thresholds = StuckThresholds {
+        gyro: threshold("stuck_gyro", defaults.gyro)?,
+        accel: threshold("stuck_accel", defaults.accel)?,
+        mag: threshold("stuck_mag", defaults.mag)?,
+    };
+
     Service::new(
-        Config::new("mai400-service")
-            .map_err(|err| {
-                error!("Failed to load service config: {:?}", err);
-                err
-            })
-            .unwrap(),
-        Subsystem::new("/dev/ttyS5", Arc::new(ReadData::new())).map_err(|err| {
+        config,
+        Subsystem::new(
+            "/dev/ttyS5",
+            Arc::new(ReadData::with_thresholds(thresholds)),
+        )
+        .map_err(|err| {
             error!("Failed to initialize subsystem: {:?}", err);
             err
         })?,
@@ -16,8 +16,9 @@
 
 use failure::{bail, Error};
 use kubos_service::{process_errors, push_err, run};
-use log::{error, info};
+use log::{error, info, warn};
 use mai400_api::*;
+use std::collections::HashMap;
 use std::sync::mpsc::channel;
 use std::sync::mpsc::{Receiver, Sender, TryRecvError};
 use std::sync::{Arc, Mutex, RwLock};
@@ -26,24 +27,120 @@ use std::time::Duration;
 
 use crate::objects::*;
 
+// Number of consecutive identical samples after which a telemetry field
+// is considered to be stuck. The MAI-400 sends telemetry every 250ms,
+// so the default of 20 samples equates to five seconds.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StuckThresholds {
+    pub gyro: u32,
+    pub accel: u32,
+    pub mag: u32,
+}
+
+impl Default for StuckThresholds {
+    fn default() -> Self {
+        StuckThresholds {
+            gyro: 20,
+            accel: 20,
+            mag: 20,
+        }
+    }
+}
+
+struct StuckCounter {
+    last: i16,
+    count: u32,
+    threshold: u32,
+}
+
+// Tracks how many consecutive samples each sensor field has reported the
+// same value for. A frozen sensor will usually keep reporting its last
+// reading, whereas a live one will always have some amount of noise.
+pub struct StuckDetector {
+    thresholds: StuckThresholds,
+    counters: HashMap<String, StuckCounter>,
+}
+
+impl StuckDetector {
+    pub fn new(thresholds: StuckThresholds) -> Self {
+        StuckDetector {
+            thresholds,
+            counters: HashMap::new(),
+        }
+    }
+
+    fn check(&mut self, name: String, value: i16, threshold: u32) {
+        let counter = self.counters.entry(name).or_insert(StuckCounter {
+            last: value,
+            count: 0,
+            threshold,
+        });
+
+        if counter.last == value {
+            counter.count = counter.count.saturating_add(1);
+        } else {
+            counter.last = value;
+            counter.count = 1;
+        }
+    }
+
+    pub fn update_std(&mut self, telem: &StandardTelemetry) {
+        let threshold = self.thresholds.mag;
+        for (axis, value) in ["x", "y", "z"].iter().zip(telem.i_b_field_meas.iter()) {
+            self.check(format!("i_b_field_meas_{}", axis), *value, threshold);
+        }
+    }
+
+    pub fn update_imu(&mut self, imu: &RawIMU) {
+        let threshold = self.thresholds.gyro;
+        for (axis, value) in ["x", "y", "z"].iter().zip(imu.gyro.iter()) {
+            self.check(format!("gyro_{}", axis), *value, threshold);
+        }
+
+        let threshold = self.thresholds.accel;
+        for (axis, value) in ["x", "y", "z"].iter().zip(imu.accel.iter()) {
+            self.check(format!("accel_{}", axis), *value, threshold);
+        }
+    }
+
+    pub fn stuck_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self
+            .counters
+            .iter()
+            .filter(|(_, counter)| counter.count >= counter.threshold)
+            .map(|(name, _)| name.clone())
+            .collect();
+        fields.sort();
+        fields
+    }
+}
+
 pub struct ReadData {
     pub std_telem: Mutex<StandardTelemetry>,
     pub irehs_telem: Mutex<IREHSTelemetry>,
     pub imu: Mutex<RawIMU>,
     pub rotating: Mutex<RotatingTelemetry>,
+    pub stuck: Mutex<StuckDetector>,
 }
 
 impl ReadData {
     pub fn new() -> ReadData {
+        ReadData::with_thresholds(StuckThresholds::default())
+    }
+
+    pub fn with_thresholds(thresholds: StuckThresholds) -> ReadData {
         ReadData {
             std_telem: Mutex::new(StandardTelemetry::default()),
             irehs_telem: Mutex::new(IREHSTelemetry::default()),
             imu: Mutex::new(RawIMU::default()),
             rotating: Mutex::new(RotatingTelemetry::default()),
+            stuck: Mutex::new(StuckDetector::new(thresholds)),
         }
     }
 
     pub fn update_std(&self, telem: StandardTelemetry) {
+        self.update_stuck(|stuck| stuck.update_std(&telem));
+
         {
             let mut local = self.std_telem.lock().unwrap();
             *local = telem.clone();
@@ -59,9 +156,24 @@ impl ReadData {
     }
 
     pub fn update_imu(&self, imu: RawIMU) {
+        self.update_stuck(|stuck| stuck.update_imu(&imu));
+
         let mut local = self.imu.lock().unwrap();
         *local = imu;
     }
+
+    fn update_stuck<F: FnOnce(&mut StuckDetector)>(&self, update: F) {
+        let mut stuck = self.stuck.lock().unwrap();
+        let before = stuck.stuck_fields();
+
+        update(&mut stuck);
+
+        for field in stuck.stuck_fields() {
+            if !before.contains(&field) {
+                warn!("Telemetry field {} appears to be stuck", field);
+            }
+        }
+    }
 }
 
 // The MAI-400 sends a set of telemtery messages every 250ms
@@ -95,6 +207,12 @@ pub fn read_thread(mai: MAI400, data: Arc<ReadData>, sender: Sender<String>) {
                         sleep(Duration::from_millis(100));
                         err_count += 1;
                     }
+                    MAIError::ChecksumMismatch { .. }
+                    | MAIError::FramingError { .. }
+                    | MAIError::SyncLost => {
+                        // A corrupted message set or a noisy line. Drop it and wait for the next one
+                        warn!("Dropping telemetry: {}", err);
+                    }
                     _ => {
                         let errors = process_errors!(err);
                         error!(
@@ -237,6 +355,10 @@ impl Subsystem {
         })
     }
 
+    pub fn get_stuck_fields(&self) -> Result<Vec<String>, Error> {
+        Ok(self.persistent.stuck.lock().unwrap().stuck_fields())
+    }
+
     pub fn get_mode(&self) -> Result<Mode, Error> {
         let raw = match self.persistent.std_telem.lock() {
             Ok(telem) => telem.acs_mode,
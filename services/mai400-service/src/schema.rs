@@ -256,6 +256,16 @@ graphql_object!(QueryRoot: Context as "Query" |&self| {
     field spin(&executor) -> FieldResult<Spin> {
         Ok(executor.context().subsystem().get_spin()?)
     }
+
+    // Get the list of sensor fields which have reported an identical value for at least
+    // as many consecutive samples as their configured threshold, indicating a likely frozen sensor
+    //
+    // {
+    //     stuckFields: [String]
+    // }
+    field stuck_fields(&executor) -> FieldResult<Vec<String>> {
+        Ok(executor.context().subsystem().get_stuck_fields()?)
+    }
 });
 
 pub struct MutationRoot;
@@ -28,6 +28,7 @@ macro_rules! service_new {
             irehs_telem: Mutex::new(IREHS),
             imu: Mutex::new(IMU),
             rotating: Mutex::new(ROTATING),
+            stuck: Mutex::new(StuckDetector::new(StuckThresholds::default())),
         });
         service_new!($mock, data)
     }};
@@ -39,11 +40,12 @@ macro_rules! service_new {
 
         let (sender, receiver) = channel();
 
-        let mai = MAI400 {
-            conn: Arc::new(Mutex::new(Connection {
+        let mai = MAI400Builder::new()
+            .connection(Connection {
                 stream: Box::new($mock),
-            })),
-        };
+            })
+            .build()
+            .unwrap();
 
         // We don't actually want to do anything with this thread, the channel
         // sender just needs to live through the lifetime of each test
@@ -83,11 +85,12 @@ macro_rules! service_new_with_read {
 
         let (sender, receiver) = channel();
 
-        let mai = MAI400 {
-            conn: Arc::new(Mutex::new(Connection {
+        let mai = MAI400Builder::new()
+            .connection(Connection {
                 stream: Box::new($mock),
-            })),
-        };
+            })
+            .build()
+            .unwrap();
 
         let mai_ref = mai.clone();
         let data_ref = $data.clone();
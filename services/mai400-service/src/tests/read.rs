@@ -47,6 +47,36 @@ fn read_good() {
     );
 }
 
+#[test]
+fn read_checksum_mismatch() {
+    let mut mock = MockStream::default();
+    let data = Arc::new(ReadData::new());
+
+    // A corrupted message set, followed by a good one
+    let mut raw = RAW_READ.to_vec();
+    raw[10] ^= 0x01;
+    raw.extend_from_slice(&RAW_READ);
+    mock.read.set_output(raw);
+
+    let service = service_new_with_read!(mock, data);
+
+    // Give it a sec to work
+    thread::sleep(Duration::from_millis(500));
+
+    // The corrupted set should be dropped without stopping the read thread
+    assert_eq!(data.std_telem.lock().unwrap().clone(), STD);
+
+    let query = r#"{
+            errors
+        }"#;
+
+    let expected = json!({
+            "errors": []
+    });
+
+    test!(service, query, expected);
+}
+
 #[test]
 fn read_panic() {
     let mut mock = MockStream::default();
@@ -69,3 +99,135 @@ fn read_panic() {
 
     test!(service, query, expected);
 }
+
+#[test]
+fn stuck_constant() {
+    let mut detector = StuckDetector::new(StuckThresholds {
+        gyro: 3,
+        accel: 5,
+        mag: 3,
+    });
+
+    for _ in 0..3 {
+        detector.update_imu(&IMU);
+        detector.update_std(&STD);
+    }
+
+    assert_eq!(
+        detector.stuck_fields(),
+        vec![
+            "gyro_x",
+            "gyro_y",
+            "gyro_z",
+            "i_b_field_meas_x",
+            "i_b_field_meas_y",
+            "i_b_field_meas_z",
+        ]
+    );
+
+    for _ in 0..2 {
+        detector.update_imu(&IMU);
+    }
+
+    assert_eq!(
+        detector.stuck_fields(),
+        vec![
+            "accel_x",
+            "accel_y",
+            "accel_z",
+            "gyro_x",
+            "gyro_y",
+            "gyro_z",
+            "i_b_field_meas_x",
+            "i_b_field_meas_y",
+            "i_b_field_meas_z",
+        ]
+    );
+}
+
+#[test]
+fn stuck_threshold_boundary() {
+    let mut detector = StuckDetector::new(StuckThresholds {
+        gyro: 3,
+        accel: 3,
+        mag: 3,
+    });
+
+    for _ in 0..2 {
+        detector.update_imu(&IMU);
+    }
+
+    assert!(detector.stuck_fields().is_empty());
+
+    detector.update_imu(&IMU);
+
+    assert_eq!(
+        detector.stuck_fields(),
+        vec!["accel_x", "accel_y", "accel_z", "gyro_x", "gyro_y", "gyro_z"]
+    );
+}
+
+#[test]
+fn stuck_varying() {
+    let mut detector = StuckDetector::new(StuckThresholds {
+        gyro: 3,
+        accel: 3,
+        mag: 3,
+    });
+
+    for i in 0..10 {
+        let mut imu = IMU;
+        imu.gyro = [i, -i, i * 2];
+        imu.accel = [i, i + 1, i + 2];
+        detector.update_imu(&imu);
+    }
+
+    assert!(detector.stuck_fields().is_empty());
+}
+
+#[test]
+fn stuck_recovered() {
+    let mut detector = StuckDetector::new(StuckThresholds {
+        gyro: 3,
+        accel: 3,
+        mag: 3,
+    });
+
+    for _ in 0..3 {
+        detector.update_imu(&IMU);
+    }
+
+    let mut imu = IMU;
+    imu.gyro[1] += 1;
+    detector.update_imu(&imu);
+
+    assert_eq!(
+        detector.stuck_fields(),
+        vec!["accel_x", "accel_y", "accel_z", "gyro_x", "gyro_z"]
+    );
+}
+
+#[test]
+fn stuck_fields_query() {
+    let mock = MockStream::default();
+    let data = Arc::new(ReadData::with_thresholds(StuckThresholds {
+        gyro: 2,
+        accel: 1000,
+        mag: 1000,
+    }));
+
+    data.update_imu(IMU);
+    data.update_imu(IMU);
+
+    let service = service_new!(mock, data);
+
+    let query = r#"{
+            stuckFields
+        }"#;
+
+    let expected = json!({
+            "stuckFields": ["gyro_x", "gyro_y", "gyro_z"]
+    });
+
+    test!(service, query, expected);
+}
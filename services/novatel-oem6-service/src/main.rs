@@ -24,8 +24,29 @@
 //! The service can be configured in the `/etc/kubos-config.toml` with the following fields:
 //!
 //! - `bus` - Specifies the UART bus the OEM6 is connected to
+//! - `baud_rate` - (Optional) Data rate used to talk to the OEM6. Must match the rate the receiver's
+//!   port is currently set to, and be one of 9600, 19200, 38400, 57600 or 115200. Defaults to 9600
+//! - `replay_file` - (Optional) Path to a file of raw bytes previously captured from the OEM6.
+//!   If given, the service reads from this file instead of `bus`, parsing and caching the logs
+//!   exactly as it would live data. Commands are discarded, so requests which need a response
+//!   from the receiver will fail. Intended for regression testing and reproducing field issues
 //! - `ip` - Specifies the service's IP address
 //! - `port` - Specifies the port on which the service will be listening for UDP packets
+//! - `telemetry_max_age` - (Optional) Age, in seconds, after which cached telemetry
+//!   (`lockStatus`, `lockInfo`, `velocityInfo`, `clockInfo`, `dopInfo`, `hardwareMonitor`,
+//!   `rangeObservations`, `constellationStatus` and `trackingStatus`)
+//!   is reported as stale. Must not be negative.
+//!   Defaults to 5 seconds
+//! - `link_timeout` - (Optional) Time, in seconds, without any logs from the receiver after which
+//!   the link is considered lost. This is only checked while periodic logs are configured, and is
+//!   extended to twice the shortest log interval if that is longer. When the link is lost, the
+//!   service reopens the port and requests every log in `appliedConfiguration` again, so that
+//!   logging resumes after a dropped connection or a receiver reset. A value of 0 disables this,
+//!   and negative values are rejected. Defaults to 10 seconds
+//! - `persist_logs` - (Optional) If `true`, the logs requested from the receiver are left running
+//!   when the service is stopped. Otherwise, the service issues `UNLOGALL` (including held logs)
+//!   when it receives SIGTERM or SIGINT, so the receiver isn't left transmitting to a closed UART.
+//!   Defaults to `false`
 //!
 //! For example:
 //!
@@ -81,6 +102,20 @@
 //! }
 //! ```
 //!
+//! ## New Errors
+//!
+//! Get only the errors encountered since the last time this field was queried
+//!
+//! Each error is returned by exactly one `newErrors` query, regardless of whether
+//! it has also been returned (and cleared) by the `errors` query.
+//! `errors` and `systemStatus.errors` are not affected by this query
+//!
+//! ```json
+//! {
+//!     newErrors: [String]
+//! }
+//! ```
+//!
 //! ## Power Status
 //!
 //! Get the current power state of the system
@@ -110,6 +145,84 @@
 //! }
 //! ```
 //!
+//! ## Active Logs
+//!
+//! Get the logs currently being generated by the receiver, as read back from
+//! the receiver's own configuration (RXCONFIG). Entries for commands other than
+//! `LOG` are omitted.
+//!
+//! If the receiver does not respond, an empty list will be returned and the
+//! failure will be recorded in `errors`
+//!
+//! ```json
+//! {
+//!     configuration {
+//!         interval: Float,
+//!         name: String,
+//!         trigger: String
+//!     }
+//! }
+//! ```
+//!
+//! ## Port Configuration
+//!
+//! Get the port each log currently being generated by the receiver is output on,
+//! as read back from the receiver's own configuration (RXCONFIG). Entries for
+//! commands other than `LOG` are omitted.
+//!
+//! If the receiver does not respond, an empty list will be returned and the
+//! failure will be recorded in `errors`
+//!
+//! ```json
+//! {
+//!     portConfig {
+//!         name: String,
+//!         port: String
+//!     }
+//! }
+//! ```
+//!
+//! ## Applied Configuration
+//!
+//! Get the configuration options which the service has successfully applied with
+//! `configureHardware`, without querying the receiver. `LOG_*` options are listed
+//! until they are removed by the matching `UNLOG_*` option or by `UNLOG_ALL`.
+//! Since this reflects the service's requests, it will not include settings restored
+//! from the receiver's saved configuration.
+//! If the link to the receiver is lost, these options are requested again (see `link_timeout`)
+//!
+//! ```json
+//! {
+//!     appliedConfiguration {
+//!         format: LogFormat,
+//!         hold: Boolean,
+//!         interval: Float,
+//!         offset: Float,
+//!         option: ConfigOption,
+//!         port: OemPort
+//!     }
+//! }
+//! ```
+//!
+//! ## Config Options
+//!
+//! Get the configuration options accepted by `configureHardware`, along with whether each
+//! one starts log output (`LOG`), stops it (`UNLOG`) or neither (`OTHER`), and which of the
+//! `interval`, `offset` and `hold` input fields it uses. Fields which aren't used by an option
+//! are ignored. This does not communicate with the receiver
+//!
+//! ```json
+//! {
+//!     configOptions {
+//!         hold: Boolean,
+//!         interval: Boolean,
+//!         kind: ConfigOptionKind,
+//!         offset: Boolean,
+//!         option: ConfigOption
+//!     }
+//! }
+//! ```
+//!
 //! ## Test Results
 //!
 //! Get the test results of the last run test
@@ -128,24 +241,82 @@
 //!
 //! Get the current system status and errors
 //!
+//! `antennaOpen` and `antennaShort` are decoded from the receiver status flags
+//! and will be `false` if the current status could not be read
+//!
+//! Any error conditions indicated by the status flags (for example, an antenna
+//! short circuit or a buffer overrun) are also described in `errors`
+//!
 //! ```json
 //! {
 //!     systemStatus {
+//!        antennaOpen: Boolean,
+//!        antennaShort: Boolean,
 //!        errors: Vec<String>,
 //!        status: Vec<String>
 //!     }
 //! }
 //! ```
 //!
+//! ## Health
+//!
+//! Get a summary of the system's health in a single request
+//!
+//! `powered` will be `false` if the receiver did not respond to a status request and
+//! is not sending logs. `locked` will be `true` if the last received position solution
+//! was computed, is not stale and the receiver is still connected
+//!
+//! ```json
+//! {
+//!     health {
+//!        errors: Vec<String>,
+//!        locked: Boolean,
+//!        powered: Boolean,
+//!        statusFlags: Vec<String>
+//!     }
+//! }
+//! ```
+//!
 //! ## Lock Status
 //!
 //! Get current status of position information gathering
 //!
+//! `differentialAge` is the age, in seconds, of the differential corrections used
+//! in the position solution. It will be -1.0 if the position type is not a differential solution
+//!
+//! `lockProgress` is an approximate indication (0-100) of how close the receiver is to a
+//! converged position solution, derived from the position status and type. It increases
+//! through the normal cold-start sequence (`COLD_START` 10, `PENDING` 25,
+//! `INSUFFICIENT_OBSERVATIONS` 40, other rejected solutions 50, standalone solution 75,
+//! differential/float solution 90, fixed/integer solution 100) and is 0 for `INVALID_FIX`
+//! and `UNAUTHORIZED`
+//!
+//! `rejectedMeasurements` is the number of tracked satellites which were not used in the
+//! position solution. A rising count, especially alongside an `INTEGRITY_WARNING` position
+//! status, can indicate multipath or interference. It also includes satellites excluded for other
+//! reasons (ex. below the elevation mask), and is 0 until a position log has been received
+//!
+//! Each status and type field also has a matching `*Raw: Int` field (ex. `positionTypeRaw`)
+//! containing the numeric value reported by the receiver. This preserves the original value
+//! when the enum field is `KUBOS_INVALID`
+//!
+//! `receivedAt` is the Unix time at which the service received the status. `stale` is `true`
+//! if no status has been received or it is older than `telemetry_max_age`
+//!
+//! `deviceConnected` is `false` if no log has ever been received from the receiver. In that case
+//! the other fields are defaults, not a connected receiver which is still searching
+//!
 //! ```json
 //! {
 //!     lockStatus {
+//!         deviceConnected: Boolean,
+//!         differentialAge: Float,
+//!         lockProgress: Int,
 //!         positionStatus: SolutionStatus,
 //!           positionType: PosVelType,
+//!         receivedAt: Float,
+//!         rejectedMeasurements: Int,
+//!         stale: Boolean,
 //!           time {
 //!             ms: Int,
 //!               week: Int
@@ -157,14 +328,42 @@
 //! }
 //! ```
 //!
+//! All `time` fields also provide a computed `unixTime(utcOffset: Float): Float` field, which
+//! converts the GPS week and milliseconds into a Unix timestamp. `utcOffset` defaults to -18
+//! seconds and may be set to the `utcOffset` value returned by the `clockInfo` query.
+//!
 //! ## Lock Information
 //!
 //! Get the last known good position information
 //!
+//! `geodetic` is computed from the ECEF `position` using the WGS-84 ellipsoid.
+//! Latitude and longitude are in degrees and altitude is the height above the ellipsoid in meters.
+//! All values will be 0.0 if no position is available
+//!
+//! `solutionAge` is the age of the solution reported by the receiver, or -1.0 if no solution has
+//! been received. `receivedAt` is the Unix time at which the service received the solution.
+//! `stale` is `true` if no solution has been received or it is older than `telemetry_max_age`
+//!
+//! `speed` is the magnitude of the velocity vector in m/s. `heading` is the course over ground in
+//! degrees from True North, or null if the receiver is stationary (horizontal speed below 0.01 m/s)
+//!
+//! `deviceConnected` is `false` if no log has ever been received from the receiver
+//!
 //! ```json
 //! {
 //!     lockInfo {
+//!        deviceConnected: Boolean,
+//!        geodetic {
+//!            altitude: Float,
+//!            latitude: Float,
+//!            longitude: Float
+//!        },
+//!        heading: Float,
 //!        position: Vec<Float>,
+//!        receivedAt: Float,
+//!        solutionAge: Float,
+//!        speed: Float,
+//!        stale: Boolean,
 //!        time {
 //!            ms: Int,
 //!            week: Int
@@ -174,29 +373,320 @@
 //! }
 //! ```
 //!
+//! ## Velocity Information
+//!
+//! Get the last received BestVel velocity (see `LOG_VELOCITY_DATA`).
+//! `latency` is the latency of the velocity time tag in seconds, and `track` is the direction of
+//! motion over ground in degrees from True North.
+//! `receivedAt` is the Unix time at which the service received the BestVel log.
+//! `stale` will be `true` until a BestVel log has been received, or if the last one is older
+//! than `telemetry_max_age`
+//!
+//! ```json
+//! {
+//!     velocityInfo {
+//!        differentialAge: Float,
+//!        horizontalSpeed: Float,
+//!        latency: Float,
+//!        receivedAt: Float,
+//!        stale: Boolean,
+//!        time {
+//!            ms: Int,
+//!            week: Int
+//!        },
+//!        track: Float,
+//!        velocityStatus: SolutionStatus,
+//!        velocityStatusRaw: Int,
+//!        velocityType: PosVelType,
+//!        velocityTypeRaw: Int,
+//!        verticalSpeed: Float
+//!     }
+//! }
+//! ```
+//!
+//! ## Clock Information
+//!
+//! Get the last received receiver clock and GPS-to-UTC offset information.
+//! `receivedAt` is the Unix time at which the service received the TIME log.
+//! `stale` will be `true` until a TIME log has been received (see `LOG_TIME_DATA`),
+//! or if the last one is older than `telemetry_max_age`
+//!
+//! ```json
+//! {
+//!     clockInfo {
+//!        clockOffset: Float,
+//!        clockOffsetStdDev: Float,
+//!        receivedAt: Float,
+//!        stale: Boolean,
+//!        time {
+//!            ms: Int,
+//!            week: Int
+//!        },
+//!        utcOffset: Float
+//!     }
+//! }
+//! ```
+//!
+//! ## DOP Information
+//!
+//! Get the last received dilution of precision information.
+//! All values will be 9999.0 if no DOP information is available (see `LOG_DOP_DATA`).
+//! `receivedAt` is the Unix time at which the service received the DOP information.
+//! `stale` is `true` if no DOP information is available or it is older than `telemetry_max_age`
+//!
+//! ```json
+//! {
+//!     dopInfo {
+//!        hdop: Float,
+//!        pdop: Float,
+//!        receivedAt: Float,
+//!        stale: Boolean,
+//!        tdop: Float,
+//!        time {
+//!            ms: Int,
+//!            week: Int
+//!        },
+//!        vdop: Float
+//!     }
+//! }
+//! ```
+//!
+//! ## Hardware Monitor
+//!
+//! Get the last received hardware monitor readings.
+//! All values will be 9999.0 if no hardware monitor information is available (see `LOG_HW_MONITOR`).
+//! `temperature` is in degrees Celsius, `antennaCurrent` is in Amps and `coreVoltage` is in Volts.
+//! `receivedAt` is the Unix time at which the service received the hardware monitor information.
+//! `stale` is `true` if no hardware monitor information is available or it is older than `telemetry_max_age`
+//!
+//! ```json
+//! {
+//!     hardwareMonitor {
+//!        antennaCurrent: Float,
+//!        coreVoltage: Float,
+//!        receivedAt: Float,
+//!        stale: Boolean,
+//!        temperature: Float,
+//!        time {
+//!            ms: Int,
+//!            week: Int
+//!        }
+//!     }
+//! }
+//! ```
+//!
+//! ## Range Observations
+//!
+//! Get the raw observations from the last received RANGE log (see `LOG_RANGE_DATA`).
+//! `observations` contains one entry per tracked satellite signal and is empty if no RANGE
+//! log has been received. `pseudorange` is in meters, `carrierPhase` is in cycles
+//! (accumulated Doppler range, as reported by the receiver), `dopplerHz` is in Hz,
+//! `cno` is in dB-Hz and `lockTime` is in seconds.
+//! `system` is null if the receiver reports a satellite system not covered by `Constellation`.
+//! `receivedAt` is the Unix time at which the service received the observations.
+//! `stale` is `true` if no observations are available or they are older than `telemetry_max_age`
+//!
+//! ```json
+//! {
+//!     rangeObservations {
+//!        observations {
+//!            carrierPhase: Float,
+//!            cno: Float,
+//!            dopplerHz: Float,
+//!            lockTime: Float,
+//!            prn: Int,
+//!            pseudorange: Float,
+//!            system: Constellation
+//!        },
+//!        receivedAt: Float,
+//!        stale: Boolean,
+//!        time {
+//!            ms: Int,
+//!            week: Int
+//!        }
+//!     }
+//! }
+//! ```
+//!
+//! ## Constellation Status
+//!
+//! Get the number of satellites tracked and used in the position solution for each
+//! satellite system, from the last received TRACKSTAT log (see `LOG_TRACKING_DATA`).
+//! `constellations` contains one entry per system and is empty if no TRACKSTAT log has been received.
+//! A satellite counts as tracked if any receiver channel is tracking one of its signals,
+//! and as used if any of those signals was used in the solution.
+//! Satellites tracked on several signals are only counted once.
+//! `receivedAt` is the Unix time at which the service received the tracking status.
+//! `stale` is `true` if no tracking status is available or it is older than `telemetry_max_age`
+//!
+//! ```json
+//! {
+//!     constellationStatus {
+//!        constellations {
+//!            system: Constellation,
+//!            tracked: Int,
+//!            used: Int
+//!        },
+//!        receivedAt: Float,
+//!        stale: Boolean,
+//!        time {
+//!            ms: Int,
+//!            week: Int
+//!        }
+//!     }
+//! }
+//! ```
+//!
+//! ## Tracking Status
+//!
+//! Get the tracking status of each active receiver channel, from the last received
+//! TRACKSTAT log (see `LOG_TRACKING_DATA`).
+//! `channels` contains one entry per channel with a satellite assigned, and is empty if no
+//! TRACKSTAT log has been received. Idle channels are omitted, and at most 64 channels are returned.
+//! `channel` is the channel's index within the TRACKSTAT log.
+//! `system` is null if the receiver reports a satellite system not covered by `Constellation`,
+//! and `state` is null if it reports an unknown tracking state.
+//! `receivedAt` is the Unix time at which the service received the tracking status.
+//! `stale` is `true` if no tracking status is available or it is older than `telemetry_max_age`
+//!
+//! ```json
+//! {
+//!     trackingStatus {
+//!        channels {
+//!            channel: Int,
+//!            prn: Int,
+//!            system: Constellation,
+//!            cno: Float,
+//!            state: TrackingState
+//!        },
+//!        receivedAt: Float,
+//!        stale: Boolean,
+//!        time {
+//!            ms: Int,
+//!            week: Int
+//!        }
+//!     }
+//! }
+//! ```
+//!
+//! ## Mark Time Events
+//!
+//! Get the external events captured on the receiver's mark inputs since the last
+//! time this query was run (see `LOG_MARK_TIME`).
+//! The event buffer is cleared on read, so each event is only returned once.
+//! Up to 100 events are kept between queries. Once full, the oldest events are dropped.
+//! `eventId` is 1 for MARK1TIME events and 2 for MARK2TIME events
+//!
+//! ```json
+//! {
+//!     markTimeEvents {
+//!        eventId: Int,
+//!        ms: Int,
+//!        week: Int
+//!     }
+//! }
+//! ```
+//!
+//! ## Snapshot
+//!
+//! Get a consistent set of telemetry captured from the most recent BestXYZ log.
+//! All fields describe the same epoch. Returns null until a BestXYZ log has been received.
+//! `dopInfo` is null until a PsrDop log with the same GPS time has been received
+//!
+//! ```json
+//! {
+//!     snapshot {
+//!        groundVelocity {
+//!            horizontalSpeed: Float,
+//!            track: Float,
+//!            verticalSpeed: Float
+//!        },
+//!        dopInfo {...},
+//!        lockInfo {...},
+//!        lockStatus {...},
+//!        systemStatus {
+//!            errors: Vec<String>,
+//!            status: Vec<String>
+//!        },
+//!        time {
+//!            ms: Int,
+//!            week: Int
+//!        }
+//!     }
+//! }
+//! ```
+//!
+//! ## Batch Telemetry
+//!
+//! Get the power state, system status, lock status, lock information and debug
+//! telemetry in a single request.
+//!
+//! The receiver is queried at most once, and only if `power` or `systemStatus`
+//! is requested. The other fields are served from the service's caches, so
+//! `telemetryDebug` is null until the version information has been read
+//! (for example, by a `telemetry` or `batchTelemetry` request which included `power`
+//! or `systemStatus`). Fields which could not be populated are null.
+//!
+//! ```json
+//! {
+//!     batchTelemetry {
+//!         lockInfo {...},
+//!         lockStatus {...},
+//!         power {
+//!             state: PowerState,
+//!             uptime: Int
+//!         },
+//!         systemStatus {
+//!             errors: Vec<String>,
+//!             status: Vec<String>
+//!         },
+//!         telemetryDebug {...}
+//!     }
+//! }
+//! ```
+//!
 //! ## Telemetry
 //!
 //! Get current telemetry information for the system
 //!
+//! The debug (version) information is read from the device once and then cached,
+//! since it doesn't change within a session. Requests which only ask for `debug`
+//! are served from the cache. Use the `refreshVersion` mutation to re-read it.
+//! The cache is also cleared by the `reset` mutation.
+//! Components which could not be fully read are still listed, with `valid` set to false.
+//!
+//! `nominal.logHeader` holds diagnostic fields from the header of the most recent
+//! BestXYZ log, and is null if none has been received. `idleTime` is the percentage
+//! of time the receiver's processor was idle, `port` is the receiver port which the
+//! log was output on, and `sequence` counts down to 0 across a set of related logs
+//! output together (it is 0 for logs output on their own).
+//!
 //! ```json
 //! {
 //!     telemetry{
 //!         debug {
 //!             components: [{
 //!                 bootVersion: String,
-//!                 compType: Int,
+//!                 compType: ComponentType,
+//!                 compTypeRaw: Int,
 //!                 compileDate: String,
 //!                 compileTime: String,
 //!                 hwVersion: String,
 //!                 model: String,
 //!                 serialNum: String,
 //!                 swVersion: String,
+//!                 valid: Boolean,
 //!             }],
 //!             numComponents: Int
 //!         },
 //!         nominal{
 //!             lockInfo {...},
 //!             lockStatus {...},
+//!             logHeader {
+//!                idleTime: Float,
+//!                port: String,
+//!                sequence: Int
+//!             },
 //!             systemStatus: {
 //!                errors: Vec<String>,
 //!                status: Vec<String>
@@ -208,6 +698,14 @@
 //!
 //! # Mutations
 //!
+//! Mutation responses include an `errorDetails` list alongside the `errors` string,
+//! with one entry per error encountered:
+//!
+//! - code: Response ID reported by the receiver for `COMMAND` errors. Otherwise -1
+//! - category: General type of the error (`UART`, `TIMEOUT`, `PARSE`, `INVALID_INPUT`,
+//!   `COMMAND` or `INTERNAL`)
+//! - message: Description of the error
+//!
 //! ## Errors
 //!
 //! Get all errors encountered while processing this GraphQL request
@@ -257,13 +755,38 @@
 //!           For `UNLOG_ALL` requests, specifies whether the 'hold' value in previous
 //!           `LOG_*` requests should be ignored.
 //!   - interval: Interval at which log messages should be generated.
-//!               Note: Only applies to `LOG_POSITION_DATA` requests. Ignored otherwise
+//!               Note: Only applies to `LOG_HW_MONITOR` and `LOG_*_DATA` requests other than
+//!               `LOG_ERROR_DATA`.
+//!               Ignored otherwise
+//!               Must be 0 (log once) or between 0.05 and 3600 seconds.
 //!   - offset: Offset of interval at which log messages should be generated.
-//!             Note: Only applies to `LOG_POSITION_DATA` requests. Ignored otherwise
+//!             Note: Only applies to `LOG_HW_MONITOR` and `LOG_*_DATA` requests other than
+//!             `LOG_ERROR_DATA`.
+//!             Ignored otherwise
+//!             Must not be negative.
+//!   - format: Format in which log messages should be generated (`BINARY` or `ASCII`).
+//!             Note: Only applies to `LOG_*_DATA` requests other than `LOG_ERROR_DATA`.
+//!             `LOG_RANGE_DATA` and `LOG_TRACKING_DATA` are always generated in binary.
+//!             Defaults to `BINARY`
+//!   - verify: For `LOG_*` requests, wait for the first requested log message to arrive
+//!             before reporting success. The request fails with a `TIMEOUT` error if no
+//!             message arrives within the interval plus offset plus one second.
+//!             The interval plus offset must not exceed 9 seconds.
+//!             Not supported for `LOG_ERROR_DATA` or `LOG_MARK_TIME`.
+//!             Defaults to `false`
+//!
+//! Options are applied in the order given. A failing option does not stop the
+//! remaining options from being attempted, and any options applied before the
+//! failure remain applied. `success` will be false if any option failed,
+//! and `errors` will name each failing option.
+//!
+//! Including a `SAVE_CONFIG` request as the last entry will save the resulting
+//! configuration to non-volatile memory, so that it persists across power cycles.
+//! See `saveConfiguration` for the caveats
 //!
 //! ```json
 //! mutation {
-//!     configureHardware(config: [{option: ConfigOption, hold: Boolean, interval: Float, offset: Float},...]) {
+//!     configureHardware(config: [{option: ConfigOption, hold: Boolean, interval: Float, offset: Float, format: LogFormat, verify: Boolean},...]) {
 //!         config: String
 //!         errors: String,
 //!         success: Boolean,
@@ -295,12 +818,188 @@
 //! }
 //! ```
 //!
+//! ## Reset
+//!
+//! Reset the receiver
+//!
+//! - resetType: Type of reset which should be performed
+//!   - `HOT`: Reset the receiver. Saved configuration and satellite data are retained
+//!   - `WARM`: Clear the saved ephemeris data and reset the receiver
+//!   - `COLD`: Clear the saved ephemeris and almanac data and reset the receiver
+//!   - `FACTORY`: Clear all saved data and reset the receiver.
+//!              Note: This will also clear any saved configuration, restoring the
+//!              factory default settings. `appliedConfiguration` is cleared, so any logs
+//!              requested with `configureHardware` will need to be requested again
+//!
+//! The reset type has no default and must always be specified
+//!
+//! ```json
+//! mutation {
+//!     reset(resetType: ResetType) {
+//!         errors: String,
+//!         success: Boolean
+//!     }
+//! }
+//! ```
+//!
+//! ## Refresh Version
+//!
+//! Re-read the version information from the device, replacing the cached copy
+//! used by the `telemetry` query's `debug` field
+//!
+//! ```json
+//! mutation {
+//!     refreshVersion {
+//!         errors: String,
+//!         success: Boolean
+//!     }
+//! }
+//! ```
+//!
+//! ## Save Configuration
+//!
+//! Save the current configuration, including all requested logs, to non-volatile
+//! memory so that it is restored after a reset or power cycle
+//!
+//! Note: The non-volatile memory has limited write-cycle endurance, so this should
+//! only be issued after the configuration has actually changed, not periodically.
+//! A `FACTORY` reset will clear the saved configuration
+//!
+//! ```json
+//! mutation {
+//!     saveConfiguration {
+//!         errors: String,
+//!         success: Boolean
+//!     }
+//! }
+//! ```
+//!
+//! ## Elevation Mask
+//!
+//! Set the elevation mask. Satellites below this elevation angle are not tracked
+//!
+//! - degrees: Elevation angle, in degrees, between 0 and 90 inclusive.
+//!            Values outside of this range are rejected without being sent.
+//!
+//! ```json
+//! mutation {
+//!     setElevationMask(degrees: Int!) {
+//!         errors: String,
+//!         success: Boolean
+//!     }
+//! }
+//! ```
+//!
+//! ## Constellations
+//!
+//! Select the satellite systems the receiver should track (GPS, GLONASS, SBAS,
+//! GALILEO, BEIDOU, QZSS). Any system not in the list is locked out.
+//! Requests with an empty list are rejected without being sent.
+//!
+//! ```json
+//! mutation {
+//!     setConstellations(systems: [Constellation!]!) {
+//!         errors: String,
+//!         success: Boolean
+//!     }
+//! }
+//! ```
+//!
+//!
+//! ## Baud Rate
+//!
+//! Change the data rate of the receiver port the service is connected to.
+//!
+//! The service sends the `COM` command, reopens its own port at the new rate, and then requests the
+//! receiver's version information to confirm communication. If no response is received, the service
+//! sends a `COM` command for the previous rate (without waiting for the response), reopens its port
+//! at the previous rate and the mutation fails.
+//!
+//! The change isn't saved on the receiver unless `saveConfiguration` is used afterwards, so update
+//! the `baud_rate` config value at the same time.
+//!
+//! - rate: New data rate. Supported rates are 9600, 19200, 38400, 57600 and 115200.
+//!   Other values are rejected without being sent.
+//!
+//! ```json
+//! mutation {
+//!     setBaud(rate: Int!) {
+//!         errors: String,
+//!         success: Boolean
+//!     }
+//! }
+//! ```
+//!
+//! ## Approximate Position
+//!
+//! Give the receiver an approximate position to shorten the time to first fix
+//! after a cold start. It is only used until the receiver computes its own solution
+//!
+//! - lat: Latitude, in degrees, between -90 and 90 inclusive
+//! - lon: Longitude, in degrees, between -180 and 180 inclusive
+//! - height: Height above mean sea level, in meters
+//!
+//! Latitudes and longitudes outside of these ranges are rejected without being sent.
+//!
+//! ```json
+//! mutation {
+//!     setApproximatePosition(lat: Float!, lon: Float!, height: Float!) {
+//!         errors: String,
+//!         success: Boolean
+//!     }
+//! }
+//! ```
+//!
+//! ## Approximate Time
+//!
+//! Give the receiver an approximate GPS time to shorten the time to first fix
+//! after a cold start. It is ignored if the receiver has already established its own time
+//!
+//! - week: GPS reference week. Must not be negative
+//! - seconds: Seconds into the GPS reference week, from 0 up to (but not including) 604800
+//!
+//! Values outside of these ranges are rejected without being sent.
+//!
+//! ```json
+//! mutation {
+//!     setApproximateTime(week: Int!, seconds: Float!) {
+//!         errors: String,
+//!         success: Boolean
+//!     }
+//! }
+//! ```
+//!
+//! ## Log Port
+//!
+//! Move a log to a different receiver port, such as one connected to a payload.
+//! The log is stopped on the port it was previously output on, then requested on the new
+//! port with the interval, offset, hold and format it was last applied with through
+//! `configureHardware`.
+//!
+//! - log: `LOG_*` option whose log should be output. The option must currently be applied
+//! - port: Port the log should be output on. One of `COM1`, `COM2` or `COM3`
+//!
+//! Other options and ports are rejected without being sent. The new port is recorded in
+//! `appliedConfiguration`, so the log is requested on it again if the link to the
+//! receiver is lost.
+//!
+//! ```json
+//! mutation {
+//!     setLogPort(log: ConfigOption!, port: OemPort!) {
+//!         errors: String,
+//!         success: Boolean
+//!     }
+//! }
+//! ```
+//!
 //! ## Passthrough
 //!
 //! Pass a custom command through to the system
 //!
 //! - command: String containing the hex values to be sent (ex. "C3")
 //!          It will be converted to a byte array before transfer.
+//!          Malformed hex strings and commands longer than 1024 bytes
+//!          will be rejected without being sent.
 //!
 //! ```json
 //! mutation {
@@ -324,13 +1023,42 @@ mod schema;
 #[cfg(test)]
 mod tests;
 
-use crate::model::{LockData, Subsystem};
+use crate::model::{
+    LockData, Subsystem, DEFAULT_BAUD_RATE, DEFAULT_LINK_TIMEOUT, DEFAULT_TELEMETRY_MAX_AGE,
+};
 pub use crate::objects::*;
 use crate::schema::{MutationRoot, QueryRoot};
 use kubos_service::{Config, Logger, Service};
-use log::error;
-use novatel_oem6_api::OEMResult;
+use log::{error, info};
+use novatel_oem6_api::{OEMError, OEMResult, ReplaySource, SUPPORTED_BAUD_RATES};
+use signal_hook::iterator::Signals;
+use signal_hook::{SIGINT, SIGTERM};
+use std::process;
 use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+// Stop all of the receiver's logs before exiting, so that it isn't left transmitting
+// to a UART which nothing is reading from
+fn shutdown_on_signal(subsystem: Subsystem) {
+    let signals = match Signals::new(&[SIGINT, SIGTERM]) {
+        Ok(signals) => signals,
+        Err(err) => {
+            error!("Failed to register shutdown handler: {}", err);
+            return;
+        }
+    };
+
+    thread::spawn(move || {
+        if let Some(signal) = signals.forever().next() {
+            info!("Received signal {}. Shutting down", signal);
+            if let Err(err) = subsystem.shutdown() {
+                error!("Failed to stop logs during shutdown: {}", err);
+            }
+            process::exit(0);
+        }
+    });
+}
 
 fn main() -> OEMResult<()> {
     Logger::init("novatel-oem6-service").unwrap();
@@ -356,7 +1084,66 @@ fn main() -> OEMResult<()> {
         })
         .unwrap();
 
-    let subsystem = Subsystem::new(bus, Arc::new(LockData::new()))?;
+    let seconds =
+        |name: &str, default: Duration| match config.get(name).and_then(|val| val.as_integer()) {
+            Some(num) if num < 0 => {
+                error!(
+                    "Invalid '{}' config value {}. Must not be negative",
+                    name, num
+                );
+                Err(OEMError::GenericError)
+            }
+            Some(num) => Ok(Duration::from_secs(num as u64)),
+            None => Ok(default),
+        };
+
+    let telemetry_max_age = seconds("telemetry_max_age", DEFAULT_TELEMETRY_MAX_AGE)?;
+
+    let link_timeout = seconds("link_timeout", DEFAULT_LINK_TIMEOUT)?;
+
+    let persist_logs = config
+        .get("persist_logs")
+        .and_then(|val| val.as_bool())
+        .unwrap_or(false);
+
+    let baud_rate = config
+        .get("baud_rate")
+        .and_then(|val| val.as_integer())
+        .map_or(DEFAULT_BAUD_RATE, |num| num as u32);
+
+    if !SUPPORTED_BAUD_RATES.contains(&baud_rate) {
+        error!(
+            "Unsupported 'baud_rate' config value {}. Must be one of {:?}",
+            baud_rate, SUPPORTED_BAUD_RATES
+        );
+        return Err(OEMError::GenericError);
+    }
+
+    let data = Arc::new(LockData::new(telemetry_max_age));
+
+    let subsystem = match config.get("replay_file") {
+        Some(path) => {
+            let path = path.as_str().ok_or_else(|| {
+                error!("Failed to parse 'replay_file' config value");
+                OEMError::GenericError
+            })?;
+            Subsystem::replay(ReplaySource::from_file(path)?, data)
+        }
+        None => {
+            let subsystem = Subsystem::new(bus, baud_rate, data)?;
+
+            if link_timeout > Duration::from_secs(0) {
+                let monitor = subsystem.clone();
+                thread::spawn(move || monitor.monitor_link(link_timeout));
+            }
+
+            subsystem
+        }
+    };
+
+    if !persist_logs {
+        shutdown_on_signal(subsystem.clone());
+    }
 
     Service::new(config, subsystem, QueryRoot, MutationRoot).start();
 
@@ -15,32 +15,223 @@
 //
 
 use failure::Error;
-use kubos_service::{process_errors, push_err, run};
-use log::{error, info};
+use kubos_service::{process_errors, push_err};
+use log::{error, info, warn};
 use novatel_oem6_api::Log::*;
 use novatel_oem6_api::*;
-use std::sync::mpsc::{sync_channel, Receiver, SyncSender, TryRecvError, TrySendError};
-use std::sync::{Arc, Mutex, RwLock};
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{
+    sync_channel, Receiver, RecvTimeoutError, SyncSender, TryRecvError, TrySendError,
+};
+use std::sync::{Arc, Condvar, Mutex, RwLock};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+use crate::objects::Constellation;
+use crate::objects::LogFormat;
+use crate::objects::RangeObservation;
 use crate::objects::*;
 
 pub const RECV_TIMEOUT: Duration = Duration::from_millis(350);
+// Number of RXCONFIG entries which can be buffered while a configuration request is read
+pub const RXCONFIG_BUFFER_LEN: usize = 50;
+// Largest raw command, in bytes, which will be passed through to the OEM6
+pub const MAX_RAW_COMMAND_LEN: usize = 1024;
+// Number of mark input events which are kept until the next `markTimeEvents` query.
+// Once full, the oldest events are dropped
+pub const MARK_EVENT_BUFFER_LEN: usize = 100;
+// Default age after which cached telemetry is reported as stale.
+// The OEM6 is expected to output BestXYZ logs once per second
+pub const DEFAULT_TELEMETRY_MAX_AGE: Duration = Duration::from_secs(5);
+// Data rate used to talk to the OEM6 if none is configured
+pub const DEFAULT_BAUD_RATE: u32 = 9600;
+// Default time to wait for any log from the OEM6, while periodic logs are configured,
+// before the link is considered lost and the port is reopened
+pub const DEFAULT_LINK_TIMEOUT: Duration = Duration::from_secs(10);
+// Length of a GPS week, used to validate approximate times
+pub const SECONDS_PER_WEEK: f64 = 604_800.0;
+
+// Opens the local serial port used to talk to the OEM6 at the requested data rate
+pub type PortOpener = Arc<dyn Fn(&str, u32) -> OEMResult<Connection> + Send + Sync>;
+
+pub fn serial_port_opener() -> PortOpener {
+    Arc::new(|bus: &str, baud: u32| OEM6::open_port(bus, BaudRate::from_speed(baud as usize)))
+}
+
+// Equivalent to the `run!` macro, but returns the structured error information
+// alongside the error message
+macro_rules! run_oem {
+    ($func:expr, $master:expr) => {{
+        let result = $func.map_err(ServiceError::from);
+
+        if let Err(ref err) = result {
+            // Record the name of the function which failed, the same way `run!` does
+            let mut name = stringify!($func).split('(').next().unwrap();
+            name = name.split(&[':', '.'][..]).last().unwrap();
+            push_err!(
+                $master,
+                format!("{} ({}:{}): {}", name, file!(), line!(), err)
+            );
+        }
+
+        result
+    }};
+}
+
+// Current time as a Unix timestamp, in seconds
+fn unix_now() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|time| time.as_secs_f64())
+        .unwrap_or(0.0)
+}
+
+// Convert the hex values in the string into actual hex values
+// Ex. "c3c2" -> [0xc3, 0xc2]
+fn decode_hex(command: &str) -> Result<Vec<u8>, String> {
+    if command.is_empty() {
+        return Err("Command must not be empty".to_owned());
+    }
+
+    if command.len() % 2 != 0 {
+        return Err("Command must contain an even number of hex characters".to_owned());
+    }
+
+    if command.len() / 2 > MAX_RAW_COMMAND_LEN {
+        return Err(format!(
+            "Command exceeds maximum length of {} bytes",
+            MAX_RAW_COMMAND_LEN
+        ));
+    }
+
+    command
+        .as_bytes()
+        .chunks(2)
+        .map(|chunk| {
+            if chunk.iter().all(u8::is_ascii_hexdigit) {
+                // Safe to unwrap, since we've verified these are valid ASCII hex characters
+                Ok(u8::from_str_radix(::std::str::from_utf8(chunk).unwrap(), 16).unwrap())
+            } else {
+                Err(format!(
+                    "Invalid hex value: {}",
+                    String::from_utf8_lossy(chunk)
+                ))
+            }
+        })
+        .collect()
+}
 
 pub struct LockData {
     pub status: Mutex<LockStatus>,
     pub info: Mutex<LockInfo>,
+    pub clock: Mutex<ClockInfo>,
+    pub velocity: Mutex<VelocityInfo>,
+    pub dop: Mutex<DopInfo>,
+    pub hw_monitor: Mutex<HardwareMonitor>,
+    pub range: Mutex<RangeObservations>,
+    pub log_header: Mutex<Option<LogHeader>>,
+    pub constellations: Mutex<ConstellationStatus>,
+    pub tracking: Mutex<TrackingStatus>,
+    pub snapshot: Mutex<Option<TelemetrySnapshot>>,
+    pub mark_events: Mutex<VecDeque<MarkTimeEvent>>,
+    pub telemetry_max_age: Duration,
+    // Set once the first log is received from the receiver
+    pub connected: AtomicBool,
+    // Number of messages received for each log, keyed by message ID.
+    // `log_received` is notified whenever a count changes
+    pub log_counts: Mutex<HashMap<u16, u64>>,
+    pub log_received: Condvar,
 }
 
 impl LockData {
-    pub fn new() -> Self {
+    pub fn new(telemetry_max_age: Duration) -> Self {
         LockData {
             status: Mutex::new(LockStatus::default()),
             info: Mutex::new(LockInfo::default()),
+            clock: Mutex::new(ClockInfo::default()),
+            velocity: Mutex::new(VelocityInfo::default()),
+            dop: Mutex::new(DopInfo::default()),
+            hw_monitor: Mutex::new(HardwareMonitor::default()),
+            range: Mutex::new(RangeObservations::default()),
+            log_header: Mutex::new(None),
+            constellations: Mutex::new(ConstellationStatus::default()),
+            tracking: Mutex::new(TrackingStatus::default()),
+            snapshot: Mutex::new(None),
+            mark_events: Mutex::new(VecDeque::new()),
+            telemetry_max_age,
+            connected: AtomicBool::new(false),
+            log_counts: Mutex::new(HashMap::new()),
+            log_received: Condvar::new(),
         }
     }
 
+    // Get a copy of a cached value, with its `stale` flag brought up to date
+    pub fn read<T: CachedTelemetry + Clone>(&self, cached: &Mutex<T>) -> T {
+        let mut value = cached.lock().unwrap().clone();
+        self.check_stale(&mut value);
+        value
+    }
+
+    pub fn check_stale<T: CachedTelemetry>(&self, value: &mut T) {
+        value.check_stale(unix_now(), self.telemetry_max_age);
+    }
+
+    // Whether any log has ever been received from the receiver
+    pub fn is_connected(&self) -> bool {
+        self.connected.load(Ordering::Relaxed)
+    }
+
+    pub fn mark_connected(&self) {
+        self.connected.store(true, Ordering::Relaxed);
+    }
+
+    pub fn mark_disconnected(&self) {
+        self.connected.store(false, Ordering::Relaxed);
+    }
+
+    pub fn count_log(&self, id: MessageID) {
+        let mut counts = self.log_counts.lock().unwrap();
+        *counts.entry(id as u16).or_insert(0) += 1;
+        self.log_received.notify_all();
+    }
+
+    pub fn log_count(&self, id: MessageID) -> u64 {
+        let counts = self.log_counts.lock().unwrap();
+        counts.get(&(id as u16)).cloned().unwrap_or(0)
+    }
+
+    // Wait until more than `seen` messages of the requested log have been received.
+    // Returns false if that doesn't happen before the timeout expires
+    pub fn wait_for_log(&self, id: MessageID, seen: u64, timeout: Duration) -> bool {
+        let counts = self.log_counts.lock().unwrap();
+        let (_counts, result) = self
+            .log_received
+            .wait_timeout_while(counts, timeout, |counts| {
+                counts.get(&(id as u16)).cloned().unwrap_or(0) <= seen
+            })
+            .unwrap();
+        !result.timed_out()
+    }
+
+    // Total number of messages received, across all logs
+    pub fn total_log_count(&self) -> u64 {
+        self.log_counts.lock().unwrap().values().sum()
+    }
+
+    // Wait until more than `seen` messages have been received in total, across all logs.
+    // Returns false if that doesn't happen before the timeout expires
+    pub fn wait_for_any_log(&self, seen: u64, timeout: Duration) -> bool {
+        let counts = self.log_counts.lock().unwrap();
+        let (_counts, result) = self
+            .log_received
+            .wait_timeout_while(counts, timeout, |counts| {
+                counts.values().sum::<u64>() <= seen
+            })
+            .unwrap();
+        !result.timed_out()
+    }
+
     pub fn update_status(&self, status: LockStatus) {
         let mut local = self.status.lock().unwrap();
         *local = status;
@@ -50,52 +241,269 @@ impl LockData {
         let mut local = self.info.lock().unwrap();
         *local = info;
     }
+
+    pub fn update_clock(&self, clock: ClockInfo) {
+        let mut local = self.clock.lock().unwrap();
+        *local = clock;
+    }
+
+    pub fn update_velocity(&self, velocity: VelocityInfo) {
+        let mut local = self.velocity.lock().unwrap();
+        *local = velocity;
+    }
+
+    pub fn update_dop(&self, dop: DopInfo) {
+        let mut local = self.dop.lock().unwrap();
+        *local = dop;
+    }
+
+    pub fn update_hw_monitor(&self, hw_monitor: HardwareMonitor) {
+        let mut local = self.hw_monitor.lock().unwrap();
+        *local = hw_monitor;
+    }
+
+    pub fn update_range(&self, range: RangeObservations) {
+        let mut local = self.range.lock().unwrap();
+        *local = range;
+    }
+
+    pub fn update_log_header(&self, header: LogHeader) {
+        let mut local = self.log_header.lock().unwrap();
+        *local = Some(header);
+    }
+
+    pub fn update_constellations(&self, constellations: ConstellationStatus) {
+        let mut local = self.constellations.lock().unwrap();
+        *local = constellations;
+    }
+
+    pub fn update_tracking(&self, tracking: TrackingStatus) {
+        let mut local = self.tracking.lock().unwrap();
+        *local = tracking;
+    }
+
+    pub fn update_snapshot(&self, snapshot: TelemetrySnapshot) {
+        let mut local = self.snapshot.lock().unwrap();
+        *local = Some(snapshot);
+    }
+
+    pub fn add_mark_event(&self, event: MarkTimeEvent) {
+        let mut local = self.mark_events.lock().unwrap();
+        if local.len() == MARK_EVENT_BUFFER_LEN {
+            local.pop_front();
+        }
+        local.push_back(event);
+    }
 }
 
 // Listen for log messages from the OEM6 and route data to the appropriate
 // listener or structure.
 //
-// The OEM6 will send us one of three log messages:
+// The OEM6 will send us one of eleven log messages:
 // - Lock information. The OEM6 will likely be set up to output this data
 //   once per second.
+// - Velocity information. If enabled, this will be output by the OEM6 at the
+//   requested interval.
+// - Clock information. If enabled, this will be output by the OEM6 at the
+//   requested interval.
+// - Dilution of precision information. If enabled, this will be output by
+//   the OEM6 at the requested interval.
+// - Hardware monitor information. If enabled, this will be output by the OEM6
+//   at the requested interval.
+// - Raw range observations. If enabled, this will be output by the OEM6 at the
+//   requested interval.
+// - Channel tracking status. If enabled, this will be output by the OEM6 at the
+//   requested interval.
 // - Version information. This data will be output immediately upon request by
 //   the `noop` and `get_test_results` functions
+// - Configuration information. One message per configuration command will be
+//   output immediately upon request by the `get_configuration` function
 // - Error information. If enabled, this will be output by the OEM6 when an
 //   error or event occurs.
+// - Mark time information. If enabled, this will be output by the OEM6 when
+//   an external event occurs on one of the mark inputs.
 pub fn log_thread(
     oem: &OEM6,
     data: &Arc<LockData>,
     error_send: &SyncSender<RxStatusEventLog>,
     version_send: &SyncSender<VersionLog>,
+    rxconfig_send: &SyncSender<RxConfigLog>,
 ) {
     loop {
-        match oem.get_log().unwrap_or_else(|err| {
+        let log = oem.get_log().unwrap_or_else(|err| {
             error!("get_log failed: {:?}. Log read thread bailing", err);
             panic!("Underlying read thread no longer communicating")
-        }) {
+        });
+
+        data.mark_connected();
+        data.count_log(log.id());
+
+        match log {
+            BestVel(log) => data.update_velocity(VelocityInfo {
+                time: OEMTime {
+                    week: i32::from(log.week),
+                    ms: log.ms,
+                },
+                velocity_status: log.sol_status,
+                velocity_type: log.vel_type,
+                latency: f64::from(log.latency),
+                differential_age: f64::from(log.diff_age),
+                horizontal_speed: log.hor_speed,
+                vertical_speed: log.vert_speed,
+                track: log.track,
+                received_at: unix_now(),
+                stale: false,
+            }),
             BestXYZ(log) => {
-                if log.pos_status == 0 && log.vel_status == 0 {
-                    data.update_info(LockInfo {
-                        time: OEMTime {
-                            week: i32::from(log.week),
-                            ms: log.ms,
-                        },
+                data.update_log_header(LogHeader::new(&log));
+
+                let time = OEMTime {
+                    week: i32::from(log.week),
+                    ms: log.ms,
+                };
+
+                let info = if log.pos_status == 0 && log.vel_status == 0 {
+                    let info = LockInfo {
+                        time: time.clone(),
                         position: log.position,
                         velocity: log.velocity,
-                    });
-                }
-                data.update_status(LockStatus {
+                        solution_age: f64::from(log.sol_age),
+                        received_at: unix_now(),
+                        stale: false,
+                        device_connected: true,
+                    };
+                    data.update_info(info.clone());
+                    Some(info)
+                } else {
+                    None
+                };
+
+                let status = LockStatus {
                     time_status: log.time_status,
-                    time: OEMTime {
-                        week: i32::from(log.week),
-                        ms: log.ms,
-                    },
+                    time: time.clone(),
                     position_status: log.pos_status,
                     position_type: log.pos_type,
                     velocity_status: log.vel_status,
                     velocity_type: log.vel_type,
+                    differential_age: LockStatus::differential_age(log.pos_type, log.diff_age),
+                    rejected_measurements: LockStatus::rejected_measurements(
+                        log.num_sats,
+                        log.num_sat_vehicles,
+                    ),
+                    received_at: unix_now(),
+                    stale: false,
+                    device_connected: true,
+                };
+                data.update_status(status.clone());
+
+                // The cached DOP values may be from an earlier epoch. If so, they're left out
+                // until the PsrDop log for this epoch arrives
+                let dop_info = {
+                    let dop = data.dop.lock().unwrap();
+                    if dop.received_at != 0.0 && dop.time == time {
+                        Some(dop.clone())
+                    } else {
+                        None
+                    }
+                };
+
+                data.update_snapshot(TelemetrySnapshot {
+                    time,
+                    lock_status: status,
+                    lock_info: info,
+                    ground_velocity: GroundVelocity::from_ecef(log.position, log.velocity),
+                    dop_info,
+                    // The errors are filled in when the snapshot is requested
+                    system_status: SystemStatus::new(log.recv_status, vec![]),
                 });
             }
+            HwMonitor(log) => {
+                // Readings the receiver doesn't report keep the no-data value
+                let reading = |reading_type| {
+                    log.reading(reading_type)
+                        .map_or(HW_MONITOR_NO_DATA, f64::from)
+                };
+                data.update_hw_monitor(HardwareMonitor {
+                    time: OEMTime {
+                        week: i32::from(log.week),
+                        ms: log.ms,
+                    },
+                    temperature: reading(HW_TEMPERATURE),
+                    antenna_current: reading(HW_ANTENNA_CURRENT),
+                    core_voltage: reading(HW_CORE_VOLTAGE),
+                    received_at: unix_now(),
+                    stale: false,
+                })
+            }
+            MarkTime(log) => data.add_mark_event(MarkTimeEvent {
+                week: log.mark_week as i32,
+                ms: (log.seconds * 1000.0).round() as i32,
+                event_id: i32::from(log.event_id),
+            }),
+            PsrDop(log) => {
+                // The receiver reports zeroed DOP values when it has no solution
+                let dop = if log.pdop > 0.0 {
+                    let pdop = f64::from(log.pdop);
+                    let hdop = f64::from(log.hdop);
+                    DopInfo {
+                        time: OEMTime {
+                            week: i32::from(log.week),
+                            ms: log.ms,
+                        },
+                        pdop,
+                        hdop,
+                        vdop: (pdop * pdop - hdop * hdop).max(0.0).sqrt(),
+                        tdop: f64::from(log.tdop),
+                        received_at: unix_now(),
+                        stale: false,
+                    }
+                } else {
+                    DopInfo::default()
+                };
+
+                // The DOP log may follow the BestXYZ log for the same epoch
+                if let Some(ref mut snapshot) = *data.snapshot.lock().unwrap() {
+                    if dop.received_at != 0.0 && dop.time == snapshot.time {
+                        snapshot.dop_info = Some(dop.clone());
+                    }
+                }
+
+                data.update_dop(dop)
+            }
+            Range(log) => data.update_range(RangeObservations {
+                time: OEMTime {
+                    week: i32::from(log.week),
+                    ms: log.ms,
+                },
+                observations: log
+                    .observations
+                    .iter()
+                    .map(|obs| RangeObservation {
+                        prn: i32::from(obs.prn),
+                        system: obs.system().map(Constellation::from),
+                        pseudorange: obs.psr,
+                        carrier_phase: obs.adr,
+                        doppler_hz: f64::from(obs.doppler),
+                        cno: f64::from(obs.cno),
+                        lock_time: f64::from(obs.lock_time),
+                    })
+                    .collect(),
+                received_at: unix_now(),
+                stale: false,
+            }),
+            RxConfig(log) => rxconfig_send
+                .try_send(log)
+                .or_else::<TrySendError<RxConfigLog>, _>(|err| match err {
+                    // Our buffer is full, but the receiver should still be alive, so let's keep going
+                    TrySendError::Full(_) => Ok(()),
+                    TrySendError::Disconnected(_) => {
+                        let msg =
+                            "Config receiver disconnected. Assuming system has become corrupted";
+                        error!("{}", msg);
+                        panic!("{}", msg)
+                    }
+                })
+                .unwrap(),
             RxStatusEvent(log) => error_send
                 .try_send(log)
                 .or_else::<TrySendError<RxStatusEventLog>, _>(|err| match err {
@@ -109,6 +517,37 @@ pub fn log_thread(
                     }
                 })
                 .unwrap(),
+            Time(log) => data.update_clock(ClockInfo {
+                time: OEMTime {
+                    week: i32::from(log.week),
+                    ms: log.ms,
+                },
+                utc_offset: log.utc_offset,
+                clock_offset: log.offset,
+                clock_offset_std_dev: log.offset_std,
+                received_at: unix_now(),
+                stale: false,
+            }),
+            TrackStat(log) => {
+                let time = OEMTime {
+                    week: i32::from(log.week),
+                    ms: log.ms,
+                };
+                let received_at = unix_now();
+
+                data.update_constellations(ConstellationStatus {
+                    time: time.clone(),
+                    constellations: ConstellationTracking::from_channels(&log.channels),
+                    received_at,
+                    stale: false,
+                });
+                data.update_tracking(TrackingStatus {
+                    time,
+                    channels: TrackingChannel::from_channels(&log.channels),
+                    received_at,
+                    stale: false,
+                });
+            }
             Version(log) => version_send
                 .try_send(log)
                 .or_else::<TrySendError<VersionLog>, _>(|err| match err {
@@ -126,29 +565,74 @@ pub fn log_thread(
     }
 }
 
+// Read position of the `newErrors` query within the master errors list
+#[derive(Default)]
+pub struct ErrorCursor {
+    // Index of the first entry in `errors` which hasn't been returned yet
+    index: usize,
+    // Unreturned entries which were removed from `errors` by the `errors` query
+    carried: Vec<String>,
+}
+
 #[derive(Clone)]
 pub struct Subsystem {
     pub oem: OEM6,
     pub last_cmd: Arc<RwLock<AckCommand>>,
     pub errors: Arc<RwLock<Vec<String>>>,
+    pub error_cursor: Arc<Mutex<ErrorCursor>>,
     pub lock_data: Arc<LockData>,
     pub error_recv: Arc<Mutex<Receiver<RxStatusEventLog>>>,
     pub version_recv: Arc<Mutex<Receiver<VersionLog>>>,
+    pub rxconfig_recv: Arc<Mutex<Receiver<RxConfigLog>>>,
+    pub version_info: Arc<RwLock<Option<VersionInfo>>>,
+    pub applied_config: Arc<RwLock<Vec<AppliedConfig>>>,
+    pub bus: String,
+    pub baud_rate: Arc<RwLock<u32>>,
+    pub open_port: PortOpener,
 }
 
 impl Subsystem {
-    pub fn new(bus: &str, data: Arc<LockData>) -> OEMResult<Subsystem> {
+    pub fn new(bus: &str, baud_rate: u32, data: Arc<LockData>) -> OEMResult<Subsystem> {
+        let open_port = serial_port_opener();
+        let conn = open_port(bus, baud_rate)?;
+
+        let subsystem = Subsystem::from_connection(conn, bus, baud_rate, open_port, data);
+
+        info!("Kubos OEM6 service started");
+
+        Ok(subsystem)
+    }
+
+    // Drive the service from previously captured OEM6 output rather than a live device.
+    // The capture goes through the same read thread and log parsing as live data, so the
+    // cached telemetry ends up exactly as it would have in flight. There is no port to reopen,
+    // so `setBaud` requests will fail
+    pub fn replay(source: ReplaySource, data: Arc<LockData>) -> Subsystem {
+        let subsystem = Subsystem::from_connection(
+            Connection::new(Box::new(source)),
+            "replay",
+            DEFAULT_BAUD_RATE,
+            Arc::new(|_: &str, _: u32| Err(OEMError::GenericError)),
+            data,
+        );
+
+        info!("Kubos OEM6 service started in replay mode");
+
+        subsystem
+    }
+
+    fn from_connection(
+        conn: Connection,
+        bus: &str,
+        baud_rate: u32,
+        open_port: PortOpener,
+        data: Arc<LockData>,
+    ) -> Subsystem {
         let (log_send, log_recv) = sync_channel(5);
         let (response_send, response_recv) = sync_channel(5);
         let (response_abbrv_send, response_abbrv_recv) = sync_channel(5);
 
-        let oem = OEM6::new(
-            bus,
-            BaudRate::Baud9600,
-            log_recv,
-            response_recv,
-            response_abbrv_recv,
-        )?;
+        let oem = OEM6::from_connection(conn, log_recv, response_recv, response_abbrv_recv);
 
         let rx_conn = oem.conn.clone();
         thread::spawn(move || {
@@ -157,44 +641,114 @@ impl Subsystem {
 
         let (error_send, error_recv) = sync_channel(10);
         let (version_send, version_recv) = sync_channel(1);
+        let (rxconfig_send, rxconfig_recv) = sync_channel(RXCONFIG_BUFFER_LEN);
 
         let data_ref = data.clone();
         let oem_ref = oem.clone();
-        thread::spawn(move || log_thread(&oem_ref, &data_ref, &error_send, &version_send));
-
-        info!("Kubos OEM6 service started");
+        thread::spawn(move || {
+            log_thread(
+                &oem_ref,
+                &data_ref,
+                &error_send,
+                &version_send,
+                &rxconfig_send,
+            )
+        });
 
-        Ok(Subsystem {
+        Subsystem {
             oem,
             last_cmd: Arc::new(RwLock::new(AckCommand::None)),
             errors: Arc::new(RwLock::new(vec![])),
+            error_cursor: Arc::new(Mutex::new(ErrorCursor::default())),
             lock_data: data,
             error_recv: Arc::new(Mutex::new(error_recv)),
             version_recv: Arc::new(Mutex::new(version_recv)),
-        })
+            rxconfig_recv: Arc::new(Mutex::new(rxconfig_recv)),
+            version_info: Arc::new(RwLock::new(None)),
+            applied_config: Arc::new(RwLock::new(vec![])),
+            bus: bus.to_owned(),
+            baud_rate: Arc::new(RwLock::new(baud_rate)),
+            open_port,
+        }
     }
 
-    fn get_version_log(&self) -> Result<VersionLog, String> {
+    fn get_version_log(&self) -> Result<VersionLog, ServiceError> {
         let result = match self.oem.request_version() {
             Ok(_) => match self
                 .version_recv
                 .lock()
-                .map_err(|err| format!("Failed to obtain version_recv mutex: {:?}", err))?
+                .map_err(|err| {
+                    ServiceError::new(
+                        ErrorCategory::Internal,
+                        format!("Failed to obtain version_recv mutex: {:?}", err),
+                    )
+                })?
                 .recv_timeout(RECV_TIMEOUT)
             {
                 Ok(log) => Ok(log),
-                Err(err) => Err(format!("Failed to receive version info - {}", err)),
+                Err(err) => Err(ServiceError::new(
+                    match err {
+                        RecvTimeoutError::Timeout => ErrorCategory::Timeout,
+                        RecvTimeoutError::Disconnected => ErrorCategory::Internal,
+                    },
+                    format!("Failed to receive version info - {}", err),
+                )),
             },
-            Err(err) => Err(process_errors!(err)),
+            Err(err) => Err(ServiceError::from(err)),
         };
 
-        if result.is_err() {
-            error!("{:?}", result);
+        match result {
+            // The firmware information never changes within a session, so keep a copy
+            // to serve future debug telemetry requests from
+            Ok(ref log) => self.cache_version(Some(VersionInfo::new(log))),
+            Err(_) => error!("{:?}", result),
         }
 
         result
     }
 
+    fn cache_version(&self, info: Option<VersionInfo>) {
+        match self.version_info.write() {
+            Ok(mut cache) => *cache = info,
+            Err(_) => error!("Failed to borrow version info cache"),
+        }
+    }
+
+    // Get the version information, only querying the device if it hasn't been read yet
+    fn get_version_info(&self) -> Result<VersionInfo, ServiceError> {
+        if let Ok(cache) = self.version_info.read() {
+            if let Some(ref info) = *cache {
+                return Ok(info.clone());
+            }
+        }
+
+        self.get_version_log().map(|log| VersionInfo::new(&log))
+    }
+
+    fn get_rxconfig_logs(&self) -> Result<Vec<RxConfigLog>, String> {
+        self.oem
+            .request_rxconfig()
+            .map_err(|err| process_errors!(err))?;
+
+        let recv = self
+            .rxconfig_recv
+            .lock()
+            .map_err(|err| format!("Failed to obtain rxconfig_recv mutex: {:?}", err))?;
+
+        let first = recv
+            .recv_timeout(RECV_TIMEOUT)
+            .map_err(|err| format!("Failed to receive configuration info - {}", err))?;
+
+        // The OEM6 sends one log per configuration command, so keep reading
+        // until it stops sending them
+        let mut logs = vec![first];
+        while let Ok(log) = recv.recv_timeout(RECV_TIMEOUT) {
+            logs.push(log);
+        }
+
+        Ok(logs)
+    }
+
     // Queries
 
     pub fn get_errors(&self) {
@@ -233,6 +787,49 @@ impl Subsystem {
         }
     }
 
+    // Return and clear the master errors list.
+    // Entries which `newErrors` hasn't returned yet are kept aside for it
+    pub fn take_errors(&self) -> Vec<String> {
+        self.get_errors();
+
+        match self.errors.write() {
+            Ok(mut master_vec) => {
+                if let Ok(mut cursor) = self.error_cursor.lock() {
+                    let start = cursor.index.min(master_vec.len());
+                    cursor.carried.extend_from_slice(&master_vec[start..]);
+                    cursor.index = 0;
+                }
+
+                let current = master_vec.clone();
+                master_vec.clear();
+                master_vec.shrink_to_fit();
+                current
+            }
+            _ => vec!["Error: Failed to borrow master errors vector".to_owned()],
+        }
+    }
+
+    // Return only the errors which have been recorded since the last call
+    pub fn get_new_errors(&self) -> Vec<String> {
+        self.get_errors();
+
+        let master_vec = match self.errors.read() {
+            Ok(master_vec) => master_vec,
+            _ => return vec!["Error: Failed to borrow master errors vector".to_owned()],
+        };
+
+        match self.error_cursor.lock() {
+            Ok(mut cursor) => {
+                let start = cursor.index.min(master_vec.len());
+                let mut new = std::mem::replace(&mut cursor.carried, vec![]);
+                new.extend_from_slice(&master_vec[start..]);
+                cursor.index = master_vec.len();
+                new
+            }
+            _ => vec!["Error: Failed to obtain error_cursor mutex".to_owned()],
+        }
+    }
+
     pub fn get_power(&self) -> Result<GetPowerResponse, Error> {
         let (state, uptime) = if self.get_version_log().is_ok() {
             (PowerState::On, 1)
@@ -243,55 +840,192 @@ impl Subsystem {
         Ok(GetPowerResponse { state, uptime })
     }
 
-    pub fn get_system_status(&self) -> Result<SystemStatus, Error> {
+    // Collect any new errors from the receiver and return a copy of the master list
+    fn current_errors(&self, caller: &str) -> Vec<String> {
         self.get_errors();
 
-        let mut errors = match self.errors.read() {
+        match self.errors.read() {
             Ok(master_vec) => master_vec.clone(),
             _ => {
-                error!("get_system_status - Failed to borrow master errors vector");
+                error!("{} - Failed to borrow master errors vector", caller);
                 vec!["Error: Failed to borrow master errors vector".to_owned()]
             }
-        };
+        }
+    }
+
+    pub fn get_system_status(&self) -> Result<SystemStatus, Error> {
+        Ok(self.query_system_status().0)
+    }
 
-        let status = match self.get_version_log() {
-            Ok(log) => log.recv_status,
+    // Request the current receiver status. Also returns whether the receiver responded
+    fn query_system_status(&self) -> (SystemStatus, bool) {
+        let mut errors = self.current_errors("get_system_status");
+
+        let (status, responded) = match self.get_version_log() {
+            Ok(log) => (log.recv_status, true),
             Err(err) => {
                 errors.push(format!("System Status: {}", err));
                 /* My first thought was to use ReceiverStatusFlags::empty(),
                  * but I'm worried that that is easily mistaken for this function
                  * actually having succeeded
                  */
-                ReceiverStatusFlags::all()
+                (ReceiverStatusFlags::all(), false)
             }
         };
 
-        Ok(SystemStatus {
-            status: ReceiverStatus(status),
-            errors,
+        (SystemStatus::new(status, errors), responded)
+    }
+
+    pub fn get_health(&self) -> Result<Health, Error> {
+        let (status, responded) = self.query_system_status();
+
+        // A receiver which is still sending logs is powered, even if it missed the request
+        let lock_status = self.get_lock_status()?;
+        let powered = responded || lock_status.device_connected;
+        let locked =
+            lock_status.position_status == 0 && !lock_status.stale && lock_status.device_connected;
+
+        Ok(Health {
+            powered,
+            locked,
+            errors: status.errors,
+            status_flags: status.status,
         })
     }
 
+    pub fn get_configuration(&self) -> Result<Vec<ActiveLog>, Error> {
+        match self.get_rxconfig_logs() {
+            Ok(logs) => Ok(logs
+                .iter()
+                .filter_map(RxConfigLog::log_request)
+                .map(|request| ActiveLog::from_request(&request))
+                .collect()),
+            Err(err) => {
+                push_err!(self.errors, format!("Configuration: {}", err));
+                Ok(vec![])
+            }
+        }
+    }
+
+    pub fn get_port_config(&self) -> Result<Vec<LogPortAssignment>, Error> {
+        match self.get_rxconfig_logs() {
+            Ok(logs) => Ok(logs
+                .iter()
+                .filter_map(RxConfigLog::log_request)
+                .map(|request| LogPortAssignment::from_request(&request))
+                .collect()),
+            Err(err) => {
+                push_err!(self.errors, format!("Port config: {}", err));
+                Ok(vec![])
+            }
+        }
+    }
+
     pub fn get_lock_status(&self) -> Result<LockStatus, Error> {
-        Ok(self.lock_data.status.lock().unwrap().clone())
+        let mut status = self.lock_data.read(&self.lock_data.status);
+        status.device_connected = self.lock_data.is_connected();
+        Ok(status)
     }
 
+    // The cached solution is only updated on a good fix, so the connection state
+    // needs to be filled in separately
     pub fn get_lock_info(&self) -> Result<LockInfo, Error> {
-        Ok(self.lock_data.info.lock().unwrap().clone())
+        let mut info = self.lock_data.read(&self.lock_data.info);
+        info.device_connected = self.lock_data.is_connected();
+        Ok(info)
+    }
+
+    pub fn get_velocity_info(&self) -> Result<VelocityInfo, Error> {
+        Ok(self.lock_data.read(&self.lock_data.velocity))
+    }
+
+    pub fn get_clock_info(&self) -> Result<ClockInfo, Error> {
+        Ok(self.lock_data.read(&self.lock_data.clock))
+    }
+
+    pub fn get_dop_info(&self) -> Result<DopInfo, Error> {
+        Ok(self.lock_data.read(&self.lock_data.dop))
+    }
+
+    pub fn get_applied_configuration(&self) -> Result<Vec<AppliedConfig>, Error> {
+        match self.applied_config.read() {
+            Ok(applied) => Ok(applied.clone()),
+            Err(_) => {
+                error!("Failed to borrow applied configuration");
+                Ok(vec![])
+            }
+        }
+    }
+
+    pub fn get_hardware_monitor(&self) -> Result<HardwareMonitor, Error> {
+        Ok(self.lock_data.read(&self.lock_data.hw_monitor))
+    }
+
+    pub fn get_range_observations(&self) -> Result<RangeObservations, Error> {
+        Ok(self.lock_data.read(&self.lock_data.range))
+    }
+
+    pub fn get_log_header(&self) -> Option<LogHeader> {
+        self.lock_data.log_header.lock().unwrap().clone()
+    }
+
+    pub fn get_constellation_status(&self) -> Result<ConstellationStatus, Error> {
+        Ok(self.lock_data.read(&self.lock_data.constellations))
+    }
+
+    pub fn get_tracking_status(&self) -> Result<TrackingStatus, Error> {
+        Ok(self.lock_data.read(&self.lock_data.tracking))
+    }
+
+    // Events are removed from the buffer once they've been returned
+    pub fn get_mark_time_events(&self) -> Result<Vec<MarkTimeEvent>, Error> {
+        Ok(self
+            .lock_data
+            .mark_events
+            .lock()
+            .unwrap()
+            .drain(..)
+            .collect())
+    }
+
+    pub fn get_snapshot(&self) -> Result<Option<TelemetrySnapshot>, Error> {
+        let mut snapshot = self.lock_data.snapshot.lock().unwrap().clone();
+
+        if let Some(ref mut snapshot) = snapshot {
+            self.lock_data.check_stale(&mut snapshot.lock_status);
+            if let Some(ref mut dop) = snapshot.dop_info {
+                self.lock_data.check_stale(dop);
+            }
+            if let Some(ref mut info) = snapshot.lock_info {
+                self.lock_data.check_stale(info);
+            }
+
+            // Report the same errors as the `systemStatus` query
+            snapshot.system_status.errors = self.current_errors("get_snapshot");
+        }
+
+        Ok(snapshot)
     }
 
     pub fn get_test_results(&self) -> Result<IntegrationTestResults, Error> {
-        let telem = self.get_telemetry()?;
+        let (telem, error) = self.read_telemetry();
 
         Ok(IntegrationTestResults {
             success: telem.debug.is_some(),
             errors: telem.nominal.system_status.errors.join("; "),
+            error_details: error.into_iter().collect(),
             telemetry_debug: telem.debug.clone(),
             telemetry_nominal: telem.nominal,
         })
     }
 
     pub fn get_telemetry(&self) -> Result<Telemetry, Error> {
+        Ok(self.read_telemetry().0)
+    }
+
+    // Get the current telemetry, along with the error encountered while requesting
+    // the version information, if any
+    fn read_telemetry(&self) -> (Telemetry, Option<ServiceError>) {
         self.get_errors();
 
         let mut errors = match self.errors.read() {
@@ -302,37 +1036,121 @@ impl Subsystem {
             }
         };
 
-        let (status, version_info) = match self.get_version_log() {
-            Ok(log) => (
-                log.recv_status,
-                Some(VersionInfo {
-                    num_components: log.num_components as i32,
-                    components: log
-                        .components
-                        .iter()
-                        .map(|comp| VersionComponent(comp.clone()))
-                        .collect(),
-                }),
-            ),
+        let (status, version_info, error) = match self.get_version_log() {
+            Ok(log) => (log.recv_status, Some(VersionInfo::new(&log)), None),
             Err(err) => {
                 let temp = format!("Get Telemetry: {}", err);
                 errors.push(temp.clone());
                 push_err!(self.errors, temp);
-                (ReceiverStatusFlags::all(), None)
+                (ReceiverStatusFlags::all(), None, Some(err))
             }
         };
 
         let lock_status = self.get_lock_status().ok();
         let lock_info = self.get_lock_info().ok();
 
-        Ok(Telemetry {
+        let telem = Telemetry {
             nominal: TelemetryNominal {
-                system_status: SystemStatus {
-                    status: ReceiverStatus(status),
-                    errors,
-                },
+                system_status: SystemStatus::new(status, errors),
                 lock_status,
                 lock_info,
+                log_header: self.get_log_header(),
+            },
+            debug: version_info,
+        };
+
+        (telem, error)
+    }
+
+    // Gather the commonly requested telemetry in a single call. The receiver is only queried,
+    // once, if the power state or system status is needed. Everything else comes from the caches
+    pub fn get_batch_telemetry(&self, status: bool, debug: bool) -> Result<BatchTelemetry, Error> {
+        let (power, system_status) = if status {
+            self.get_errors();
+
+            let errors = match self.errors.read() {
+                Ok(master_vec) => master_vec.clone(),
+                _ => {
+                    error!("get_batch_telemetry - Failed to borrow master errors vector");
+                    vec!["Error: Failed to borrow master errors vector".to_owned()]
+                }
+            };
+
+            match self.get_version_log() {
+                Ok(log) => (
+                    Some(GetPowerResponse {
+                        state: PowerState::On,
+                        uptime: 1,
+                    }),
+                    Some(SystemStatus::new(log.recv_status, errors)),
+                ),
+                Err(err) => {
+                    push_err!(self.errors, format!("Batch Telemetry: {}", err));
+                    (
+                        Some(GetPowerResponse {
+                            state: PowerState::Off,
+                            uptime: 0,
+                        }),
+                        None,
+                    )
+                }
+            }
+        } else {
+            (None, None)
+        };
+
+        // A successful version request above will have refreshed the cache
+        let telemetry_debug = if debug {
+            match self.version_info.read() {
+                Ok(cache) => cache.clone(),
+                Err(_) => {
+                    error!("Failed to borrow version info cache");
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        Ok(BatchTelemetry {
+            power,
+            system_status,
+            lock_status: self.get_lock_status().ok(),
+            lock_info: self.get_lock_info().ok(),
+            telemetry_debug,
+        })
+    }
+
+    // Get the debug telemetry without requesting the nominal system status from the device.
+    // The version information is served from the cache once it has been read
+    pub fn get_telemetry_debug(&self) -> Result<Telemetry, Error> {
+        self.get_errors();
+
+        let mut errors = match self.errors.read() {
+            Ok(master_vec) => master_vec.clone(),
+            _ => {
+                error!("get_telemetry_debug - Failed to borrow master errors vector");
+                vec!["Error: Failed to borrow master errors vector".to_owned()]
+            }
+        };
+
+        let version_info = match self.get_version_info() {
+            Ok(info) => Some(info),
+            Err(err) => {
+                let temp = format!("Get Telemetry: {}", err);
+                errors.push(temp.clone());
+                push_err!(self.errors, temp);
+                None
+            }
+        };
+
+        // The nominal telemetry wasn't requested, so the receiver status is left unknown
+        Ok(Telemetry {
+            nominal: TelemetryNominal {
+                system_status: SystemStatus::new(ReceiverStatusFlags::all(), errors),
+                lock_status: self.get_lock_status().ok(),
+                lock_info: self.get_lock_info().ok(),
+                log_header: self.get_log_header(),
             },
             debug: version_info,
         })
@@ -345,16 +1163,20 @@ impl Subsystem {
 
         let success = result.is_ok();
 
-        let errors = match result {
-            Ok(_) => "".to_owned(),
+        let (errors, error_details) = match result {
+            Ok(_) => ("".to_owned(), vec![]),
             Err(err) => {
                 let temp = format!("Noop: {}", err);
                 push_err!(self.errors, temp);
-                err
+                (err.message.clone(), vec![err])
             }
         };
 
-        Ok(GenericResponse { success, errors })
+        Ok(GenericResponse {
+            success,
+            errors,
+            error_details,
+        })
     }
 
     pub fn configure_hardware(
@@ -363,66 +1185,586 @@ impl Subsystem {
     ) -> Result<ConfigureHardwareResponse, Error> {
         let mut success = true;
         let mut errors = "".to_owned();
+        let mut error_details = vec![];
         let mut config = "".to_owned();
 
         for entry in input.iter() {
-            let result = run!(
+            if !config.is_empty() {
+                config.push_str(", ");
+            }
+            config.push_str(&format!("{:?}(Hold: {})", entry.option, entry.hold));
+            if entry.interval != 0.0 {
+                config.push_str(&format!(": {}+{}sec", entry.interval, entry.offset));
+            }
+            if entry.format == LogFormat::Ascii {
+                config.push_str(" (ASCII)");
+            }
+
+            // Invalid values are never sent to the device, since they can wedge the receiver
+            if let Err(err) = entry.validate() {
+                push_err!(
+                    self.errors,
+                    format!("configureHardware: {:?}: {}", entry.option, err)
+                );
+                success = false;
+                if !errors.is_empty() {
+                    errors.push_str(". ");
+                }
+                errors.push_str(&format!("{:?}: {}", entry.option, err));
+                error_details.push(ServiceError::new(
+                    ErrorCategory::InvalidInput,
+                    format!("{:?}: {}", entry.option, err),
+                ));
+                continue;
+            }
+
+            // Logs which were already arriving don't count towards verification
+            let seen = entry.verified_log().map(|id| self.lock_data.log_count(id));
+
+            let result = run_oem!(
                 match entry.option {
+                    ConfigOption::LogDopData => self.oem.request_log(
+                        MessageID::PsrDop,
+                        entry.format.into(),
+                        entry.interval,
+                        entry.offset,
+                        entry.hold,
+                    ),
                     ConfigOption::LogErrorData => self.oem.request_errors(entry.hold),
-                    ConfigOption::LogPositionData => {
+                    ConfigOption::LogHwMonitor => {
+                        self.oem
+                            .request_hw_monitor(entry.interval, entry.offset, entry.hold)
+                    }
+                    ConfigOption::LogMarkTime => self.oem.request_mark_time(entry.hold),
+                    ConfigOption::LogPositionData => self.oem.request_log(
+                        MessageID::BestXYZ,
+                        entry.format.into(),
+                        entry.interval,
+                        entry.offset,
+                        entry.hold,
+                    ),
+                    ConfigOption::LogRangeData => {
                         self.oem
-                            .request_position(entry.interval, entry.offset, entry.hold)
+                            .request_range(entry.interval, entry.offset, entry.hold)
                     }
+                    ConfigOption::LogTimeData => self.oem.request_log(
+                        MessageID::Time,
+                        entry.format.into(),
+                        entry.interval,
+                        entry.offset,
+                        entry.hold,
+                    ),
+                    ConfigOption::LogTrackingData => {
+                        self.oem
+                            .request_track_stat(entry.interval, entry.offset, entry.hold)
+                    }
+                    ConfigOption::LogVelocityData => self.oem.request_log(
+                        MessageID::BestVel,
+                        entry.format.into(),
+                        entry.interval,
+                        entry.offset,
+                        entry.hold,
+                    ),
+                    ConfigOption::SaveConfig => self.oem.request_save_config(),
                     ConfigOption::UnlogAll => self.oem.request_unlog_all(entry.hold),
+                    ConfigOption::UnlogDopData => self.oem.request_unlog(MessageID::PsrDop),
                     ConfigOption::UnlogErrorData => {
                         self.oem.request_unlog(MessageID::RxStatusEvent)
                     }
+                    ConfigOption::UnlogHwMonitor => self.oem.request_unlog(MessageID::HwMonitor),
+                    ConfigOption::UnlogMarkTime => self
+                        .oem
+                        .request_unlog(MessageID::Mark1Time)
+                        .and_then(|_| self.oem.request_unlog(MessageID::Mark2Time)),
                     ConfigOption::UnlogPositionData => self.oem.request_unlog(MessageID::BestXYZ),
+                    ConfigOption::UnlogRangeData => self.oem.request_unlog(MessageID::Range),
+                    ConfigOption::UnlogTimeData => self.oem.request_unlog(MessageID::Time),
+                    ConfigOption::UnlogTrackingData => self.oem.request_unlog(MessageID::TrackStat),
+                    ConfigOption::UnlogVelocityData => self.oem.request_unlog(MessageID::BestVel),
                 },
                 self.errors
             );
 
+            let result = match (result, entry.verified_log(), seen) {
+                (Ok(()), Some(id), Some(seen)) if entry.verify => self.verify_log(entry, id, seen),
+                (result, _, _) => result,
+            };
+
             success &= result.is_ok();
-            if let Err(err) = result {
+            if result.is_ok() {
+                match self.applied_config.write() {
+                    Ok(mut applied) => AppliedConfig::apply(&mut applied, entry),
+                    Err(_) => error!("Failed to borrow applied configuration"),
+                }
+            }
+            if let Err(mut err) = result {
+                err.message = format!("{:?}: {}", entry.option, err);
                 if !errors.is_empty() {
                     errors.push_str(". ");
                 }
-                errors.push_str(&format!("{:?}: {}", entry.option, err));
-            }
-
-            if !config.is_empty() {
-                config.push_str(", ");
-            }
-            config.push_str(&format!("{:?}(Hold: {})", entry.option, entry.hold));
-            if entry.interval != 0.0 {
-                config.push_str(&format!(": {}+{}sec", entry.interval, entry.offset));
+                errors.push_str(&err.message);
+                error_details.push(err);
             }
         }
 
         Ok(ConfigureHardwareResponse {
             success,
             errors,
+            error_details,
             config,
         })
     }
 
-    pub fn passthrough(&self, command: String) -> Result<GenericResponse, Error> {
-        // Convert the hex values in the string into actual hex values
-        // Ex. "c3c2" -> [0xc3, 0xc2]
-        let tx: Vec<u8> = command
-            .as_bytes()
-            .chunks(2)
-            .map(|chunk| u8::from_str_radix(::std::str::from_utf8(chunk).unwrap(), 16).unwrap())
-            .collect();
+    // Wait for the first message of a newly requested log to arrive
+    fn verify_log(
+        &self,
+        entry: &ConfigStruct,
+        id: MessageID,
+        seen: u64,
+    ) -> Result<(), ServiceError> {
+        let wait = entry.verify_wait();
+        if self
+            .lock_data
+            .wait_for_log(id, seen, Duration::from_secs_f64(wait))
+        {
+            return Ok(());
+        }
+
+        let err = ServiceError::new(
+            ErrorCategory::Timeout,
+            format!("No {:?} log received within {}sec", id, wait),
+        );
+        push_err!(
+            self.errors,
+            format!("configureHardware: {:?}: {}", entry.option, err)
+        );
+        Err(err)
+    }
+
+    pub fn save_configuration(&self) -> Result<GenericResponse, Error> {
+        Ok(GenericResponse::from(run_oem!(
+            self.oem.request_save_config(),
+            self.errors
+        )))
+    }
+
+    pub fn set_elevation_mask(&self, degrees: i32) -> Result<GenericResponse, Error> {
+        if !(0..=90).contains(&degrees) {
+            let err = ServiceError::new(
+                ErrorCategory::InvalidInput,
+                format!(
+                    "Elevation mask must be between 0 and 90 degrees, not {}",
+                    degrees
+                ),
+            );
+            push_err!(self.errors, format!("setElevationMask: {}", err));
+            return Ok(GenericResponse::from(Err(err)));
+        }
+
+        Ok(GenericResponse::from(run_oem!(
+            self.oem.request_elevation_cutoff(degrees as f32),
+            self.errors
+        )))
+    }
+
+    pub fn set_constellations(
+        &self,
+        systems: Vec<Constellation>,
+    ) -> Result<GenericResponse, Error> {
+        if systems.is_empty() {
+            let err = ServiceError::new(
+                ErrorCategory::InvalidInput,
+                "At least one constellation must be selected".to_owned(),
+            );
+            push_err!(self.errors, format!("setConstellations: {}", err));
+            return Ok(GenericResponse::from(Err(err)));
+        }
 
-        let result = run!(self.oem.passthrough(tx.as_slice()), self.errors);
+        let selected: Vec<novatel_oem6_api::Constellation> =
+            systems.into_iter().map(|system| system.into()).collect();
+
+        // Every system the receiver knows about is explicitly locked out or restored,
+        // so that the result doesn't depend on any earlier selection
+        let result = novatel_oem6_api::Constellation::ALL
+            .iter()
+            .try_for_each(|&system| {
+                if selected.contains(&system) {
+                    run_oem!(self.oem.request_unlockout_system(system), self.errors)
+                } else {
+                    run_oem!(self.oem.request_lockout_system(system), self.errors)
+                }
+            });
+
+        Ok(GenericResponse::from(result))
+    }
+
+    pub fn set_approximate_position(
+        &self,
+        lat: f64,
+        lon: f64,
+        height: f64,
+    ) -> Result<GenericResponse, Error> {
+        let reason = if !(-90.0..=90.0).contains(&lat) {
+            Some(format!(
+                "Latitude must be between -90 and 90 degrees, not {}",
+                lat
+            ))
+        } else if !(-180.0..=180.0).contains(&lon) {
+            Some(format!(
+                "Longitude must be between -180 and 180 degrees, not {}",
+                lon
+            ))
+        } else if !height.is_finite() {
+            Some(format!("Height must be finite, not {}", height))
+        } else {
+            None
+        };
+
+        if let Some(reason) = reason {
+            let err = ServiceError::new(ErrorCategory::InvalidInput, reason);
+            push_err!(self.errors, format!("setApproximatePosition: {}", err));
+            return Ok(GenericResponse::from(Err(err)));
+        }
+
+        Ok(GenericResponse::from(run_oem!(
+            self.oem.request_approx_position(lat, lon, height),
+            self.errors
+        )))
+    }
+
+    pub fn set_approximate_time(&self, week: i32, seconds: f64) -> Result<GenericResponse, Error> {
+        let reason = if week < 0 {
+            Some(format!("GPS week must not be negative, not {}", week))
+        } else if !(0.0..SECONDS_PER_WEEK).contains(&seconds) {
+            Some(format!(
+                "Seconds must be between 0 and {}, not {}",
+                SECONDS_PER_WEEK, seconds
+            ))
+        } else {
+            None
+        };
+
+        if let Some(reason) = reason {
+            let err = ServiceError::new(ErrorCategory::InvalidInput, reason);
+            push_err!(self.errors, format!("setApproximateTime: {}", err));
+            return Ok(GenericResponse::from(Err(err)));
+        }
+
+        Ok(GenericResponse::from(run_oem!(
+            self.oem.request_approx_time(week as u32, seconds),
+            self.errors
+        )))
+    }
+
+    pub fn set_log_port(
+        &self,
+        log: ConfigOption,
+        new_port: OemPort,
+    ) -> Result<GenericResponse, Error> {
+        let port = Port::from(new_port);
+
+        // The log is re-requested with the same settings it was originally applied with
+        let config = if log.log_messages().is_empty() {
+            Err(format!("{:?} is not a LOG_* option", log))
+        } else if !SUPPORTED_LOG_PORTS.contains(&port) {
+            Err(format!(
+                "Unsupported log port {:?}. Must be one of {:?}",
+                port, SUPPORTED_LOG_PORTS
+            ))
+        } else {
+            self.get_applied_configuration()?
+                .into_iter()
+                .find(|config| config.option == log)
+                .ok_or_else(|| format!("{:?} has not been applied with configureHardware", log))
+        };
+
+        let mut config = match config {
+            Ok(config) => config,
+            Err(reason) => {
+                let err = ServiceError::new(ErrorCategory::InvalidInput, reason);
+                push_err!(self.errors, format!("setLogPort: {}", err));
+                return Ok(GenericResponse::from(Err(err)));
+            }
+        };
+
+        // Stop the log on its current port first, so it isn't output on both
+        let old_port = Port::from(config.port);
+        if old_port != port {
+            let result = log.log_messages().iter().try_for_each(|id| {
+                run_oem!(self.oem.request_unlog_on_port(old_port, *id), self.errors)
+            });
+            if let Err(err) = result {
+                return Ok(GenericResponse::from(Err(err)));
+            }
+        }
+
+        config.port = new_port;
+        let result = self.request_log_on_port(&config);
+
+        if result.is_ok() {
+            match self.applied_config.write() {
+                Ok(mut applied) => {
+                    if let Some(entry) = applied.iter_mut().find(|entry| entry.option == log) {
+                        entry.port = new_port;
+                    }
+                }
+                Err(_) => error!("Failed to borrow applied configuration"),
+            }
+        }
+
+        Ok(GenericResponse::from(result))
+    }
+
+    // Request an applied log on the port recorded for it
+    fn request_log_on_port(&self, config: &AppliedConfig) -> Result<(), ServiceError> {
+        // Only these logs are available in ASCII. Everything else is always requested in binary
+        let format = match config.option {
+            ConfigOption::LogDopData
+            | ConfigOption::LogPositionData
+            | ConfigOption::LogTimeData
+            | ConfigOption::LogVelocityData => config.format.into(),
+            _ => novatel_oem6_api::LogFormat::Binary,
+        };
+
+        config.option.log_messages().iter().try_for_each(|id| {
+            run_oem!(
+                self.oem.request_log_on_port(
+                    Port::from(config.port),
+                    *id,
+                    format,
+                    config.interval,
+                    config.offset,
+                    config.hold,
+                ),
+                self.errors
+            )
+        })
+    }
+
+    pub fn set_baud(&self, rate: i32) -> Result<GenericResponse, Error> {
+        if !SUPPORTED_BAUD_RATES
+            .iter()
+            .any(|&supported| supported as i32 == rate)
+        {
+            let err = ServiceError::new(
+                ErrorCategory::InvalidInput,
+                format!(
+                    "Unsupported baud rate {}. Must be one of {:?}",
+                    rate, SUPPORTED_BAUD_RATES
+                ),
+            );
+            push_err!(self.errors, format!("setBaud: {}", err));
+            return Ok(GenericResponse::from(Err(err)));
+        }
+
+        let previous = *self.baud_rate.read().unwrap_or_else(|err| err.into_inner());
+
+        Ok(GenericResponse::from(
+            self.change_baud(previous, rate as u32),
+        ))
+    }
+
+    // Switch the OEM6 and the local port over to the new data rate, and then make sure we can
+    // still talk to the device. If we can't, the OEM6 is asked to go back to the previous rate
+    // (it has already switched, so its response can't be read) and the local port follows it
+    fn change_baud(&self, previous: u32, rate: u32) -> Result<(), ServiceError> {
+        run_oem!(self.oem.request_baud_rate(rate), self.errors)?;
+        run_oem!(self.reopen_port(rate), self.errors)?;
+
+        match self.get_version_log() {
+            Ok(_) => {
+                *self
+                    .baud_rate
+                    .write()
+                    .unwrap_or_else(|err| err.into_inner()) = rate;
+                Ok(())
+            }
+            Err(err) => {
+                push_err!(
+                    self.errors,
+                    format!(
+                        "setBaud: No response at {} baud. Reverting to {} baud: {}",
+                        rate, previous, err
+                    )
+                );
+                if let Err(err) = self.oem.send_baud_rate(previous) {
+                    warn!("setBaud: Failed to request {} baud: {}", previous, err);
+                }
+                run_oem!(self.reopen_port(previous), self.errors)?;
+                Err(err)
+            }
+        }
+    }
+
+    fn reopen_port(&self, rate: u32) -> OEMResult<()> {
+        (self.open_port)(&self.bus, rate).map(|conn| self.oem.set_connection(conn))
+    }
+
+    // Watch for the logs from the OEM6 stopping, which happens if the UART link drops or the
+    // receiver resets. When they do, reopen the port and request the applied configuration
+    // again. Never returns
+    pub fn monitor_link(&self, timeout: Duration) {
+        loop {
+            let seen = self.lock_data.total_log_count();
+
+            // Without any periodic logs, silence is expected
+            let limit = match self.link_silence_limit(timeout) {
+                Some(limit) => limit,
+                None => {
+                    thread::sleep(timeout);
+                    continue;
+                }
+            };
+
+            if self.lock_data.wait_for_any_log(seen, limit) {
+                continue;
+            }
+
+            self.lock_data.mark_disconnected();
+            warn!(
+                "No logs received from OEM6 in {}sec. Reconnecting",
+                limit.as_secs_f64()
+            );
+
+            if self.reconnect().is_ok() {
+                warn!("Reconnected to OEM6 and re-applied logs");
+            }
+        }
+    }
+
+    // How long the link may go without any logs before it's considered lost. Logs are
+    // allowed to miss one interval, so slow logs don't trigger a reconnect.
+    // `None` if no logs which are guaranteed to be output are configured
+    fn link_silence_limit(&self, timeout: Duration) -> Option<Duration> {
+        let applied = self
+            .applied_config
+            .read()
+            .unwrap_or_else(|err| err.into_inner());
+
+        applied
+            .iter()
+            .filter(|config| config.interval > 0.0 && config.request().verified_log().is_some())
+            .map(|config| config.interval)
+            .fold(None, |shortest: Option<f64>, interval| {
+                Some(shortest.map_or(interval, |shortest| shortest.min(interval)))
+            })
+            .map(|interval| timeout.max(Duration::from_secs_f64(2.0 * interval)))
+    }
+
+    // Reopen the port at the current data rate and request every applied log again,
+    // on the port it was last output on
+    pub fn reconnect(&self) -> Result<(), ServiceError> {
+        let rate = *self.baud_rate.read().unwrap_or_else(|err| err.into_inner());
+        run_oem!(self.reopen_port(rate), self.errors)?;
+
+        let (applied, moved): (Vec<AppliedConfig>, Vec<AppliedConfig>) = self
+            .applied_config
+            .read()
+            .unwrap_or_else(|err| err.into_inner())
+            .iter()
+            .cloned()
+            .partition(|config| config.port == OemPort::Com1);
+
+        // Any failures have already been recorded by `configure_hardware`
+        let response = self
+            .configure_hardware(applied.iter().map(AppliedConfig::request).collect())
+            .map_err(|err| ServiceError::new(ErrorCategory::Internal, err.to_string()))?;
+        let mut result = match response.error_details.into_iter().next() {
+            Some(err) => Err(err),
+            None => Ok(()),
+        };
+
+        for config in moved {
+            let moved_result = self.request_log_on_port(&config);
+            if result.is_ok() {
+                result = moved_result;
+            }
+        }
+
+        result
+    }
+
+    pub fn refresh_version(&self) -> Result<GenericResponse, Error> {
+        self.cache_version(None);
+
+        let result = self.get_version_log();
+
+        let success = result.is_ok();
+
+        let (errors, error_details) = match result {
+            Ok(_) => ("".to_owned(), vec![]),
+            Err(err) => {
+                let temp = format!("Refresh Version: {}", err);
+                push_err!(self.errors, temp);
+                (err.message.clone(), vec![err])
+            }
+        };
 
         Ok(GenericResponse {
-            success: result.is_ok(),
-            errors: match result {
-                Ok(_) => "".to_owned(),
-                Err(err) => err,
-            },
+            success,
+            errors,
+            error_details,
         })
     }
+
+    pub fn reset(&self, reset_type: ResetType) -> Result<GenericResponse, Error> {
+        // A reset (particularly a factory reset) may change the reported versions
+        self.cache_version(None);
+
+        let result = match reset_type {
+            ResetType::Hot => run_oem!(self.oem.request_reset(0), self.errors),
+            ResetType::Warm => run_oem!(
+                self.oem.request_freset(FResetTarget::GpsEphemeris),
+                self.errors
+            ),
+            ResetType::Cold => run_oem!(
+                self.oem
+                    .request_freset(FResetTarget::GpsEphemeris)
+                    .and_then(|_| self.oem.request_freset(FResetTarget::GpsAlmanac)),
+                self.errors
+            ),
+            ResetType::Factory => {
+                run_oem!(self.oem.request_freset(FResetTarget::Standard), self.errors)
+            }
+        };
+
+        // The factory settings don't include any of the service's logs, so they
+        // mustn't be requested again if the link is lost
+        if let (ResetType::Factory, Ok(())) = (reset_type, &result) {
+            match self.applied_config.write() {
+                Ok(mut applied) => applied.clear(),
+                Err(_) => error!("Failed to borrow applied configuration"),
+            }
+        }
+
+        Ok(GenericResponse::from(result))
+    }
+
+    // Stop all logs, including held logs, so the receiver is left in a clean state
+    // once the service exits
+    pub fn shutdown(&self) -> OEMResult<()> {
+        info!("Stopping all logs");
+
+        // Anything left over from an interrupted request would be mistaken
+        // for the UNLOGALL response
+        self.oem.flush_responses()?;
+        self.oem.request_unlog_all(true)
+    }
+
+    pub fn passthrough(&self, command: String) -> Result<GenericResponse, Error> {
+        let tx = match decode_hex(&command) {
+            Ok(tx) => tx,
+            Err(err) => {
+                push_err!(self.errors, format!("issueRawCommand: {}", err));
+                return Ok(GenericResponse::from(Err(ServiceError::new(
+                    ErrorCategory::InvalidInput,
+                    err,
+                ))));
+            }
+        };
+
+        let result = run_oem!(self.oem.passthrough(tx.as_slice()), self.errors);
+
+        Ok(GenericResponse::from(result))
+    }
 }
@@ -14,9 +14,20 @@
 // limitations under the License.
 //
 
+use failure::Error;
 use juniper::parser::{ParseError, ScalarToken, Token};
 use juniper::{ParseScalarResult, Value};
-use novatel_oem6_api::{Component, ReceiverStatusFlags};
+use kubos_service::process_errors;
+use log::warn;
+use novatel_oem6_api::{
+    port_name, BestXYZLog, Component, LogRequest, MessageID, OEMError, ReceiverStatusFlags,
+    TrackStatChannel, UartError, VersionLog,
+};
+use serde::de::{self, Deserializer};
+use serde::ser::Serializer;
+use serde_derive::{Deserialize, Serialize};
+use std::fmt;
+use std::time::Duration;
 
 /// Common response fields structure for requests
 /// which don't return any specific data
@@ -24,10 +35,106 @@ use novatel_oem6_api::{Component, ReceiverStatusFlags};
 pub struct GenericResponse {
     /// Any errors encountered by the request
     pub errors: String,
+    /// Structured information about each error in `errors`
+    pub error_details: Vec<ServiceError>,
     /// Request completion success or failure
     pub success: bool,
 }
 
+impl From<Result<(), ServiceError>> for GenericResponse {
+    fn from(result: Result<(), ServiceError>) -> Self {
+        match result {
+            Ok(_) => GenericResponse {
+                errors: "".to_owned(),
+                error_details: vec![],
+                success: true,
+            },
+            Err(err) => GenericResponse {
+                errors: err.message.clone(),
+                error_details: vec![err],
+                success: false,
+            },
+        }
+    }
+}
+
+/// General type of an error encountered while processing a request
+#[derive(GraphQLEnum, Clone, Copy, Debug, PartialEq)]
+pub enum ErrorCategory {
+    /// Communication with the receiver over the serial port failed
+    Uart,
+    /// The receiver did not respond in time
+    Timeout,
+    /// A message from the receiver could not be understood
+    Parse,
+    /// The request was invalid and was not sent to the receiver
+    InvalidInput,
+    /// The receiver rejected the command. The error code is the receiver's response ID
+    Command,
+    /// An internal service error, such as a stopped thread or poisoned mutex
+    Internal,
+}
+
+/// Value of the `code` field for errors which didn't come with a receiver response ID
+pub const ERROR_CODE_NONE: i32 = -1;
+
+/// Structured error information, returned as the `errorDetails` response field
+#[derive(GraphQLObject, Clone, Debug, PartialEq)]
+pub struct ServiceError {
+    /// Response ID reported by the receiver for `COMMAND` errors. Otherwise -1
+    pub code: i32,
+    /// General type of the error
+    pub category: ErrorCategory,
+    /// Description of the error
+    pub message: String,
+}
+
+impl ServiceError {
+    /// Create a new error which has no receiver response ID
+    pub fn new(category: ErrorCategory, message: String) -> Self {
+        ServiceError {
+            code: ERROR_CODE_NONE,
+            category,
+            message,
+        }
+    }
+}
+
+impl fmt::Display for ServiceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl From<OEMError> for ServiceError {
+    fn from(err: OEMError) -> Self {
+        let (code, category) = match err {
+            OEMError::CommandError { ref id, .. } => (id.clone() as i32, ErrorCategory::Command),
+            OEMError::NoResponse => (ERROR_CODE_NONE, ErrorCategory::Timeout),
+            OEMError::ResponseMismatch | OEMError::UnknownMessage { .. } => {
+                (ERROR_CODE_NONE, ErrorCategory::Parse)
+            }
+            OEMError::UartError {
+                cause:
+                    UartError::IoError {
+                        cause: ::std::io::ErrorKind::TimedOut,
+                        ..
+                    },
+            } => (ERROR_CODE_NONE, ErrorCategory::Timeout),
+            OEMError::UartError { .. } => (ERROR_CODE_NONE, ErrorCategory::Uart),
+            OEMError::GenericError | OEMError::MutexError | OEMError::ThreadCommError => {
+                (ERROR_CODE_NONE, ErrorCategory::Internal)
+            }
+        };
+
+        ServiceError {
+            code,
+            category,
+            message: process_errors!(err),
+        }
+    }
+}
+
 /// Return field for 'ack' query
 ///
 /// Indicates last mutation executed by the service
@@ -45,6 +152,137 @@ pub enum AckCommand {
     TestHardware,
     /// A raw command was passed through to the system
     IssueRawCommand,
+    /// The system was reset
+    Reset,
+    /// The cached version information was re-read from the system
+    RefreshVersion,
+    /// The current configuration was saved to non-volatile memory
+    SaveConfiguration,
+    /// The elevation mask was changed
+    SetElevationMask,
+    /// The set of tracked satellite systems was changed
+    SetConstellations,
+    /// The serial data rate was changed
+    SetBaud,
+    /// An approximate position was given to the receiver
+    SetApproximatePosition,
+    /// An approximate GPS time was given to the receiver
+    SetApproximateTime,
+    /// A log was requested on a specific port
+    SetLogPort,
+}
+
+/// Input field for 'reset' mutation
+///
+/// Indicates the type of reset which should be performed
+#[derive(GraphQLEnum, Clone, Copy, Debug)]
+pub enum ResetType {
+    /// Reset the receiver. All saved configuration and satellite data is retained
+    Hot,
+    /// Clear the saved ephemeris data and reset the receiver
+    Warm,
+    /// Clear the saved ephemeris and almanac data and reset the receiver
+    Cold,
+    /// Clear all saved data, including any saved configuration, and reset the receiver
+    /// to its factory default settings
+    Factory,
+}
+
+/// Input field for 'configureHardware' mutation
+///
+/// Indicates the format in which log messages should be generated
+#[derive(GraphQLEnum, Clone, Copy, Debug, PartialEq)]
+pub enum LogFormat {
+    /// ASCII log messages
+    Ascii,
+    /// Binary log messages
+    Binary,
+}
+
+impl From<LogFormat> for novatel_oem6_api::LogFormat {
+    fn from(t: LogFormat) -> novatel_oem6_api::LogFormat {
+        match t {
+            LogFormat::Ascii => novatel_oem6_api::LogFormat::Ascii,
+            LogFormat::Binary => novatel_oem6_api::LogFormat::Binary,
+        }
+    }
+}
+
+/// Input field for 'setLogPort' mutation
+///
+/// Receiver port which a log should be output on
+#[derive(GraphQLEnum, Clone, Copy, Debug, PartialEq)]
+pub enum OemPort {
+    /// Serial port COM1
+    #[graphql(name = "COM1")]
+    Com1,
+    /// Serial port COM2
+    #[graphql(name = "COM2")]
+    Com2,
+    /// Serial port COM3
+    #[graphql(name = "COM3")]
+    Com3,
+    /// Whichever port the command was received on
+    #[graphql(name = "THISPORT")]
+    ThisPort,
+}
+
+impl From<OemPort> for novatel_oem6_api::Port {
+    fn from(t: OemPort) -> novatel_oem6_api::Port {
+        match t {
+            OemPort::Com1 => novatel_oem6_api::Port::COM1,
+            OemPort::Com2 => novatel_oem6_api::Port::COM2,
+            OemPort::Com3 => novatel_oem6_api::Port::COM3,
+            OemPort::ThisPort => novatel_oem6_api::Port::ThisPort,
+        }
+    }
+}
+
+/// Input field for 'setConstellations' mutation.
+/// Also used in the response fields of the `rangeObservations`, `constellationStatus`
+/// and `trackingStatus` queries
+///
+/// Satellite systems which the receiver should track
+#[derive(GraphQLEnum, Clone, Copy, Debug, PartialEq)]
+pub enum Constellation {
+    /// Global Positioning System
+    Gps,
+    /// GLONASS
+    Glonass,
+    /// Satellite-Based Augmentation System
+    Sbas,
+    /// Galileo
+    Galileo,
+    /// BeiDou
+    Beidou,
+    /// Quasi-Zenith Satellite System
+    Qzss,
+}
+
+impl From<Constellation> for novatel_oem6_api::Constellation {
+    fn from(t: Constellation) -> novatel_oem6_api::Constellation {
+        match t {
+            Constellation::Gps => novatel_oem6_api::Constellation::Gps,
+            Constellation::Glonass => novatel_oem6_api::Constellation::Glonass,
+            Constellation::Sbas => novatel_oem6_api::Constellation::Sbas,
+            Constellation::Galileo => novatel_oem6_api::Constellation::Galileo,
+            Constellation::Beidou => novatel_oem6_api::Constellation::Beidou,
+            Constellation::Qzss => novatel_oem6_api::Constellation::Qzss,
+        }
+    }
+}
+
+impl From<novatel_oem6_api::Constellation> for Constellation {
+    fn from(t: novatel_oem6_api::Constellation) -> Constellation {
+        match t {
+            novatel_oem6_api::Constellation::Gps => Constellation::Gps,
+            novatel_oem6_api::Constellation::Glonass => Constellation::Glonass,
+            novatel_oem6_api::Constellation::Sbas => Constellation::Sbas,
+            novatel_oem6_api::Constellation::Galileo => Constellation::Galileo,
+            novatel_oem6_api::Constellation::Beidou => Constellation::Beidou,
+            novatel_oem6_api::Constellation::Qzss => Constellation::Qzss,
+        }
+    }
 }
 
 /// Input structure for 'configureHardware' mutation
@@ -64,23 +302,327 @@ pub struct ConfigStruct {
     /// messages should be generated by the system
     #[graphql(default = "0.0")]
     pub offset: f64,
+    /// (For "Log*" config options) The format in which log messages should be
+    /// generated by the system
+    #[graphql(default = "LogFormat::Binary")]
+    pub format: LogFormat,
+    /// (For "Log*" config options) Wait for the first requested log message to arrive
+    /// before reporting success. The request fails if it doesn't arrive in time.
+    /// Not supported for `LOG_ERROR_DATA` or `LOG_MARK_TIME`, since
+    /// those logs aren't guaranteed to be output
+    #[graphql(default = "false")]
+    pub verify: bool,
+}
+
+/// Shortest log interval, in seconds, supported by the OEM6
+pub const MIN_LOG_INTERVAL: f64 = 0.05;
+/// Longest log interval, in seconds, supported by the OEM6
+pub const MAX_LOG_INTERVAL: f64 = 3600.0;
+/// Time, in seconds, allowed on top of a log's interval and offset for its first message to arrive
+pub const LOG_VERIFY_MARGIN: f64 = 1.0;
+/// Longest time, in seconds, `configureHardware` will wait for a log to be verified
+pub const MAX_LOG_VERIFY_WAIT: f64 = 10.0;
+
+impl ConfigStruct {
+    /// Verify that the interval and offset values are within the range the OEM6
+    /// supports. An interval of zero requests a single log message.
+    ///
+    /// Only applies to options which make use of the interval and offset values
+    pub fn validate(&self) -> Result<(), String> {
+        if self.verify {
+            if self.verified_log().is_none() {
+                return Err("Log verification is not supported for this option".to_owned());
+            }
+
+            if self.verify_wait() > MAX_LOG_VERIFY_WAIT {
+                return Err(format!(
+                    "Interval {}+{}sec is too long to verify (limit {}sec)",
+                    self.interval,
+                    self.offset,
+                    MAX_LOG_VERIFY_WAIT - LOG_VERIFY_MARGIN
+                ));
+            }
+        }
+
+        match self.option {
+            ConfigOption::LogDopData
+            | ConfigOption::LogHwMonitor
+            | ConfigOption::LogPositionData
+            | ConfigOption::LogRangeData
+            | ConfigOption::LogTimeData
+            | ConfigOption::LogTrackingData
+            | ConfigOption::LogVelocityData => {}
+            _ => return Ok(()),
+        }
+
+        if self.interval != 0.0
+            && !(self.interval >= MIN_LOG_INTERVAL && self.interval <= MAX_LOG_INTERVAL)
+        {
+            return Err(format!(
+                "Interval {} outside of supported range ({}-{}sec)",
+                self.interval, MIN_LOG_INTERVAL, MAX_LOG_INTERVAL
+            ));
+        }
+
+        if !(self.offset >= 0.0 && self.offset.is_finite()) {
+            return Err(format!("Offset {} must be non-negative", self.offset));
+        }
+
+        Ok(())
+    }
+
+    /// The log message which must arrive for a `verify` request to succeed.
+    ///
+    /// `None` if the option doesn't produce a log which is guaranteed to be output
+    pub fn verified_log(&self) -> Option<MessageID> {
+        match self.option {
+            ConfigOption::LogDopData => Some(MessageID::PsrDop),
+            ConfigOption::LogHwMonitor => Some(MessageID::HwMonitor),
+            ConfigOption::LogPositionData => Some(MessageID::BestXYZ),
+            ConfigOption::LogRangeData => Some(MessageID::Range),
+            ConfigOption::LogTimeData => Some(MessageID::Time),
+            ConfigOption::LogTrackingData => Some(MessageID::TrackStat),
+            ConfigOption::LogVelocityData => Some(MessageID::BestVel),
+            _ => None,
+        }
+    }
+
+    /// How long, in seconds, to wait for the first message of a `verify` request
+    pub fn verify_wait(&self) -> f64 {
+        self.interval + self.offset + LOG_VERIFY_MARGIN
+    }
 }
 
 /// Input field for 'configureHardware' mutation
 ///
 /// Indicates which configuration operation should be performed
-#[derive(GraphQLEnum, Debug)]
+#[derive(GraphQLEnum, Clone, Copy, Debug, PartialEq)]
 pub enum ConfigOption {
+    /// Configure system to output dilution of precision data at a requested interval
+    LogDopData,
     /// Configure system to output error data when errors or events occur
     LogErrorData,
+    /// Configure system to output hardware monitor data (temperature, antenna current,
+    /// voltages) at a requested interval
+    LogHwMonitor,
+    /// Configure system to output the time of each event on the mark inputs
+    LogMarkTime,
     /// Configure system to output position data at a requested interval
     LogPositionData,
+    /// Configure system to output raw satellite range measurements (pseudorange,
+    /// carrier phase, Doppler) at a requested interval
+    LogRangeData,
+    /// Configure system to output clock data at a requested interval
+    LogTimeData,
+    /// Configure system to output the tracking status of each receiver channel
+    /// at a requested interval
+    LogTrackingData,
+    /// Configure system to output velocity data at a requested interval
+    LogVelocityData,
+    /// Save the current configuration to non-volatile memory, so that it is restored
+    /// after a reset or power cycle
+    SaveConfig,
     /// Stop generation of all output data from device
     UnlogAll,
+    /// Stop generation of dilution of precision data from device
+    UnlogDopData,
     /// Stop generation of error data from device
     UnlogErrorData,
+    /// Stop generation of hardware monitor data from device
+    UnlogHwMonitor,
+    /// Stop generation of mark input event data from device
+    UnlogMarkTime,
     /// Stop generation of position data from device
     UnlogPositionData,
+    /// Stop generation of raw satellite range measurements from device
+    UnlogRangeData,
+    /// Stop generation of clock data from device
+    UnlogTimeData,
+    /// Stop generation of channel tracking status data from device
+    UnlogTrackingData,
+    /// Stop generation of velocity data from device
+    UnlogVelocityData,
+}
+
+impl ConfigOption {
+    /// Every configuration option, in declaration order
+    pub const ALL: [ConfigOption; 20] = [
+        ConfigOption::LogDopData,
+        ConfigOption::LogErrorData,
+        ConfigOption::LogHwMonitor,
+        ConfigOption::LogMarkTime,
+        ConfigOption::LogPositionData,
+        ConfigOption::LogRangeData,
+        ConfigOption::LogTimeData,
+        ConfigOption::LogTrackingData,
+        ConfigOption::LogVelocityData,
+        ConfigOption::SaveConfig,
+        ConfigOption::UnlogAll,
+        ConfigOption::UnlogDopData,
+        ConfigOption::UnlogErrorData,
+        ConfigOption::UnlogHwMonitor,
+        ConfigOption::UnlogMarkTime,
+        ConfigOption::UnlogPositionData,
+        ConfigOption::UnlogRangeData,
+        ConfigOption::UnlogTimeData,
+        ConfigOption::UnlogTrackingData,
+        ConfigOption::UnlogVelocityData,
+    ];
+
+    /// Whether this option starts or stops log output
+    pub fn kind(self) -> ConfigOptionKind {
+        match self {
+            ConfigOption::SaveConfig => ConfigOptionKind::Other,
+            ConfigOption::UnlogAll => ConfigOptionKind::Unlog,
+            option if option.unlog_target().is_some() => ConfigOptionKind::Unlog,
+            _ => ConfigOptionKind::Log,
+        }
+    }
+
+    /// Whether the `interval` and `offset` input fields are used by this option.
+    /// Only true for logs which are generated periodically, rather than on events
+    pub fn uses_interval(self) -> bool {
+        match self {
+            ConfigOption::LogErrorData | ConfigOption::LogMarkTime => false,
+            option => option.kind() == ConfigOptionKind::Log,
+        }
+    }
+
+    /// Whether the `hold` input field is used by this option. True for all `LOG_*` options,
+    /// and for `UNLOG_ALL`, where it also removes held logs
+    pub fn uses_hold(self) -> bool {
+        self == ConfigOption::UnlogAll || self.kind() == ConfigOptionKind::Log
+    }
+
+    /// The log messages which this `LOG_*` option requests. Empty for all other options
+    pub fn log_messages(self) -> &'static [MessageID] {
+        match self {
+            ConfigOption::LogDopData => &[MessageID::PsrDop],
+            ConfigOption::LogErrorData => &[MessageID::RxStatusEvent],
+            ConfigOption::LogHwMonitor => &[MessageID::HwMonitor],
+            ConfigOption::LogMarkTime => &[MessageID::Mark1Time, MessageID::Mark2Time],
+            ConfigOption::LogPositionData => &[MessageID::BestXYZ],
+            ConfigOption::LogRangeData => &[MessageID::Range],
+            ConfigOption::LogTimeData => &[MessageID::Time],
+            ConfigOption::LogTrackingData => &[MessageID::TrackStat],
+            ConfigOption::LogVelocityData => &[MessageID::BestVel],
+            _ => &[],
+        }
+    }
+
+    /// The `LOG_*` option whose output this `UNLOG_*` option stops, if any
+    pub fn unlog_target(self) -> Option<ConfigOption> {
+        match self {
+            ConfigOption::UnlogDopData => Some(ConfigOption::LogDopData),
+            ConfigOption::UnlogErrorData => Some(ConfigOption::LogErrorData),
+            ConfigOption::UnlogHwMonitor => Some(ConfigOption::LogHwMonitor),
+            ConfigOption::UnlogMarkTime => Some(ConfigOption::LogMarkTime),
+            ConfigOption::UnlogPositionData => Some(ConfigOption::LogPositionData),
+            ConfigOption::UnlogRangeData => Some(ConfigOption::LogRangeData),
+            ConfigOption::UnlogTimeData => Some(ConfigOption::LogTimeData),
+            ConfigOption::UnlogTrackingData => Some(ConfigOption::LogTrackingData),
+            ConfigOption::UnlogVelocityData => Some(ConfigOption::LogVelocityData),
+            _ => None,
+        }
+    }
+}
+
+/// Category of a configuration option. Used in the response fields of the `configOptions` query
+#[derive(GraphQLEnum, Clone, Copy, Debug, PartialEq)]
+pub enum ConfigOptionKind {
+    /// Starts generating log output
+    Log,
+    /// Stops generating log output
+    Unlog,
+    /// Neither starts nor stops log output
+    Other,
+}
+
+/// Description of a configuration option and the `configureHardware` input fields it uses.
+/// Used in the response fields of the `configOptions` query
+#[derive(Clone, Debug, PartialEq, GraphQLObject)]
+pub struct ConfigOptionInfo {
+    /// The configuration option
+    pub option: ConfigOption,
+    /// Whether the option starts or stops log output
+    pub kind: ConfigOptionKind,
+    /// Whether the `interval` input field is used
+    pub interval: bool,
+    /// Whether the `offset` input field is used
+    pub offset: bool,
+    /// Whether the `hold` input field is used
+    pub hold: bool,
+}
+
+impl From<ConfigOption> for ConfigOptionInfo {
+    fn from(option: ConfigOption) -> Self {
+        ConfigOptionInfo {
+            option,
+            kind: option.kind(),
+            interval: option.uses_interval(),
+            offset: option.uses_interval(),
+            hold: option.uses_hold(),
+        }
+    }
+}
+
+/// Configuration option which the service has successfully applied. Used in the
+/// response fields of the `appliedConfiguration` query
+#[derive(Clone, Debug, PartialEq, GraphQLObject)]
+pub struct AppliedConfig {
+    /// Configuration option which was applied
+    pub option: ConfigOption,
+    /// Interval, in seconds, which was requested
+    pub interval: f64,
+    /// Offset, in seconds, which was requested
+    pub offset: f64,
+    /// Whether the log was excluded from removal by `UNLOG_ALL` requests
+    pub hold: bool,
+    /// Format which was requested
+    pub format: LogFormat,
+    /// Port the log is output on. Logs are applied on `COM1`, and may be moved with `setLogPort`
+    pub port: OemPort,
+}
+
+impl AppliedConfig {
+    /// Update a list of applied options to reflect a newly applied configuration request
+    ///
+    /// `LOG_*` requests replace any previous entry for the same option, `UNLOG_*` requests
+    /// remove the matching `LOG_*` entry, and `UNLOG_ALL` requests remove every entry which
+    /// isn't held (or every entry, if the `UNLOG_ALL` request itself has `hold` set).
+    pub fn apply(applied: &mut Vec<AppliedConfig>, entry: &ConfigStruct) {
+        match entry.option {
+            ConfigOption::SaveConfig => {}
+            ConfigOption::UnlogAll => applied.retain(|config| config.hold && !entry.hold),
+            option => match option.unlog_target() {
+                Some(target) => applied.retain(|config| config.option != target),
+                None => {
+                    applied.retain(|config| config.option != option);
+                    applied.push(AppliedConfig {
+                        option,
+                        interval: entry.interval,
+                        offset: entry.offset,
+                        hold: entry.hold,
+                        format: entry.format,
+                        port: OemPort::Com1,
+                    });
+                }
+            },
+        }
+    }
+
+    /// The `configureHardware` request which applies this option again.
+    /// Only reproduces the original request if the log is output on `COM1`
+    pub fn request(&self) -> ConfigStruct {
+        ConfigStruct {
+            option: self.option,
+            hold: self.hold,
+            interval: self.interval,
+            offset: self.offset,
+            format: self.format,
+            verify: false,
+        }
+    }
 }
 
 /// Response fields for 'configureHardware' mutation
@@ -90,6 +632,8 @@ pub struct ConfigureHardwareResponse {
     pub config: String,
     /// Any errors encountered by the request
     pub errors: String,
+    /// Structured information about each failed configuration option
+    pub error_details: Vec<ServiceError>,
     /// Request completion success or failure
     pub success: bool,
 }
@@ -129,6 +673,8 @@ graphql_union!(TestResults: () where Scalar = <S> |&self| {
 pub struct IntegrationTestResults {
     /// Any errors encountered by the request
     pub errors: String,
+    /// Structured information about any errors encountered while reading from the device
+    pub error_details: Vec<ServiceError>,
     /// Request completion success or failure
     pub success: bool,
     /// Nominal telemetry
@@ -142,14 +688,34 @@ pub struct IntegrationTestResults {
 pub struct HardwareTestResults {
     /// Any errors encountered by the request
     pub errors: String,
+    /// Structured information about any errors encountered by the request
+    pub error_details: Vec<ServiceError>,
     /// Request completion success or failure
     pub success: bool,
     /// Test results
     pub data: String,
 }
 
+/// Telemetry which is cached by the service as logs are received, and so may be out of date
+/// by the time it is requested
+pub trait CachedTelemetry {
+    /// Unix timestamp (seconds) at which the service received the value.
+    /// 0.0 if no value has been received
+    fn received_at(&self) -> f64;
+
+    /// Set the value's `stale` flag
+    fn set_stale(&mut self, stale: bool);
+
+    /// Update the `stale` flag, given the current Unix time and the maximum age
+    /// of a value before it is considered stale
+    fn check_stale(&mut self, now: f64, max_age: Duration) {
+        let received_at = self.received_at();
+        self.set_stale(received_at == 0.0 || now - received_at > max_age.as_secs_f64());
+    }
+}
+
 /// Response fields for `lockStatus` query
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct LockStatus {
     /// Validity of time data
     pub time_status: u8,
@@ -163,23 +729,141 @@ pub struct LockStatus {
     pub velocity_status: u32,
     /// Velocity data type
     pub velocity_type: u32,
+    /// Age of the differential corrections used in the position solution, in seconds.
+    /// `DIFF_AGE_NOT_APPLICABLE` if the position type does not use corrections
+    pub differential_age: f64,
+    /// Number of tracked satellites which were not used in the position solution
+    #[serde(default)]
+    pub rejected_measurements: i32,
+    /// Unix timestamp (seconds) at which the service received the status.
+    /// 0.0 if no status has been received
+    pub received_at: f64,
+    /// Whether the status is older than the service's `telemetry_max_age`,
+    /// or no status has been received
+    pub stale: bool,
+    /// Whether any log has ever been received from the receiver.
+    /// If false, the other fields are defaults rather than a real (bad) fix
+    #[serde(default)]
+    pub device_connected: bool,
 }
 
-impl Default for LockStatus {
-    fn default() -> LockStatus {
+/// Sentinel value for the `differentialAge` field when the current position
+/// type is not a differential solution
+pub const DIFF_AGE_NOT_APPLICABLE: f64 = -1.0;
+
+impl LockStatus {
+    /// Get the reported differential age if the position type uses differential corrections
+    pub fn differential_age(position_type: u32, diff_age: f32) -> f64 {
+        match position_type.into() {
+            PosVelType::PSRDiff
+            | PosVelType::WAAS
+            | PosVelType::Omnistar
+            | PosVelType::L1Float
+            | PosVelType::IonoFreeFloat
+            | PosVelType::NarrowFloat
+            | PosVelType::L1Integer
+            | PosVelType::NarrowInteger
+            | PosVelType::OmnistarHP
+            | PosVelType::OmnistarXP => f64::from(diff_age),
+            _ => DIFF_AGE_NOT_APPLICABLE,
+        }
+    }
+
+    /// Get the number of tracked satellites which were left out of the position solution
+    ///
+    /// BestXYZ logs don't report RAIM/FDE exclusions directly, so this also counts satellites
+    /// excluded for other reasons (ex. below the elevation mask, or unhealthy)
+    pub fn rejected_measurements(num_sats: u8, num_used: u8) -> i32 {
+        i32::from(num_sats.saturating_sub(num_used))
+    }
+
+    /// Approximate progress (0-100) towards a converged position solution.
+    ///
+    /// This is a heuristic based on the current position status and type, which increases
+    /// through the normal cold-start acquisition sequence:
+    ///
+    /// - 0: `INVALID_FIX`, `UNAUTHORIZED`, or an unknown status
+    /// - 10: `COLD_START`
+    /// - 25: `PENDING`
+    /// - 40: `INSUFFICIENT_OBSERVATIONS`
+    /// - 50: Any other status where a solution was attempted but rejected
+    /// - 75: `SOL_COMPUTED` with a standalone solution (for example, `SINGLE` or `PROPAGATED`)
+    /// - 90: `SOL_COMPUTED` with a differential, floating-ambiguity or converging PPP solution
+    /// - 100: `SOL_COMPUTED` with a fixed position, integer-ambiguity or converged PPP solution
+    pub fn lock_progress(&self) -> i32 {
+        match self.position_status.into() {
+            SolutionStatus::InvalidFix
+            | SolutionStatus::Unauthorized
+            | SolutionStatus::KubosInvalid => 0,
+            SolutionStatus::ColdStart => 10,
+            SolutionStatus::Pending => 25,
+            SolutionStatus::InsufficientObservations => 40,
+            SolutionStatus::SolComputed => match self.position_type.into() {
+                PosVelType::PSRDiff
+                | PosVelType::WAAS
+                | PosVelType::Omnistar
+                | PosVelType::L1Float
+                | PosVelType::IonoFreeFloat
+                | PosVelType::NarrowFloat
+                | PosVelType::PPPConverging
+                | PosVelType::PPPBasicConverging => 90,
+                PosVelType::FixedPos
+                | PosVelType::L1Integer
+                | PosVelType::NarrowInteger
+                | PosVelType::OmnistarHP
+                | PosVelType::OmnistarXP
+                | PosVelType::PPP
+                | PosVelType::PPPBasic => 100,
+                _ => 75,
+            },
+            _ => 50,
+        }
+    }
+
+    /// Status to report before any BestXYZ log has been received: unknown time,
+    /// insufficient observations and no position or velocity solution
+    pub fn unknown() -> LockStatus {
         LockStatus {
-            time_status: 20, // Unknown
+            time_status: RefTimeStatus::Unknown.into(),
             time: OEMTime { week: 0, ms: 0 },
-            position_status: 1, // Insufficient Observations
-            position_type: 0,   // None
-            velocity_status: 1, // Insufficient Observations
-            velocity_type: 0,   // None
+            position_status: SolutionStatus::InsufficientObservations.into(),
+            position_type: PosVelType::None.into(),
+            velocity_status: SolutionStatus::InsufficientObservations.into(),
+            velocity_type: PosVelType::None.into(),
+            differential_age: DIFF_AGE_NOT_APPLICABLE,
+            rejected_measurements: 0,
+            received_at: 0.0,
+            stale: true,
+            device_connected: false,
         }
     }
 }
 
+impl Default for LockStatus {
+    fn default() -> LockStatus {
+        LockStatus::unknown()
+    }
+}
+
+impl CachedTelemetry for LockStatus {
+    fn received_at(&self) -> f64 {
+        self.received_at
+    }
+
+    fn set_stale(&mut self, stale: bool) {
+        self.stale = stale;
+    }
+}
+
+/// Unix timestamp of the GPS epoch (1980-01-06T00:00:00Z)
+pub const GPS_EPOCH_UNIX: i64 = 315_964_800;
+
+/// Offset, in seconds, of GPS system time from UTC time (-18 as of 2017-01-01).
+/// Matches the sign convention of the `utcOffset` field of the `clockInfo` query
+pub const GPS_UTC_OFFSET: f64 = -18.0;
+
 /// Time structure for `lockStatus` and `lockInfo` response fields
-#[derive(Clone, Default, GraphQLObject)]
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct OEMTime {
     /// GPS reference week number
     pub week: i32,
@@ -187,6 +871,51 @@ pub struct OEMTime {
     pub ms: i32,
 }
 
+impl OEMTime {
+    /// Convert the GPS week and milliseconds into a Unix timestamp (in seconds),
+    /// applying the given GPS-to-UTC offset
+    pub fn unix_time(&self, utc_offset: f64) -> f64 {
+        GPS_EPOCH_UNIX as f64
+            + f64::from(self.week) * 604_800.0
+            + f64::from(self.ms) / 1000.0
+            + utc_offset
+    }
+
+    /// Convert the GPS week and milliseconds into whole seconds since the GPS epoch
+    ///
+    /// This is the format expected by the MAI-400's `set_gps_time` command. Both devices
+    /// count from the GPS epoch without leap seconds, so no UTC offset is applied.
+    /// Milliseconds are truncated to the second.
+    ///
+    /// Returns `None` if either field is negative or if the result does not fit in a `u32`
+    pub fn gps_seconds(&self) -> Option<u32> {
+        if self.week < 0 || self.ms < 0 {
+            return None;
+        }
+
+        (self.week as u32)
+            .checked_mul(604_800)?
+            .checked_add(self.ms as u32 / 1000)
+    }
+}
+
+graphql_object!(OEMTime: () where Scalar = <S> | &self | {
+    field week() -> i32 {
+        self.week
+    }
+
+    field ms() -> i32 {
+        self.ms
+    }
+
+    // Unix timestamp, in seconds, of this GPS time.
+    // The optional `utcOffset` argument can be used to supply the current
+    // GPS-to-UTC offset (ex. from the `clockInfo` query)
+    field unix_time(utc_offset = (GPS_UTC_OFFSET): f64) -> f64 {
+        self.unix_time(utc_offset)
+    }
+});
+
 /// Enum for the `positionStatus` and `velocityStatus` response fields
 /// of the `lockStatus` query
 #[derive(GraphQLEnum, Debug)]
@@ -240,7 +969,35 @@ impl From<u32> for SolutionStatus {
             18 => SolutionStatus::Pending,
             19 => SolutionStatus::InvalidFix,
             20 => SolutionStatus::Unauthorized,
-            _ => SolutionStatus::KubosInvalid,
+            _ => {
+                warn!("Unknown solution status value encountered: {}", t);
+                SolutionStatus::KubosInvalid
+            }
+        }
+    }
+}
+
+/// Wire value used for `SolutionStatus::KubosInvalid`. This value is not used by the OEM6
+pub const SOLUTION_STATUS_INVALID: u32 = 0xFFFF_FFFF;
+
+impl From<SolutionStatus> for u32 {
+    fn from(t: SolutionStatus) -> u32 {
+        match t {
+            SolutionStatus::SolComputed => 0,
+            SolutionStatus::InsufficientObservations => 1,
+            SolutionStatus::NoConvergence => 2,
+            SolutionStatus::Singularity => 3,
+            SolutionStatus::CovarianceTraceExceeded => 4,
+            SolutionStatus::TestDistanceExceeded => 5,
+            SolutionStatus::ColdStart => 6,
+            SolutionStatus::HeightVelocityExceeded => 7,
+            SolutionStatus::VarianceExceeded => 8,
+            SolutionStatus::ResidualsTooLarge => 9,
+            SolutionStatus::IntegrityWarning => 13,
+            SolutionStatus::Pending => 18,
+            SolutionStatus::InvalidFix => 19,
+            SolutionStatus::Unauthorized => 20,
+            SolutionStatus::KubosInvalid => SOLUTION_STATUS_INVALID,
         }
     }
 }
@@ -325,7 +1082,44 @@ impl From<u32> for PosVelType {
             72 => PosVelType::OutOfBounds,
             77 => PosVelType::PPPBasicConverging,
             78 => PosVelType::PPPBasic,
-            _ => PosVelType::KubosInvalid,
+            _ => {
+                warn!("Unknown position/velocity type value encountered: {}", t);
+                PosVelType::KubosInvalid
+            }
+        }
+    }
+}
+
+/// Wire value used for `PosVelType::KubosInvalid`. This value is not used by the OEM6
+pub const POS_VEL_TYPE_INVALID: u32 = 0xFFFF_FFFF;
+
+impl From<PosVelType> for u32 {
+    fn from(t: PosVelType) -> u32 {
+        match t {
+            PosVelType::None => 0,
+            PosVelType::FixedPos => 1,
+            PosVelType::FixedHeight => 2,
+            PosVelType::DopplerVelocity => 8,
+            PosVelType::Single => 16,
+            PosVelType::PSRDiff => 17,
+            PosVelType::WAAS => 18,
+            PosVelType::Propagated => 19,
+            PosVelType::Omnistar => 20,
+            PosVelType::L1Float => 32,
+            PosVelType::IonoFreeFloat => 33,
+            PosVelType::NarrowFloat => 34,
+            PosVelType::L1Integer => 48,
+            PosVelType::NarrowInteger => 50,
+            PosVelType::OmnistarHP => 64,
+            PosVelType::OmnistarXP => 65,
+            PosVelType::PPPConverging => 68,
+            PosVelType::PPP => 69,
+            PosVelType::Operational => 70,
+            PosVelType::Warning => 71,
+            PosVelType::OutOfBounds => 72,
+            PosVelType::PPPBasicConverging => 77,
+            PosVelType::PPPBasic => 78,
+            PosVelType::KubosInvalid => POS_VEL_TYPE_INVALID,
         }
     }
 }
@@ -373,7 +1167,86 @@ impl From<u8> for RefTimeStatus {
             170 => RefTimeStatus::FineBackupSteering,
             180 => RefTimeStatus::FineSteering,
             200 => RefTimeStatus::SatTime,
-            _ => RefTimeStatus::KubosInvalid,
+            _ => {
+                warn!("Unknown time status value encountered: {}", t);
+                RefTimeStatus::KubosInvalid
+            }
+        }
+    }
+}
+
+/// Wire value used for `RefTimeStatus::KubosInvalid`. This value is not used by the OEM6
+pub const REF_TIME_STATUS_INVALID: u8 = 0xFF;
+
+impl From<RefTimeStatus> for u8 {
+    fn from(t: RefTimeStatus) -> u8 {
+        match t {
+            RefTimeStatus::Unknown => 20,
+            RefTimeStatus::Approximate => 60,
+            RefTimeStatus::CoarseAdjusting => 80,
+            RefTimeStatus::Coarse => 100,
+            RefTimeStatus::CoarseSteering => 120,
+            RefTimeStatus::FreeWheeling => 130,
+            RefTimeStatus::FineAdjusting => 140,
+            RefTimeStatus::Fine => 160,
+            RefTimeStatus::FineBackupSteering => 170,
+            RefTimeStatus::FineSteering => 180,
+            RefTimeStatus::SatTime => 200,
+            RefTimeStatus::KubosInvalid => REF_TIME_STATUS_INVALID,
+        }
+    }
+}
+
+/// Enum for the `compType` response field of the `telemetry` debug components
+#[derive(GraphQLEnum, Debug)]
+pub enum ComponentType {
+    /// Unknown component
+    Unknown,
+    /// OEM6 family GPS card
+    GpsCard,
+    /// Reserved
+    Controller,
+    /// OEM card enclosure
+    Enclosure,
+    /// IMU integrated in the enclosure
+    ImuCard,
+    /// Application specific information
+    UserInfo,
+    /// Wi-Fi radio firmware
+    WiFi,
+    /// UHF radio component
+    Radio,
+    /// Web server content
+    WwwContent,
+    /// Regulatory configuration
+    Regulatory,
+    /// Height/track model database
+    DbHeightModel,
+    /// User application firmware
+    DbUserApp,
+    /// Unknown component type value encountered
+    KubosInvalid,
+}
+
+impl From<u32> for ComponentType {
+    fn from(t: u32) -> ComponentType {
+        match t {
+            0 => ComponentType::Unknown,
+            1 => ComponentType::GpsCard,
+            2 => ComponentType::Controller,
+            3 => ComponentType::Enclosure,
+            7 => ComponentType::ImuCard,
+            8 => ComponentType::UserInfo,
+            12 => ComponentType::WiFi,
+            13 => ComponentType::Radio,
+            14 => ComponentType::WwwContent,
+            15 => ComponentType::Regulatory,
+            981_073_920 => ComponentType::DbHeightModel,
+            981_073_921 => ComponentType::DbUserApp,
+            _ => {
+                warn!("Unknown component type value encountered: {}", t);
+                ComponentType::KubosInvalid
+            }
         }
     }
 }
@@ -384,6 +1257,10 @@ graphql_object!(LockStatus: () where Scalar = <S> | &self | {
         self.time_status.into()
     }
 
+    field time_status_raw() -> i32 {
+        self.time_status as i32
+    }
+
     field time() -> OEMTime {
         self.time.clone()
     }
@@ -392,22 +1269,69 @@ graphql_object!(LockStatus: () where Scalar = <S> | &self | {
         self.position_status.into()
     }
 
+    field position_status_raw() -> i32 {
+        self.position_status as i32
+    }
+
     field position_type() -> PosVelType {
         self.position_type.into()
     }
 
+    field position_type_raw() -> i32 {
+        self.position_type as i32
+    }
+
     field velocity_status() -> SolutionStatus {
         self.velocity_status.into()
     }
 
+    field velocity_status_raw() -> i32 {
+        self.velocity_status as i32
+    }
+
     field velocity_type() -> PosVelType {
         self.velocity_type.into()
     }
+
+    field velocity_type_raw() -> i32 {
+        self.velocity_type as i32
+    }
+
+    field differential_age() -> f64 {
+        self.differential_age
+    }
+
+    field lock_progress() -> i32 {
+        self.lock_progress()
+    }
+
+    field rejected_measurements() -> i32 {
+        self.rejected_measurements
+    }
+
+    field received_at() -> f64 {
+        self.received_at
+    }
+
+    field stale() -> bool {
+        self.stale
+    }
+
+    field device_connected() -> bool {
+        self.device_connected
+    }
 });
 
+/// Value reported for `solutionAge` when no position solution has been received
+pub const SOLUTION_AGE_NO_DATA: f64 = -1.0;
+
+/// Horizontal speed (m/s) below which the receiver is considered stationary
+/// and no heading is reported
+pub const HEADING_MIN_SPEED: f64 = 0.01;
+
 /// Current system lock information. Used in the response fields of
 /// the `lockInfo` query
-#[derive(Clone, Default)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct LockInfo {
     /// Timestamp when the other fields were last updated
     pub time: OEMTime,
@@ -415,6 +1339,63 @@ pub struct LockInfo {
     pub position: [f64; 3],
     /// Last known good velocity
     pub velocity: [f64; 3],
+    /// Age of the solution, in seconds, as reported by the receiver
+    pub solution_age: f64,
+    /// Unix timestamp (seconds) at which the service received the solution.
+    /// 0.0 if no solution has been received
+    pub received_at: f64,
+    /// Whether the solution is older than the service's `telemetry_max_age`,
+    /// or no solution has been received
+    pub stale: bool,
+    /// Whether any log has ever been received from the receiver.
+    /// If false, the other fields are defaults rather than a real solution
+    #[serde(default)]
+    pub device_connected: bool,
+}
+
+impl Default for LockInfo {
+    fn default() -> Self {
+        LockInfo {
+            time: OEMTime::default(),
+            position: [0.0; 3],
+            velocity: [0.0; 3],
+            solution_age: SOLUTION_AGE_NO_DATA,
+            received_at: 0.0,
+            stale: true,
+            device_connected: false,
+        }
+    }
+}
+
+impl CachedTelemetry for LockInfo {
+    fn received_at(&self) -> f64 {
+        self.received_at
+    }
+
+    fn set_stale(&mut self, stale: bool) {
+        self.stale = stale;
+    }
+}
+
+impl LockInfo {
+    /// Magnitude of the velocity vector (m/s)
+    pub fn speed(&self) -> f64 {
+        let [vx, vy, vz] = self.velocity;
+        (vx * vx + vy * vy + vz * vz).sqrt()
+    }
+
+    /// Course over ground with respect to True North (degrees), derived from the
+    /// local east/north velocity at the current position.
+    /// `None` if the receiver is stationary or no position is available
+    pub fn heading(&self) -> Option<f64> {
+        let ground = GroundVelocity::from_ecef(self.position, self.velocity);
+
+        if ground.horizontal_speed < HEADING_MIN_SPEED {
+            None
+        } else {
+            Some(ground.track)
+        }
+    }
 }
 
 graphql_object!(LockInfo: ()  where Scalar = <S> | &self | {
@@ -429,8 +1410,610 @@ graphql_object!(LockInfo: ()  where Scalar = <S> | &self | {
     field velocity() -> Vec<f64> {
         self.velocity.to_vec()
     }
+
+    field geodetic() -> Geodetic {
+        Geodetic::from_ecef(self.position)
+    }
+
+    field solution_age() -> f64 {
+        self.solution_age
+    }
+
+    field received_at() -> f64 {
+        self.received_at
+    }
+
+    field stale() -> bool {
+        self.stale
+    }
+
+    field device_connected() -> bool {
+        self.device_connected
+    }
+
+    field speed() -> f64 {
+        self.speed()
+    }
+
+    field heading() -> Option<f64> {
+        self.heading()
+    }
+});
+
+/// Geodetic coordinates, derived from the ECEF position using the WGS-84 ellipsoid
+#[derive(Clone, Debug, Default, GraphQLObject)]
+pub struct Geodetic {
+    /// Geodetic latitude (degrees). Positive values are north of the equator
+    pub latitude: f64,
+    /// Longitude (degrees). Positive values are east of the prime meridian
+    pub longitude: f64,
+    /// Height above the WGS-84 ellipsoid (m)
+    pub altitude: f64,
+}
+
+impl Geodetic {
+    /// Convert an ECEF position into geodetic coordinates.
+    /// A zero position vector results in all-zero coordinates
+    pub fn from_ecef(position: [f64; 3]) -> Self {
+        match ecef_to_geodetic(position) {
+            Some((lat, lon, alt)) => Geodetic {
+                latitude: lat.to_degrees(),
+                longitude: lon.to_degrees(),
+                altitude: alt,
+            },
+            None => Geodetic::default(),
+        }
+    }
+}
+
+// Convert an ECEF position into geodetic latitude (rad), longitude (rad) and
+// ellipsoidal height (m) using the WGS-84 ellipsoid and Bowring's method.
+// Returns `None` for a zero position vector
+fn ecef_to_geodetic(position: [f64; 3]) -> Option<(f64, f64, f64)> {
+    const A: f64 = 6_378_137.0;
+    const F: f64 = 1.0 / 298.257_223_563;
+
+    let [x, y, z] = position;
+
+    let p = x.hypot(y);
+    if p == 0.0 && z == 0.0 {
+        return None;
+    }
+
+    let b = A * (1.0 - F);
+    let e2 = F * (2.0 - F);
+    let ep2 = (A * A - b * b) / (b * b);
+    let theta = (z * A).atan2(p * b);
+    let lat = (z + ep2 * b * theta.sin().powi(3)).atan2(p - e2 * A * theta.cos().powi(3));
+    let lon = y.atan2(x);
+
+    let (sin_lat, cos_lat) = lat.sin_cos();
+    let alt = p * cos_lat + z * sin_lat - A * (1.0 - e2 * sin_lat * sin_lat).sqrt();
+
+    Some((lat, lon, alt))
+}
+
+/// Current receiver clock information. Used in the response fields of
+/// the `clockInfo` query
+#[derive(Clone, GraphQLObject)]
+pub struct ClockInfo {
+    /// Timestamp when the other fields were last updated
+    pub time: OEMTime,
+    /// Offset, in seconds, of GPS system time from UTC time
+    pub utc_offset: f64,
+    /// Receiver clock offset, in seconds, from GPS system time
+    pub clock_offset: f64,
+    /// Receiver clock offset standard deviation (seconds)
+    pub clock_offset_std_dev: f64,
+    /// Unix timestamp (seconds) at which the service received the TIME log.
+    /// 0.0 if no TIME log has been received
+    pub received_at: f64,
+    /// Whether the values are older than the service's `telemetry_max_age`,
+    /// or are defaults because no TIME log has been received yet
+    pub stale: bool,
+}
+
+impl Default for ClockInfo {
+    fn default() -> Self {
+        ClockInfo {
+            time: OEMTime::default(),
+            utc_offset: 0.0,
+            clock_offset: 0.0,
+            clock_offset_std_dev: 0.0,
+            received_at: 0.0,
+            stale: true,
+        }
+    }
+}
+
+impl CachedTelemetry for ClockInfo {
+    fn received_at(&self) -> f64 {
+        self.received_at
+    }
+
+    fn set_stale(&mut self, stale: bool) {
+        self.stale = stale;
+    }
+}
+
+/// Last received BestVel velocity. Used in the response fields of the `velocityInfo` query
+#[derive(Clone)]
+pub struct VelocityInfo {
+    /// Timestamp when the other fields were last updated
+    pub time: OEMTime,
+    /// Validity of velocity data
+    pub velocity_status: u32,
+    /// Velocity data type
+    pub velocity_type: u32,
+    /// Latency of the velocity time tag, in seconds, with respect to `time`
+    pub latency: f64,
+    /// Differential age (seconds)
+    pub differential_age: f64,
+    /// Horizontal speed over ground (m/s)
+    pub horizontal_speed: f64,
+    /// Vertical speed (m/s). Positive values indicate the receiver is moving upwards
+    pub vertical_speed: f64,
+    /// Actual direction of motion over ground with respect to True North (degrees)
+    pub track: f64,
+    /// Unix timestamp (seconds) at which the service received the BestVel log.
+    /// 0.0 if no BestVel log has been received
+    pub received_at: f64,
+    /// Whether the values are older than the service's `telemetry_max_age`,
+    /// or are defaults because no BestVel log has been received yet
+    pub stale: bool,
+}
+
+impl Default for VelocityInfo {
+    fn default() -> Self {
+        VelocityInfo {
+            time: OEMTime::default(),
+            velocity_status: SolutionStatus::InsufficientObservations.into(),
+            velocity_type: PosVelType::None.into(),
+            latency: 0.0,
+            differential_age: 0.0,
+            horizontal_speed: 0.0,
+            vertical_speed: 0.0,
+            track: 0.0,
+            received_at: 0.0,
+            stale: true,
+        }
+    }
+}
+
+impl CachedTelemetry for VelocityInfo {
+    fn received_at(&self) -> f64 {
+        self.received_at
+    }
+
+    fn set_stale(&mut self, stale: bool) {
+        self.stale = stale;
+    }
+}
+
+graphql_object!(VelocityInfo: () where Scalar = <S> | &self | {
+
+    field time() -> OEMTime {
+        self.time.clone()
+    }
+
+    field velocity_status() -> SolutionStatus {
+        self.velocity_status.into()
+    }
+
+    field velocity_status_raw() -> i32 {
+        self.velocity_status as i32
+    }
+
+    field velocity_type() -> PosVelType {
+        self.velocity_type.into()
+    }
+
+    field velocity_type_raw() -> i32 {
+        self.velocity_type as i32
+    }
+
+    field latency() -> f64 {
+        self.latency
+    }
+
+    field differential_age() -> f64 {
+        self.differential_age
+    }
+
+    field horizontal_speed() -> f64 {
+        self.horizontal_speed
+    }
+
+    field vertical_speed() -> f64 {
+        self.vertical_speed
+    }
+
+    field track() -> f64 {
+        self.track
+    }
+
+    field received_at() -> f64 {
+        self.received_at
+    }
+
+    field stale() -> bool {
+        self.stale
+    }
 });
 
+/// Value reported for all DOP fields when no DOP information is available
+pub const DOP_NO_DATA: f64 = 9999.0;
+
+/// Current dilution of precision information. Used in the response fields of
+/// the `dopInfo` query
+#[derive(Clone, GraphQLObject)]
+pub struct DopInfo {
+    /// Timestamp when the other fields were last updated
+    pub time: OEMTime,
+    /// Position dilution of precision
+    pub pdop: f64,
+    /// Horizontal dilution of precision
+    pub hdop: f64,
+    /// Vertical dilution of precision
+    pub vdop: f64,
+    /// Time dilution of precision
+    pub tdop: f64,
+    /// Unix timestamp (seconds) at which the service received the DOP information.
+    /// 0.0 if no DOP information is available
+    pub received_at: f64,
+    /// Whether the values are older than the service's `telemetry_max_age`,
+    /// or no DOP information is available
+    pub stale: bool,
+}
+
+impl Default for DopInfo {
+    fn default() -> Self {
+        DopInfo {
+            time: OEMTime::default(),
+            pdop: DOP_NO_DATA,
+            hdop: DOP_NO_DATA,
+            vdop: DOP_NO_DATA,
+            tdop: DOP_NO_DATA,
+            received_at: 0.0,
+            stale: true,
+        }
+    }
+}
+
+impl CachedTelemetry for DopInfo {
+    fn received_at(&self) -> f64 {
+        self.received_at
+    }
+
+    fn set_stale(&mut self, stale: bool) {
+        self.stale = stale;
+    }
+}
+
+/// Value reported for all hardware monitor fields when no hardware monitor
+/// information is available
+pub const HW_MONITOR_NO_DATA: f64 = 9999.0;
+
+/// Current receiver hardware monitor readings. Used in the response fields of
+/// the `hardwareMonitor` query
+#[derive(Clone, GraphQLObject)]
+pub struct HardwareMonitor {
+    /// Timestamp when the other fields were last updated
+    pub time: OEMTime,
+    /// Receiver card temperature (degrees Celsius)
+    pub temperature: f64,
+    /// Antenna current (Amps)
+    pub antenna_current: f64,
+    /// Digital core voltage (Volts)
+    pub core_voltage: f64,
+    /// Unix timestamp (seconds) at which the service received the hardware monitor information.
+    /// 0.0 if no hardware monitor information is available
+    pub received_at: f64,
+    /// Whether the values are older than the service's `telemetry_max_age`,
+    /// or no hardware monitor information is available
+    pub stale: bool,
+}
+
+impl Default for HardwareMonitor {
+    fn default() -> Self {
+        HardwareMonitor {
+            time: OEMTime::default(),
+            temperature: HW_MONITOR_NO_DATA,
+            antenna_current: HW_MONITOR_NO_DATA,
+            core_voltage: HW_MONITOR_NO_DATA,
+            received_at: 0.0,
+            stale: true,
+        }
+    }
+}
+
+impl CachedTelemetry for HardwareMonitor {
+    fn received_at(&self) -> f64 {
+        self.received_at
+    }
+
+    fn set_stale(&mut self, stale: bool) {
+        self.stale = stale;
+    }
+}
+
+/// Raw observation for a single tracked satellite signal. Used in the response
+/// fields of the `rangeObservations` query
+#[derive(Clone, Debug, PartialEq, GraphQLObject)]
+pub struct RangeObservation {
+    /// Satellite PRN number (or GLONASS slot number)
+    pub prn: i32,
+    /// Satellite system of the signal. Null if the receiver reported an unknown system
+    pub system: Option<Constellation>,
+    /// Pseudorange measurement (meters)
+    pub pseudorange: f64,
+    /// Carrier phase, in cycles (accumulated Doppler range)
+    pub carrier_phase: f64,
+    /// Instantaneous carrier Doppler frequency (Hz)
+    pub doppler_hz: f64,
+    /// Carrier to noise density ratio (dB-Hz)
+    pub cno: f64,
+    /// Number of seconds of continuous tracking (no cycle slipping)
+    pub lock_time: f64,
+}
+
+/// Raw observations from the most recent RANGE log. Used in the response fields of
+/// the `rangeObservations` query
+#[derive(Clone, GraphQLObject)]
+pub struct RangeObservations {
+    /// Timestamp of the observations
+    pub time: OEMTime,
+    /// One entry per tracked satellite signal
+    pub observations: Vec<RangeObservation>,
+    /// Unix timestamp (seconds) at which the service received the observations.
+    /// 0.0 if no observations are available
+    pub received_at: f64,
+    /// Whether the observations are older than the service's `telemetry_max_age`,
+    /// or no observations are available
+    pub stale: bool,
+}
+
+impl Default for RangeObservations {
+    fn default() -> Self {
+        RangeObservations {
+            time: OEMTime::default(),
+            observations: vec![],
+            received_at: 0.0,
+            stale: true,
+        }
+    }
+}
+
+impl CachedTelemetry for RangeObservations {
+    fn received_at(&self) -> f64 {
+        self.received_at
+    }
+
+    fn set_stale(&mut self, stale: bool) {
+        self.stale = stale;
+    }
+}
+
+/// Number of satellites tracked and used by the receiver for a single satellite system.
+/// Used in the response fields of the `constellationStatus` query
+#[derive(Clone, Debug, PartialEq, GraphQLObject)]
+pub struct ConstellationTracking {
+    /// Satellite system
+    pub system: Constellation,
+    /// Number of satellites with at least one tracked signal
+    pub tracked: i32,
+    /// Number of satellites with at least one signal used in the position solution
+    pub used: i32,
+}
+
+impl ConstellationTracking {
+    /// Count the tracked and used satellites of each system from the channels of a
+    /// TRACKSTAT log. Satellites tracked on several signals are only counted once
+    pub fn from_channels(channels: &[TrackStatChannel]) -> Vec<ConstellationTracking> {
+        novatel_oem6_api::Constellation::ALL
+            .iter()
+            .map(|&system| {
+                let mut tracked = vec![];
+                let mut used = vec![];
+                for chan in channels
+                    .iter()
+                    .filter(|chan| chan.is_tracking() && chan.system() == Some(system))
+                {
+                    if !tracked.contains(&chan.prn) {
+                        tracked.push(chan.prn);
+                    }
+                    if chan.used_in_solution() && !used.contains(&chan.prn) {
+                        used.push(chan.prn);
+                    }
+                }
+
+                ConstellationTracking {
+                    system: system.into(),
+                    tracked: tracked.len() as i32,
+                    used: used.len() as i32,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Per-constellation satellite counts from the most recent TRACKSTAT log. Used in the
+/// response fields of the `constellationStatus` query
+#[derive(Clone, GraphQLObject)]
+pub struct ConstellationStatus {
+    /// Timestamp of the tracking status
+    pub time: OEMTime,
+    /// One entry per satellite system. Empty if no TRACKSTAT log has been received
+    pub constellations: Vec<ConstellationTracking>,
+    /// Unix timestamp (seconds) at which the service received the tracking status.
+    /// 0.0 if no tracking status is available
+    pub received_at: f64,
+    /// Whether the tracking status is older than the service's `telemetry_max_age`,
+    /// or no tracking status is available
+    pub stale: bool,
+}
+
+impl Default for ConstellationStatus {
+    fn default() -> Self {
+        ConstellationStatus {
+            time: OEMTime::default(),
+            constellations: vec![],
+            received_at: 0.0,
+            stale: true,
+        }
+    }
+}
+
+impl CachedTelemetry for ConstellationStatus {
+    fn received_at(&self) -> f64 {
+        self.received_at
+    }
+
+    fn set_stale(&mut self, stale: bool) {
+        self.stale = stale;
+    }
+}
+
+/// Tracking state of a receiver channel. Used in the response fields of the
+/// `trackingStatus` query
+#[derive(GraphQLEnum, Clone, Copy, Debug, PartialEq)]
+pub enum TrackingState {
+    /// The channel is not tracking a signal
+    Idle,
+    /// Searching for a signal across the sky
+    SkySearch,
+    /// Wide frequency band pull-in
+    WideFrequencyPullIn,
+    /// Narrow frequency band pull-in
+    NarrowFrequencyPullIn,
+    /// Phase lock loop. The signal is fully tracked
+    PhaseLockLoop,
+    /// Channel steering
+    ChannelSteering,
+    /// Frequency lock loop
+    FrequencyLockLoop,
+    /// Channel alignment
+    ChannelAlignment,
+    /// Code search
+    CodeSearch,
+    /// Aided phase lock loop
+    AidedPhaseLockLoop,
+}
+
+impl From<novatel_oem6_api::TrackingState> for TrackingState {
+    fn from(t: novatel_oem6_api::TrackingState) -> TrackingState {
+        match t {
+            novatel_oem6_api::TrackingState::Idle => TrackingState::Idle,
+            novatel_oem6_api::TrackingState::SkySearch => TrackingState::SkySearch,
+            novatel_oem6_api::TrackingState::WideFrequencyPullIn => {
+                TrackingState::WideFrequencyPullIn
+            }
+            novatel_oem6_api::TrackingState::NarrowFrequencyPullIn => {
+                TrackingState::NarrowFrequencyPullIn
+            }
+            novatel_oem6_api::TrackingState::PhaseLockLoop => TrackingState::PhaseLockLoop,
+            novatel_oem6_api::TrackingState::ChannelSteering => TrackingState::ChannelSteering,
+            novatel_oem6_api::TrackingState::FrequencyLockLoop => TrackingState::FrequencyLockLoop,
+            novatel_oem6_api::TrackingState::ChannelAlignment => TrackingState::ChannelAlignment,
+            novatel_oem6_api::TrackingState::CodeSearch => TrackingState::CodeSearch,
+            novatel_oem6_api::TrackingState::AidedPhaseLockLoop => {
+                TrackingState::AidedPhaseLockLoop
+            }
+        }
+    }
+}
+
+/// Maximum number of channels returned by the `trackingStatus` query.
+/// Any further active channels in a TRACKSTAT log are dropped
+pub const MAX_TRACKING_CHANNELS: usize = 64;
+
+/// Tracking status of a single receiver channel. Used in the response fields of the
+/// `trackingStatus` query
+#[derive(Clone, Debug, PartialEq, GraphQLObject)]
+pub struct TrackingChannel {
+    /// Index of the channel within the receiver's TRACKSTAT log
+    pub channel: i32,
+    /// Satellite PRN number (or GLONASS slot number)
+    pub prn: i32,
+    /// Satellite system of the signal. Null if the receiver reported an unknown system
+    pub system: Option<Constellation>,
+    /// Carrier to noise density ratio (dB-Hz)
+    pub cno: f64,
+    /// Tracking state of the channel. Null if the receiver reported an unknown state
+    pub state: Option<TrackingState>,
+}
+
+impl TrackingChannel {
+    /// Convert the channels of a TRACKSTAT log. Idle channels (no PRN assigned) are
+    /// skipped, and at most [`MAX_TRACKING_CHANNELS`] entries are returned
+    ///
+    /// [`MAX_TRACKING_CHANNELS`]: constant.MAX_TRACKING_CHANNELS.html
+    pub fn from_channels(channels: &[TrackStatChannel]) -> Vec<TrackingChannel> {
+        channels
+            .iter()
+            .enumerate()
+            .filter(|(_, chan)| chan.prn != 0)
+            .take(MAX_TRACKING_CHANNELS)
+            .map(|(index, chan)| TrackingChannel {
+                channel: index as i32,
+                prn: i32::from(chan.prn),
+                system: chan.system().map(Constellation::from),
+                cno: f64::from(chan.cno),
+                state: chan.tracking_state().map(TrackingState::from),
+            })
+            .collect()
+    }
+}
+
+/// Per-channel tracking status from the most recent TRACKSTAT log. Used in the
+/// response fields of the `trackingStatus` query
+#[derive(Clone, GraphQLObject)]
+pub struct TrackingStatus {
+    /// Timestamp of the tracking status
+    pub time: OEMTime,
+    /// One entry per active receiver channel. Empty if no TRACKSTAT log has been received
+    pub channels: Vec<TrackingChannel>,
+    /// Unix timestamp (seconds) at which the service received the tracking status.
+    /// 0.0 if no tracking status is available
+    pub received_at: f64,
+    /// Whether the tracking status is older than the service's `telemetry_max_age`,
+    /// or no tracking status is available
+    pub stale: bool,
+}
+
+impl Default for TrackingStatus {
+    fn default() -> Self {
+        TrackingStatus {
+            time: OEMTime::default(),
+            channels: vec![],
+            received_at: 0.0,
+            stale: true,
+        }
+    }
+}
+
+impl CachedTelemetry for TrackingStatus {
+    fn received_at(&self) -> f64 {
+        self.received_at
+    }
+
+    fn set_stale(&mut self, stale: bool) {
+        self.stale = stale;
+    }
+}
+
+/// External event captured on one of the receiver's mark inputs. Used in
+/// the response fields of the `markTimeEvents` query
+#[derive(Clone, Debug, PartialEq, GraphQLObject)]
+pub struct MarkTimeEvent {
+    /// GPS reference week of the event
+    pub week: i32,
+    /// Milliseconds into the GPS reference week of the event
+    pub ms: i32,
+    /// Mark input which generated the event (1 for MARK1TIME, 2 for MARK2TIME)
+    pub event_id: i32,
+}
+
 /// Response field for 'power' query
 #[derive(GraphQLEnum, Clone, Eq, PartialEq, Debug)]
 pub enum PowerState {
@@ -454,20 +2037,160 @@ pub struct GetPowerResponse {
     pub uptime: i32,
 }
 
+/// Log currently being generated by the receiver. Used in the response fields of
+/// the `configuration` query
+#[derive(Clone, Debug, GraphQLObject)]
+pub struct ActiveLog {
+    /// Name of the log message (ex. "BESTXYZ")
+    pub name: String,
+    /// Interval, in seconds, at which the log is being generated
+    pub interval: f64,
+    /// Condition which triggers the log to be generated (ex. "ONTIME")
+    pub trigger: String,
+}
+
+impl ActiveLog {
+    /// Convert a LOG request read back from the receiver's configuration
+    pub fn from_request(request: &LogRequest) -> Self {
+        let name = match request.msg_id {
+            37 => "VERSION".to_owned(),
+            43 => "RANGE".to_owned(),
+            83 => "TRACKSTAT".to_owned(),
+            94 => "RXSTATUSEVENT".to_owned(),
+            99 => "BESTVEL".to_owned(),
+            101 => "TIME".to_owned(),
+            128 => "RXCONFIG".to_owned(),
+            174 => "PSRDOP".to_owned(),
+            231 => "MARK1TIME".to_owned(),
+            241 => "BESTXYZ".to_owned(),
+            616 => "MARK2TIME".to_owned(),
+            963 => "HWMONITOR".to_owned(),
+            id => format!("UNKNOWN({})", id),
+        };
+
+        let trigger = match request.trigger {
+            0 => "ONNEW".to_owned(),
+            1 => "ONCHANGED".to_owned(),
+            2 => "ONTIME".to_owned(),
+            3 => "ONNEXT".to_owned(),
+            4 => "ONCE".to_owned(),
+            5 => "ONMARK".to_owned(),
+            trigger => format!("UNKNOWN({})", trigger),
+        };
+
+        ActiveLog {
+            name,
+            interval: request.period,
+            trigger,
+        }
+    }
+}
+
+/// Port assignment of a log currently being generated by the receiver. Used in the
+/// response fields of the `portConfig` query
+#[derive(Clone, Debug, GraphQLObject)]
+pub struct LogPortAssignment {
+    /// Name of the log message (ex. "BESTXYZ")
+    pub name: String,
+    /// Port the log is being output on (ex. "COM2")
+    pub port: String,
+}
+
+impl LogPortAssignment {
+    /// Convert a LOG request read back from the receiver's configuration
+    pub fn from_request(request: &LogRequest) -> Self {
+        LogPortAssignment {
+            name: ActiveLog::from_request(request).name,
+            port: request.port_name(),
+        }
+    }
+}
+
 /// Response fields for `systemStatus` query
-#[derive(Clone, GraphQLObject)]
+#[derive(Clone, GraphQLObject, Serialize, Deserialize)]
 pub struct SystemStatus {
     /// Current receiver status. If all flags are present, then the service was unable to acquire
     /// the current status value.
     pub status: ReceiverStatus,
-    /// Error messages received from the system
+    /// Error messages received from the system, followed by descriptions of any
+    /// error conditions indicated by the receiver status flags
     pub errors: Vec<String>,
+    /// Whether the receiver reports an open circuit in the antenna.
+    /// False if the current status could not be acquired
+    pub antenna_open: bool,
+    /// Whether the receiver reports a short circuit in the antenna.
+    /// False if the current status could not be acquired
+    pub antenna_short: bool,
+}
+
+impl SystemStatus {
+    /// Build a system status response, decoding the antenna state from the receiver status flags
+    pub fn new(status: ReceiverStatusFlags, mut errors: Vec<String>) -> Self {
+        // All flags being set indicates that the status couldn't be read
+        let valid = status != ReceiverStatusFlags::all();
+
+        // Spell out any problems indicated by the status flags, so that they don't need
+        // to be looked up in the OEM6 manual
+        if valid {
+            errors.extend(
+                status
+                    .error_descriptions()
+                    .into_iter()
+                    .map(|desc| format!("Receiver Status: {}", desc)),
+            );
+        }
+
+        SystemStatus {
+            status: ReceiverStatus(status),
+            errors,
+            antenna_open: valid && status.contains(ReceiverStatusFlags::ANTENNA_OPEN),
+            antenna_short: valid && status.contains(ReceiverStatusFlags::ANTENNA_SHORTENED),
+        }
+    }
 }
 
 /// Receiver status
 #[derive(Clone)]
 pub struct ReceiverStatus(pub ReceiverStatusFlags);
 
+impl ReceiverStatus {
+    // Individual flags present in the status, in bit order
+    fn flags(&self) -> impl Iterator<Item = ReceiverStatusFlags> + '_ {
+        (0..32)
+            .filter_map(|bit| ReceiverStatusFlags::from_bits(1 << bit))
+            .filter(move |flag| self.0.contains(*flag))
+    }
+}
+
+// Serialized as the list of flag names, matching the GraphQL representation
+impl serde::Serialize for ReceiverStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.flags().map(|flag| format!("{:?}", flag)))
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ReceiverStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let names: Vec<String> = serde::Deserialize::deserialize(deserializer)?;
+        let all = ReceiverStatus(ReceiverStatusFlags::all());
+
+        let mut status = ReceiverStatusFlags::empty();
+        for name in names {
+            match all.flags().find(|flag| format!("{:?}", flag) == name) {
+                Some(flag) => status |= flag,
+                None => {
+                    return Err(de::Error::custom(format!(
+                        "Unknown receiver status flag: {}",
+                        name
+                    )))
+                }
+            }
+        }
+
+        Ok(ReceiverStatus(status))
+    }
+}
+
 graphql_scalar!(ReceiverStatus where Scalar = <S> {
    resolve(&self) -> Value {
         Value::list(self.0.to_vec().iter().map(|flag| Value::scalar(flag.to_owned())).collect())
@@ -490,6 +2213,21 @@ graphql_scalar!(ReceiverStatus where Scalar = <S> {
     }
 });
 
+/// Response fields for `health` query
+#[derive(Clone, GraphQLObject)]
+pub struct Health {
+    /// Whether the receiver responded to a status request or is sending logs
+    pub powered: bool,
+    /// Whether the last received position solution was computed, and is recent enough
+    /// not to be stale
+    pub locked: bool,
+    /// Error messages received from the system
+    pub errors: Vec<String>,
+    /// Current receiver status. If all flags are present, then the service was unable to acquire
+    /// the current status value.
+    pub status_flags: ReceiverStatus,
+}
+
 /// Response fields for `telemetry` query
 #[derive(GraphQLObject)]
 pub struct Telemetry {
@@ -499,8 +2237,25 @@ pub struct Telemetry {
     pub debug: Option<VersionInfo>,
 }
 
+/// Response fields for `batchTelemetry` query
+///
+/// Fields which could not be populated are null
+#[derive(GraphQLObject)]
+pub struct BatchTelemetry {
+    /// Current power state of the system. See the `power` query
+    pub power: Option<GetPowerResponse>,
+    /// Receiver status flags and recent errors. See the `systemStatus` query
+    pub system_status: Option<SystemStatus>,
+    /// Cached lock status. See the `lockStatus` query
+    pub lock_status: Option<LockStatus>,
+    /// Cached lock information. See the `lockInfo` query
+    pub lock_info: Option<LockInfo>,
+    /// Cached version information. See the `debug` field of the `telemetry` query
+    pub telemetry_debug: Option<VersionInfo>,
+}
+
 /// Response fields for 'telemetry(telem: NOMINAL)' query
-#[derive(Clone, GraphQLObject)]
+#[derive(Clone, GraphQLObject, Serialize, Deserialize)]
 pub struct TelemetryNominal {
     /// System status
     pub system_status: SystemStatus,
@@ -508,6 +2263,101 @@ pub struct TelemetryNominal {
     pub lock_status: Option<LockStatus>,
     /// Last known good lock information
     pub lock_info: Option<LockInfo>,
+    /// Diagnostic fields from the header of the most recent BestXYZ log
+    #[serde(default)]
+    pub log_header: Option<LogHeader>,
+}
+
+/// Diagnostic fields from the header of a BestXYZ log.
+/// Used in the response fields of the `telemetry` query
+#[derive(Clone, Debug, PartialEq, GraphQLObject, Serialize, Deserialize)]
+pub struct LogHeader {
+    /// Percentage of time the receiver's processor was idle. A rough measure of CPU load
+    pub idle_time: f64,
+    /// Receiver port which the log was output on (ex. "COM1")
+    pub port: String,
+    /// Position of the log within a set of related logs output together.
+    /// Counts down to 0, and is 0 for logs output on their own
+    pub sequence: i32,
+}
+
+impl LogHeader {
+    /// Extract the header fields of a BestXYZ log
+    pub fn new(log: &BestXYZLog) -> Self {
+        LogHeader {
+            // Reported in units of 0.5%
+            idle_time: f64::from(log.idle_time) / 2.0,
+            port: port_name(log.port_addr),
+            sequence: i32::from(log.seq),
+        }
+    }
+}
+
+/// Velocity over ground, derived from the ECEF position and velocity
+/// reported in a single BestXYZ log
+#[derive(Clone, Default, GraphQLObject)]
+pub struct GroundVelocity {
+    /// Horizontal speed over ground (m/s)
+    pub horizontal_speed: f64,
+    /// Vertical speed (m/s). Positive values indicate the receiver is moving upwards
+    pub vertical_speed: f64,
+    /// Actual direction of motion over ground with respect to True North (degrees)
+    pub track: f64,
+}
+
+impl GroundVelocity {
+    /// Convert an ECEF velocity into local horizontal/vertical components at the given
+    /// ECEF position, using the WGS-84 ellipsoid
+    pub fn from_ecef(position: [f64; 3], velocity: [f64; 3]) -> Self {
+        let [vx, vy, vz] = velocity;
+
+        let (lat, lon) = match ecef_to_geodetic(position) {
+            Some((lat, lon, _)) => (lat, lon),
+            None => return GroundVelocity::default(),
+        };
+
+        let (sin_lat, cos_lat) = lat.sin_cos();
+        let (sin_lon, cos_lon) = lon.sin_cos();
+
+        let east = -sin_lon * vx + cos_lon * vy;
+        let north = -sin_lat * cos_lon * vx - sin_lat * sin_lon * vy + cos_lat * vz;
+        let up = cos_lat * cos_lon * vx + cos_lat * sin_lon * vy + sin_lat * vz;
+
+        let horizontal_speed = east.hypot(north);
+        let track = if horizontal_speed == 0.0 {
+            0.0
+        } else {
+            east.atan2(north).to_degrees().rem_euclid(360.0)
+        };
+
+        GroundVelocity {
+            horizontal_speed,
+            vertical_speed: up,
+            track,
+        }
+    }
+}
+
+/// Response fields for `snapshot` query
+///
+/// All fields are captured together from the same BestXYZ log, so they
+/// always describe the same epoch
+#[derive(Clone, GraphQLObject)]
+pub struct TelemetrySnapshot {
+    /// Timestamp of the epoch the snapshot was captured from
+    pub time: OEMTime,
+    /// Lock status at this epoch
+    pub lock_status: LockStatus,
+    /// Lock information at this epoch. Null if the receiver did not have a good
+    /// position and velocity solution
+    pub lock_info: Option<LockInfo>,
+    /// Velocity over ground at this epoch
+    pub ground_velocity: GroundVelocity,
+    /// Dilution of precision values for this epoch. Null if no PsrDop log has been
+    /// received for the same GPS time
+    pub dop_info: Option<DopInfo>,
+    /// Receiver status at this epoch, along with any errors received by the service
+    pub system_status: SystemStatus,
 }
 
 /// Version information about the device, returned as the
@@ -520,12 +2370,30 @@ pub struct VersionInfo {
     pub components: Vec<VersionComponent>,
 }
 
+impl VersionInfo {
+    /// Convert a VERSION log into its response fields
+    pub fn new(log: &VersionLog) -> Self {
+        VersionInfo {
+            num_components: log.num_components as i32,
+            components: log
+                .components
+                .iter()
+                .map(|comp| VersionComponent(comp.clone()))
+                .collect(),
+        }
+    }
+}
+
 /// System component data
 #[derive(Clone)]
 pub struct VersionComponent(pub Component);
 
 graphql_object!(VersionComponent: () where Scalar = <S> | &self | {
-    field comp_type() -> i32 {
+    field comp_type() -> ComponentType {
+        self.0.comp_type.into()
+    }
+
+    field comp_type_raw() -> i32 {
         self.0.comp_type as i32
     }
 
@@ -556,4 +2424,8 @@ graphql_object!(VersionComponent: () where Scalar = <S> | &self | {
     field compile_time() -> String {
         self.0.compile_time.clone()
     }
+
+    field valid() -> bool {
+        self.0.valid
+    }
 });
@@ -16,7 +16,7 @@
 
 use crate::model::*;
 use crate::objects::*;
-use juniper::FieldResult;
+use juniper::{FieldResult, LookAheadMethods};
 
 type Context = kubos_service::Context<Subsystem>;
 
@@ -56,17 +56,21 @@ graphql_object!(QueryRoot: Context as "Query" |&self| {
     // }
     field errors(&executor) -> FieldResult<Vec<String>>
     {
-        executor.context().subsystem().get_errors();
+        Ok(executor.context().subsystem().take_errors())
+    }
 
-        match executor.context().subsystem().errors.write() {
-            Ok(mut master_vec) => {
-                let current = master_vec.clone();
-                master_vec.clear();
-                master_vec.shrink_to_fit();
-                Ok(current)
-            },
-            _ => Ok(vec!["Error: Failed to borrow master errors vector".to_owned()])
-        }
+    // Get only the errors encountered since the last time this field was queried
+    //
+    // Each error is returned by exactly one `newErrors` query, regardless of whether
+    // it has also been returned (and cleared) by the `errors` query.
+    // `errors` and `systemStatus.errors` are not affected by this query
+    //
+    // {
+    //     newErrors: [String]
+    // }
+    field new_errors(&executor) -> FieldResult<Vec<String>>
+    {
+        Ok(executor.context().subsystem().get_new_errors())
     }
 
     // Get the current power state of the system
@@ -98,6 +102,84 @@ graphql_object!(QueryRoot: Context as "Query" |&self| {
         Ok(String::from("Not Implemented"))
     }
 
+    // Get the logs currently being generated by the receiver, as read back from
+    // the receiver's own configuration (RXCONFIG). Entries for commands other than
+    // `LOG` are omitted.
+    //
+    // If the receiver does not respond, an empty list will be returned and the
+    // failure will be recorded in `errors`
+    //
+    // {
+    //     configuration {
+    //         interval: Float,
+    //         name: String,
+    //         trigger: String
+    //     }
+    // }
+    field configuration(&executor) -> FieldResult<Vec<ActiveLog>>
+    {
+        Ok(executor.context().subsystem().get_configuration()?)
+    }
+
+    // Get the port each log currently being generated by the receiver is output on,
+    // as read back from the receiver's own configuration (RXCONFIG). Entries for
+    // commands other than `LOG` are omitted.
+    //
+    // If the receiver does not respond, an empty list will be returned and the
+    // failure will be recorded in `errors`
+    //
+    // {
+    //     portConfig {
+    //         name: String,
+    //         port: String
+    //     }
+    // }
+    field port_config(&executor) -> FieldResult<Vec<LogPortAssignment>>
+    {
+        Ok(executor.context().subsystem().get_port_config()?)
+    }
+
+    // Get the configuration options which the service has successfully applied with
+    // `configureHardware`, without querying the receiver. `LOG_*` options are listed
+    // until they are removed by the matching `UNLOG_*` option or by `UNLOG_ALL`.
+    // Since this reflects the service's requests, it will not include settings restored
+    // from the receiver's saved configuration.
+    // If the link to the receiver is lost, these options are requested again (see `link_timeout`)
+    //
+    // {
+    //     appliedConfiguration {
+    //         format: LogFormat,
+    //         hold: Boolean,
+    //         interval: Float,
+    //         offset: Float,
+    //         option: ConfigOption,
+    //         port: OemPort
+    //     }
+    // }
+    field applied_configuration(&executor) -> FieldResult<Vec<AppliedConfig>>
+    {
+        Ok(executor.context().subsystem().get_applied_configuration()?)
+    }
+
+    // Get the configuration options accepted by `configureHardware`, along with whether each
+    // one starts log output (`LOG`), stops it (`UNLOG`) or neither (`OTHER`), and which of the
+    // `interval`, `offset` and `hold` input fields it uses. Fields which aren't used by an option
+    // are ignored. This does not communicate with the receiver
+    //
+    // {
+    //     configOptions {
+    //         hold: Boolean,
+    //         interval: Boolean,
+    //         kind: ConfigOptionKind,
+    //         offset: Boolean,
+    //         option: ConfigOption
+    //     }
+    // }
+    field config_options() -> FieldResult<Vec<ConfigOptionInfo>>
+    {
+        Ok(ConfigOption::ALL.iter().map(|&option| ConfigOptionInfo::from(option)).collect())
+    }
+
     // Get the test results of the last run test
     //
     // {
@@ -113,8 +195,16 @@ graphql_object!(QueryRoot: Context as "Query" |&self| {
 
     // Get the current system status and errors
     //
+    // `antennaOpen` and `antennaShort` are decoded from the receiver status flags
+    // and will be `false` if the current status could not be read
+    //
+    // Any error conditions indicated by the status flags (for example, an antenna
+    // short circuit or a buffer overrun) are also described in `errors`
+    //
     // {
     //     systemStatus {
+    //        antennaOpen: Boolean,
+    //        antennaShort: Boolean,
     //        errors: Vec<String>,
     //        status: Vec<String>
     //     }
@@ -124,12 +214,62 @@ graphql_object!(QueryRoot: Context as "Query" |&self| {
         Ok(executor.context().subsystem().get_system_status()?)
     }
 
+    // Get a summary of the system's health in a single request
+    //
+    // `powered` will be `false` if the receiver did not respond to a status request and
+    // is not sending logs. `locked` will be `true` if the last received position solution
+    // was computed, is not stale and the receiver is still connected
+    //
+    // {
+    //     health {
+    //        errors: Vec<String>,
+    //        locked: Boolean,
+    //        powered: Boolean,
+    //        statusFlags: Vec<String>
+    //     }
+    // }
+    field health(&executor) -> FieldResult<Health>
+    {
+        Ok(executor.context().subsystem().get_health()?)
+    }
+
     // Get current status of position information gathering
     //
+    // `differentialAge` is the age, in seconds, of the differential corrections used
+    // in the position solution. It will be -1.0 if the position type is not a differential solution
+    //
+    // `lockProgress` is an approximate indication (0-100) of how close the receiver is to a
+    // converged position solution, derived from the position status and type. It increases
+    // through the normal cold-start sequence (`COLD_START` 10, `PENDING` 25,
+    // `INSUFFICIENT_OBSERVATIONS` 40, other rejected solutions 50, standalone solution 75,
+    // differential/float solution 90, fixed/integer solution 100) and is 0 for `INVALID_FIX`
+    // and `UNAUTHORIZED`
+    //
+    // `rejectedMeasurements` is the number of tracked satellites which were not used in the
+    // position solution. A rising count, especially alongside an `INTEGRITY_WARNING` position
+    // status, can indicate multipath or interference. It also includes satellites excluded for other
+    // reasons (ex. below the elevation mask), and is 0 until a position log has been received
+    //
+    // Each status and type field also has a matching `*Raw: Int` field (ex. `positionTypeRaw`)
+    // containing the numeric value reported by the receiver. This preserves the original value
+    // when the enum field is `KUBOS_INVALID`
+    //
+    // `receivedAt` is the Unix time at which the service received the status. `stale` is `true`
+    // if no status has been received or it is older than `telemetry_max_age`
+    //
+    // `deviceConnected` is `false` if no log has ever been received from the receiver. In that case
+    // the other fields are defaults, not a connected receiver which is still searching
+    //
     // {
     //     lockStatus {
+    //         deviceConnected: Boolean,
+    //         differentialAge: Float,
+    //         lockProgress: Int,
     //         positionStatus: SolutionStatus,
     //           positionType: PosVelType,
+    //         receivedAt: Float,
+    //         rejectedMeasurements: Int,
+    //         stale: Boolean,
     //           time {
     //             ms: Int,
     //               week: Int
@@ -146,9 +286,33 @@ graphql_object!(QueryRoot: Context as "Query" |&self| {
 
     // Get the last known good position information
     //
+    // `geodetic` is computed from the ECEF `position` using the WGS-84 ellipsoid.
+    // Latitude and longitude are in degrees and altitude is the height above the ellipsoid in meters.
+    // All values will be 0.0 if no position is available
+    //
+    // `solutionAge` is the age of the solution reported by the receiver, or -1.0 if no solution has
+    // been received. `receivedAt` is the Unix time at which the service received the solution.
+    // `stale` is `true` if no solution has been received or it is older than `telemetry_max_age`
+    //
+    // `speed` is the magnitude of the velocity vector in m/s. `heading` is the course over ground in
+    // degrees from True North, or null if the receiver is stationary (horizontal speed below 0.01 m/s)
+    //
+    // `deviceConnected` is `false` if no log has ever been received from the receiver
+    //
     // {
     //     lockInfo {
+    //        deviceConnected: Boolean,
+    //        geodetic {
+    //            altitude: Float,
+    //            latitude: Float,
+    //            longitude: Float
+    //        },
+    //        heading: Float,
     //        position: Vec<Float>,
+    //        receivedAt: Float,
+    //        solutionAge: Float,
+    //        speed: Float,
+    //        stale: Boolean,
     //        time {
     //            ms: Int,
     //            week: Int
@@ -161,26 +325,319 @@ graphql_object!(QueryRoot: Context as "Query" |&self| {
         Ok(executor.context().subsystem().get_lock_info()?)
     }
 
+    // Get the last received BestVel velocity (see `LOG_VELOCITY_DATA`).
+    // `latency` is the latency of the velocity time tag in seconds, and `track` is the direction of
+    // motion over ground in degrees from True North.
+    // `receivedAt` is the Unix time at which the service received the BestVel log.
+    // `stale` will be `true` until a BestVel log has been received, or if the last one is older
+    // than `telemetry_max_age`
+    //
+    // {
+    //     velocityInfo {
+    //        differentialAge: Float,
+    //        horizontalSpeed: Float,
+    //        latency: Float,
+    //        receivedAt: Float,
+    //        stale: Boolean,
+    //        time {
+    //            ms: Int,
+    //            week: Int
+    //        },
+    //        track: Float,
+    //        velocityStatus: SolutionStatus,
+    //        velocityStatusRaw: Int,
+    //        velocityType: PosVelType,
+    //        velocityTypeRaw: Int,
+    //        verticalSpeed: Float
+    //     }
+    // }
+    field velocity_info(&executor) -> FieldResult<VelocityInfo>
+    {
+        Ok(executor.context().subsystem().get_velocity_info()?)
+    }
+
+    // Get the last received receiver clock and GPS-to-UTC offset information.
+    // `receivedAt` is the Unix time at which the service received the TIME log.
+    // `stale` will be `true` until a TIME log has been received (see `LOG_TIME_DATA`),
+    // or if the last one is older than `telemetry_max_age`
+    //
+    // {
+    //     clockInfo {
+    //        clockOffset: Float,
+    //        clockOffsetStdDev: Float,
+    //        receivedAt: Float,
+    //        stale: Boolean,
+    //        time {
+    //            ms: Int,
+    //            week: Int
+    //        },
+    //        utcOffset: Float
+    //     }
+    // }
+    field clock_info(&executor) -> FieldResult<ClockInfo>
+    {
+        Ok(executor.context().subsystem().get_clock_info()?)
+    }
+
+    // Get the last received dilution of precision information.
+    // All values will be 9999.0 if no DOP information is available (see `LOG_DOP_DATA`).
+    // `receivedAt` is the Unix time at which the service received the DOP information.
+    // `stale` is `true` if no DOP information is available or it is older than `telemetry_max_age`
+    //
+    // {
+    //     dopInfo {
+    //        hdop: Float,
+    //        pdop: Float,
+    //        receivedAt: Float,
+    //        stale: Boolean,
+    //        tdop: Float,
+    //        time {
+    //            ms: Int,
+    //            week: Int
+    //        },
+    //        vdop: Float
+    //     }
+    // }
+    field dop_info(&executor) -> FieldResult<DopInfo>
+    {
+        Ok(executor.context().subsystem().get_dop_info()?)
+    }
+
+    // Get the last received hardware monitor readings.
+    // All values will be 9999.0 if no hardware monitor information is available (see `LOG_HW_MONITOR`).
+    // `temperature` is in degrees Celsius, `antennaCurrent` is in Amps and `coreVoltage` is in Volts.
+    // `receivedAt` is the Unix time at which the service received the hardware monitor information.
+    // `stale` is `true` if no hardware monitor information is available or it is older than `telemetry_max_age`
+    //
+    // {
+    //     hardwareMonitor {
+    //        antennaCurrent: Float,
+    //        coreVoltage: Float,
+    //        receivedAt: Float,
+    //        stale: Boolean,
+    //        temperature: Float,
+    //        time {
+    //            ms: Int,
+    //            week: Int
+    //        }
+    //     }
+    // }
+    field hardware_monitor(&executor) -> FieldResult<HardwareMonitor>
+    {
+        Ok(executor.context().subsystem().get_hardware_monitor()?)
+    }
+
+    // Get the raw observations from the last received RANGE log (see `LOG_RANGE_DATA`).
+    // `observations` contains one entry per tracked satellite signal and is empty if no RANGE
+    // log has been received. `pseudorange` is in meters, `carrierPhase` is in cycles
+    // (accumulated Doppler range, as reported by the receiver), `dopplerHz` is in Hz,
+    // `cno` is in dB-Hz and `lockTime` is in seconds.
+    // `system` is null if the receiver reports a satellite system not covered by `Constellation`.
+    // `receivedAt` is the Unix time at which the service received the observations.
+    // `stale` is `true` if no observations are available or they are older than `telemetry_max_age`
+    //
+    // {
+    //     rangeObservations {
+    //        observations {
+    //            carrierPhase: Float,
+    //            cno: Float,
+    //            dopplerHz: Float,
+    //            lockTime: Float,
+    //            prn: Int,
+    //            pseudorange: Float,
+    //            system: Constellation
+    //        },
+    //        receivedAt: Float,
+    //        stale: Boolean,
+    //        time {
+    //            ms: Int,
+    //            week: Int
+    //        }
+    //     }
+    // }
+    field range_observations(&executor) -> FieldResult<RangeObservations>
+    {
+        Ok(executor.context().subsystem().get_range_observations()?)
+    }
+
+    // Get the number of satellites tracked and used in the position solution for each
+    // satellite system, from the last received TRACKSTAT log (see `LOG_TRACKING_DATA`).
+    // `constellations` contains one entry per system and is empty if no TRACKSTAT log has been received.
+    // A satellite counts as tracked if any receiver channel is tracking one of its signals,
+    // and as used if any of those signals was used in the solution.
+    // Satellites tracked on several signals are only counted once.
+    // `receivedAt` is the Unix time at which the service received the tracking status.
+    // `stale` is `true` if no tracking status is available or it is older than `telemetry_max_age`
+    //
+    // {
+    //     constellationStatus {
+    //        constellations {
+    //            system: Constellation,
+    //            tracked: Int,
+    //            used: Int
+    //        },
+    //        receivedAt: Float,
+    //        stale: Boolean,
+    //        time {
+    //            ms: Int,
+    //            week: Int
+    //        }
+    //     }
+    // }
+    field constellation_status(&executor) -> FieldResult<ConstellationStatus>
+    {
+        Ok(executor.context().subsystem().get_constellation_status()?)
+    }
+
+    // Get the tracking status of each active receiver channel, from the last received
+    // TRACKSTAT log (see `LOG_TRACKING_DATA`).
+    // `channels` contains one entry per channel with a satellite assigned, and is empty if no
+    // TRACKSTAT log has been received. Idle channels are omitted, and at most 64 channels are returned.
+    // `channel` is the channel's index within the TRACKSTAT log.
+    // `system` is null if the receiver reports a satellite system not covered by `Constellation`,
+    // and `state` is null if it reports an unknown tracking state.
+    // `receivedAt` is the Unix time at which the service received the tracking status.
+    // `stale` is `true` if no tracking status is available or it is older than `telemetry_max_age`
+    //
+    // {
+    //     trackingStatus {
+    //        channels {
+    //            channel: Int,
+    //            prn: Int,
+    //            system: Constellation,
+    //            cno: Float,
+    //            state: TrackingState
+    //        },
+    //        receivedAt: Float,
+    //        stale: Boolean,
+    //        time {
+    //            ms: Int,
+    //            week: Int
+    //        }
+    //     }
+    // }
+    field tracking_status(&executor) -> FieldResult<TrackingStatus>
+    {
+        Ok(executor.context().subsystem().get_tracking_status()?)
+    }
+
+    // Get the external events captured on the receiver's mark inputs since the last
+    // time this query was run (see `LOG_MARK_TIME`).
+    // The event buffer is cleared on read, so each event is only returned once.
+    // Up to 100 events are kept between queries. Once full, the oldest events are dropped.
+    // `eventId` is 1 for MARK1TIME events and 2 for MARK2TIME events
+    //
+    // {
+    //     markTimeEvents {
+    //        eventId: Int,
+    //        ms: Int,
+    //        week: Int
+    //     }
+    // }
+    field mark_time_events(&executor) -> FieldResult<Vec<MarkTimeEvent>>
+    {
+        Ok(executor.context().subsystem().get_mark_time_events()?)
+    }
+
+    // Get a consistent set of telemetry captured from the most recent BestXYZ log.
+    // All fields describe the same epoch. Returns null until a BestXYZ log has been received.
+    // `dopInfo` is null until a PsrDop log with the same GPS time has been received
+    //
+    // {
+    //     snapshot {
+    //        groundVelocity {
+    //            horizontalSpeed: Float,
+    //            track: Float,
+    //            verticalSpeed: Float
+    //        },
+    //        dopInfo {...},
+    //        lockInfo {...},
+    //        lockStatus {...},
+    //        systemStatus {
+    //            errors: Vec<String>,
+    //            status: Vec<String>
+    //        },
+    //        time {
+    //            ms: Int,
+    //            week: Int
+    //        }
+    //     }
+    // }
+    field snapshot(&executor) -> FieldResult<Option<TelemetrySnapshot>>
+    {
+        Ok(executor.context().subsystem().get_snapshot()?)
+    }
+
+    // Get the power state, system status, lock status, lock information and debug
+    // telemetry in a single request.
+    //
+    // The receiver is queried at most once, and only if `power` or `systemStatus`
+    // is requested. The other fields are served from the service's caches, so
+    // `telemetryDebug` is null until the version information has been read.
+    // Fields which could not be populated are null.
+    //
+    // {
+    //     batchTelemetry {
+    //         lockInfo {...},
+    //         lockStatus {...},
+    //         power {
+    //             state: PowerState,
+    //             uptime: Int
+    //         },
+    //         systemStatus {
+    //             errors: Vec<String>,
+    //             status: Vec<String>
+    //         },
+    //         telemetryDebug {...}
+    //     }
+    // }
+    field batch_telemetry(&executor) -> FieldResult<BatchTelemetry>
+    {
+        let look_ahead = executor.look_ahead();
+        let status = look_ahead.has_child("power") || look_ahead.has_child("systemStatus");
+        let debug = look_ahead.has_child("telemetryDebug");
+        Ok(executor.context().subsystem().get_batch_telemetry(status, debug)?)
+    }
+
     // Get current telemetry information for the system
     //
+    // The debug (version) information is read from the device once and then cached,
+    // since it doesn't change within a session. Requests which only ask for `debug`
+    // are served from the cache. Use the `refreshVersion` mutation to re-read it.
+    // The cache is also cleared by the `reset` mutation.
+    // Components which could not be fully read are still listed, with `valid` set to false.
+    //
+    // `nominal.logHeader` holds diagnostic fields from the header of the most recent
+    // BestXYZ log, and is null if none has been received. `idleTime` is the percentage
+    // of time the receiver's processor was idle, `port` is the receiver port which the
+    // log was output on, and `sequence` counts down to 0 across a set of related logs
+    // output together (it is 0 for logs output on their own).
+    //
     // {
     //     telemetry{
     //         debug {
     //             components: [{
     //                 bootVersion: String,
-    //                 compType: Int,
+    //                 compType: ComponentType,
+    //                 compTypeRaw: Int,
     //                 compileDate: String,
     //                 compileTime: String,
     //                 hwVersion: String,
     //                 model: String,
     //                 serialNum: String,
     //                 swVersion: String,
+    //                 valid: Boolean,
     //             }],
     //             numComponents: Int
     //         },
     //         nominal{
     //             lockInfo {...},
     //             lockStatus {...},
+    //             logHeader {
+    //                idleTime: Float,
+    //                port: String,
+    //                sequence: Int
+    //             },
     //             systemStatus: {
     //                errors: Vec<String>,
     //                status: Vec<String>
@@ -190,7 +647,13 @@ graphql_object!(QueryRoot: Context as "Query" |&self| {
     // }
     field telemetry(&executor) -> FieldResult<Telemetry>
     {
-        Ok(executor.context().subsystem().get_telemetry()?)
+        // The receiver status needs a fresh VERSION log, so only query the device
+        // when the nominal telemetry has been requested
+        if executor.look_ahead().has_child("nominal") {
+            Ok(executor.context().subsystem().get_telemetry()?)
+        } else {
+            Ok(executor.context().subsystem().get_telemetry_debug()?)
+        }
     }
 });
 
@@ -220,6 +683,7 @@ graphql_object!(MutationRoot: Context as "Mutation" |&self| {
     //
     // mutation {
     //     noop {
+    //         errorDetails: [{code: Int, category: ErrorCategory, message: String}],
     //         errors: String,
     //         success: Boolean
     //    }
@@ -254,13 +718,39 @@ graphql_object!(MutationRoot: Context as "Mutation" |&self| {
     //           For `UNLOG_ALL` requests, specifies whether the 'hold' value in previous
     //           `LOG_*` requests should be ignored.
     //   - interval: Interval at which log messages should be generated.
-    //               Note: Only applies to `LOG_POSITION_DATA` requests. Ignored otherwise
+    //               Note: Only applies to `LOG_HW_MONITOR` and `LOG_*_DATA` requests other than
+    //               `LOG_ERROR_DATA`.
+    //               Ignored otherwise
+    //               Must be 0 (log once) or between 0.05 and 3600 seconds.
     //   - offset: Offset of interval at which log messages should be generated.
-    //             Note: Only applies to `LOG_POSITION_DATA` requests. Ignored otherwise
+    //             Note: Only applies to `LOG_HW_MONITOR` and `LOG_*_DATA` requests other than
+    //             `LOG_ERROR_DATA`.
+    //             Ignored otherwise
+    //             Must not be negative.
+    //   - format: Format in which log messages should be generated (`BINARY` or `ASCII`).
+    //             Note: Only applies to `LOG_*_DATA` requests other than `LOG_ERROR_DATA`.
+    //             `LOG_RANGE_DATA` and `LOG_TRACKING_DATA` are always generated in binary.
+    //             Defaults to `BINARY`
+    //   - verify: For `LOG_*` requests, wait for the first requested log message to arrive
+    //             before reporting success. The request fails with a `TIMEOUT` error if no
+    //             message arrives within the interval plus offset plus one second.
+    //             The interval plus offset must not exceed 9 seconds.
+    //             Not supported for `LOG_ERROR_DATA` or `LOG_MARK_TIME`.
+    //             Defaults to `false`
+    //
+    // Options are applied in the order given. A failing option does not stop the
+    // remaining options from being attempted, and any options applied before the
+    // failure remain applied. `success` will be false if any option failed,
+    // and `errors` will name each failing option.
+    //
+    // Including a `SAVE_CONFIG` request as the last entry will save the resulting
+    // configuration to non-volatile memory, so that it persists across power cycles.
+    // See `saveConfiguration` for the caveats
     //
     // mutation {
-    //     configureHardware(config: [{option: ConfigOption, hold: Boolean, interval: Float, offset: Float},...]) {
+    //     configureHardware(config: [{option: ConfigOption, hold: Boolean, interval: Float, offset: Float, format: LogFormat, verify: Boolean},...]) {
     //         config: String
+    //         errorDetails: [{code: Int, category: ErrorCategory, message: String}],
     //         errors: String,
     //         success: Boolean,
     //     }
@@ -282,6 +772,7 @@ graphql_object!(MutationRoot: Context as "Mutation" |&self| {
     // mutation {
     //     testHardware(test: TestType) {
     //         ... on IntegrationTestResults {
+    //             errorDetails: [{code: Int, category: ErrorCategory, message: String}],
     //             errors: String,
     //             success: Boolean,
     //             telemetryNominal{...},
@@ -302,17 +793,220 @@ graphql_object!(MutationRoot: Context as "Mutation" |&self| {
             TestType::Integration => Ok(TestResults::Integration(executor.context().subsystem()
                     .get_test_results().unwrap())),
             TestType::Hardware => Ok(TestResults::Hardware(HardwareTestResults {
-                        errors: "Not Implemented".to_owned(), success: true, data: "".to_owned()}))
+                        errors: "Not Implemented".to_owned(), error_details: vec![], success: true,
+                        data: "".to_owned()}))
         }
     }
 
-    // Pass a custom command through to the system
+    // Reset the receiver
+    //
+    // - resetType: Type of reset which should be performed
+    //   - `HOT`: Reset the receiver. Saved configuration and satellite data are retained
+    //   - `WARM`: Clear the saved ephemeris data and reset the receiver
+    //   - `COLD`: Clear the saved ephemeris and almanac data and reset the receiver
+    //   - `FACTORY`: Clear all saved data and reset the receiver.
+    //              Note: This will also clear any saved configuration, restoring the
+    //              factory default settings. `appliedConfiguration` is cleared, so any logs
+    //              requested with `configureHardware` will need to be requested again
+    //
+    // The reset type has no default and must always be specified
+    //
+    // mutation {
+    //     reset(resetType: ResetType) {
+    //         errorDetails: [{code: Int, category: ErrorCategory, message: String}],
+    //         errors: String,
+    //         success: Boolean
+    //     }
+    // }
+    field reset(&executor, reset_type: ResetType) -> FieldResult<GenericResponse>
+    {
+        let mut last_cmd = executor.context().subsystem().last_cmd.write()?;
+        *last_cmd = AckCommand::Reset;
+        Ok(executor.context().subsystem().reset(reset_type)?)
+    }
+
+    // Re-read the version information from the device, replacing the cached copy
+    // used by the `telemetry` query's `debug` field
+    //
+    // mutation {
+    //     refreshVersion {
+    //         errorDetails: [{code: Int, category: ErrorCategory, message: String}],
+    //         errors: String,
+    //         success: Boolean
+    //     }
+    // }
+    field refresh_version(&executor) -> FieldResult<GenericResponse>
+    {
+        let mut last_cmd = executor.context().subsystem().last_cmd.write()?;
+        *last_cmd = AckCommand::RefreshVersion;
+        Ok(executor.context().subsystem().refresh_version()?)
+    }
+
+    // Save the current configuration, including all requested logs, to non-volatile
+    // memory so that it is restored after a reset or power cycle
+    //
+    // Note: The non-volatile memory has limited write-cycle endurance, so this should
+    // only be issued after the configuration has actually changed, not periodically.
+    // A `FACTORY` reset will clear the saved configuration
+    //
+    // mutation {
+    //     saveConfiguration {
+    //         errorDetails: [{code: Int, category: ErrorCategory, message: String}],
+    //         errors: String,
+    //         success: Boolean
+    //     }
+    // }
+    field save_configuration(&executor) -> FieldResult<GenericResponse>
+    {
+        let mut last_cmd = executor.context().subsystem().last_cmd.write()?;
+        *last_cmd = AckCommand::SaveConfiguration;
+        Ok(executor.context().subsystem().save_configuration()?)
+    }
+
+    //
+    // Set the elevation mask. Satellites below this elevation angle are not tracked
+    //
+    // degrees: Elevation angle, in degrees, between 0 and 90 inclusive.
+    //          Values outside of this range are rejected without being sent.
+    //
+    // mutation {
+    //     setElevationMask(degrees: Int!) {
+    //         errorDetails: [{code: Int, category: ErrorCategory, message: String}],
+    //         errors: String,
+    //         success: Boolean
+    //     }
+    // }
+    field set_elevation_mask(&executor, degrees: i32) -> FieldResult<GenericResponse>
+    {
+        let mut last_cmd = executor.context().subsystem().last_cmd.write()?;
+        *last_cmd = AckCommand::SetElevationMask;
+        Ok(executor.context().subsystem().set_elevation_mask(degrees)?)
+    }
+
+    //
+    // Select the satellite systems the receiver should track.
+    // Any system not in the list is locked out.
+    //
+    // systems: List of satellite systems to track. Must not be empty.
+    //
+    // mutation {
+    //     setConstellations(systems: [Constellation!]!) {
+    //         errorDetails: [{code: Int, category: ErrorCategory, message: String}],
+    //         errors: String,
+    //         success: Boolean
+    //     }
+    // }
+    field set_constellations(&executor, systems: Vec<Constellation>) -> FieldResult<GenericResponse>
+    {
+        let mut last_cmd = executor.context().subsystem().last_cmd.write()?;
+        *last_cmd = AckCommand::SetConstellations;
+        Ok(executor.context().subsystem().set_constellations(systems)?)
+    }
+
+    //
+    // Change the data rate of the receiver port the service is connected to.
+    // The local port is reopened at the new rate and communication is confirmed
+    // by requesting the version information. If that fails, the receiver is sent
+    // back to the previous rate and the local port is reopened to match.
+    //
+    // rate: New data rate. One of 9600, 19200, 38400, 57600 or 115200.
+    //       Other values are rejected without being sent.
+    //
+    // mutation {
+    //     setBaud(rate: Int!) {
+    //         errorDetails: [{code: Int, category: ErrorCategory, message: String}],
+    //         errors: String,
+    //         success: Boolean
+    //     }
+    // }
+    field set_baud(&executor, rate: i32) -> FieldResult<GenericResponse>
+    {
+        let mut last_cmd = executor.context().subsystem().last_cmd.write()?;
+        *last_cmd = AckCommand::SetBaud;
+        Ok(executor.context().subsystem().set_baud(rate)?)
+    }
+
+    //
+    // Give the receiver an approximate position to shorten the time to first fix.
+    //
+    // lat: Latitude, in degrees, between -90 and 90 inclusive
+    // lon: Longitude, in degrees, between -180 and 180 inclusive
+    // height: Height above mean sea level, in meters
+    //
+    // Latitudes and longitudes outside of these ranges are rejected without being sent.
+    //
+    // mutation {
+    //     setApproximatePosition(lat: Float!, lon: Float!, height: Float!) {
+    //         errorDetails: [{code: Int, category: ErrorCategory, message: String}],
+    //         errors: String,
+    //         success: Boolean
+    //     }
+    // }
+    field set_approximate_position(&executor, lat: f64, lon: f64, height: f64) -> FieldResult<GenericResponse>
+    {
+        let mut last_cmd = executor.context().subsystem().last_cmd.write()?;
+        *last_cmd = AckCommand::SetApproximatePosition;
+        Ok(executor.context().subsystem().set_approximate_position(lat, lon, height)?)
+    }
+
+    //
+    // Give the receiver an approximate GPS time to shorten the time to first fix.
+    //
+    // week: GPS reference week. Must not be negative
+    // seconds: Seconds into the GPS reference week, from 0 up to (but not including) 604800
+    //
+    // Values outside of these ranges are rejected without being sent.
+    //
+    // mutation {
+    //     setApproximateTime(week: Int!, seconds: Float!) {
+    //         errorDetails: [{code: Int, category: ErrorCategory, message: String}],
+    //         errors: String,
+    //         success: Boolean
+    //     }
+    // }
+    field set_approximate_time(&executor, week: i32, seconds: f64) -> FieldResult<GenericResponse>
+    {
+        let mut last_cmd = executor.context().subsystem().last_cmd.write()?;
+        *last_cmd = AckCommand::SetApproximateTime;
+        Ok(executor.context().subsystem().set_approximate_time(week, seconds)?)
+    }
+
+    //
+    // Move a log to a different receiver port, such as one connected to a payload.
+    // The log is stopped on the port it was previously output on, then requested on the new
+    // port with the interval, offset, hold and format it was last applied with through
+    // `configureHardware`.
+    //
+    // log: `LOG_*` option whose log should be output. The option must currently be applied
+    // port: Port the log should be output on. One of `COM1`, `COM2` or `COM3`
+    //
+    // Other options and ports are rejected without being sent. The new port is recorded in
+    // `appliedConfiguration`, so the log is requested on it again if the link to the
+    // receiver is lost.
+    //
+    // mutation {
+    //     setLogPort(log: ConfigOption!, port: OemPort!) {
+    //         errorDetails: [{code: Int, category: ErrorCategory, message: String}],
+    //         errors: String,
+    //         success: Boolean
+    //     }
+    // }
+    field set_log_port(&executor, log: ConfigOption, port: OemPort) -> FieldResult<GenericResponse>
+    {
+        let mut last_cmd = executor.context().subsystem().last_cmd.write()?;
+        *last_cmd = AckCommand::SetLogPort;
+        Ok(executor.context().subsystem().set_log_port(log, port)?)
+    }
+
     //
     // command: String containing the hex values to be sent (ex. "C3")
     //          It will be converted to a byte array before transfer.
+    //          Malformed hex strings and commands longer than 1024 bytes
+    //          will be rejected without being sent.
     //
     // mutation {
     //     issueRawCommand(command: String) {
+    //         errorDetails: [{code: Int, category: ErrorCategory, message: String}],
     //         errors: String,
     //         success: Boolean,
     //         response: String
@@ -18,9 +18,9 @@ use novatel_oem6_api::mock::*;
 use novatel_oem6_api::*;
 
 #[macro_export]
-/// Mock a service for the OEM6.
-macro_rules! service_new {
-    ($mock:ident) => {{
+/// Mock the OEM6 subsystem which backs the service
+macro_rules! subsystem_new {
+    (stream: $stream:expr) => {{
         use novatel_oem6_api::Connection;
         use std::sync::{Arc, Mutex, RwLock};
         use std::thread;
@@ -28,17 +28,14 @@ macro_rules! service_new {
         use $crate::objects::AckCommand;
 
         let (log_send, log_recv) = sync_channel(5);
-        let (response_send, response_recv) = sync_channel(5);
+        // The mock delivers all of its output at once, so this needs to be able to hold
+        // every response for mutations which send several commands
+        let (response_send, response_recv) = sync_channel(10);
         let (response_abbrv_send, response_abbrv_recv) = sync_channel(5);
 
-        $mock.read.set_result(Err(UartError::IoError {
-            cause: ::std::io::ErrorKind::TimedOut,
-            description: "Mock Timeout".to_owned(),
-        }));
-
         let oem = OEM6 {
             conn: Arc::new(Mutex::new(Connection {
-                stream: Box::new($mock),
+                stream: Box::new($stream),
             })),
             log_recv: Arc::new(Mutex::new(log_recv)),
             response_recv: Arc::new(Mutex::new(response_recv)),
@@ -51,13 +48,22 @@ macro_rules! service_new {
             read_thread(&rx_conn, &log_send, &response_send, &response_abbrv_send)
         });
 
-        let data = Arc::new(LockData::new());
+        let data = Arc::new(LockData::new(DEFAULT_TELEMETRY_MAX_AGE));
         let (error_send, error_recv) = sync_channel(10);
         let (version_send, version_recv) = sync_channel(1);
+        let (rxconfig_send, rxconfig_recv) = sync_channel(RXCONFIG_BUFFER_LEN);
 
         let data_ref = data.clone();
         let oem_ref = oem.clone();
-        thread::spawn(move || log_thread(&oem_ref, &data_ref, &error_send, &version_send));
+        thread::spawn(move || {
+            log_thread(
+                &oem_ref,
+                &data_ref,
+                &error_send,
+                &version_send,
+                &rxconfig_send,
+            )
+        });
 
         // The read thread needs some time to intake and process the
         // sample data we give it.
@@ -65,6 +71,37 @@ macro_rules! service_new {
         // run on CircleCI, it needs to be 500ms
         thread::sleep(Duration::from_millis(500));
 
+        Subsystem {
+            oem,
+            last_cmd: Arc::new(RwLock::new(AckCommand::None)),
+            errors: Arc::new(RwLock::new(vec![])),
+            error_cursor: Arc::new(Mutex::new(ErrorCursor::default())),
+            lock_data: data.clone(),
+            error_recv: Arc::new(Mutex::new(error_recv)),
+            version_recv: Arc::new(Mutex::new(version_recv)),
+            rxconfig_recv: Arc::new(Mutex::new(rxconfig_recv)),
+            version_info: Arc::new(RwLock::new(None)),
+            applied_config: Arc::new(RwLock::new(vec![])),
+            bus: "/dev/mock".to_owned(),
+            baud_rate: Arc::new(RwLock::new(DEFAULT_BAUD_RATE)),
+            // Tests which change the data rate provide their own ports
+            open_port: Arc::new(|_: &str, _: u32| Err(OEMError::GenericError)),
+        }
+    }};
+    ($mock:ident) => {{
+        $mock.read.set_result(Err(UartError::IoError {
+            cause: ::std::io::ErrorKind::TimedOut,
+            description: "Mock Timeout".to_owned(),
+        }));
+
+        subsystem_new!(stream: $mock)
+    }};
+}
+
+#[macro_export]
+/// Mock a service for the OEM6.
+macro_rules! service_new {
+    (subsystem: $subsystem:expr) => {{
         let config = r#"
             [novatel-oem6-service.addr]
             ip = "127.0.0.1"
@@ -72,18 +109,18 @@ macro_rules! service_new {
 
         Service::new(
             Config::new_from_str("novatel-oem6-service", &config).unwrap(),
-            Subsystem {
-                oem,
-                last_cmd: Arc::new(RwLock::new(AckCommand::None)),
-                errors: Arc::new(RwLock::new(vec![])),
-                lock_data: data.clone(),
-                error_recv: Arc::new(Mutex::new(error_recv)),
-                version_recv: Arc::new(Mutex::new(version_recv)),
-            },
+            $subsystem,
             QueryRoot,
             MutationRoot,
         )
     }};
+    ($mock:ident) => {{
+        service_new!(subsystem: subsystem_new!($mock))
+    }};
 }
 
+mod objects;
+mod reconnect;
+mod replay;
 mod schema;
+mod shutdown;
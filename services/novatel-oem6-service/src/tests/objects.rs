@@ -0,0 +1,505 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use crate::model::LockData;
+use crate::objects::*;
+use log::{Level, Log, Metadata, Record};
+use novatel_oem6_api::{OEMError, ReceiverStatusFlags, ResponseID, TrackStatChannel, UartError};
+use std::cell::RefCell;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// Captures warnings emitted on the current thread, so that tests running
+// in parallel don't see each other's messages
+struct WarningCapture;
+
+thread_local! {
+    static WARNINGS: RefCell<Vec<String>> = RefCell::new(vec![]);
+}
+
+impl Log for WarningCapture {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= Level::Warn
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            WARNINGS.with(|warnings| warnings.borrow_mut().push(format!("{}", record.args())));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: WarningCapture = WarningCapture;
+
+fn capture_warnings<F: FnOnce()>(func: F) -> Vec<String> {
+    // Only the first call can succeed. Later calls reuse the same logger
+    let _ = log::set_logger(&LOGGER);
+    log::set_max_level(log::LevelFilter::Warn);
+
+    WARNINGS.with(|warnings| warnings.borrow_mut().clear());
+    func();
+    WARNINGS.with(|warnings| warnings.borrow().clone())
+}
+
+#[test]
+fn pos_vel_type_round_trip() {
+    let codes: [u32; 23] = [
+        0, 1, 2, 8, 16, 17, 18, 19, 20, 32, 33, 34, 48, 50, 64, 65, 68, 69, 70, 71, 72, 77, 78,
+    ];
+
+    for code in codes.iter() {
+        assert_eq!(u32::from(PosVelType::from(*code)), *code);
+    }
+}
+
+#[test]
+fn pos_vel_type_invalid() {
+    assert_eq!(u32::from(PosVelType::from(3)), POS_VEL_TYPE_INVALID);
+    assert_eq!(
+        u32::from(PosVelType::from(POS_VEL_TYPE_INVALID)),
+        POS_VEL_TYPE_INVALID
+    );
+}
+
+#[test]
+fn solution_status_round_trip() {
+    let codes: [u32; 14] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 13, 18, 19, 20];
+
+    for code in codes.iter() {
+        assert_eq!(u32::from(SolutionStatus::from(*code)), *code);
+    }
+}
+
+#[test]
+fn solution_status_invalid() {
+    assert_eq!(u32::from(SolutionStatus::from(10)), SOLUTION_STATUS_INVALID);
+}
+
+#[test]
+fn ref_time_status_round_trip() {
+    let codes: [u8; 11] = [20, 60, 80, 100, 120, 130, 140, 160, 170, 180, 200];
+
+    for code in codes.iter() {
+        assert_eq!(u8::from(RefTimeStatus::from(*code)), *code);
+    }
+}
+
+#[test]
+fn ref_time_status_invalid() {
+    assert_eq!(u8::from(RefTimeStatus::from(0)), REF_TIME_STATUS_INVALID);
+}
+
+#[test]
+fn lock_status_default() {
+    let status = LockStatus::default();
+
+    assert_eq!(status.time_status, 20);
+    assert_eq!(status.position_status, 1);
+    assert_eq!(status.position_type, 0);
+    assert_eq!(status.velocity_status, 1);
+    assert_eq!(status.velocity_type, 0);
+    assert_eq!(status.differential_age, DIFF_AGE_NOT_APPLICABLE);
+    assert_eq!(status.received_at, 0.0);
+    assert!(status.stale);
+}
+
+#[test]
+fn component_type_known() {
+    assert!(matches!(ComponentType::from(1), ComponentType::GpsCard));
+    assert!(matches!(
+        ComponentType::from(981_073_921),
+        ComponentType::DbUserApp
+    ));
+}
+
+#[test]
+fn component_type_invalid() {
+    assert!(matches!(
+        ComponentType::from(4),
+        ComponentType::KubosInvalid
+    ));
+}
+
+#[test]
+fn lock_status_serde_round_trip() {
+    let status = LockStatus {
+        time_status: 180,
+        time: OEMTime {
+            week: 3025,
+            ms: 164_195_000,
+        },
+        position_status: 0,
+        position_type: 17,
+        velocity_status: 0,
+        velocity_type: 17,
+        differential_age: 4.5,
+        rejected_measurements: 2,
+        received_at: 1_600_000_000.0,
+        stale: false,
+        device_connected: true,
+    };
+
+    let json = serde_json::to_string(&status).unwrap();
+    let result: LockStatus = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(result.time_status, 180);
+    assert_eq!(result.time.week, 3025);
+    assert_eq!(result.time.ms, 164_195_000);
+    assert_eq!(result.position_status, 0);
+    assert_eq!(result.position_type, 17);
+    assert_eq!(result.velocity_status, 0);
+    assert_eq!(result.velocity_type, 17);
+    assert_eq!(result.differential_age, 4.5);
+    assert_eq!(result.rejected_measurements, 2);
+}
+
+#[test]
+fn rejected_measurements() {
+    assert_eq!(LockStatus::rejected_measurements(10, 8), 2);
+    assert_eq!(LockStatus::rejected_measurements(8, 8), 0);
+    // Never negative, even if the log reports more satellites used than tracked
+    assert_eq!(LockStatus::rejected_measurements(0, 3), 0);
+}
+
+#[test]
+fn config_option_info_unlog_all() {
+    let info = ConfigOptionInfo::from(ConfigOption::UnlogAll);
+
+    assert_eq!(info.kind, ConfigOptionKind::Unlog);
+    assert!(!info.interval);
+    assert!(!info.offset);
+    assert!(info.hold);
+}
+
+#[test]
+fn config_option_info_event_log() {
+    let info = ConfigOptionInfo::from(ConfigOption::LogMarkTime);
+
+    assert_eq!(info.kind, ConfigOptionKind::Log);
+    assert!(!info.interval);
+    assert!(!info.offset);
+    assert!(info.hold);
+}
+
+#[test]
+fn receiver_status_serde() {
+    let status = ReceiverStatus(
+        ReceiverStatusFlags::ANTENNA_OPEN | ReceiverStatusFlags::CLOCK_MODEL_INVALID,
+    );
+
+    let json = serde_json::to_value(&status).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!(["ANTENNA_OPEN", "CLOCK_MODEL_INVALID"])
+    );
+
+    let result: ReceiverStatus = serde_json::from_value(json).unwrap();
+    assert_eq!(result.0, status.0);
+}
+
+#[test]
+fn receiver_status_serde_unknown_flag() {
+    let result: Result<ReceiverStatus, _> =
+        serde_json::from_value(serde_json::json!(["NOT_A_FLAG"]));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn geodetic_known() {
+    // 45N 45E, 1000m above the ellipsoid
+    let geo = Geodetic::from_ecef([3_194_919.145, 3_194_919.145, 4_488_055.516]);
+
+    assert!((geo.latitude - 45.0).abs() < 1e-6);
+    assert!((geo.longitude - 45.0).abs() < 1e-6);
+    assert!((geo.altitude - 1000.0).abs() < 1.0);
+}
+
+#[test]
+fn geodetic_southern_western() {
+    // 33.5S 70.25W, 500m above the ellipsoid
+    let geo = Geodetic::from_ecef([1_799_233.010, -5_011_279.006, -3_500_610.257]);
+
+    assert!((geo.latitude + 33.5).abs() < 1e-6);
+    assert!((geo.longitude + 70.25).abs() < 1e-6);
+    assert!((geo.altitude - 500.0).abs() < 1.0);
+}
+
+#[test]
+fn geodetic_zero() {
+    let geo = Geodetic::from_ecef([0.0, 0.0, 0.0]);
+
+    assert_eq!(geo.latitude, 0.0);
+    assert_eq!(geo.longitude, 0.0);
+    assert_eq!(geo.altitude, 0.0);
+}
+
+#[test]
+fn oem_time_gps_seconds() {
+    // Jan 01, 2018 00:00:00 UTC
+    let time = OEMTime {
+        week: 1982,
+        ms: 86_418_500,
+    };
+
+    assert_eq!(time.gps_seconds(), Some(1_198_800_018));
+}
+
+#[test]
+fn oem_time_gps_seconds_invalid() {
+    assert_eq!(OEMTime { week: -1, ms: 0 }.gps_seconds(), None);
+    assert_eq!(OEMTime { week: 0, ms: -1 }.gps_seconds(), None);
+    assert_eq!(OEMTime { week: 7102, ms: 0 }.gps_seconds(), None);
+}
+
+#[test]
+fn kubos_invalid_warns() {
+    let warnings = capture_warnings(|| {
+        let _ = SolutionStatus::from(99);
+        let _ = PosVelType::from(99);
+        let _ = RefTimeStatus::from(99);
+        let _ = ComponentType::from(99);
+    });
+
+    assert_eq!(
+        warnings,
+        vec![
+            "Unknown solution status value encountered: 99",
+            "Unknown position/velocity type value encountered: 99",
+            "Unknown time status value encountered: 99",
+            "Unknown component type value encountered: 99",
+        ]
+    );
+}
+
+#[test]
+fn valid_values_dont_warn() {
+    let warnings = capture_warnings(|| {
+        let _ = SolutionStatus::from(0);
+        let _ = PosVelType::from(17);
+        let _ = RefTimeStatus::from(180);
+        let _ = ComponentType::from(1);
+    });
+
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn lock_info_stale() {
+    let mut info = LockInfo {
+        received_at: 100.0,
+        stale: false,
+        ..Default::default()
+    };
+
+    info.check_stale(104.0, Duration::from_secs(5));
+    assert!(!info.stale);
+
+    info.check_stale(106.0, Duration::from_secs(5));
+    assert!(info.stale);
+}
+
+#[test]
+fn cached_telemetry_stale() {
+    let data = LockData::new(Duration::from_secs(5));
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs_f64();
+
+    data.update_clock(ClockInfo {
+        received_at: now - 10.0,
+        stale: false,
+        ..Default::default()
+    });
+    assert!(data.read(&data.clock).stale);
+
+    data.update_clock(ClockInfo {
+        received_at: now,
+        stale: false,
+        ..Default::default()
+    });
+    assert!(!data.read(&data.clock).stale);
+}
+
+#[test]
+fn cached_telemetry_stale_no_data() {
+    let data = LockData::new(Duration::from_secs(5));
+
+    assert!(data.read(&data.status).stale);
+    assert!(data.read(&data.info).stale);
+    assert!(data.read(&data.clock).stale);
+    assert!(data.read(&data.dop).stale);
+}
+
+#[test]
+fn lock_info_stale_no_solution() {
+    let mut info = LockInfo::default();
+
+    info.check_stale(1.0, Duration::from_secs(5));
+    assert!(info.stale);
+    assert_eq!(info.solution_age, SOLUTION_AGE_NO_DATA);
+}
+
+#[test]
+fn lock_info_speed_heading() {
+    // On the equator at the prime meridian, +Y is east and +Z is north
+    let info = LockInfo {
+        position: [6_378_137.0, 0.0, 0.0],
+        velocity: [0.0, 3.0, 4.0],
+        ..Default::default()
+    };
+
+    assert!((info.speed() - 5.0).abs() < 0.001);
+    assert!((info.heading().unwrap() - 36.869_9).abs() < 0.001);
+}
+
+#[test]
+fn lock_info_heading_stationary() {
+    let info = LockInfo {
+        position: [6_378_137.0, 0.0, 0.0],
+        velocity: [0.0, 0.001, 0.001],
+        ..Default::default()
+    };
+
+    assert!(info.speed() < 0.01);
+    assert_eq!(info.heading(), None);
+}
+
+#[test]
+fn lock_info_heading_vertical() {
+    // Moving straight up has a speed, but no course over ground
+    let info = LockInfo {
+        position: [6_378_137.0, 0.0, 0.0],
+        velocity: [2.0, 0.0, 0.0],
+        ..Default::default()
+    };
+
+    assert!((info.speed() - 2.0).abs() < 0.001);
+    assert_eq!(info.heading(), None);
+}
+
+#[test]
+fn service_error_command() {
+    let err = ServiceError::from(OEMError::CommandError {
+        id: ResponseID::InvalidID,
+        description: "Invalid message ID".to_owned(),
+    });
+
+    assert_eq!(err.code, 6);
+    assert_eq!(err.category, ErrorCategory::Command);
+    assert_eq!(err.message, "Command Error(InvalidID): Invalid message ID");
+}
+
+#[test]
+fn service_error_no_response() {
+    let err = ServiceError::from(OEMError::NoResponse);
+
+    assert_eq!(err.code, ERROR_CODE_NONE);
+    assert_eq!(err.category, ErrorCategory::Timeout);
+    assert_eq!(err.message, "Failed to get command response");
+}
+
+#[test]
+fn service_error_uart_timeout() {
+    let err = ServiceError::from(OEMError::UartError {
+        cause: UartError::IoError {
+            cause: ::std::io::ErrorKind::TimedOut,
+            description: "Mock Timeout".to_owned(),
+        },
+    });
+
+    assert_eq!(err.category, ErrorCategory::Timeout);
+}
+
+#[test]
+fn service_error_uart_generic() {
+    let err = ServiceError::from(OEMError::UartError {
+        cause: UartError::GenericError,
+    });
+
+    assert_eq!(err.code, ERROR_CODE_NONE);
+    assert_eq!(err.category, ErrorCategory::Uart);
+    assert_eq!(err.message, "UART Error, Generic Error");
+}
+
+fn lock_status(position_status: u32, position_type: u32) -> LockStatus {
+    LockStatus {
+        time_status: 0,
+        time: OEMTime::default(),
+        position_status,
+        position_type,
+        velocity_status: position_status,
+        velocity_type: position_type,
+        differential_age: DIFF_AGE_NOT_APPLICABLE,
+        rejected_measurements: 0,
+        received_at: 0.0,
+        stale: true,
+        device_connected: false,
+    }
+}
+
+#[test]
+fn lock_progress_acquisition() {
+    // ColdStart, Pending, InsufficientObservations, then increasingly precise solutions
+    let sequence = [(6, 0), (18, 0), (1, 0), (0, 16), (0, 17), (0, 34), (0, 50)];
+
+    let progress: Vec<i32> = sequence
+        .iter()
+        .map(|&(status, pos_type)| lock_status(status, pos_type).lock_progress())
+        .collect();
+
+    assert!(progress.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(progress.first(), Some(&10));
+    assert_eq!(progress.last(), Some(&100));
+}
+
+#[test]
+fn lock_progress_invalid() {
+    // InvalidFix
+    assert_eq!(lock_status(19, 0).lock_progress(), 0);
+    // Unauthorized
+    assert_eq!(lock_status(20, 0).lock_progress(), 0);
+}
+
+#[test]
+fn tracking_channels_capped() {
+    // Every other channel is idle. Active channels are GPS signals in phase lock
+    let channels: Vec<TrackStatChannel> = (0..200)
+        .map(|index| TrackStatChannel {
+            prn: if index % 2 == 0 { index / 2 + 1 } else { 0 },
+            tracking_status: 0x08109C04,
+            cno: 40.0,
+            ..Default::default()
+        })
+        .collect();
+
+    let result = TrackingChannel::from_channels(&channels);
+
+    assert_eq!(result.len(), MAX_TRACKING_CHANNELS);
+    assert_eq!(
+        result[1],
+        TrackingChannel {
+            channel: 2,
+            prn: 2,
+            system: Some(Constellation::Gps),
+            cno: 40.0,
+            state: Some(TrackingState::PhaseLockLoop),
+        }
+    );
+    assert_eq!(result.last().unwrap().channel, 126);
+}
@@ -0,0 +1,217 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::schema::test_data::{
+    LOG_DOP_COMMAND, LOG_RESPONSE_GOOD, LOG_TIME_COM2_COMMAND, LOG_TIME_COMMAND,
+    UNLOG_RESPONSE_GOOD, UNLOG_TIME_COMMAND,
+};
+use super::*;
+use crate::model::*;
+use crate::objects::{ConfigOption, ConfigStruct, LogFormat, OemPort};
+use novatel_oem6_api::Connection;
+use std::sync::mpsc::sync_channel;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+// LOG PSRDOPB ONTIME 0.1
+const LOG_DOP_FAST_COMMAND: [u8; 64] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0xAE, 0x0, 0x0, 0x0,
+    0x2, 0x0, 0x0, 0x0, 0x9A, 0x99, 0x99, 0x99, 0x99, 0x99, 0xB9, 0x3F, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x80, 0x35, 0xDF, 0xDB,
+];
+
+fn log_request(option: ConfigOption, interval: f64, hold: bool) -> ConfigStruct {
+    ConfigStruct {
+        option,
+        hold,
+        interval,
+        offset: 0.0,
+        format: LogFormat::Binary,
+        verify: false,
+    }
+}
+
+// Mock the port which is opened once the link comes back. It acknowledges one LOG
+// command for each of the expected commands
+fn port_mock(commands: &[&[u8]]) -> MockStream {
+    let mut mock = MockStream::default();
+
+    let mut output = vec![];
+    for command in commands {
+        mock.write.set_input(command.to_vec());
+        output.extend_from_slice(&LOG_RESPONSE_GOOD);
+    }
+    mock.read.set_output(output);
+
+    mock.read.set_result(Err(UartError::IoError {
+        cause: ::std::io::ErrorKind::TimedOut,
+        description: "Mock Timeout".to_owned(),
+    }));
+
+    mock
+}
+
+// Hand out the given ports in order, recording the data rate each one was opened at.
+// Once they run out, the device is treated as unavailable
+fn port_opener(ports: Vec<MockStream>, opened: Arc<Mutex<Vec<u32>>>) -> PortOpener {
+    let ports = Mutex::new(ports);
+
+    Arc::new(move |_: &str, baud: u32| {
+        opened.lock().unwrap().push(baud);
+        let mut ports = ports.lock().unwrap();
+        if ports.is_empty() {
+            return Err(OEMError::GenericError);
+        }
+        Ok(Connection {
+            stream: Box::new(ports.remove(0)),
+        })
+    })
+}
+
+#[test]
+fn reconnect_reapplies_logs() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(LOG_DOP_COMMAND.to_vec());
+    mock.write.set_input(LOG_TIME_COMMAND.to_vec());
+
+    let mut output = LOG_RESPONSE_GOOD.to_vec();
+    output.extend_from_slice(&LOG_RESPONSE_GOOD);
+    mock.read.set_output(output);
+
+    let new_port = port_mock(&[&LOG_DOP_COMMAND, &LOG_TIME_COMMAND]);
+    let new_tracker = new_port.tracker();
+    let opened = Arc::new(Mutex::new(vec![]));
+
+    let mut subsystem = subsystem_new!(mock);
+    subsystem.open_port = port_opener(vec![new_port], opened.clone());
+
+    let response = subsystem
+        .configure_hardware(vec![
+            log_request(ConfigOption::LogDopData, 1.0, false),
+            log_request(ConfigOption::LogTimeData, 1.0, true),
+        ])
+        .unwrap();
+    assert!(response.success);
+    let applied = subsystem.get_applied_configuration().unwrap();
+
+    // The link drops. Both logs are requested again on the reopened port
+    assert_eq!(subsystem.reconnect(), Ok(()));
+
+    new_tracker.assert_consumed();
+    assert_eq!(*opened.lock().unwrap(), vec![9600]);
+    assert_eq!(subsystem.get_applied_configuration().unwrap(), applied);
+}
+
+#[test]
+fn reconnect_keeps_log_port() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(LOG_TIME_COMMAND.to_vec());
+    mock.write.set_input(UNLOG_TIME_COMMAND.to_vec());
+    mock.write.set_input(LOG_TIME_COM2_COMMAND.to_vec());
+
+    let mut output = LOG_RESPONSE_GOOD.to_vec();
+    output.extend_from_slice(&UNLOG_RESPONSE_GOOD);
+    output.extend_from_slice(&LOG_RESPONSE_GOOD);
+    mock.read.set_output(output);
+
+    let new_port = port_mock(&[&LOG_TIME_COM2_COMMAND]);
+    let new_tracker = new_port.tracker();
+    let opened = Arc::new(Mutex::new(vec![]));
+
+    let mut subsystem = subsystem_new!(mock);
+    subsystem.open_port = port_opener(vec![new_port], opened.clone());
+
+    let response = subsystem
+        .configure_hardware(vec![log_request(ConfigOption::LogTimeData, 1.0, true)])
+        .unwrap();
+    assert!(response.success);
+    let response = subsystem
+        .set_log_port(ConfigOption::LogTimeData, OemPort::Com2)
+        .unwrap();
+    assert!(response.success);
+    let applied = subsystem.get_applied_configuration().unwrap();
+
+    // The link drops. The log is only requested again on the port it was moved to
+    assert_eq!(subsystem.reconnect(), Ok(()));
+
+    new_tracker.assert_consumed();
+    assert_eq!(subsystem.get_applied_configuration().unwrap(), applied);
+    assert_eq!(applied[0].port, OemPort::Com2);
+}
+
+#[test]
+fn reconnect_port_unavailable() {
+    let mut mock = MockStream::default();
+
+    let opened = Arc::new(Mutex::new(vec![]));
+
+    let mut subsystem = subsystem_new!(mock);
+    subsystem.open_port = port_opener(vec![], opened.clone());
+
+    assert!(subsystem.reconnect().is_err());
+    assert_eq!(*opened.lock().unwrap(), vec![9600]);
+    assert_eq!(subsystem.take_errors().len(), 1);
+}
+
+#[test]
+fn monitor_link_reconnects_after_silence() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(LOG_DOP_FAST_COMMAND.to_vec());
+    mock.read.set_output(LOG_RESPONSE_GOOD.to_vec());
+
+    let new_port = port_mock(&[&LOG_DOP_FAST_COMMAND]);
+    let new_tracker = new_port.tracker();
+    let opened = Arc::new(Mutex::new(vec![]));
+
+    let mut subsystem = subsystem_new!(mock);
+    subsystem.open_port = port_opener(vec![new_port], opened.clone());
+
+    let response = subsystem
+        .configure_hardware(vec![log_request(ConfigOption::LogDopData, 0.1, false)])
+        .unwrap();
+    assert!(response.success);
+
+    // No DOP logs ever arrive, so the link is considered lost after two intervals
+    let monitor = subsystem.clone();
+    thread::spawn(move || monitor.monitor_link(Duration::from_millis(100)));
+    thread::sleep(Duration::from_millis(1000));
+
+    new_tracker.assert_consumed();
+    assert_eq!(opened.lock().unwrap()[0], 9600);
+    assert!(!subsystem.lock_data.is_connected());
+}
+
+#[test]
+fn monitor_link_idle_without_logs() {
+    let mut mock = MockStream::default();
+
+    let opened = Arc::new(Mutex::new(vec![]));
+
+    let mut subsystem = subsystem_new!(mock);
+    subsystem.open_port = port_opener(vec![], opened.clone());
+
+    // Nothing is expected from the receiver, so the silence isn't a lost link
+    let monitor = subsystem.clone();
+    thread::spawn(move || monitor.monitor_link(Duration::from_millis(50)));
+    thread::sleep(Duration::from_millis(300));
+
+    assert!(opened.lock().unwrap().is_empty());
+}
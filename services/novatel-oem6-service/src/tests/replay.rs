@@ -0,0 +1,80 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::schema::test_data::{POSITION_LOG_GOOD, POSITION_LOG_NO_LOCK};
+use super::*;
+use crate::model::*;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+// Wait for the replayed logs to make it through the read and log threads
+fn wait_for<F: Fn() -> bool>(done: F) {
+    let start = Instant::now();
+    while !done() {
+        assert!(
+            start.elapsed() < Duration::from_secs(2),
+            "Replayed logs were not processed"
+        );
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+#[test]
+fn replay_updates_lock_caches() {
+    // A good fix, followed by losing lock
+    let mut capture = POSITION_LOG_GOOD.to_vec();
+    capture.extend_from_slice(&POSITION_LOG_NO_LOCK);
+
+    let subsystem = Subsystem::replay(
+        ReplaySource::new(capture),
+        Arc::new(LockData::new(DEFAULT_TELEMETRY_MAX_AGE)),
+    );
+
+    wait_for(|| {
+        let status = subsystem.get_lock_status().unwrap();
+        let info = subsystem.get_lock_info().unwrap();
+        info.received_at != 0.0 && status.position_status == 1
+    });
+
+    let status = subsystem.get_lock_status().unwrap();
+    assert!(status.device_connected);
+    assert_eq!(status.time.week, 3025);
+    assert_eq!(status.time.ms, 164_195_000);
+    assert_eq!(status.position_type, 0);
+
+    // The last known good position is kept after the lock is lost
+    let info = subsystem.get_lock_info().unwrap();
+    assert_eq!(info.position, [1.1, 2.2, 3.3]);
+    assert_eq!(info.velocity, [4.4, 5.5, 6.6]);
+}
+
+#[test]
+fn replay_from_file() {
+    let path = ::std::env::temp_dir().join("novatel-oem6-service-replay-test.bin");
+    ::std::fs::write(&path, &POSITION_LOG_GOOD[..]).unwrap();
+
+    let source = ReplaySource::from_file(&path).unwrap();
+    ::std::fs::remove_file(&path).unwrap();
+
+    let subsystem = Subsystem::replay(source, Arc::new(LockData::new(DEFAULT_TELEMETRY_MAX_AGE)));
+
+    wait_for(|| subsystem.get_lock_info().unwrap().received_at != 0.0);
+
+    let status = subsystem.get_lock_status().unwrap();
+    assert_eq!(status.position_status, 0);
+    assert_eq!(status.position_type, 17);
+}
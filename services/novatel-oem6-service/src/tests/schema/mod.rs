@@ -52,7 +52,7 @@ macro_rules! test {
 
 mod mutations;
 mod queries;
-mod test_data;
+pub(super) mod test_data;
 
 #[test]
 fn ping() {
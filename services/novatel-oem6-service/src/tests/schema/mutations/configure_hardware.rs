@@ -16,6 +16,49 @@
 
 use super::*;
 use serde_json::json;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+// Simulated receiver which only sends its reply once a command has been written,
+// so that logs can be made to arrive after a configuration request
+struct ReplyStream {
+    mock: MockStream,
+    pending: Mutex<VecDeque<u8>>,
+    reply: Vec<u8>,
+}
+
+impl ReplyStream {
+    fn new(mock: MockStream, initial: &[u8], reply: &[u8]) -> Self {
+        ReplyStream {
+            mock,
+            pending: Mutex::new(initial.iter().cloned().collect()),
+            reply: reply.to_vec(),
+        }
+    }
+}
+
+impl Stream for ReplyStream {
+    fn write(&self, data: &[u8]) -> UartResult<()> {
+        self.mock.write(data)?;
+        self.pending.lock().unwrap().extend(self.reply.iter());
+        Ok(())
+    }
+
+    fn read(&self, len: usize, _timeout: Duration) -> UartResult<Vec<u8>> {
+        let mut pending = self.pending.lock().unwrap();
+        if pending.len() < len {
+            drop(pending);
+            thread::sleep(Duration::from_millis(10));
+            return Err(UartError::IoError {
+                cause: ::std::io::ErrorKind::TimedOut,
+                description: "Mock Timeout".to_owned(),
+            });
+        }
+        Ok(pending.drain(..len).collect())
+    }
+}
 
 #[test]
 fn configure_hardware_bad_single() {
@@ -42,6 +85,35 @@ fn configure_hardware_bad_single() {
     test!(service, query, expected);
 }
 
+#[test]
+fn configure_hardware_bad_error_details() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            configureHardware(config: [{option: LOG_ERROR_DATA}]) {
+                errorDetails {
+                    code,
+                    category,
+                    message
+                }
+            }
+        }"#;
+
+    let expected = json!({
+            "configureHardware": {
+                "errorDetails": [{
+                    "code": -1,
+                    "category": "UART",
+                    "message": "LogErrorData: UART Error, Generic Error"
+                }]
+            }
+    });
+
+    test!(service, query, expected);
+}
+
 #[test]
 fn configure_hardware_bad_multi() {
     let mut mock = MockStream::default();
@@ -230,6 +302,48 @@ fn configure_hardware_log_errors_no_defaults() {
     test!(service, query, expected);
 }
 
+#[test]
+fn configure_hardware_log_mark_time() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0xE7, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x95, 0x23, 0xDF, 0xE1,
+    ]);
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0x68, 0x2, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x2C, 0x80, 0x9F, 0xEF,
+    ]);
+
+    let mut output = LOG_RESPONSE_GOOD.to_vec();
+    output.extend_from_slice(&LOG_RESPONSE_GOOD);
+    mock.read.set_output(output);
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            configureHardware(config: [{option: LOG_MARK_TIME}]) {
+                config,
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "configureHardware": {
+                "config": "LogMarkTime(Hold: false)",
+                "errors": "",
+                "success": true
+            }
+    });
+
+    test!(service, query, expected);
+}
+
 #[test]
 fn configure_hardware_log_position_default() {
     let mut mock = MockStream::default();
@@ -299,21 +413,22 @@ fn configure_hardware_log_position_no_defaults() {
 }
 
 #[test]
-fn configure_hardware_unlog_all_no_hold() {
+fn configure_hardware_log_position_binary() {
     let mut mock = MockStream::default();
 
     mock.write.set_input(vec![
-        0xAA, 0x44, 0x12, 0x1C, 0x26, 0x0, 0x0, 0xC0, 0x8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
-        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
-        0x0, 0xD5, 0xEA, 0xAF, 0x8D,
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0xF1, 0x0, 0x0,
+        0x0, 0x2, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xF0, 0x3F, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xB9, 0x54, 0x12, 0x6F,
     ]);
 
-    mock.read.set_output(UNLOG_ALL_RESPONSE_GOOD.to_vec());
+    mock.read.set_output(LOG_RESPONSE_GOOD.to_vec());
 
     let service = service_new!(mock);
 
     let query = r#"mutation {
-            configureHardware(config: [{option: UNLOG_ALL, hold: false}]) {
+            configureHardware(config: [{option: LOG_POSITION_DATA, interval: 1.0, format: BINARY}]) {
                 config,
                 errors,
                 success
@@ -322,7 +437,7 @@ fn configure_hardware_unlog_all_no_hold() {
 
     let expected = json!({
             "configureHardware": {
-                "config": "UnlogAll(Hold: false)",
+                "config": "LogPositionData(Hold: false): 1+0sec",
                 "errors": "",
                 "success": true
             }
@@ -332,21 +447,22 @@ fn configure_hardware_unlog_all_no_hold() {
 }
 
 #[test]
-fn configure_hardware_unlog_all_hold() {
+fn configure_hardware_log_position_ascii() {
     let mut mock = MockStream::default();
 
     mock.write.set_input(vec![
-        0xAA, 0x44, 0x12, 0x1C, 0x26, 0x0, 0x0, 0xC0, 0x8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
-        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0x1, 0x0, 0x0,
-        0x0, 0xB0, 0x8D, 0x13, 0x35,
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0xF1, 0x0,
+        0x20, 0x0, 0x2, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xF0, 0x3F, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x8, 0xE4, 0xC7, 0xE0,
     ]);
 
-    mock.read.set_output(UNLOG_ALL_RESPONSE_GOOD.to_vec());
+    mock.read.set_output(LOG_RESPONSE_GOOD.to_vec());
 
     let service = service_new!(mock);
 
     let query = r#"mutation {
-            configureHardware(config: [{option: UNLOG_ALL, hold: true}]) {
+            configureHardware(config: [{option: LOG_POSITION_DATA, interval: 1.0, format: ASCII}]) {
                 config,
                 errors,
                 success
@@ -355,7 +471,7 @@ fn configure_hardware_unlog_all_hold() {
 
     let expected = json!({
             "configureHardware": {
-                "config": "UnlogAll(Hold: true)",
+                "config": "LogPositionData(Hold: false): 1+0sec (ASCII)",
                 "errors": "",
                 "success": true
             }
@@ -365,21 +481,22 @@ fn configure_hardware_unlog_all_hold() {
 }
 
 #[test]
-fn configure_hardware_unlog_errors() {
+fn configure_hardware_log_time_ascii() {
     let mut mock = MockStream::default();
 
     mock.write.set_input(vec![
-        0xAA, 0x44, 0x12, 0x1C, 0x24, 0x0, 0x0, 0xC0, 0x8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
-        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0x5E, 0x0, 0x0,
-        0x0, 0x51, 0x9F, 0xB8, 0x9E,
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0x65, 0x0,
+        0x20, 0x0, 0x4, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0xA4, 0x1E, 0x25, 0x66,
     ]);
 
-    mock.read.set_output(UNLOG_RESPONSE_GOOD.to_vec());
+    mock.read.set_output(LOG_RESPONSE_GOOD.to_vec());
 
     let service = service_new!(mock);
 
     let query = r#"mutation {
-            configureHardware(config: [{option: UNLOG_ERROR_DATA}]) {
+            configureHardware(config: [{option: LOG_TIME_DATA, hold: true, format: ASCII}]) {
                 config,
                 errors,
                 success
@@ -388,7 +505,7 @@ fn configure_hardware_unlog_errors() {
 
     let expected = json!({
             "configureHardware": {
-                "config": "UnlogErrorData(Hold: false)",
+                "config": "LogTimeData(Hold: true) (ASCII)",
                 "errors": "",
                 "success": true
             }
@@ -398,21 +515,22 @@ fn configure_hardware_unlog_errors() {
 }
 
 #[test]
-fn configure_hardware_unlog_position() {
+fn configure_hardware_log_velocity_no_defaults() {
     let mut mock = MockStream::default();
 
     mock.write.set_input(vec![
-        0xAA, 0x44, 0x12, 0x1C, 0x24, 0x0, 0x0, 0xC0, 0x8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
-        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0xF1, 0x0, 0x0,
-        0x0, 0x2, 0x96, 0xB0, 0x8B,
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0x63, 0x0, 0x0,
+        0x0, 0x2, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xF0, 0x3F, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0xE0, 0x3F, 0x1, 0x0, 0x0, 0x0, 0x63, 0xA2, 0x51, 0x20,
     ]);
 
-    mock.read.set_output(UNLOG_RESPONSE_GOOD.to_vec());
+    mock.read.set_output(LOG_RESPONSE_GOOD.to_vec());
 
     let service = service_new!(mock);
 
     let query = r#"mutation {
-            configureHardware(config: [{option: UNLOG_POSITION_DATA}]) {
+            configureHardware(config: [{option: LOG_VELOCITY_DATA, hold: true, interval: 1.0, offset: 0.5}]) {
                 config,
                 errors,
                 success
@@ -421,7 +539,595 @@ fn configure_hardware_unlog_position() {
 
     let expected = json!({
             "configureHardware": {
-                "config": "UnlogPositionData(Hold: false)",
+                "config": "LogVelocityData(Hold: true): 1+0.5sec",
+                "errors": "",
+                "success": true
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn configure_hardware_log_dop() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0xAE, 0x0, 0x0,
+        0x0, 0x2, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xF0, 0x3F, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x92, 0xD9, 0x94, 0x10,
+    ]);
+
+    mock.read.set_output(LOG_RESPONSE_GOOD.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            configureHardware(config: [{option: LOG_DOP_DATA, interval: 1.0}]) {
+                config,
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "configureHardware": {
+                "config": "LogDopData(Hold: false): 1+0sec",
+                "errors": "",
+                "success": true
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn configure_hardware_log_hw_monitor() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0xC3, 0x3, 0x0,
+        0x0, 0x2, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xF0, 0x3F, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x30, 0xF1, 0xD, 0xF2,
+    ]);
+
+    mock.read.set_output(LOG_RESPONSE_GOOD.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            configureHardware(config: [{option: LOG_HW_MONITOR, interval: 1.0}]) {
+                config,
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "configureHardware": {
+                "config": "LogHwMonitor(Hold: false): 1+0sec",
+                "errors": "",
+                "success": true
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn configure_hardware_log_range() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0x2B, 0x0, 0x0,
+        0x0, 0x2, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xF0, 0x3F, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x7B, 0x5E, 0x8, 0xCD,
+    ]);
+
+    mock.read.set_output(LOG_RESPONSE_GOOD.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            configureHardware(config: [{option: LOG_RANGE_DATA, interval: 1.0}]) {
+                config,
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "configureHardware": {
+                "config": "LogRangeData(Hold: false): 1+0sec",
+                "errors": "",
+                "success": true
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn configure_hardware_verify_confirmed() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0x2B, 0x0, 0x0,
+        0x0, 0x2, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xF0, 0x3F, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x7B, 0x5E, 0x8, 0xCD,
+    ]);
+
+    let reply = [&LOG_RESPONSE_GOOD[..], &RANGE_LOG[..]].concat();
+    let service =
+        service_new!(subsystem: subsystem_new!(stream: ReplyStream::new(mock, &[], &reply)));
+
+    let query = r#"mutation {
+            configureHardware(config: [{option: LOG_RANGE_DATA, interval: 1.0, verify: true}]) {
+                config,
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "configureHardware": {
+                "config": "LogRangeData(Hold: false): 1+0sec",
+                "errors": "",
+                "success": true
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn configure_hardware_verify_timeout() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0x2B, 0x0, 0x0,
+        0x0, 0x2, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xF0, 0x3F, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x7B, 0x5E, 0x8, 0xCD,
+    ]);
+
+    // The receiver acknowledges the request but never outputs the log.
+    // The RANGE log which arrived before the request doesn't count
+    let service = service_new!(
+        subsystem: subsystem_new!(stream: ReplyStream::new(mock, &RANGE_LOG, &LOG_RESPONSE_GOOD))
+    );
+
+    let query = r#"mutation {
+            configureHardware(config: [{option: LOG_RANGE_DATA, interval: 1.0, verify: true}]) {
+                errorDetails {
+                    code,
+                    category,
+                    message
+                },
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "configureHardware": {
+                "errorDetails": [{
+                    "code": -1,
+                    "category": "TIMEOUT",
+                    "message": "LogRangeData: No Range log received within 2sec"
+                }],
+                "errors": "LogRangeData: No Range log received within 2sec",
+                "success": false
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn configure_hardware_verify_unsupported() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            configureHardware(config: [{option: LOG_ERROR_DATA, verify: true}]) {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "configureHardware": {
+                "errors": "LogErrorData: Log verification is not supported for this option",
+                "success": false
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn configure_hardware_log_tracking() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0x53, 0x0, 0x0,
+        0x0, 0x2, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xF0, 0x3F, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xA3, 0x83, 0x38, 0x80,
+    ]);
+
+    mock.read.set_output(LOG_RESPONSE_GOOD.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            configureHardware(config: [{option: LOG_TRACKING_DATA, interval: 1.0}]) {
+                config,
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "configureHardware": {
+                "config": "LogTrackingData(Hold: false): 1+0sec",
+                "errors": "",
+                "success": true
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn configure_hardware_log_time() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0x65, 0x0, 0x0,
+        0x0, 0x2, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xF0, 0x3F, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x9D, 0xFF, 0x64, 0xA8,
+    ]);
+
+    mock.read.set_output(LOG_RESPONSE_GOOD.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            configureHardware(config: [{option: LOG_TIME_DATA, interval: 1.0}]) {
+                config,
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "configureHardware": {
+                "config": "LogTimeData(Hold: false): 1+0sec",
+                "errors": "",
+                "success": true
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn configure_hardware_unlog_all_no_hold() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x26, 0x0, 0x0, 0xC0, 0x8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0xD5, 0xEA, 0xAF, 0x8D,
+    ]);
+
+    mock.read.set_output(UNLOG_ALL_RESPONSE_GOOD.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            configureHardware(config: [{option: UNLOG_ALL, hold: false}]) {
+                config,
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "configureHardware": {
+                "config": "UnlogAll(Hold: false)",
+                "errors": "",
+                "success": true
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn configure_hardware_unlog_all_hold() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x26, 0x0, 0x0, 0xC0, 0x8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0x1, 0x0, 0x0,
+        0x0, 0xB0, 0x8D, 0x13, 0x35,
+    ]);
+
+    mock.read.set_output(UNLOG_ALL_RESPONSE_GOOD.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            configureHardware(config: [{option: UNLOG_ALL, hold: true}]) {
+                config,
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "configureHardware": {
+                "config": "UnlogAll(Hold: true)",
+                "errors": "",
+                "success": true
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn configure_hardware_unlog_errors() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x24, 0x0, 0x0, 0xC0, 0x8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0x5E, 0x0, 0x0,
+        0x0, 0x51, 0x9F, 0xB8, 0x9E,
+    ]);
+
+    mock.read.set_output(UNLOG_RESPONSE_GOOD.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            configureHardware(config: [{option: UNLOG_ERROR_DATA}]) {
+                config,
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "configureHardware": {
+                "config": "UnlogErrorData(Hold: false)",
+                "errors": "",
+                "success": true
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn configure_hardware_unlog_mark_time() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x24, 0x0, 0x0, 0xC0, 0x8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0xE7, 0x0, 0x0,
+        0x0, 0x41, 0x9E, 0xC2, 0xFE,
+    ]);
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x24, 0x0, 0x0, 0xC0, 0x8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0x68, 0x2, 0x0,
+        0x0, 0x42, 0xEC, 0x7C, 0x48,
+    ]);
+
+    let mut output = UNLOG_RESPONSE_GOOD.to_vec();
+    output.extend_from_slice(&UNLOG_RESPONSE_GOOD);
+    mock.read.set_output(output);
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            configureHardware(config: [{option: UNLOG_MARK_TIME}]) {
+                config,
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "configureHardware": {
+                "config": "UnlogMarkTime(Hold: false)",
+                "errors": "",
+                "success": true
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn configure_hardware_unlog_position() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x24, 0x0, 0x0, 0xC0, 0x8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0xF1, 0x0, 0x0,
+        0x0, 0x2, 0x96, 0xB0, 0x8B,
+    ]);
+
+    mock.read.set_output(UNLOG_RESPONSE_GOOD.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            configureHardware(config: [{option: UNLOG_POSITION_DATA}]) {
+                config,
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "configureHardware": {
+                "config": "UnlogPositionData(Hold: false)",
+                "errors": "",
+                "success": true
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn configure_hardware_unlog_velocity() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(vec![
+        0xAA, 0x44, 0x12, 0x1C, 0x24, 0x0, 0x0, 0xC0, 0x8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0x63, 0x0, 0x0,
+        0x0, 0x2D, 0xBF, 0xF9, 0x9C,
+    ]);
+
+    mock.read.set_output(UNLOG_RESPONSE_GOOD.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            configureHardware(config: [{option: UNLOG_VELOCITY_DATA}]) {
+                config,
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "configureHardware": {
+                "config": "UnlogVelocityData(Hold: false)",
+                "errors": "",
+                "success": true
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn configure_hardware_bad_interval() {
+    let mut mock = MockStream::default();
+
+    // If the request made it to the device, the write would succeed and the
+    // error would be about the missing response instead
+    mock.write.set_result(Ok(()));
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            configureHardware(config: [{option: LOG_POSITION_DATA, interval: 5000.0}]) {
+                config,
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "configureHardware": {
+                "config": "LogPositionData(Hold: false): 5000+0sec",
+                "errors": "LogPositionData: Interval 5000 outside of supported range (0.05-3600sec)",
+                "success": false
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn configure_hardware_bad_interval_negative() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_result(Ok(()));
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            configureHardware(config: [{option: LOG_VELOCITY_DATA, interval: -1.0}]) {
+                config,
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "configureHardware": {
+                "config": "LogVelocityData(Hold: false): -1+0sec",
+                "errors": "LogVelocityData: Interval -1 outside of supported range (0.05-3600sec)",
+                "success": false
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn configure_hardware_bad_offset() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_result(Ok(()));
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            configureHardware(config: [{option: LOG_POSITION_DATA, interval: 1.0, offset: -0.5}]) {
+                config,
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "configureHardware": {
+                "config": "LogPositionData(Hold: false): 1+-0.5sec",
+                "errors": "LogPositionData: Offset -0.5 must be non-negative",
+                "success": false
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn configure_hardware_save_config() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(SAVE_CONFIG_COMMAND.to_vec());
+
+    mock.read.set_output(SAVE_CONFIG_RESPONSE_GOOD.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            configureHardware(config: [{option: SAVE_CONFIG}]) {
+                config,
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "configureHardware": {
+                "config": "SaveConfig(Hold: false)",
                 "errors": "",
                 "success": true
             }
@@ -64,3 +64,113 @@ fn issue_raw_command_bad() {
 
     test!(service, query, expected);
 }
+
+#[test]
+fn issue_raw_command_bad_hex() {
+    let mut mock = MockStream::default();
+
+    // If the command made it to the device, this write would succeed
+    mock.write.set_result(Ok(()));
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            issueRawCommand(command: \"0102zz04\"){
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "issueRawCommand": {
+                "errors": "Invalid hex value: zz",
+                "success": false
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn issue_raw_command_bad_hex_error_details() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_result(Ok(()));
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            issueRawCommand(command: \"0102zz04\"){
+                errorDetails {
+                    code,
+                    category,
+                    message
+                }
+            }
+        }"#;
+
+    let expected = json!({
+            "issueRawCommand": {
+                "errorDetails": [{
+                    "code": -1,
+                    "category": "INVALID_INPUT",
+                    "message": "Invalid hex value: zz"
+                }]
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn issue_raw_command_odd_length() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_result(Ok(()));
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            issueRawCommand(command: \"01020\"){
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "issueRawCommand": {
+                "errors": "Command must contain an even number of hex characters",
+                "success": false
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn issue_raw_command_too_long() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_result(Ok(()));
+
+    let service = service_new!(mock);
+
+    let query = format!(
+        r#"mutation {{
+            issueRawCommand(command: \"{}\"){{
+                errors,
+                success
+            }}
+        }}"#,
+        "AB".repeat(1025)
+    );
+
+    let expected = json!({
+            "issueRawCommand": {
+                "errors": "Command exceeds maximum length of 1024 bytes",
+                "success": false
+            }
+    });
+
+    test!(service, query, expected);
+}
@@ -21,4 +21,13 @@ mod control_power;
 mod errors;
 mod issue_raw_command;
 mod noop;
+mod refresh_version;
+mod reset;
+mod save_configuration;
+mod set_approximate_position;
+mod set_approximate_time;
+mod set_baud;
+mod set_constellations;
+mod set_elevation_mask;
+mod set_log_port;
 mod test_hardware;
@@ -0,0 +1,116 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+use serde_json::json;
+
+#[test]
+fn refresh_version_good() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(LOG_VERSION_COMMAND.to_vec());
+
+    let mut output = LOG_RESPONSE_GOOD.to_vec();
+    output.extend_from_slice(&VERSION_LOG);
+    mock.read.set_output(output);
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            refreshVersion {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "refreshVersion": {
+                "errors": "",
+                "success": true
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn refresh_version_no_log() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(LOG_VERSION_COMMAND.to_vec());
+
+    mock.read.set_output(LOG_RESPONSE_GOOD.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            refreshVersion {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "refreshVersion": {
+                "errors": "Failed to receive version info - timed out waiting on channel",
+                "success": false
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn refresh_version_updates_cache() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(LOG_VERSION_COMMAND.to_vec());
+    mock.write.set_input(LOG_VERSION_COMMAND.to_vec());
+
+    let mut output = LOG_RESPONSE_GOOD.to_vec();
+    output.extend_from_slice(&VERSION_LOG);
+    output.extend_from_slice(&LOG_RESPONSE_GOOD);
+    mock.read.set_output(output);
+
+    let service = service_new!(mock);
+
+    let telemetry = r#"{
+            telemetry {
+                debug {
+                    numComponents
+                }
+            }
+        }"#;
+
+    request!(service, telemetry);
+
+    let refresh = r#"mutation {
+            refreshVersion {
+                success
+            }
+        }"#;
+
+    request!(service, refresh);
+
+    // The refresh failed, so the old cached copy should no longer be reported
+    let expected = json!({
+            "telemetry": {
+                "debug": null
+            }
+    });
+
+    test!(service, telemetry, expected);
+}
@@ -0,0 +1,263 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+use serde_json::json;
+
+#[test]
+fn reset_hot() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(RESET_COMMAND.to_vec());
+
+    mock.read.set_output(RESET_RESPONSE_GOOD.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            reset(resetType: HOT) {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "reset": {
+                "errors": "",
+                "success": true
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn reset_warm() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(FRESET_EPHEMERIS_COMMAND.to_vec());
+
+    mock.read.set_output(FRESET_RESPONSE_GOOD.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            reset(resetType: WARM) {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "reset": {
+                "errors": "",
+                "success": true
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn reset_cold() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(FRESET_EPHEMERIS_COMMAND.to_vec());
+    mock.write.set_input(FRESET_ALMANAC_COMMAND.to_vec());
+
+    let mut output = FRESET_RESPONSE_GOOD.to_vec();
+    output.extend_from_slice(&FRESET_RESPONSE_GOOD);
+    mock.read.set_output(output);
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            reset(resetType: COLD) {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "reset": {
+                "errors": "",
+                "success": true
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn reset_factory() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(FRESET_STANDARD_COMMAND.to_vec());
+
+    mock.read.set_output(FRESET_RESPONSE_GOOD.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            reset(resetType: FACTORY) {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "reset": {
+                "errors": "",
+                "success": true
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn reset_factory_clears_applied_configuration() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(LOG_TIME_COMMAND.to_vec());
+    mock.write.set_input(FRESET_STANDARD_COMMAND.to_vec());
+
+    let mut output = LOG_RESPONSE_GOOD.to_vec();
+    output.extend_from_slice(&FRESET_RESPONSE_GOOD);
+    mock.read.set_output(output);
+
+    let service = service_new!(mock);
+
+    let configure = r#"mutation {
+            configureHardware(config: [{option: LOG_TIME_DATA, interval: 1.0, hold: true}]) {
+                success
+            }
+        }"#;
+    request!(service, configure);
+
+    let reset = r#"mutation {
+            reset(resetType: FACTORY) {
+                success
+            }
+        }"#;
+    request!(service, reset);
+
+    let query = r#"{
+            appliedConfiguration {
+                option
+            }
+        }"#;
+
+    let expected = json!({
+            "appliedConfiguration": []
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn reset_no_response() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(RESET_COMMAND.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            reset(resetType: HOT) {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "reset": {
+                "errors": "Failed to get command response",
+                "success": false
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn reset_requires_type() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            reset {
+                success
+            }
+        }"#;
+
+    // The reset type must always be explicitly given
+    let result = request!(service, query);
+    let body: serde_json::Value = serde_json::from_slice(result.body()).unwrap();
+
+    assert!(body.get("errors").is_some());
+}
+
+#[test]
+fn reset_clears_version_cache() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(LOG_VERSION_COMMAND.to_vec());
+    mock.write.set_input(RESET_COMMAND.to_vec());
+
+    let mut output = LOG_RESPONSE_GOOD.to_vec();
+    output.extend_from_slice(&VERSION_LOG);
+    output.extend_from_slice(&RESET_RESPONSE_GOOD);
+    mock.read.set_output(output);
+
+    let service = service_new!(mock);
+
+    let telemetry = r#"{
+            telemetry {
+                debug {
+                    numComponents
+                }
+            }
+        }"#;
+
+    let expected = json!({
+            "telemetry": {
+                "debug": {
+                    "numComponents": 1
+                }
+            }
+    });
+
+    test!(service, telemetry, expected);
+
+    let reset = r#"mutation {
+            reset(resetType: HOT) {
+                success
+            }
+        }"#;
+
+    request!(service, reset);
+
+    // The version information must be re-read from the device, which no longer responds
+    let expected = json!({
+            "telemetry": {
+                "debug": null
+            }
+    });
+
+    test!(service, telemetry, expected);
+}
@@ -0,0 +1,68 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+use serde_json::json;
+
+#[test]
+fn save_configuration_good() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(SAVE_CONFIG_COMMAND.to_vec());
+
+    mock.read.set_output(SAVE_CONFIG_RESPONSE_GOOD.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            saveConfiguration {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "saveConfiguration": {
+                "errors": "",
+                "success": true
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn save_configuration_fail() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            saveConfiguration {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "saveConfiguration": {
+                "errors": "UART Error, Generic Error",
+                "success": false
+            }
+    });
+
+    test!(service, query, expected);
+}
@@ -0,0 +1,114 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+use serde_json::json;
+
+#[test]
+fn set_approximate_position_good() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(SETAPPROXPOS_COMMAND.to_vec());
+
+    mock.read.set_output(SETAPPROXPOS_RESPONSE_GOOD.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            setApproximatePosition(lat: 51.116, lon: -114.038, height: 1048.0) {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "setApproximatePosition": {
+                "errors": "",
+                "success": true
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn set_approximate_position_bad_latitude() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            setApproximatePosition(lat: 90.5, lon: -114.038, height: 1048.0) {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "setApproximatePosition": {
+                "errors": "Latitude must be between -90 and 90 degrees, not 90.5",
+                "success": false
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn set_approximate_position_bad_longitude() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            setApproximatePosition(lat: 51.116, lon: -180.25, height: 1048.0) {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "setApproximatePosition": {
+                "errors": "Longitude must be between -180 and 180 degrees, not -180.25",
+                "success": false
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn set_approximate_position_fail() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            setApproximatePosition(lat: 51.116, lon: -114.038, height: 1048.0) {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "setApproximatePosition": {
+                "errors": "UART Error, Generic Error",
+                "success": false
+            }
+    });
+
+    test!(service, query, expected);
+}
@@ -0,0 +1,114 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+use serde_json::json;
+
+#[test]
+fn set_approximate_time_good() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(SETAPPROXTIME_COMMAND.to_vec());
+
+    mock.read.set_output(SETAPPROXTIME_RESPONSE_GOOD.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            setApproximateTime(week: 1930, seconds: 428637.0) {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "setApproximateTime": {
+                "errors": "",
+                "success": true
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn set_approximate_time_negative_week() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            setApproximateTime(week: -1, seconds: 428637.0) {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "setApproximateTime": {
+                "errors": "GPS week must not be negative, not -1",
+                "success": false
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn set_approximate_time_bad_seconds() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            setApproximateTime(week: 1930, seconds: 604800.0) {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "setApproximateTime": {
+                "errors": "Seconds must be between 0 and 604800, not 604800",
+                "success": false
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn set_approximate_time_fail() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            setApproximateTime(week: 1930, seconds: 428637.0) {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "setApproximateTime": {
+                "errors": "UART Error, Generic Error",
+                "success": false
+            }
+    });
+
+    test!(service, query, expected);
+}
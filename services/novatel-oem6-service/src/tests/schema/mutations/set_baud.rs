@@ -0,0 +1,186 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+use novatel_oem6_api::Connection;
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+
+// Mock the port which is opened after the data rate changes
+fn port_mock(input: Option<Vec<u8>>, output: Option<Vec<u8>>) -> MockStream {
+    let mut mock = MockStream::default();
+
+    if let Some(input) = input {
+        mock.write.set_input(input);
+    }
+
+    if let Some(output) = output {
+        mock.read.set_output(output);
+    }
+
+    mock.read.set_result(Err(UartError::IoError {
+        cause: ::std::io::ErrorKind::TimedOut,
+        description: "Mock Timeout".to_owned(),
+    }));
+
+    mock
+}
+
+// Hand out the given ports in order, recording the data rate each one was opened at
+fn port_opener(ports: Vec<MockStream>, opened: Arc<Mutex<Vec<u32>>>) -> PortOpener {
+    let ports = Mutex::new(ports);
+
+    Arc::new(move |_: &str, baud: u32| {
+        opened.lock().unwrap().push(baud);
+        Ok(Connection {
+            stream: Box::new(ports.lock().unwrap().remove(0)),
+        })
+    })
+}
+
+#[test]
+fn set_baud_good() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(COM_115200_COMMAND.to_vec());
+
+    mock.read.set_output(COM_RESPONSE_GOOD.to_vec());
+
+    let mut output = LOG_RESPONSE_GOOD.to_vec();
+    output.extend_from_slice(&VERSION_LOG);
+    let new_port = port_mock(Some(LOG_VERSION_COMMAND.to_vec()), Some(output));
+
+    let old_tracker = mock.tracker();
+    let new_tracker = new_port.tracker();
+    let opened = Arc::new(Mutex::new(vec![]));
+
+    let mut subsystem = subsystem_new!(mock);
+    subsystem.open_port = port_opener(vec![new_port], opened.clone());
+    let baud_rate = subsystem.baud_rate.clone();
+
+    let service = service_new!(subsystem: subsystem);
+
+    let query = r#"mutation {
+            setBaud(rate: 115200) {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "setBaud": {
+                "errors": "",
+                "success": true
+            }
+    });
+
+    test!(service, query, expected);
+
+    // The COM command goes out on the original port and the version request
+    // goes out on the port reopened at the new rate
+    old_tracker.assert_consumed();
+    new_tracker.assert_consumed();
+    assert_eq!(*opened.lock().unwrap(), vec![115_200]);
+    assert_eq!(*baud_rate.read().unwrap(), 115_200);
+}
+
+#[test]
+fn set_baud_rollback() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(COM_115200_COMMAND.to_vec());
+
+    mock.read.set_output(COM_RESPONSE_GOOD.to_vec());
+
+    // Nothing is heard at the new rate, so the OEM6 is sent back to the old one
+    let mut new_port = port_mock(Some(LOG_VERSION_COMMAND.to_vec()), None);
+    new_port.write.set_input(COM_9600_COMMAND.to_vec());
+    let new_tracker = new_port.tracker();
+    let old_port = port_mock(None, None);
+
+    let opened = Arc::new(Mutex::new(vec![]));
+
+    let mut subsystem = subsystem_new!(mock);
+    subsystem.open_port = port_opener(vec![new_port, old_port], opened.clone());
+    let baud_rate = subsystem.baud_rate.clone();
+
+    let service = service_new!(subsystem: subsystem);
+
+    let query = r#"mutation {
+            setBaud(rate: 115200) {
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "setBaud": {
+                "success": false
+            }
+    });
+
+    test!(service, query, expected);
+
+    new_tracker.assert_consumed();
+    assert_eq!(*opened.lock().unwrap(), vec![115_200, 9600]);
+    assert_eq!(*baud_rate.read().unwrap(), 9600);
+}
+
+#[test]
+fn set_baud_unsupported() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            setBaud(rate: 4800) {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "setBaud": {
+                "errors": "Unsupported baud rate 4800. Must be one of [9600, 19200, 38400, 57600, 115200]",
+                "success": false
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn set_baud_fail() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            setBaud(rate: 115200) {
+                errors,
+                success
+            }
+        }"#;
+
+    // The port isn't reopened if the receiver doesn't accept the command
+    let expected = json!({
+            "setBaud": {
+                "errors": "UART Error, Generic Error",
+                "success": false
+            }
+    });
+
+    test!(service, query, expected);
+}
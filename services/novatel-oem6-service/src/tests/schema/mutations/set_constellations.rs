@@ -0,0 +1,101 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+use serde_json::json;
+
+#[test]
+fn set_constellations_good() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(UNLOCKOUT_GPS_COMMAND.to_vec());
+    mock.write.set_input(UNLOCKOUT_GLONASS_COMMAND.to_vec());
+    mock.write.set_input(LOCKOUT_SBAS_COMMAND.to_vec());
+    mock.write.set_input(LOCKOUT_GALILEO_COMMAND.to_vec());
+    mock.write.set_input(LOCKOUT_BEIDOU_COMMAND.to_vec());
+    mock.write.set_input(LOCKOUT_QZSS_COMMAND.to_vec());
+
+    let mut output = UNLOCKOUT_SYSTEM_RESPONSE_GOOD.to_vec();
+    output.extend_from_slice(&UNLOCKOUT_SYSTEM_RESPONSE_GOOD);
+    for _ in 0..4 {
+        output.extend_from_slice(&LOCKOUT_SYSTEM_RESPONSE_GOOD);
+    }
+    mock.read.set_output(output);
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            setConstellations(systems: [GLONASS, GPS]) {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "setConstellations": {
+                "errors": "",
+                "success": true
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn set_constellations_empty() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            setConstellations(systems: []) {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "setConstellations": {
+                "errors": "At least one constellation must be selected",
+                "success": false
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn set_constellations_fail() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            setConstellations(systems: [GPS]) {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "setConstellations": {
+                "errors": "UART Error, Generic Error",
+                "success": false
+            }
+    });
+
+    test!(service, query, expected);
+}
@@ -0,0 +1,114 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+use serde_json::json;
+
+#[test]
+fn set_elevation_mask_good() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(ECUTOFF_COMMAND.to_vec());
+
+    mock.read.set_output(ECUTOFF_RESPONSE_GOOD.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            setElevationMask(degrees: 10) {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "setElevationMask": {
+                "errors": "",
+                "success": true
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn set_elevation_mask_too_high() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            setElevationMask(degrees: 91) {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "setElevationMask": {
+                "errors": "Elevation mask must be between 0 and 90 degrees, not 91",
+                "success": false
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn set_elevation_mask_negative() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            setElevationMask(degrees: -1) {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "setElevationMask": {
+                "errors": "Elevation mask must be between 0 and 90 degrees, not -1",
+                "success": false
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn set_elevation_mask_fail() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            setElevationMask(degrees: 10) {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "setElevationMask": {
+                "errors": "UART Error, Generic Error",
+                "success": false
+            }
+    });
+
+    test!(service, query, expected);
+}
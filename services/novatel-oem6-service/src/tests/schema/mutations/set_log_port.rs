@@ -0,0 +1,224 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+use serde_json::json;
+
+const CONFIGURE_TIME: &str = r#"mutation {
+        configureHardware(config: [{option: LOG_TIME_DATA, interval: 1.0, hold: true}]) {
+            success
+        }
+    }"#;
+
+#[test]
+fn set_log_port_good() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(LOG_TIME_COMMAND.to_vec());
+    mock.write.set_input(UNLOG_TIME_COMMAND.to_vec());
+    mock.write.set_input(LOG_TIME_COM2_COMMAND.to_vec());
+
+    let mut output = LOG_RESPONSE_GOOD.to_vec();
+    output.extend_from_slice(&UNLOG_RESPONSE_GOOD);
+    output.extend_from_slice(&LOG_RESPONSE_GOOD);
+    mock.read.set_output(output);
+
+    let service = service_new!(mock);
+
+    request!(service, CONFIGURE_TIME);
+
+    let query = r#"mutation {
+            setLogPort(log: LOG_TIME_DATA, port: COM2) {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "setLogPort": {
+                "errors": "",
+                "success": true
+            }
+    });
+
+    test!(service, query, expected);
+
+    let query = r#"{
+            appliedConfiguration {
+                option,
+                port
+            }
+        }"#;
+
+    let expected = json!({
+            "appliedConfiguration": [{
+                "option": "LOG_TIME_DATA",
+                "port": "COM2"
+            }]
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn set_log_port_same_port() {
+    let mut mock = MockStream::default();
+
+    // The log is already output on COM1, so there's nothing to stop
+    mock.write.set_input(LOG_TIME_COMMAND.to_vec());
+    mock.write.set_input(LOG_TIME_COMMAND.to_vec());
+
+    let mut output = LOG_RESPONSE_GOOD.to_vec();
+    output.extend_from_slice(&LOG_RESPONSE_GOOD);
+    mock.read.set_output(output);
+
+    let service = service_new!(mock);
+
+    request!(service, CONFIGURE_TIME);
+
+    let query = r#"mutation {
+            setLogPort(log: LOG_TIME_DATA, port: COM1) {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "setLogPort": {
+                "errors": "",
+                "success": true
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn set_log_port_not_applied() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            setLogPort(log: LOG_TIME_DATA, port: COM2) {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "setLogPort": {
+                "errors": "LogTimeData has not been applied with configureHardware",
+                "success": false
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn set_log_port_not_log_option() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"mutation {
+            setLogPort(log: UNLOG_TIME_DATA, port: COM2) {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "setLogPort": {
+                "errors": "UnlogTimeData is not a LOG_* option",
+                "success": false
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn set_log_port_unsupported_port() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(LOG_TIME_COMMAND.to_vec());
+
+    mock.read.set_output(LOG_RESPONSE_GOOD.to_vec());
+
+    let service = service_new!(mock);
+
+    request!(service, CONFIGURE_TIME);
+
+    let query = r#"mutation {
+            setLogPort(log: LOG_TIME_DATA, port: THISPORT) {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "setLogPort": {
+                "errors": "Unsupported log port ThisPort. Must be one of [COM1, COM2, COM3]",
+                "success": false
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn set_log_port_fail() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(LOG_TIME_COMMAND.to_vec());
+
+    mock.read.set_output(LOG_RESPONSE_GOOD.to_vec());
+
+    let service = service_new!(mock);
+
+    request!(service, CONFIGURE_TIME);
+
+    let query = r#"mutation {
+            setLogPort(log: LOG_TIME_DATA, port: COM2) {
+                errors,
+                success
+            }
+        }"#;
+
+    let expected = json!({
+            "setLogPort": {
+                "errors": "UART Error, Generic Error",
+                "success": false
+            }
+    });
+
+    test!(service, query, expected);
+    let query = r#"{
+            appliedConfiguration {
+                port
+            }
+        }"#;
+
+    let expected = json!({
+            "appliedConfiguration": [{
+                "port": "COM1"
+            }]
+    });
+
+    test!(service, query, expected);
+}
@@ -38,6 +38,7 @@ fn test_hardware_integration_good() {
                         components {
                             bootVersion,
                             compType,
+                            compTypeRaw,
                             compileDate,
                             compileTime, 
                             hwVersion,
@@ -83,7 +84,8 @@ fn test_hardware_integration_good() {
                 "telemetryDebug": {
                     "components": [{
                         "bootVersion": "OEM060201RB0000",
-                        "compType": 1,
+                        "compType": "GPS_CARD",
+                        "compTypeRaw": 1,
                         "compileDate": "2015/Jan/28",
                         "compileTime": "15:27:29",
                         "hwVersion": "OEM615-2.00",
@@ -141,6 +143,7 @@ fn test_hardware_integration_no_response() {
                         components {
                             bootVersion,
                             compType,
+                            compTypeRaw,
                             compileDate,
                             compileTime, 
                             hwVersion,
@@ -260,6 +263,7 @@ fn test_hardware_integration_no_log() {
                         components {
                             bootVersion,
                             compType,
+                            compTypeRaw,
                             compileDate,
                             compileTime, 
                             hwVersion,
@@ -158,3 +158,203 @@ fn ack_issue_raw_command() {
 
     test!(service, query, expected);
 }
+
+#[test]
+fn ack_reset() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let mutation = r#"mutation {
+            reset(resetType: HOT){
+                success
+            }
+        }"#;
+
+    request!(service, mutation);
+
+    let query = r#"{
+            ack
+        }"#;
+
+    let expected = json!({
+            "ack": "RESET"
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn ack_save_configuration() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let mutation = r#"mutation {
+            saveConfiguration {
+                success
+            }
+        }"#;
+
+    request!(service, mutation);
+
+    let query = r#"{
+            ack
+        }"#;
+
+    let expected = json!({
+            "ack": "SAVE_CONFIGURATION"
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn ack_set_elevation_mask() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let mutation = r#"mutation {
+            setElevationMask(degrees: 10) {
+                success
+            }
+        }"#;
+
+    request!(service, mutation);
+
+    let query = r#"{
+            ack
+        }"#;
+
+    let expected = json!({
+            "ack": "SET_ELEVATION_MASK"
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn ack_set_constellations() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let mutation = r#"mutation {
+            setConstellations(systems: [GPS]) {
+                success
+            }
+        }"#;
+
+    request!(service, mutation);
+
+    let query = r#"{
+            ack
+        }"#;
+
+    let expected = json!({
+            "ack": "SET_CONSTELLATIONS"
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn ack_set_baud() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let mutation = r#"mutation {
+            setBaud(rate: 9600) {
+                success
+            }
+        }"#;
+
+    request!(service, mutation);
+
+    let query = r#"{
+            ack
+        }"#;
+
+    let expected = json!({
+            "ack": "SET_BAUD"
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn ack_set_approximate_position() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let mutation = r#"mutation {
+            setApproximatePosition(lat: 51.116, lon: -114.038, height: 1048.0) {
+                success
+            }
+        }"#;
+
+    request!(service, mutation);
+
+    let query = r#"{
+            ack
+        }"#;
+
+    let expected = json!({
+            "ack": "SET_APPROXIMATE_POSITION"
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn ack_set_approximate_time() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let mutation = r#"mutation {
+            setApproximateTime(week: 1930, seconds: 428637.0) {
+                success
+            }
+        }"#;
+
+    request!(service, mutation);
+
+    let query = r#"{
+            ack
+        }"#;
+
+    let expected = json!({
+            "ack": "SET_APPROXIMATE_TIME"
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn ack_set_log_port() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let mutation = r#"mutation {
+            setLogPort(log: LOG_TIME_DATA, port: COM2) {
+                success
+            }
+        }"#;
+
+    request!(service, mutation);
+
+    let query = r#"{
+            ack
+        }"#;
+
+    let expected = json!({
+            "ack": "SET_LOG_PORT"
+    });
+
+    test!(service, query, expected);
+}
@@ -0,0 +1,149 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+
+const UNLOG_DOP_COMMAND: [u8; 40] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x24, 0x0, 0x0, 0xC0, 0x8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0xAE, 0x0, 0x0, 0x0,
+    0xF6, 0x89, 0xDE, 0x18,
+];
+
+#[test]
+fn applied_configuration_default() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            appliedConfiguration {
+                option
+            }
+        }"#;
+
+    let expected = json!({
+            "appliedConfiguration": []
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn applied_configuration_log_then_unlog() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(LOG_DOP_COMMAND.to_vec());
+    mock.write.set_input(LOG_TIME_COMMAND.to_vec());
+    mock.write.set_input(UNLOG_DOP_COMMAND.to_vec());
+
+    let mut output = LOG_RESPONSE_GOOD.to_vec();
+    output.extend_from_slice(&LOG_RESPONSE_GOOD);
+    output.extend_from_slice(&UNLOG_RESPONSE_GOOD);
+    mock.read.set_output(output);
+
+    let service = service_new!(mock);
+
+    let configure = r#"mutation {
+            configureHardware(config: [
+                {option: LOG_DOP_DATA, interval: 1.0},
+                {option: LOG_TIME_DATA, interval: 1.0, hold: true}
+            ]) {
+                success
+            }
+        }"#;
+
+    request!(service, configure);
+
+    let query = r#"{
+            appliedConfiguration {
+                format,
+                hold,
+                interval,
+                offset,
+                option
+            }
+        }"#;
+
+    let expected = json!({
+            "appliedConfiguration": [
+                {
+                    "format": "BINARY",
+                    "hold": false,
+                    "interval": 1.0,
+                    "offset": 0.0,
+                    "option": "LOG_DOP_DATA"
+                },
+                {
+                    "format": "BINARY",
+                    "hold": true,
+                    "interval": 1.0,
+                    "offset": 0.0,
+                    "option": "LOG_TIME_DATA"
+                }
+            ]
+    });
+
+    test!(service, query, expected);
+
+    let unlog = r#"mutation {
+            configureHardware(config: [{option: UNLOG_DOP_DATA}]) {
+                success
+            }
+        }"#;
+
+    request!(service, unlog);
+
+    let expected = json!({
+            "appliedConfiguration": [
+                {
+                    "format": "BINARY",
+                    "hold": true,
+                    "interval": 1.0,
+                    "offset": 0.0,
+                    "option": "LOG_TIME_DATA"
+                }
+            ]
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn applied_configuration_failed_not_recorded() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let configure = r#"mutation {
+            configureHardware(config: [{option: LOG_DOP_DATA, interval: 1.0}]) {
+                success
+            }
+        }"#;
+
+    request!(service, configure);
+
+    let query = r#"{
+            appliedConfiguration {
+                option
+            }
+        }"#;
+
+    let expected = json!({
+            "appliedConfiguration": []
+    });
+
+    test!(service, query, expected);
+}
@@ -0,0 +1,240 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+use serde_json::json;
+
+#[test]
+fn get_batch_telemetry_good() {
+    let mut mock = MockStream::default();
+
+    // Only one VERSION log request is expected. Any further requests would fail
+    mock.write.set_input(LOG_VERSION_COMMAND.to_vec());
+
+    let mut output = LOG_RESPONSE_GOOD.to_vec();
+    output.extend_from_slice(&VERSION_LOG);
+    mock.read.set_output(output);
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            batchTelemetry {
+                lockInfo {
+                    position,
+                    time {
+                        ms,
+                        week
+                    },
+                    velocity
+                },
+                lockStatus {
+                    positionStatus,
+                    positionType,
+                    timeStatus
+                },
+                power {
+                    state,
+                    uptime
+                },
+                systemStatus {
+                    errors,
+                    status
+                },
+                telemetryDebug {
+                    numComponents
+                }
+            }
+        }"#;
+
+    let expected = json!({
+            "batchTelemetry": {
+                "lockInfo": {
+                    "position": [0.0, 0.0, 0.0],
+                    "time": {
+                        "ms": 0,
+                        "week": 0
+                    },
+                    "velocity": [0.0, 0.0, 0.0]
+                },
+                "lockStatus": {
+                    "positionStatus": "INSUFFICIENT_OBSERVATIONS",
+                    "positionType": "NONE",
+                    "timeStatus": "UNKNOWN"
+                },
+                "power": {
+                    "state": "ON",
+                    "uptime": 1
+                },
+                "systemStatus": {
+                    "errors": [],
+                    "status": ["POSITION_SOLUTION_INVALID", "CLOCK_MODEL_INVALID"]
+                },
+                "telemetryDebug": {
+                    "numComponents": 1
+                }
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_batch_telemetry_matches_queries() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(LOG_VERSION_COMMAND.to_vec());
+
+    let mut output = POSITION_LOG_NO_LOCK.to_vec();
+    output.extend_from_slice(&LOG_RESPONSE_GOOD);
+    output.extend_from_slice(&VERSION_LOG);
+    mock.read.set_output(output);
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            batchTelemetry {
+                lockInfo {
+                    position,
+                    time {
+                        ms,
+                        week
+                    },
+                    velocity
+                },
+                lockStatus {
+                    positionStatus,
+                    positionType,
+                    timeStatus,
+                    velocityStatus,
+                    velocityType
+                },
+                power {
+                    state,
+                    uptime
+                },
+                telemetryDebug {
+                    numComponents
+                }
+            }
+        }"#;
+
+    let res = request!(service, query);
+    let batch: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+
+    // The VERSION log has been cached by the batch request, so none of these
+    // queries need to talk to the device
+    let query = r#"{
+            lockInfo {
+                position,
+                time {
+                    ms,
+                    week
+                },
+                velocity
+            },
+            lockStatus {
+                positionStatus,
+                positionType,
+                timeStatus,
+                velocityStatus,
+                velocityType
+            },
+            telemetry {
+                debug {
+                    numComponents
+                }
+            }
+        }"#;
+
+    let res = request!(service, query);
+    let individual: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+
+    let batch = &batch["data"]["batchTelemetry"];
+    let individual = &individual["data"];
+    assert_eq!(batch["lockInfo"], individual["lockInfo"]);
+    assert_eq!(batch["lockStatus"], individual["lockStatus"]);
+    assert_eq!(batch["telemetryDebug"], individual["telemetry"]["debug"]);
+    assert_eq!(batch["power"], json!({"state": "ON", "uptime": 1}));
+}
+
+#[test]
+fn get_batch_telemetry_no_response() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(LOG_VERSION_COMMAND.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            batchTelemetry {
+                power {
+                    state,
+                    uptime
+                },
+                systemStatus {
+                    status
+                },
+                telemetryDebug {
+                    numComponents
+                }
+            }
+        }"#;
+
+    let expected = json!({
+            "batchTelemetry": {
+                "power": {
+                    "state": "OFF",
+                    "uptime": 0
+                },
+                "systemStatus": null,
+                "telemetryDebug": null
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_batch_telemetry_cache_only() {
+    let mut mock = MockStream::default();
+
+    // Nothing is written to the device if neither power nor systemStatus is requested
+    let service = service_new!(mock);
+
+    let query = r#"{
+            batchTelemetry {
+                lockStatus {
+                    positionType
+                },
+                telemetryDebug {
+                    numComponents
+                }
+            },
+            errors
+        }"#;
+
+    let expected = json!({
+            "batchTelemetry": {
+                "lockStatus": {
+                    "positionType": "NONE"
+                },
+                "telemetryDebug": null
+            },
+            "errors": []
+    });
+
+    test!(service, query, expected);
+}
@@ -0,0 +1,89 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+
+#[test]
+fn get_clock_info_default() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            clockInfo {
+                clockOffset,
+                clockOffsetStdDev,
+                stale,
+                time {
+                    ms,
+                    week
+                },
+                utcOffset
+            }
+        }"#;
+
+    let expected = json!({
+            "clockInfo": {
+                "clockOffset": 0.0,
+                "clockOffsetStdDev": 0.0,
+                "stale": true,
+                "time": {
+                    "ms": 0,
+                    "week": 0,
+                },
+                "utcOffset": 0.0,
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_clock_info_good() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(TIME_LOG.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            clockInfo {
+                clockOffset,
+                clockOffsetStdDev,
+                stale,
+                time {
+                    ms,
+                    week
+                },
+                utcOffset
+            }
+        }"#;
+
+    let expected = json!({
+            "clockInfo": {
+                "clockOffset": -1.5e-8,
+                "clockOffsetStdDev": 2.5e-9,
+                "stale": false,
+                "time": {
+                    "ms": 164_195_000,
+                    "week": 3025
+                },
+                "utcOffset": -18.0,
+            }
+    });
+
+    test!(service, query, expected);
+}
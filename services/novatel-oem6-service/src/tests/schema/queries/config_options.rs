@@ -0,0 +1,182 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+use serde_json::json;
+
+#[test]
+fn get_config_options() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            configOptions {
+                option,
+                kind,
+                interval,
+                offset,
+                hold
+            }
+        }"#;
+
+    let expected = json!({
+            "configOptions": [
+                {
+                    "option": "LOG_DOP_DATA",
+                    "kind": "LOG",
+                    "interval": true,
+                    "offset": true,
+                    "hold": true
+                },
+                {
+                    "option": "LOG_ERROR_DATA",
+                    "kind": "LOG",
+                    "interval": false,
+                    "offset": false,
+                    "hold": true
+                },
+                {
+                    "option": "LOG_HW_MONITOR",
+                    "kind": "LOG",
+                    "interval": true,
+                    "offset": true,
+                    "hold": true
+                },
+                {
+                    "option": "LOG_MARK_TIME",
+                    "kind": "LOG",
+                    "interval": false,
+                    "offset": false,
+                    "hold": true
+                },
+                {
+                    "option": "LOG_POSITION_DATA",
+                    "kind": "LOG",
+                    "interval": true,
+                    "offset": true,
+                    "hold": true
+                },
+                {
+                    "option": "LOG_RANGE_DATA",
+                    "kind": "LOG",
+                    "interval": true,
+                    "offset": true,
+                    "hold": true
+                },
+                {
+                    "option": "LOG_TIME_DATA",
+                    "kind": "LOG",
+                    "interval": true,
+                    "offset": true,
+                    "hold": true
+                },
+                {
+                    "option": "LOG_TRACKING_DATA",
+                    "kind": "LOG",
+                    "interval": true,
+                    "offset": true,
+                    "hold": true
+                },
+                {
+                    "option": "LOG_VELOCITY_DATA",
+                    "kind": "LOG",
+                    "interval": true,
+                    "offset": true,
+                    "hold": true
+                },
+                {
+                    "option": "SAVE_CONFIG",
+                    "kind": "OTHER",
+                    "interval": false,
+                    "offset": false,
+                    "hold": false
+                },
+                {
+                    "option": "UNLOG_ALL",
+                    "kind": "UNLOG",
+                    "interval": false,
+                    "offset": false,
+                    "hold": true
+                },
+                {
+                    "option": "UNLOG_DOP_DATA",
+                    "kind": "UNLOG",
+                    "interval": false,
+                    "offset": false,
+                    "hold": false
+                },
+                {
+                    "option": "UNLOG_ERROR_DATA",
+                    "kind": "UNLOG",
+                    "interval": false,
+                    "offset": false,
+                    "hold": false
+                },
+                {
+                    "option": "UNLOG_HW_MONITOR",
+                    "kind": "UNLOG",
+                    "interval": false,
+                    "offset": false,
+                    "hold": false
+                },
+                {
+                    "option": "UNLOG_MARK_TIME",
+                    "kind": "UNLOG",
+                    "interval": false,
+                    "offset": false,
+                    "hold": false
+                },
+                {
+                    "option": "UNLOG_POSITION_DATA",
+                    "kind": "UNLOG",
+                    "interval": false,
+                    "offset": false,
+                    "hold": false
+                },
+                {
+                    "option": "UNLOG_RANGE_DATA",
+                    "kind": "UNLOG",
+                    "interval": false,
+                    "offset": false,
+                    "hold": false
+                },
+                {
+                    "option": "UNLOG_TIME_DATA",
+                    "kind": "UNLOG",
+                    "interval": false,
+                    "offset": false,
+                    "hold": false
+                },
+                {
+                    "option": "UNLOG_TRACKING_DATA",
+                    "kind": "UNLOG",
+                    "interval": false,
+                    "offset": false,
+                    "hold": false
+                },
+                {
+                    "option": "UNLOG_VELOCITY_DATA",
+                    "kind": "UNLOG",
+                    "interval": false,
+                    "offset": false,
+                    "hold": false
+                }
+            ]
+    });
+
+    test!(service, query, expected);
+}
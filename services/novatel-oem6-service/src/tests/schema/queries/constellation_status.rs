@@ -0,0 +1,99 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+
+#[test]
+fn get_constellation_status_default() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            constellationStatus {
+                constellations {
+                    system
+                },
+                receivedAt,
+                stale,
+                time {
+                    ms,
+                    week
+                }
+            }
+        }"#;
+
+    let expected = json!({
+            "constellationStatus": {
+                "constellations": [],
+                "receivedAt": 0.0,
+                "stale": true,
+                "time": {
+                    "ms": 0,
+                    "week": 0,
+                }
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_constellation_status_mixed() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(TRACKSTAT_LOG.to_vec());
+
+    let service = service_new!(mock);
+
+    // Two GPS channels track different signals from PRN 12 (only one is used),
+    // one GLONASS satellite is used, one Galileo satellite is tracked but rejected,
+    // and the last channel is idle
+    let query = r#"{
+            constellationStatus {
+                constellations {
+                    system,
+                    tracked,
+                    used
+                },
+                stale,
+                time {
+                    ms,
+                    week
+                }
+            }
+        }"#;
+
+    let expected = json!({
+            "constellationStatus": {
+                "constellations": [
+                    {"system": "GPS", "tracked": 1, "used": 1},
+                    {"system": "GLONASS", "tracked": 1, "used": 1},
+                    {"system": "SBAS", "tracked": 0, "used": 0},
+                    {"system": "GALILEO", "tracked": 1, "used": 0},
+                    {"system": "BEIDOU", "tracked": 0, "used": 0},
+                    {"system": "QZSS", "tracked": 0, "used": 0}
+                ],
+                "stale": false,
+                "time": {
+                    "ms": 164_195_000,
+                    "week": 3025
+                }
+            }
+    });
+
+    test!(service, query, expected);
+}
@@ -0,0 +1,89 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+
+#[test]
+fn get_dop_info_default() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            dopInfo {
+                hdop,
+                pdop,
+                tdop,
+                time {
+                    ms,
+                    week
+                },
+                vdop
+            }
+        }"#;
+
+    let expected = json!({
+            "dopInfo": {
+                "hdop": 9999.0,
+                "pdop": 9999.0,
+                "tdop": 9999.0,
+                "time": {
+                    "ms": 0,
+                    "week": 0,
+                },
+                "vdop": 9999.0,
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_dop_info_good() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(DOP_LOG.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            dopInfo {
+                hdop,
+                pdop,
+                tdop,
+                time {
+                    ms,
+                    week
+                },
+                vdop
+            }
+        }"#;
+
+    let expected = json!({
+            "dopInfo": {
+                "hdop": 1.25,
+                "pdop": 2.0,
+                "tdop": 1.0,
+                "time": {
+                    "ms": 164_195_000,
+                    "week": 3025
+                },
+                "vdop": 1.5612494995995996,
+            }
+    });
+
+    test!(service, query, expected);
+}
@@ -0,0 +1,119 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+
+#[test]
+fn get_hardware_monitor_default() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            hardwareMonitor {
+                antennaCurrent,
+                coreVoltage,
+                receivedAt,
+                stale,
+                temperature,
+                time {
+                    ms,
+                    week
+                }
+            }
+        }"#;
+
+    let expected = json!({
+            "hardwareMonitor": {
+                "antennaCurrent": 9999.0,
+                "coreVoltage": 9999.0,
+                "receivedAt": 0.0,
+                "stale": true,
+                "temperature": 9999.0,
+                "time": {
+                    "ms": 0,
+                    "week": 0,
+                }
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_hardware_monitor_good() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(HW_MONITOR_LOG.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            hardwareMonitor {
+                antennaCurrent,
+                coreVoltage,
+                stale,
+                temperature,
+                time {
+                    ms,
+                    week
+                }
+            }
+        }"#;
+
+    let expected = json!({
+            "hardwareMonitor": {
+                "antennaCurrent": 0.0625,
+                "coreVoltage": 1.25,
+                "stale": false,
+                "temperature": 42.5,
+                "time": {
+                    "ms": 164_195_000,
+                    "week": 3025
+                }
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_hardware_monitor_missing_readings() {
+    let mut mock = MockStream::default();
+
+    mock.read
+        .set_output(HW_MONITOR_LOG_TEMPERATURE_ONLY.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            hardwareMonitor {
+                antennaCurrent,
+                coreVoltage,
+                temperature
+            }
+        }"#;
+
+    let expected = json!({
+            "hardwareMonitor": {
+                "antennaCurrent": 9999.0,
+                "coreVoltage": 9999.0,
+                "temperature": 42.5
+            }
+    });
+
+    test!(service, query, expected);
+}
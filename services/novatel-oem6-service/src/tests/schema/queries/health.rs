@@ -0,0 +1,145 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+use serde_json::json;
+
+#[test]
+fn get_health_no_lock() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(LOG_VERSION_COMMAND.to_vec());
+
+    let mut output = LOG_RESPONSE_GOOD.to_vec();
+    output.extend_from_slice(&VERSION_LOG);
+    mock.read.set_output(output);
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            health {
+                errors,
+                locked,
+                powered,
+                statusFlags
+            }
+        }"#;
+
+    let expected = json!({
+            "health": {
+                "errors": [],
+                "locked": false,
+                "powered": true,
+                "statusFlags": ["POSITION_SOLUTION_INVALID", "CLOCK_MODEL_INVALID"]
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_health_locked() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(LOG_VERSION_COMMAND.to_vec());
+
+    let mut output = POSITION_LOG_GOOD.to_vec();
+    output.extend_from_slice(&LOG_RESPONSE_GOOD);
+    output.extend_from_slice(&VERSION_LOG);
+    mock.read.set_output(output);
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            health {
+                locked,
+                powered
+            }
+        }"#;
+
+    let expected = json!({
+            "health": {
+                "locked": true,
+                "powered": true
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_health_locked_disconnected() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(LOG_VERSION_COMMAND.to_vec());
+
+    let mut output = POSITION_LOG_GOOD.to_vec();
+    output.extend_from_slice(&LOG_RESPONSE_GOOD);
+    output.extend_from_slice(&VERSION_LOG);
+    mock.read.set_output(output);
+
+    let subsystem = subsystem_new!(mock);
+    // The link to the receiver has since been lost, so the last solution
+    // no longer reflects its state
+    subsystem.lock_data.mark_disconnected();
+
+    let service = service_new!(subsystem: subsystem);
+
+    let query = r#"{
+            health {
+                locked,
+                powered
+            }
+        }"#;
+
+    let expected = json!({
+            "health": {
+                "locked": false,
+                "powered": true
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_health_no_response() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(LOG_VERSION_COMMAND.to_vec());
+
+    mock.read.set_output(LOG_RESPONSE_GOOD.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            health {
+                errors,
+                locked,
+                powered
+            }
+        }"#;
+
+    let expected = json!({
+            "health": {
+                "errors": ["System Status: Failed to receive version info - timed out waiting on channel"],
+                "locked": false,
+                "powered": false
+            }
+    });
+
+    test!(service, query, expected);
+}
@@ -147,3 +147,128 @@ fn get_lock_info_nolock_after_good() {
 
     test!(service, query, expected);
 }
+
+#[test]
+fn get_lock_info_geodetic_default() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            lockInfo {
+                geodetic {
+                    altitude,
+                    latitude,
+                    longitude
+                }
+            }
+        }"#;
+
+    let expected = json!({
+            "lockInfo": {
+                "geodetic": {
+                    "altitude": 0.0,
+                    "latitude": 0.0,
+                    "longitude": 0.0
+                }
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_lock_info_age_default() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            lockInfo {
+                receivedAt,
+                solutionAge,
+                stale
+            }
+        }"#;
+
+    let expected = json!({
+            "lockInfo": {
+                "receivedAt": 0.0,
+                "solutionAge": -1.0,
+                "stale": true
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_lock_info_age_good() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(POSITION_LOG_GOOD.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            lockInfo {
+                solutionAge,
+                stale
+            }
+        }"#;
+
+    let expected = json!({
+            "lockInfo": {
+                "solutionAge": 0.0,
+                "stale": false
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_lock_info_speed_default() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            lockInfo {
+                heading,
+                speed
+            }
+        }"#;
+
+    let expected = json!({
+            "lockInfo": {
+                "heading": null,
+                "speed": 0.0
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_lock_info_speed_good() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(POSITION_LOG_GOOD.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            lockInfo {
+                speed
+            }
+        }"#;
+
+    let expected = json!({
+            "lockInfo": {
+                "speed": 9.652460826131334
+            }
+    });
+
+    test!(service, query, expected);
+}
@@ -131,3 +131,361 @@ fn get_lock_status_nondefault() {
 
     test!(service, query, expected);
 }
+
+#[test]
+fn get_lock_status_unix_time_default() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            lockStatus {
+                time {
+                    unixTime(utcOffset: 0.0)
+                }
+            }
+        }"#;
+
+    // The GPS epoch
+    let expected = json!({
+            "lockStatus": {
+                "time": {
+                    "unixTime": 315_964_800.0
+                }
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_lock_status_unix_time() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(POSITION_LOG_GOOD.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            lockStatus {
+                time {
+                    unixTime
+                }
+            }
+        }"#;
+
+    // Week 3025, 164195s into the week, minus 18 leap seconds
+    let expected = json!({
+            "lockStatus": {
+                "time": {
+                    "unixTime": 2_145_648_977.0
+                }
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_lock_status_unix_time_offset() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(POSITION_LOG_GOOD.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            lockStatus {
+                time {
+                    unixTime(utcOffset: -17.5)
+                }
+            }
+        }"#;
+
+    let expected = json!({
+            "lockStatus": {
+                "time": {
+                    "unixTime": 2_145_648_977.5
+                }
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_lock_status_differential_age_default() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            lockStatus {
+                differentialAge
+            }
+        }"#;
+
+    let expected = json!({
+            "lockStatus": {
+                "differentialAge": -1.0
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_lock_status_differential_age() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(POSITION_LOG_DIFFERENTIAL.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            lockStatus {
+                differentialAge,
+                positionType
+            }
+        }"#;
+
+    let expected = json!({
+            "lockStatus": {
+                "differentialAge": 4.5,
+                "positionType": "PSRDIFF"
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_lock_status_differential_age_no_lock() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(POSITION_LOG_NO_LOCK.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            lockStatus {
+                differentialAge
+            }
+        }"#;
+
+    let expected = json!({
+            "lockStatus": {
+                "differentialAge": -1.0
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_lock_status_raw() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(POSITION_LOG_GOOD.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            lockStatus {
+                positionStatusRaw,
+                positionTypeRaw,
+                timeStatusRaw,
+                velocityStatusRaw,
+                velocityTypeRaw
+            }
+        }"#;
+
+    let expected = json!({
+            "lockStatus": {
+                "positionStatusRaw": 0,
+                "positionTypeRaw": 17,
+                "timeStatusRaw": 180,
+                "velocityStatusRaw": 0,
+                "velocityTypeRaw": 17
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_lock_status_unknown_raw() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(POSITION_LOG_UNKNOWN.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            lockStatus {
+                positionStatus,
+                positionStatusRaw,
+                positionType,
+                positionTypeRaw,
+                timeStatus,
+                timeStatusRaw,
+                velocityStatus,
+                velocityStatusRaw,
+                velocityType,
+                velocityTypeRaw
+            }
+        }"#;
+
+    let expected = json!({
+            "lockStatus": {
+                "positionStatus": "KUBOS_INVALID",
+                "positionStatusRaw": 21,
+                "positionType": "KUBOS_INVALID",
+                "positionTypeRaw": 99,
+                "timeStatus": "KUBOS_INVALID",
+                "timeStatusRaw": 210,
+                "velocityStatus": "KUBOS_INVALID",
+                "velocityStatusRaw": 21,
+                "velocityType": "KUBOS_INVALID",
+                "velocityTypeRaw": 99
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_lock_status_ascii() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(POSITION_LOG_ASCII.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            lockStatus {
+                differentialAge,
+                positionStatus,
+                positionType,
+                time {
+                    ms,
+                    week
+                },
+                timeStatus,
+                velocityStatus,
+                velocityType
+            }
+        }"#;
+
+    let expected = json!({
+            "lockStatus": {
+                "differentialAge": 1.0,
+                "positionStatus": "SOL_COMPUTED",
+                "positionType": "PSRDIFF",
+                "time": {
+                    "ms": 164_195_000,
+                    "week": 3025
+                },
+                "timeStatus": "FINE_STEERING",
+                "velocityStatus": "SOL_COMPUTED",
+                "velocityType": "PSRDIFF"
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_lock_status_no_device() {
+    let mut mock = MockStream::default();
+
+    // The receiver never responds to anything
+    mock.write.set_input(LOG_VERSION_COMMAND.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            lockInfo {
+                deviceConnected
+            },
+            lockStatus {
+                deviceConnected,
+                positionStatus
+            },
+            power {
+                state
+            }
+        }"#;
+
+    let expected = json!({
+            "lockInfo": {
+                "deviceConnected": false
+            },
+            "lockStatus": {
+                "deviceConnected": false,
+                "positionStatus": "INSUFFICIENT_OBSERVATIONS"
+            },
+            "power": {
+                "state": "OFF"
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_lock_status_device_connected() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(POSITION_LOG_NO_LOCK.to_vec());
+
+    let service = service_new!(mock);
+
+    // The lock information is only updated on a good fix, but the receiver
+    // has still been heard from
+    let query = r#"{
+            lockInfo {
+                deviceConnected,
+                stale
+            },
+            lockStatus {
+                deviceConnected
+            }
+        }"#;
+
+    let expected = json!({
+            "lockInfo": {
+                "deviceConnected": true,
+                "stale": true
+            },
+            "lockStatus": {
+                "deviceConnected": true
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_lock_status_rejected_measurements() {
+    let mut mock = MockStream::default();
+
+    // 10 satellites tracked, 8 used in the solution
+    mock.read.set_output(POSITION_LOG_DIFFERENTIAL.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            lockStatus {
+                rejectedMeasurements
+            }
+        }"#;
+
+    let expected = json!({
+            "lockStatus": {
+                "rejectedMeasurements": 2
+            }
+    });
+
+    test!(service, query, expected);
+}
@@ -0,0 +1,99 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+
+#[test]
+fn get_mark_time_events_empty() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            markTimeEvents {
+                eventId,
+                ms,
+                week
+            }
+        }"#;
+
+    let expected = json!({
+            "markTimeEvents": []
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_mark_time_events_good() {
+    let mut mock = MockStream::default();
+
+    let mut output = MARK1_TIME_LOG.to_vec();
+    output.extend_from_slice(&MARK2_TIME_LOG);
+    mock.read.set_output(output);
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            markTimeEvents {
+                eventId,
+                ms,
+                week
+            }
+        }"#;
+
+    let expected = json!({
+            "markTimeEvents": [
+                {
+                    "eventId": 1,
+                    "ms": 412_500_125,
+                    "week": 2001
+                },
+                {
+                    "eventId": 2,
+                    "ms": 412_501_500,
+                    "week": 2001
+                }
+            ]
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_mark_time_events_clear_after_query() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(MARK1_TIME_LOG.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            markTimeEvents {
+                eventId,
+                ms,
+                week
+            }
+        }"#;
+
+    request!(service, query);
+
+    let expected = json!({
+            "markTimeEvents": []
+    });
+
+    test!(service, query, expected);
+}
@@ -17,11 +17,27 @@
 use super::*;
 
 mod ack;
+mod applied_configuration;
+mod batch_telemetry;
+mod clock_info;
 mod config;
+mod config_options;
+mod configuration;
+mod constellation_status;
+mod dop_info;
 mod errors;
+mod hardware_monitor;
+mod health;
 mod lock_info;
 mod lock_status;
+mod mark_time_events;
+mod new_errors;
+mod port_config;
 mod power;
+mod range_observations;
+mod snapshot;
 mod system_status;
 mod telemetry;
 mod test_results;
+mod tracking_status;
+mod velocity_info;
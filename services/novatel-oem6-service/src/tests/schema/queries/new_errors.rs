@@ -0,0 +1,111 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+use serde_json::json;
+
+#[test]
+fn query_new_errors_second_poll_empty() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(ERROR_LOG.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            newErrors
+        }"#;
+
+    let expected = json!({
+            "newErrors": ["RxStatusEvent(1, 19, 1): No Valid Position Calculated"]
+    });
+
+    test!(service, query, expected);
+
+    let expected = json!({
+            "newErrors": []
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn query_new_errors_only_new() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(LOG_VERSION_COMMAND.to_vec());
+
+    mock.read.set_output(ERROR_LOG.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            newErrors
+        }"#;
+
+    request!(service, query);
+
+    let noop = r#"mutation {
+            noop {
+                success
+            }
+        }"#;
+
+    request!(service, noop);
+
+    let expected = json!({
+            "newErrors": ["Noop: Failed to receive version info - timed out waiting on channel"]
+    });
+
+    test!(service, query, expected);
+
+    // The full set is still available
+    let query = r#"{
+            errors
+        }"#;
+
+    let expected = json!({
+            "errors": ["RxStatusEvent(1, 19, 1): No Valid Position Calculated", "Noop: Failed to receive version info - timed out waiting on channel"]
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn query_new_errors_after_errors_cleared() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(ERROR_LOG.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            errors
+        }"#;
+
+    request!(service, query);
+
+    // Clearing the master list doesn't hide errors which haven't been polled yet
+    let query = r#"{
+            newErrors
+        }"#;
+
+    let expected = json!({
+            "newErrors": ["RxStatusEvent(1, 19, 1): No Valid Position Calculated"]
+    });
+
+    test!(service, query, expected);
+}
@@ -0,0 +1,112 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+
+#[test]
+fn get_range_observations_default() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            rangeObservations {
+                observations {
+                    prn
+                },
+                receivedAt,
+                stale,
+                time {
+                    ms,
+                    week
+                }
+            }
+        }"#;
+
+    let expected = json!({
+            "rangeObservations": {
+                "observations": [],
+                "receivedAt": 0.0,
+                "stale": true,
+                "time": {
+                    "ms": 0,
+                    "week": 0,
+                }
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_range_observations_good() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(RANGE_LOG.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            rangeObservations {
+                observations {
+                    carrierPhase,
+                    cno,
+                    dopplerHz,
+                    lockTime,
+                    prn,
+                    pseudorange,
+                    system
+                },
+                stale,
+                time {
+                    ms,
+                    week
+                }
+            }
+        }"#;
+
+    let expected = json!({
+            "rangeObservations": {
+                "observations": [
+                    {
+                        "carrierPhase": -112170123.5,
+                        "cno": 45.5,
+                        "dopplerHz": -1234.5,
+                        "lockTime": 1234.25,
+                        "prn": 12,
+                        "pseudorange": 21345678.125,
+                        "system": "GPS"
+                    },
+                    {
+                        "carrierPhase": -123266754.25,
+                        "cno": 38.25,
+                        "dopplerHz": 2345.75,
+                        "lockTime": 567.5,
+                        "prn": 23,
+                        "pseudorange": 23456789.5,
+                        "system": "GPS"
+                    }
+                ],
+                "stale": false,
+                "time": {
+                    "ms": 164_195_000,
+                    "week": 3025
+                }
+            }
+    });
+
+    test!(service, query, expected);
+}
@@ -0,0 +1,262 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+
+#[test]
+fn get_snapshot_default() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            snapshot {
+                time {
+                    ms,
+                    week
+                }
+            }
+        }"#;
+
+    let expected = json!({ "snapshot": null });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_snapshot_good() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(POSITION_LOG_GOOD.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            snapshot {
+                lockInfo {
+                    position,
+                    time {
+                        ms,
+                        week
+                    },
+                    velocity
+                },
+                lockStatus {
+                    positionStatus,
+                    time {
+                        ms,
+                        week
+                    },
+                    velocityStatus
+                },
+                systemStatus {
+                    errors,
+                    status
+                },
+                time {
+                    ms,
+                    week
+                }
+            }
+        }"#;
+
+    let expected = json!({
+            "snapshot": {
+                "lockInfo": {
+                    "position": [1.1, 2.2, 3.3],
+                    "time": {
+                        "ms": 164_195_000,
+                        "week": 3025
+                    },
+                    "velocity": [4.4, 5.5, 6.6],
+                },
+                "lockStatus": {
+                    "positionStatus": "SOL_COMPUTED",
+                    "time": {
+                        "ms": 164_195_000,
+                        "week": 3025
+                    },
+                    "velocityStatus": "SOL_COMPUTED"
+                },
+                "systemStatus": {
+                    "errors": [],
+                    "status": ["POSITION_SOLUTION_INVALID", "CLOCK_MODEL_INVALID"]
+                },
+                "time": {
+                    "ms": 164_195_000,
+                    "week": 3025
+                }
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_snapshot_no_lock_after_good() {
+    let mut mock = MockStream::default();
+
+    let mut output = POSITION_LOG_GOOD.to_vec();
+    output.extend_from_slice(&POSITION_LOG_NO_LOCK);
+    mock.read.set_output(output);
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            snapshot {
+                lockInfo {
+                    position
+                },
+                lockStatus {
+                    positionStatus,
+                    time {
+                        ms,
+                        week
+                    }
+                },
+                time {
+                    ms,
+                    week
+                }
+            }
+        }"#;
+
+    // The last known good lock information is from a previous epoch,
+    // so it shouldn't be included in the snapshot
+    let expected = json!({
+            "snapshot": {
+                "lockInfo": null,
+                "lockStatus": {
+                    "positionStatus": "INSUFFICIENT_OBSERVATIONS",
+                    "time": {
+                        "ms": 164_195_000,
+                        "week": 3025
+                    }
+                },
+                "time": {
+                    "ms": 164_195_000,
+                    "week": 3025
+                }
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_snapshot_dop() {
+    let mut mock = MockStream::default();
+
+    let mut output = DOP_LOG.to_vec();
+    output.extend_from_slice(&POSITION_LOG_GOOD);
+    mock.read.set_output(output);
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            snapshot {
+                dopInfo {
+                    hdop,
+                    pdop
+                }
+            }
+        }"#;
+
+    let expected = json!({
+            "snapshot": {
+                "dopInfo": {
+                    "hdop": 1.25,
+                    "pdop": 2.0
+                }
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_snapshot_dop_after_position() {
+    let mut mock = MockStream::default();
+
+    let mut output = POSITION_LOG_GOOD.to_vec();
+    output.extend_from_slice(&DOP_LOG);
+    mock.read.set_output(output);
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            snapshot {
+                dopInfo {
+                    pdop,
+                    time {
+                        ms,
+                        week
+                    }
+                }
+            }
+        }"#;
+
+    let expected = json!({
+            "snapshot": {
+                "dopInfo": {
+                    "pdop": 2.0,
+                    "time": {
+                        "ms": 164_195_000,
+                        "week": 3025
+                    }
+                }
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_snapshot_dop_other_epoch() {
+    let mut mock = MockStream::default();
+
+    let mut output = DOP_LOG_PREVIOUS_EPOCH.to_vec();
+    output.extend_from_slice(&POSITION_LOG_GOOD);
+    mock.read.set_output(output);
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            snapshot {
+                dopInfo {
+                    pdop
+                },
+                time {
+                    ms,
+                    week
+                }
+            }
+        }"#;
+
+    // The DOP values don't describe the snapshot's epoch,
+    // so they shouldn't be included
+    let expected = json!({
+            "snapshot": {
+                "dopInfo": null,
+                "time": {
+                    "ms": 164_195_000,
+                    "week": 3025
+                }
+            }
+    });
+
+    test!(service, query, expected);
+}
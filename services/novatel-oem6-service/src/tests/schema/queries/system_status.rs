@@ -31,6 +31,8 @@ fn get_system_status_good() {
 
     let query = r#"{
             systemStatus {
+                antennaOpen,
+                antennaShort,
                 errors,
                 status
             }
@@ -38,6 +40,8 @@ fn get_system_status_good() {
 
     let expected = json!({
             "systemStatus": {
+                "antennaOpen": false,
+                "antennaShort": false,
                 "errors": [],
                 "status": ["POSITION_SOLUTION_INVALID", "CLOCK_MODEL_INVALID"]
             }
@@ -88,6 +92,8 @@ fn get_system_status_bad() {
 
     let query = r#"{
             systemStatus {
+                antennaOpen,
+                antennaShort,
                 errors,
                 status
             }
@@ -95,6 +101,8 @@ fn get_system_status_bad() {
 
     let expected = json!({
             "systemStatus": {
+                "antennaOpen": false,
+                "antennaShort": false,
                 "errors": ["System Status: Failed to receive version info - timed out waiting on channel"],
                 "status": [
                            "ERROR_PRESENT",
@@ -128,3 +136,61 @@ fn get_system_status_bad() {
 
     test!(service, query, expected);
 }
+
+#[test]
+fn get_system_status_antenna_open() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(LOG_VERSION_COMMAND.to_vec());
+
+    let mut output = LOG_RESPONSE_GOOD.to_vec();
+    output.extend_from_slice(&VERSION_LOG_ANTENNA_OPEN);
+    mock.read.set_output(output);
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            systemStatus {
+                antennaOpen,
+                antennaShort,
+                status
+            }
+        }"#;
+
+    let expected = json!({
+            "systemStatus": {
+                "antennaOpen": true,
+                "antennaShort": false,
+                "status": ["ANTENNA_OPEN", "POSITION_SOLUTION_INVALID", "CLOCK_MODEL_INVALID"]
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_system_status_flag_errors() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(LOG_VERSION_COMMAND.to_vec());
+
+    let mut output = LOG_RESPONSE_GOOD.to_vec();
+    output.extend_from_slice(&VERSION_LOG_ANTENNA_OPEN);
+    mock.read.set_output(output);
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            systemStatus {
+                errors
+            }
+        }"#;
+
+    let expected = json!({
+            "systemStatus": {
+                "errors": ["Receiver Status: Antenna open circuit. Check the antenna connection"]
+            }
+    });
+
+    test!(service, query, expected);
+}
@@ -52,6 +52,11 @@ fn get_telemetry_nominal() {
                         velocityStatus,
                         velocityType
                     },
+                    logHeader {
+                        idleTime,
+                        port,
+                        sequence
+                    },
                     systemStatus {
                         errors,
                         status
@@ -82,6 +87,11 @@ fn get_telemetry_nominal() {
                         "velocityStatus": "SOL_COMPUTED",
                         "velocityType": "PSRDIFF"
                     },
+                    "logHeader": {
+                        "idleTime": 62.0,
+                        "port": "COM1",
+                        "sequence": 0
+                    },
                     "systemStatus": {
                         "errors": [],
                         "status": ["POSITION_SOLUTION_INVALID", "CLOCK_MODEL_INVALID"]
@@ -111,12 +121,14 @@ fn get_telemetry_debug() {
                     components {
                         bootVersion,
                         compType,
+                        compTypeRaw,
                         compileDate,
                         compileTime, 
                         hwVersion,
                         model,
                         serialNum,
                         swVersion,
+                        valid,
                     },
                     numComponents
                 }
@@ -128,13 +140,55 @@ fn get_telemetry_debug() {
                 "debug": {
                     "components": [{
                         "bootVersion": "OEM060201RB0000",
-                        "compType": 1,
+                        "compType": "GPS_CARD",
+                        "compTypeRaw": 1,
                         "compileDate": "2015/Jan/28",
                         "compileTime": "15:27:29",
                         "hwVersion": "OEM615-2.00",
                         "model": "G1SB0GTT0",
                         "serialNum": "BJYA15120038H",
                         "swVersion": "OEM060600RN0000",
+                        "valid": true,
+                    }],
+                    "numComponents": 1
+                }
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_telemetry_debug_cached() {
+    let mut mock = MockStream::default();
+
+    // Only one VERSION log request is expected. Any further requests would fail
+    mock.write.set_input(LOG_VERSION_COMMAND.to_vec());
+
+    let mut output = LOG_RESPONSE_GOOD.to_vec();
+    output.extend_from_slice(&VERSION_LOG);
+    mock.read.set_output(output);
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            telemetry{
+                debug {
+                    components {
+                        model,
+                        serialNum
+                    },
+                    numComponents
+                }
+            }
+        }"#;
+
+    let expected = json!({
+            "telemetry": {
+                "debug": {
+                    "components": [{
+                        "model": "G1SB0GTT0",
+                        "serialNum": "BJYA15120038H"
                     }],
                     "numComponents": 1
                 }
@@ -142,6 +196,7 @@ fn get_telemetry_debug() {
     });
 
     test!(service, query, expected);
+    test!(service, query, expected);
 }
 
 #[test]
@@ -163,6 +218,7 @@ fn get_telemetry_both() {
                     components {
                         bootVersion,
                         compType,
+                        compTypeRaw,
                         compileDate,
                         compileTime, 
                         hwVersion,
@@ -205,7 +261,8 @@ fn get_telemetry_both() {
                 "debug": {
                     "components": [{
                         "bootVersion": "OEM060201RB0000",
-                        "compType": 1,
+                        "compType": "GPS_CARD",
+                        "compTypeRaw": 1,
                         "compileDate": "2015/Jan/28",
                         "compileTime": "15:27:29",
                         "hwVersion": "OEM615-2.00",
@@ -262,6 +319,7 @@ fn get_telemetry_empty() {
                     components {
                         bootVersion,
                         compType,
+                        compTypeRaw,
                         compileDate,
                         compileTime, 
                         hwVersion,
@@ -291,6 +349,9 @@ fn get_telemetry_empty() {
                         velocityStatus,
                         velocityType
                     },
+                    logHeader {
+                        sequence
+                    },
                     systemStatus {
                         errors,
                         status
@@ -322,6 +383,7 @@ fn get_telemetry_empty() {
                         "velocityStatus": "INSUFFICIENT_OBSERVATIONS",
                         "velocityType": "NONE"
                     },
+                    "logHeader": null,
                     "systemStatus": {
                         "errors": ["Get Telemetry: Failed to receive version info - timed out waiting on channel"],
                         "status": [
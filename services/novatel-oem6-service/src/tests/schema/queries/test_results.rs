@@ -37,6 +37,7 @@ fn test_results_good() {
                     components {
                         bootVersion,
                         compType,
+                        compTypeRaw,
                         compileDate,
                         compileTime, 
                         hwVersion,
@@ -81,7 +82,8 @@ fn test_results_good() {
                 "telemetryDebug": {
                     "components": [{
                         "bootVersion": "OEM060201RB0000",
-                        "compType": 1,
+                        "compType": "GPS_CARD",
+                        "compTypeRaw": 1,
                         "compileDate": "2015/Jan/28",
                         "compileTime": "15:27:29",
                         "hwVersion": "OEM615-2.00",
@@ -138,6 +140,7 @@ fn test_results_no_response() {
                     components {
                         bootVersion,
                         compType,
+                        compTypeRaw,
                         compileDate,
                         compileTime, 
                         hwVersion,
@@ -255,6 +258,7 @@ fn test_results_no_log() {
                     components {
                         bootVersion,
                         compType,
+                        compTypeRaw,
                         compileDate,
                         compileTime, 
                         hwVersion,
@@ -0,0 +1,97 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+
+#[test]
+fn get_tracking_status_default() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            trackingStatus {
+                channels {
+                    channel
+                },
+                receivedAt,
+                stale,
+                time {
+                    ms,
+                    week
+                }
+            }
+        }"#;
+
+    let expected = json!({
+            "trackingStatus": {
+                "channels": [],
+                "receivedAt": 0.0,
+                "stale": true,
+                "time": {
+                    "ms": 0,
+                    "week": 0,
+                }
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_tracking_status_channels() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(TRACKSTAT_LOG.to_vec());
+
+    let service = service_new!(mock);
+
+    // The fifth channel is idle, so it isn't reported
+    let query = r#"{
+            trackingStatus {
+                channels {
+                    channel,
+                    prn,
+                    system,
+                    cno,
+                    state
+                },
+                stale,
+                time {
+                    ms,
+                    week
+                }
+            }
+        }"#;
+
+    let expected = json!({
+            "trackingStatus": {
+                "channels": [
+                    {"channel": 0, "prn": 12, "system": "GPS", "cno": 45.5, "state": "PHASE_LOCK_LOOP"},
+                    {"channel": 1, "prn": 12, "system": "GPS", "cno": 38.0, "state": "PHASE_LOCK_LOOP"},
+                    {"channel": 2, "prn": 45, "system": "GLONASS", "cno": 42.25, "state": "PHASE_LOCK_LOOP"},
+                    {"channel": 3, "prn": 11, "system": "GALILEO", "cno": 40.5, "state": "PHASE_LOCK_LOOP"}
+                ],
+                "stale": false,
+                "time": {
+                    "ms": 164_195_000,
+                    "week": 3025
+                }
+            }
+    });
+
+    test!(service, query, expected);
+}
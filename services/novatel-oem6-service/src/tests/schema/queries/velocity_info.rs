@@ -0,0 +1,101 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::*;
+
+#[test]
+fn get_velocity_info_default() {
+    let mut mock = MockStream::default();
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            velocityInfo {
+                horizontalSpeed,
+                stale,
+                time {
+                    ms,
+                    week
+                },
+                velocityStatus,
+                velocityType
+            }
+        }"#;
+
+    let expected = json!({
+            "velocityInfo": {
+                "horizontalSpeed": 0.0,
+                "stale": true,
+                "time": {
+                    "ms": 0,
+                    "week": 0,
+                },
+                "velocityStatus": "INSUFFICIENT_OBSERVATIONS",
+                "velocityType": "NONE"
+            }
+    });
+
+    test!(service, query, expected);
+}
+
+#[test]
+fn get_velocity_info_good() {
+    let mut mock = MockStream::default();
+
+    mock.read.set_output(VELOCITY_LOG.to_vec());
+
+    let service = service_new!(mock);
+
+    let query = r#"{
+            velocityInfo {
+                differentialAge,
+                horizontalSpeed,
+                latency,
+                stale,
+                time {
+                    ms,
+                    week
+                },
+                track,
+                velocityStatus,
+                velocityStatusRaw,
+                velocityType,
+                velocityTypeRaw,
+                verticalSpeed
+            }
+        }"#;
+
+    let expected = json!({
+            "velocityInfo": {
+                "differentialAge": 1.0,
+                "horizontalSpeed": 1.5,
+                "latency": 0.25,
+                "stale": false,
+                "time": {
+                    "ms": 164_195_000,
+                    "week": 3025
+                },
+                "track": 90.0,
+                "velocityStatus": "SOL_COMPUTED",
+                "velocityStatusRaw": 0,
+                "velocityType": "DOPPLER_VELOCITY",
+                "velocityTypeRaw": 8,
+                "verticalSpeed": -0.5
+            }
+    });
+
+    test!(service, query, expected);
+}
@@ -21,6 +21,37 @@ pub const LOG_RESPONSE_GOOD: [u8; 38] = [
     0x10, 0x9D, 0x33, 0xB1,
 ];
 
+// LOG PSRDOPB ONTIME 1
+pub const LOG_DOP_COMMAND: [u8; 64] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0xAE, 0x0, 0x0, 0x0,
+    0x2, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xF0, 0x3F, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x92, 0xD9, 0x94, 0x10,
+];
+
+// LOG TIMEB ONTIME 1 HOLD
+pub const LOG_TIME_COMMAND: [u8; 64] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0x65, 0x0, 0x0, 0x0,
+    0x2, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xF0, 0x3F, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0xF8, 0x98, 0xD8, 0x10,
+];
+
+// LOG COM2 TIMEB ONTIME 1 HOLD
+pub const LOG_TIME_COM2_COMMAND: [u8; 64] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x40, 0x0, 0x0, 0x0, 0x65, 0x0, 0x0, 0x0,
+    0x2, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xF0, 0x3F, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x8E, 0xFE, 0xF, 0x5F,
+];
+
+// UNLOG TIMEB
+pub const UNLOG_TIME_COMMAND: [u8; 40] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x24, 0x0, 0x0, 0xC0, 0x8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0x65, 0x0, 0x0, 0x0,
+    0xF1, 0xE0, 0x92, 0xB9,
+];
+
 // Mock good response to an UNLOG command
 pub const UNLOG_RESPONSE_GOOD: [u8; 38] = [
     0xAA, 0x44, 0x12, 0x1C, 0x24, 0x0, 0x80, 0x20, 0x6, 0x0, 0x0, 0x0, 0xFF, 0x78, 0xD1, 0xB, 0x85,
@@ -35,6 +66,49 @@ pub const UNLOG_ALL_RESPONSE_GOOD: [u8; 38] = [
     0x83, 0x1, 0x39, 0x5C,
 ];
 
+pub const RESET_COMMAND: [u8; 36] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x12, 0x0, 0x0, 0xC0, 0x4, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xC6, 0xC7, 0x4D, 0x2F,
+];
+
+pub const RESET_RESPONSE_GOOD: [u8; 38] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x12, 0x0, 0x80, 0x20, 0x6, 0x0, 0x0, 0x0, 0xFF, 0x78, 0xD1, 0xB, 0x6,
+    0x67, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0xFB, 0xFD, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x4F, 0x4B, 0xA,
+    0x84, 0x7E, 0x85,
+];
+
+pub const FRESET_EPHEMERIS_COMMAND: [u8; 36] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x14, 0x0, 0x0, 0xC0, 0x4, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x3, 0x0, 0x0, 0x0, 0x66, 0x66, 0x44, 0x38,
+];
+
+pub const FRESET_ALMANAC_COMMAND: [u8; 36] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x14, 0x0, 0x0, 0xC0, 0x4, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x2, 0x0, 0x0, 0x0, 0x3, 0x1, 0xF8, 0x80,
+];
+
+pub const FRESET_STANDARD_COMMAND: [u8; 36] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x14, 0x0, 0x0, 0xC0, 0x4, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x88, 0xC9, 0xF1, 0x2A,
+];
+
+pub const FRESET_RESPONSE_GOOD: [u8; 38] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x14, 0x0, 0x80, 0x20, 0x6, 0x0, 0x0, 0x0, 0xFF, 0x78, 0xD1, 0xB, 0x6,
+    0x67, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0xFB, 0xFD, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x4F, 0x4B,
+    0x3A, 0xCE, 0x3C, 0xC,
+];
+
+pub const SAVE_CONFIG_COMMAND: [u8; 32] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x13, 0x0, 0x0, 0xC0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xD0, 0x17, 0xF9, 0xF0,
+];
+
+pub const SAVE_CONFIG_RESPONSE_GOOD: [u8; 38] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x13, 0x0, 0x80, 0x20, 0x6, 0x0, 0x0, 0x0, 0xFF, 0x78, 0xD1, 0xB, 0x6,
+    0x67, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0xFB, 0xFD, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x4F, 0x4B, 0x2,
+    0x67, 0x1E, 0xB9,
+];
+
 pub const LOG_VERSION_COMMAND: [u8; 64] = [
     0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
     0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0x25, 0x0, 0x0, 0x0,
@@ -42,6 +116,48 @@ pub const LOG_VERSION_COMMAND: [u8; 64] = [
     0x0, 0x0, 0x0, 0x0, 0x0, 0x30, 0x8E, 0x33, 0x3C,
 ];
 
+pub const LOG_RXCONFIG_COMMAND: [u8; 64] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0x80, 0x0, 0x0, 0x0,
+    0x4, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x79, 0xAA, 0x10, 0xD7,
+];
+
+pub const RXCONFIG_LOG_BESTXYZ: [u8; 96] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x80, 0x0, 0x0, 0x20, 0x40, 0x0, 0x0, 0x0, 0x7C, 0x78, 0xD1, 0xB, 0xB8,
+    0x6A, 0xC9, 0x9, 0x0, 0x0, 0x48, 0x0, 0x0, 0x0, 0xFA, 0x33, 0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0,
+    0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0xF1, 0x0, 0x0, 0x0, 0x2, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0xF0, 0x3F, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0xB9, 0x54, 0x12, 0x6F, 0xF2, 0x83, 0x5A, 0x43,
+];
+
+pub const RXCONFIG_LOG_ERRORS: [u8; 96] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x80, 0x0, 0x0, 0x20, 0x40, 0x0, 0x0, 0x0, 0x7C, 0x78, 0xD1, 0xB, 0xB8,
+    0x6A, 0xC9, 0x9, 0x0, 0x0, 0x48, 0x0, 0x0, 0x0, 0xFA, 0x33, 0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0,
+    0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0x5E, 0x0, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0xD2,
+    0xC8, 0x42, 0x23, 0xF2, 0x83, 0x5A, 0x43,
+];
+
+pub const RXCONFIG_LOG_TIME_COM2: [u8; 96] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x80, 0x0, 0x0, 0x20, 0x40, 0x0, 0x0, 0x0, 0x7C, 0x78, 0xD1, 0xB, 0xB8,
+    0x6A, 0xC9, 0x9, 0x0, 0x0, 0x48, 0x0, 0x0, 0x0, 0xFA, 0x33, 0xAA, 0x44, 0x12, 0x1C, 0x1, 0x0,
+    0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x40, 0x0, 0x0, 0x0, 0x65, 0x0, 0x0, 0x0, 0x2, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0xF0, 0x3F, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0,
+    0x8E, 0xFE, 0xF, 0x5F, 0xF2, 0x83, 0x5A, 0x43,
+];
+
+pub const RXCONFIG_UNLOG: [u8; 72] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x80, 0x0, 0x0, 0x20, 0x28, 0x0, 0x0, 0x0, 0x7C, 0x78, 0xD1, 0xB, 0xB8,
+    0x6A, 0xC9, 0x9, 0x0, 0x0, 0x48, 0x0, 0x0, 0x0, 0xFA, 0x33, 0xAA, 0x44, 0x12, 0x1C, 0x24, 0x0,
+    0x0, 0xC0, 0x8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0xAE, 0x0, 0x0, 0x0, 0xF6, 0x89, 0xDE, 0x18, 0x64, 0x24,
+    0x9A, 0x10,
+];
+
 pub const VERSION_LOG: [u8; 144] = [
     0xAA, 0x44, 0x12, 0x1C, 0x25, 0x0, 0x0, 0x20, 0x70, 0x0, 0x0, 0x0, 0x7D, 0x78, 0xD1, 0xB, 0x38,
     0x5E, 0xC9, 0x9, 0x0, 0x0, 0x48, 0x0, 0x81, 0x36, 0xFA, 0x33, 0x1, 0x0, 0x0, 0x0, 0x1, 0x0,
@@ -54,6 +170,18 @@ pub const VERSION_LOG: [u8; 144] = [
     0x37, 0x3A, 0x32, 0x39, 0x0, 0x0, 0x0, 0x0, 0xC6, 0x5E, 0x86, 0x47,
 ];
 
+pub const VERSION_LOG_ANTENNA_OPEN: [u8; 144] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x25, 0x0, 0x0, 0x20, 0x70, 0x0, 0x0, 0x0, 0x7D, 0x78, 0xD1, 0xB, 0x38,
+    0x5E, 0xC9, 0x9, 0x20, 0x0, 0x48, 0x0, 0x81, 0x36, 0xFA, 0x33, 0x1, 0x0, 0x0, 0x0, 0x1, 0x0,
+    0x0, 0x0, 0x47, 0x31, 0x53, 0x42, 0x30, 0x47, 0x54, 0x54, 0x30, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x42, 0x4A, 0x59, 0x41, 0x31, 0x35, 0x31, 0x32, 0x30, 0x30, 0x33, 0x38, 0x48, 0x0, 0x0,
+    0x0, 0x4F, 0x45, 0x4D, 0x36, 0x31, 0x35, 0x2D, 0x32, 0x2E, 0x30, 0x30, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x4F, 0x45, 0x4D, 0x30, 0x36, 0x30, 0x36, 0x30, 0x30, 0x52, 0x4E, 0x30, 0x30, 0x30, 0x30, 0x0,
+    0x4F, 0x45, 0x4D, 0x30, 0x36, 0x30, 0x32, 0x30, 0x31, 0x52, 0x42, 0x30, 0x30, 0x30, 0x30, 0x0,
+    0x32, 0x30, 0x31, 0x35, 0x2F, 0x4A, 0x61, 0x6E, 0x2F, 0x32, 0x38, 0x0, 0x31, 0x35, 0x3A, 0x32,
+    0x37, 0x3A, 0x32, 0x39, 0x0, 0x0, 0x0, 0x0, 0xBF, 0xD6, 0xB2, 0xD1,
+];
+
 pub const ERROR_LOG: [u8; 76] = [
     0xAA, 0x44, 0x12, 0x1C, 0x5E, 0x00, 0x00, 0x20, 0x2C, 0x00, 0x00, 0x00, 0x34, 0x82, 0xAE, 0xB0,
     0x56, 0x18, 0x00, 0x00, 0x48, 0x00, 0x67, 0xB9, 0x00, 0x00, 0xC0, 0x07, 0x01, 0x00, 0x00, 0x00,
@@ -84,3 +212,221 @@ pub const POSITION_LOG_GOOD: [u8; 144] = [
     0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
     0x0, 0x4A, 0xF9, 0x0D, 0x23,
 ];
+
+// Mock ASCII BestXYZ log with a computed PSRDIFF position
+pub const POSITION_LOG_ASCII: &[u8] =
+    b"#BESTXYZA,COM1,0,62.0,FINESTEERING,3025,164195.000,00480000,d821,13307;\
+    SOL_COMPUTED,PSRDIFF,-1634531.5683,-3664618.0326,4942496.3270,0.0099,0.0219,0.0115,\
+    SOL_COMPUTED,PSRDIFF,0.0011,-0.0049,-0.0001,0.0199,0.0439,0.0230,\
+    \"AAAA\",0.250,1.000,0.000,12,11,11,11,0,01,0,33*615c1f1c\r\n";
+
+pub const POSITION_LOG_DIFFERENTIAL: [u8; 144] = [
+    0xAA, 0x44, 0x12, 0x1C, 0xF1, 0x0, 0x0, 0x20, 0x70, 0x0, 0x0, 0x0, 0x7C, 0xB4, 0xD1, 0xB, 0xB8,
+    0x6A, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xFA, 0x33, 0x0, 0x0, 0x0, 0x0, 0x11, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x80, 0xE3, 0xF0, 0x38, 0xC1, 0x0, 0x0, 0x0, 0x20, 0x75, 0xF5, 0x4B, 0xC1,
+    0x0, 0x0, 0x0, 0x30, 0xA8, 0xDA, 0x52, 0x41, 0x0, 0x0, 0x0, 0x3F, 0x0, 0x0, 0x40, 0x3F, 0x0,
+    0x0, 0x80, 0x3F, 0x0, 0x0, 0x0, 0x0, 0x11, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xF8,
+    0x3F, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x2, 0xC0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xE0, 0x3F, 0x0,
+    0x0, 0x0, 0x3E, 0x0, 0x0, 0x0, 0x3E, 0x0, 0x0, 0x80, 0x3E, 0x31, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x90, 0x40, 0x0, 0x0, 0x0, 0x0, 0xA, 0x8, 0x8, 0x8, 0x0, 0x0, 0x0, 0x3, 0x2C,
+    0xBB, 0x48, 0x17,
+];
+
+pub const POSITION_LOG_UNKNOWN: [u8; 144] = [
+    0xAA, 0x44, 0x12, 0x1C, 0xF1, 0x0, 0x0, 0x20, 0x70, 0x0, 0x0, 0x0, 0x7C, 0xD2, 0xD1, 0xB, 0xB8,
+    0x6A, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xFA, 0x33, 0x15, 0x0, 0x0, 0x0, 0x63, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x15,
+    0x0, 0x0, 0x0, 0x63, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xE5, 0x6F, 0x97, 0x47,
+];
+
+pub const TIME_LOG: [u8; 76] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x65, 0x0, 0x0, 0x20, 0x2C, 0x0, 0x0, 0x0, 0x7C, 0x78, 0xD1, 0xB, 0xB8,
+    0x6A, 0xC9, 0x9, 0x0, 0x0, 0x48, 0x0, 0x0, 0x0, 0xFA, 0x33, 0x0, 0x0, 0x0, 0x0, 0x2B, 0x69,
+    0xA4, 0x29, 0x2B, 0x1B, 0x50, 0xBE, 0x3A, 0x8C, 0x30, 0xE2, 0x8E, 0x79, 0x25, 0x3E, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x32, 0xC0, 0xE2, 0x7, 0x0, 0x0, 0xA, 0xF, 0xC, 0x1E, 0x98, 0x3A, 0x0, 0x0,
+    0x1, 0x0, 0x0, 0x0, 0x11, 0x30, 0xCE, 0x33,
+];
+
+pub const VELOCITY_LOG: [u8; 76] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x63, 0x0, 0x0, 0x20, 0x2C, 0x0, 0x0, 0x0, 0x7C, 0x78, 0xD1, 0xB, 0xB8,
+    0x6A, 0xC9, 0x9, 0x0, 0x0, 0x48, 0x0, 0x0, 0x0, 0xFA, 0x33, 0x0, 0x0, 0x0, 0x0, 0x8, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x80, 0x3E, 0x0, 0x0, 0x80, 0x3F, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xF8, 0x3F, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x80, 0x56, 0x40, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xE0, 0xBF, 0x0, 0x0, 0x0,
+    0x0, 0x31, 0x4, 0xA4, 0x68,
+];
+
+pub const DOP_LOG: [u8; 72] = [
+    0xAA, 0x44, 0x12, 0x1C, 0xAE, 0x0, 0x0, 0x20, 0x28, 0x0, 0x0, 0x0, 0x7C, 0x78, 0xD1, 0xB, 0xB8,
+    0x6A, 0xC9, 0x9, 0x0, 0x0, 0x48, 0x0, 0x0, 0x0, 0xFA, 0x33, 0x0, 0x0, 0x20, 0x40, 0x0, 0x0,
+    0x0, 0x40, 0x0, 0x0, 0xA0, 0x3F, 0x0, 0x0, 0xC0, 0x3F, 0x0, 0x0, 0x80, 0x3F, 0x0, 0x0, 0xA0,
+    0x40, 0x3, 0x0, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0xC, 0x0, 0x0, 0x0, 0x17, 0x0, 0x0, 0x0, 0x83,
+    0xE4, 0xBA, 0xEA,
+];
+
+// Mock PsrDop log from the epoch before POSITION_LOG_GOOD
+pub const DOP_LOG_PREVIOUS_EPOCH: [u8; 72] = [
+    0xAA, 0x44, 0x12, 0x1C, 0xAE, 0x0, 0x0, 0x20, 0x28, 0x0, 0x0, 0x0, 0x7C, 0x78, 0xD1, 0xB, 0xD0,
+    0x66, 0xC9, 0x9, 0x0, 0x0, 0x48, 0x0, 0x0, 0x0, 0xFA, 0x33, 0x0, 0x0, 0x20, 0x40, 0x0, 0x0,
+    0x0, 0x40, 0x0, 0x0, 0xA0, 0x3F, 0x0, 0x0, 0xC0, 0x3F, 0x0, 0x0, 0x80, 0x3F, 0x0, 0x0, 0xA0,
+    0x40, 0x3, 0x0, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0xC, 0x0, 0x0, 0x0, 0x17, 0x0, 0x0, 0x0, 0x4,
+    0x88, 0x7A, 0x96,
+];
+
+pub const HW_MONITOR_LOG: [u8; 68] = [
+    0xAA, 0x44, 0x12, 0x1C, 0xC3, 0x3, 0x0, 0x20, 0x24, 0x0, 0x0, 0x0, 0x0, 0x78, 0xD1, 0xB, 0xB8,
+    0x6A, 0xC9, 0x9, 0x0, 0x0, 0x48, 0x0, 0x0, 0x0, 0x0, 0x0, 0x4, 0x0, 0x0, 0x0, 0x0, 0x0, 0x2A,
+    0x42, 0x0, 0x1, 0x0, 0x0, 0x0, 0x0, 0x80, 0x3D, 0x0, 0x2, 0x0, 0x0, 0x0, 0x0, 0xA0, 0x3F, 0x0,
+    0x6, 0x0, 0x0, 0x0, 0x0, 0xA0, 0x40, 0x0, 0x7, 0x0, 0x0, 0x3B, 0x1A, 0x26, 0x9C,
+];
+
+pub const HW_MONITOR_LOG_TEMPERATURE_ONLY: [u8; 44] = [
+    0xAA, 0x44, 0x12, 0x1C, 0xC3, 0x3, 0x0, 0x20, 0xC, 0x0, 0x0, 0x0, 0x0, 0x78, 0xD1, 0xB, 0xB8,
+    0x6A, 0xC9, 0x9, 0x0, 0x0, 0x48, 0x0, 0x0, 0x0, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x2A,
+    0x42, 0x0, 0x1, 0x0, 0x0, 0x2C, 0x38, 0x62, 0x5A,
+];
+
+pub const RANGE_LOG: [u8; 124] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x2B, 0x0, 0x0, 0x20, 0x5C, 0x0, 0x0, 0x0, 0x0, 0x78, 0xD1, 0xB, 0xB8,
+    0x6A, 0xC9, 0x9, 0x0, 0x0, 0x48, 0x0, 0x0, 0x0, 0x0, 0x0, 0x2, 0x0, 0x0, 0x0, 0xC, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0xE2, 0x58, 0x5B, 0x74, 0x41, 0x0, 0x0, 0x80, 0x3E, 0x0, 0x0, 0x0, 0x2E,
+    0x52, 0xBE, 0x9A, 0xC1, 0x0, 0x0, 0x0, 0x3C, 0x0, 0x50, 0x9A, 0xC4, 0x0, 0x0, 0x36, 0x42, 0x0,
+    0x48, 0x9A, 0x44, 0x4, 0x9C, 0x10, 0x8, 0x17, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x58, 0xC1, 0x5E,
+    0x76, 0x41, 0x0, 0x0, 0x0, 0x3F, 0x0, 0x0, 0x0, 0x9, 0x9B, 0x63, 0x9D, 0xC1, 0x0, 0x0, 0x80,
+    0x3C, 0x0, 0x9C, 0x12, 0x45, 0x0, 0x0, 0x19, 0x42, 0x0, 0xE0, 0xD, 0x44, 0x24, 0x9C, 0x10,
+    0x18, 0xA, 0x7F, 0x32, 0xD,
+];
+
+pub const TRACKSTAT_LOG: [u8; 248] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x53, 0x0, 0x0, 0x20, 0xD8, 0x0, 0x0, 0x0, 0x0, 0x78, 0xD1, 0xB, 0xB8,
+    0x6A, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x10, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0xA0, 0x40, 0x5, 0x0, 0x0, 0x0, 0xC, 0x0, 0x0, 0x0, 0x4, 0x9C, 0x10, 0x8, 0x0,
+    0x0, 0x0, 0xE2, 0x58, 0x5B, 0x74, 0x41, 0x0, 0x50, 0x9A, 0xC4, 0x0, 0x0, 0x36, 0x42, 0x0, 0x48,
+    0x9A, 0x44, 0x0, 0x0, 0xC0, 0x3F, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x80, 0x3E, 0xC, 0x0, 0x0, 0x0,
+    0x24, 0x9C, 0x30, 0x1, 0x0, 0x0, 0x0, 0x8, 0x59, 0x5B, 0x74, 0x41, 0x0, 0x50, 0x70, 0xC4, 0x0,
+    0x0, 0x18, 0x42, 0x0, 0x0, 0x96, 0x44, 0x0, 0x0, 0x0, 0x0, 0x11, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x2D, 0x0, 0x8, 0x0, 0x44, 0x9C, 0x11, 0x8, 0x0, 0x0, 0x0, 0xF4, 0xAB, 0xF4, 0x72, 0x41,
+    0x0, 0x28, 0xA, 0x45, 0x0, 0x0, 0x29, 0x42, 0x0, 0xC0, 0xA0, 0x43, 0x0, 0x0, 0x20, 0xC0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x3E, 0xB, 0x0, 0x0, 0x0, 0x64, 0x9C, 0x13, 0x8, 0x0, 0x0, 0x0,
+    0x2C, 0x5, 0x6E, 0x77, 0x41, 0x0, 0x0, 0x0, 0x44, 0x0, 0x0, 0x22, 0x42, 0x0, 0x0, 0xB0, 0x42,
+    0x0, 0x0, 0x0, 0x0, 0x9, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xC, 0x64, 0xE4, 0x18,
+];
+
+pub const MARK1_TIME_LOG: [u8; 72] = [
+    0xAA, 0x44, 0x12, 0x1C, 0xE7, 0x0, 0x0, 0x20, 0x2C, 0x0, 0x0, 0x0, 0x48, 0xB4, 0xD1, 0x7, 0x9D,
+    0x40, 0x96, 0x18, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xFA, 0x33, 0xD1, 0x7, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x80, 0x50, 0x2D, 0x19, 0x41, 0xDF, 0x41, 0x3A, 0xDC, 0x11, 0xC5, 0x19, 0xBE, 0x4, 0x54, 0xEF,
+    0xD7, 0xB7, 0xDC, 0xEC, 0x3D, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x32, 0xC0, 0x0, 0x0, 0x0, 0x0,
+    0x7F, 0x82, 0x90, 0x98,
+];
+
+pub const MARK2_TIME_LOG: [u8; 72] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x68, 0x2, 0x0, 0x20, 0x2C, 0x0, 0x0, 0x0, 0x48, 0xB4, 0xD1, 0x7, 0xFC,
+    0x45, 0x96, 0x18, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xFA, 0x33, 0xD1, 0x7, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x56, 0x2D, 0x19, 0x41, 0xDF, 0x41, 0x3A, 0xDC, 0x11, 0xC5, 0x19, 0xBE, 0x4, 0x54, 0xEF,
+    0xD7, 0xB7, 0xDC, 0xEC, 0x3D, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x32, 0xC0, 0x0, 0x0, 0x0, 0x0,
+    0x2A, 0x4E, 0x1E, 0x59,
+];
+
+pub const ECUTOFF_COMMAND: [u8; 36] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x32, 0x0, 0x0, 0xC0, 0x4, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x41, 0xC2, 0x31, 0xAD, 0x8D,
+];
+
+pub const ECUTOFF_RESPONSE_GOOD: [u8; 38] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x32, 0x0, 0x80, 0x20, 0x6, 0x0, 0x0, 0x0, 0xFF, 0x78, 0xD1, 0xB, 0x6,
+    0x67, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0xFB, 0xFD, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x4F, 0x4B, 0xE,
+    0xFC, 0xA6, 0x64,
+];
+
+pub const SETAPPROXPOS_COMMAND: [u8; 56] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x79, 0x1, 0x0, 0xC0, 0x18, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x2, 0x2B, 0x87, 0x16, 0xD9, 0x8E, 0x49,
+    0x40, 0xDF, 0x4F, 0x8D, 0x97, 0x6E, 0x82, 0x5C, 0xC0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x60, 0x90,
+    0x40, 0x58, 0xA, 0xFD, 0xAB,
+];
+
+pub const SETAPPROXPOS_RESPONSE_GOOD: [u8; 38] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x79, 0x1, 0x80, 0x20, 0x6, 0x0, 0x0, 0x0, 0xFF, 0x78, 0xD1, 0xB, 0x6,
+    0x67, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0xFB, 0xFD, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x4F, 0x4B,
+    0x7A, 0xE8, 0xB7, 0x63,
+];
+
+pub const SETAPPROXTIME_COMMAND: [u8; 44] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x66, 0x0, 0x0, 0xC0, 0xC, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x8A, 0x7, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x74, 0x29, 0x1A, 0x41, 0x3D, 0x54, 0xC9, 0x94,
+];
+
+pub const SETAPPROXTIME_RESPONSE_GOOD: [u8; 38] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x66, 0x0, 0x80, 0x20, 0x6, 0x0, 0x0, 0x0, 0xFF, 0x78, 0xD1, 0xB, 0x6,
+    0x67, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0xFB, 0xFD, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x4F, 0x4B,
+    0x65, 0xBA, 0x8, 0xFD,
+];
+
+pub const UNLOCKOUT_GPS_COMMAND: [u8; 36] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x8C, 0x3, 0x0, 0xC0, 0x4, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xF3, 0x53, 0x6B, 0x55,
+];
+
+pub const UNLOCKOUT_GLONASS_COMMAND: [u8; 36] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x8C, 0x3, 0x0, 0xC0, 0x4, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x96, 0x34, 0xD7, 0xED,
+];
+
+pub const LOCKOUT_SBAS_COMMAND: [u8; 36] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x67, 0x3, 0x0, 0xC0, 0x4, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x2, 0x0, 0x0, 0x0, 0xD7, 0x1E, 0x2D, 0x71,
+];
+
+pub const LOCKOUT_GALILEO_COMMAND: [u8; 36] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x67, 0x3, 0x0, 0xC0, 0x4, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x5, 0x0, 0x0, 0x0, 0x6E, 0x26, 0xFA, 0xEC,
+];
+
+pub const LOCKOUT_BEIDOU_COMMAND: [u8; 36] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x67, 0x3, 0x0, 0xC0, 0x4, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x6, 0x0, 0x0, 0x0, 0x80, 0x89, 0x4F, 0xFE,
+];
+
+pub const LOCKOUT_QZSS_COMMAND: [u8; 36] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x67, 0x3, 0x0, 0xC0, 0x4, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x7, 0x0, 0x0, 0x0, 0xE5, 0xEE, 0xF3, 0x46,
+];
+
+pub const LOCKOUT_SYSTEM_RESPONSE_GOOD: [u8; 38] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x67, 0x3, 0x80, 0x20, 0x6, 0x0, 0x0, 0x0, 0xFF, 0x78, 0xD1, 0xB, 0x6,
+    0x67, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0xFB, 0xFD, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x4F, 0x4B, 0x1,
+    0x32, 0x60, 0x64,
+];
+
+pub const UNLOCKOUT_SYSTEM_RESPONSE_GOOD: [u8; 38] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x8C, 0x3, 0x80, 0x20, 0x6, 0x0, 0x0, 0x0, 0xFF, 0x78, 0xD1, 0xB, 0x6,
+    0x67, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0xFB, 0xFD, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x4F, 0x4B,
+    0xC7, 0x6B, 0x2D, 0xD0,
+];
+
+pub const COM_115200_COMMAND: [u8; 64] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x4, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x6, 0x0, 0x0, 0x0, 0x0, 0xC2, 0x1, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x8, 0x0, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x1, 0x0, 0x0, 0x0, 0xED, 0x67, 0x4B, 0xF1,
+];
+
+pub const COM_9600_COMMAND: [u8; 64] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x4, 0x0, 0x0, 0xC0, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x6, 0x0, 0x0, 0x0, 0x80, 0x25, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x8, 0x0, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x1, 0x0, 0x0, 0x0, 0xA8, 0xA4, 0x1, 0x9E,
+];
+
+pub const COM_RESPONSE_GOOD: [u8; 38] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x4, 0x0, 0x80, 0x20, 0x6, 0x0, 0x0, 0x0, 0xFF, 0x78, 0xD1, 0xB, 0x6,
+    0x67, 0xC9, 0x9, 0x0, 0x0, 0x0, 0x0, 0xFB, 0xFD, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, 0x4F, 0x4B,
+    0x38, 0xF2, 0xD0, 0x7C,
+];
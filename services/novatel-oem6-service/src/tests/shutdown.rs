@@ -0,0 +1,57 @@
+//
+// Copyright (C) 2018 Kubos Corporation
+//
+// Licensed under the Apache License, Version 2.0 (the "License")
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::schema::test_data::UNLOG_ALL_RESPONSE_GOOD;
+use super::*;
+use crate::model::*;
+use std::sync::mpsc::sync_channel;
+
+// UNLOGALL, including held logs
+const UNLOG_ALL_COMMAND: [u8; 40] = [
+    0xAA, 0x44, 0x12, 0x1C, 0x26, 0x0, 0x0, 0xC0, 0x8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20, 0x0, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0,
+    0xB0, 0x8D, 0x13, 0x35,
+];
+
+#[test]
+fn shutdown_unlogs_all() {
+    let mut mock = MockStream::default();
+
+    mock.write.set_input(UNLOG_ALL_COMMAND.to_vec());
+    mock.read.set_output(UNLOG_ALL_RESPONSE_GOOD.to_vec());
+
+    let tracker = mock.tracker();
+
+    let subsystem = subsystem_new!(mock);
+
+    assert_eq!(subsystem.shutdown(), Ok(()));
+
+    tracker.assert_consumed();
+}
+
+#[test]
+fn shutdown_fail() {
+    let mut mock = MockStream::default();
+
+    let subsystem = subsystem_new!(mock);
+
+    assert_eq!(
+        subsystem.shutdown(),
+        Err(OEMError::UartError {
+            cause: UartError::GenericError,
+        })
+    );
+}